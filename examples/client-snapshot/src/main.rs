@@ -152,6 +152,11 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                 }
                 *download_pb = None;
             }
+            MithrilEvent::SnapshotDigestComputationStarted { .. }
+            | MithrilEvent::SnapshotDigestComputationProgress { .. }
+            | MithrilEvent::SnapshotDigestComputationCompleted { .. } => {
+                // The message builder of this example has no feedback receiver
+            }
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id: _,
             } => {
@@ -3,7 +3,8 @@
 use serde::Deserialize;
 
 use mithril_common::entities::{
-    BlockNumber, CardanoDbBeacon, Epoch, SignedEntityType, SignedEntityTypeDiscriminants,
+    BlockNumber, CardanoDbBeacon, DataAttestationBeacon, Epoch, SignedEntityType,
+    SignedEntityTypeDiscriminants,
 };
 
 use crate::sqlite::HydrationError;
@@ -85,6 +86,23 @@ impl Hydrator {
                     })?;
                 SignedEntityType::CardanoTransactions(beacon.epoch, beacon.block_number)
             }
+            SignedEntityTypeDiscriminants::CardanoUtxoSet => {
+                let epoch: Epoch = serde_json::from_str(beacon_str).map_err(|e| {
+                    HydrationError::InvalidData(format!(
+                        "Invalid Epoch JSON representation '{beacon_str}. Error: {e}'."
+                    ))
+                })?;
+                SignedEntityType::CardanoUtxoSet(epoch)
+            }
+            SignedEntityTypeDiscriminants::DataAttestation => {
+                let beacon: DataAttestationBeacon =
+                    serde_json::from_str(beacon_str).map_err(|e| {
+                        HydrationError::InvalidData(format!(
+                        "Invalid Beacon JSON in open_message.beacon: '{beacon_str}'. Error: {e}"
+                    ))
+                    })?;
+                SignedEntityType::DataAttestation(beacon)
+            }
         };
 
         Ok(signed_entity)
@@ -106,4 +124,32 @@ mod tests {
 
         assert_eq!(expected, signed_entity);
     }
+
+    #[test]
+    fn hydrate_cardano_utxo_set_signed_entity_type() {
+        let expected = SignedEntityType::CardanoUtxoSet(Epoch(35));
+        let signed_entity = Hydrator::hydrate_signed_entity_type(
+            SignedEntityTypeDiscriminants::CardanoUtxoSet.index(),
+            &expected.get_json_beacon().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(expected, signed_entity);
+    }
+
+    #[test]
+    fn hydrate_data_attestation_signed_entity_type() {
+        let expected = SignedEntityType::DataAttestation(DataAttestationBeacon::new(
+            Epoch(35),
+            "db-sync-snapshot",
+            "abc123",
+        ));
+        let signed_entity = Hydrator::hydrate_signed_entity_type(
+            SignedEntityTypeDiscriminants::DataAttestation.index(),
+            &expected.get_json_beacon().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(expected, signed_entity);
+    }
 }
@@ -2,22 +2,26 @@ use anyhow::Context;
 use async_trait::async_trait;
 use semver::Version;
 use slog_scope::{debug, warn};
-use std::sync::Arc;
+use std::{fs::File, sync::Arc};
 use thiserror::Error;
 
 use crate::{
-    snapshot_uploaders::SnapshotLocation, snapshotter::OngoingSnapshot, SnapshotUploader,
-    Snapshotter,
+    snapshot_uploaders::SnapshotLocation, snapshot_verifier::SnapshotRestorabilityVerifier,
+    snapshotter::OngoingSnapshot, SnapshotUploader, Snapshotter,
 };
 
 use super::ArtifactBuilder;
 use mithril_common::{
     entities::{
         CardanoDbBeacon, Certificate, CompressionAlgorithm, ProtocolMessagePartKey, Snapshot,
+        SnapshotChunkChecksums,
     },
     StdResult,
 };
 
+/// Size of the chunks of the snapshot archive for which a checksum is published
+const SNAPSHOT_CHUNK_SIZE: u64 = 32 * 1024 * 1024;
+
 /// [CardanoImmutableFilesFullArtifact] error
 /// to fail.
 #[derive(Debug, Error)]
@@ -33,6 +37,7 @@ pub struct CardanoImmutableFilesFullArtifactBuilder {
     snapshotter: Arc<dyn Snapshotter>,
     snapshot_uploader: Arc<dyn SnapshotUploader>,
     compression_algorithm: CompressionAlgorithm,
+    restorability_verifier: Option<Arc<dyn SnapshotRestorabilityVerifier>>,
 }
 
 impl CardanoImmutableFilesFullArtifactBuilder {
@@ -48,9 +53,20 @@ impl CardanoImmutableFilesFullArtifactBuilder {
             snapshotter,
             snapshot_uploader,
             compression_algorithm,
+            restorability_verifier: None,
         }
     }
 
+    /// Set a [SnapshotRestorabilityVerifier] that checks each snapshot archive before it's
+    /// uploaded: an archive that can't be restored is never published.
+    pub fn with_restorability_verifier(
+        mut self,
+        restorability_verifier: Arc<dyn SnapshotRestorabilityVerifier>,
+    ) -> Self {
+        self.restorability_verifier = Some(restorability_verifier);
+        self
+    }
+
     async fn create_snapshot_archive(
         &self,
         beacon: &CardanoDbBeacon,
@@ -79,6 +95,62 @@ impl CardanoImmutableFilesFullArtifactBuilder {
         Ok(ongoing_snapshot)
     }
 
+    async fn verify_snapshot_restorability(
+        &self,
+        beacon: &CardanoDbBeacon,
+        ongoing_snapshot: &OngoingSnapshot,
+        snapshot_digest: &str,
+    ) -> StdResult<()> {
+        let Some(restorability_verifier) = &self.restorability_verifier else {
+            return Ok(());
+        };
+        debug!("CardanoImmutableFilesFullArtifactBuilder: verify snapshot archive restorability");
+
+        let result = restorability_verifier
+            .verify_restorability(ongoing_snapshot, beacon, snapshot_digest)
+            .await;
+        if result.is_err() {
+            if let Err(error) = tokio::fs::remove_file(ongoing_snapshot.get_file_path()).await {
+                warn!(
+                    " > Post restorability verification failure ongoing snapshot file removal failure: {}",
+                    error
+                );
+            }
+        }
+
+        result
+    }
+
+    async fn compute_snapshot_chunk_checksums(
+        &self,
+        ongoing_snapshot: &OngoingSnapshot,
+    ) -> Option<SnapshotChunkChecksums> {
+        debug!(
+            "CardanoImmutableFilesFullArtifactBuilder: compute snapshot archive chunk checksums"
+        );
+        let file_path = ongoing_snapshot.get_file_path().to_path_buf();
+
+        // spawn a separate thread to prevent blocking
+        let chunk_checksums = tokio::task::spawn_blocking(move || {
+            let file = File::open(&file_path)
+                .with_context(|| format!("Can not open snapshot archive: '{file_path:?}'"))?;
+            SnapshotChunkChecksums::compute(file, SNAPSHOT_CHUNK_SIZE)
+                .with_context(|| format!("Can not read snapshot archive: '{file_path:?}'"))
+        })
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|result| result);
+
+        match chunk_checksums {
+            Ok(chunk_checksums) => Some(chunk_checksums),
+            Err(error) => {
+                // The checksums are optional: clients fallback on the whole archive verification
+                warn!(" > Snapshot archive chunk checksums computation failure: {error:?}");
+                None
+            }
+        }
+    }
+
     async fn upload_snapshot_archive(
         &self,
         ongoing_snapshot: &OngoingSnapshot,
@@ -105,6 +177,7 @@ impl CardanoImmutableFilesFullArtifactBuilder {
         ongoing_snapshot: &OngoingSnapshot,
         snapshot_digest: String,
         remote_locations: Vec<String>,
+        chunk_checksums: Option<SnapshotChunkChecksums>,
     ) -> StdResult<Snapshot> {
         debug!("CardanoImmutableFilesFullArtifactBuilder: create snapshot");
 
@@ -116,6 +189,10 @@ impl CardanoImmutableFilesFullArtifactBuilder {
             self.compression_algorithm,
             &self.cardano_node_version,
         );
+        let snapshot = match chunk_checksums {
+            Some(chunk_checksums) => snapshot.with_chunk_checksums(chunk_checksums),
+            None => snapshot,
+        };
 
         Ok(snapshot)
     }
@@ -142,6 +219,14 @@ impl ArtifactBuilder<CardanoDbBeacon, Snapshot> for CardanoImmutableFilesFullArt
             .with_context(|| {
                 "Cardano Immutable Files Full Artifact Builder can not create snapshot archive"
             })?;
+        self.verify_snapshot_restorability(&beacon, &ongoing_snapshot, &snapshot_digest)
+            .await
+            .with_context(|| {
+                "Cardano Immutable Files Full Artifact Builder can not verify snapshot archive restorability"
+            })?;
+        let chunk_checksums = self
+            .compute_snapshot_chunk_checksums(&ongoing_snapshot)
+            .await;
         let locations = self
             .upload_snapshot_archive(&ongoing_snapshot)
             .await
@@ -150,7 +235,13 @@ impl ArtifactBuilder<CardanoDbBeacon, Snapshot> for CardanoImmutableFilesFullArt
             })?;
 
         let snapshot = self
-            .create_snapshot(beacon, &ongoing_snapshot, snapshot_digest, locations)
+            .create_snapshot(
+                beacon,
+                &ongoing_snapshot,
+                snapshot_digest,
+                locations,
+                chunk_checksums,
+            )
             .await?;
 
         Ok(snapshot)
@@ -160,14 +251,19 @@ impl ArtifactBuilder<CardanoDbBeacon, Snapshot> for CardanoImmutableFilesFullArt
 #[cfg(test)]
 mod tests {
     use anyhow::anyhow;
-    use std::path::Path;
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
     use tempfile::NamedTempFile;
 
     use mithril_common::{entities::CompressionAlgorithm, test_utils::fake_data};
 
     use super::*;
 
-    use crate::{snapshot_uploaders::MockSnapshotUploader, DumbSnapshotUploader, DumbSnapshotter};
+    use crate::{
+        snapshot_uploaders::MockSnapshotUploader,
+        snapshot_verifier::MockSnapshotRestorabilityVerifier, DumbSnapshotUploader,
+        DumbSnapshotter,
+    };
 
     #[tokio::test]
     async fn should_compute_valid_artifact() {
@@ -237,6 +333,51 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn compute_chunk_checksums_of_the_snapshot_archive() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"snapshot archive content").unwrap();
+        let snapshot = OngoingSnapshot::new(file.path().to_path_buf(), 24);
+
+        let cardano_immutable_files_full_artifact_builder =
+            CardanoImmutableFilesFullArtifactBuilder::new(
+                &Version::parse("1.0.0").unwrap(),
+                Arc::new(DumbSnapshotter::new()),
+                Arc::new(DumbSnapshotUploader::new()),
+                CompressionAlgorithm::default(),
+            );
+
+        let chunk_checksums = cardano_immutable_files_full_artifact_builder
+            .compute_snapshot_chunk_checksums(&snapshot)
+            .await
+            .expect("Chunk checksums should have been computed");
+
+        assert_eq!(
+            SnapshotChunkChecksums::compute(&b"snapshot archive content"[..], SNAPSHOT_CHUNK_SIZE)
+                .unwrap(),
+            chunk_checksums
+        );
+    }
+
+    #[tokio::test]
+    async fn no_chunk_checksums_if_the_snapshot_archive_can_not_be_read() {
+        let snapshot = OngoingSnapshot::new(PathBuf::from("/not/existing/archive.tar.gz"), 7331);
+
+        let cardano_immutable_files_full_artifact_builder =
+            CardanoImmutableFilesFullArtifactBuilder::new(
+                &Version::parse("1.0.0").unwrap(),
+                Arc::new(DumbSnapshotter::new()),
+                Arc::new(DumbSnapshotUploader::new()),
+                CompressionAlgorithm::default(),
+            );
+
+        let chunk_checksums = cardano_immutable_files_full_artifact_builder
+            .compute_snapshot_chunk_checksums(&snapshot)
+            .await;
+
+        assert_eq!(None, chunk_checksums);
+    }
+
     #[tokio::test]
     async fn snapshot_archive_name_after_beacon_values() {
         let beacon = CardanoDbBeacon::new("network".to_string(), 20, 145);
@@ -328,4 +469,63 @@ mod tests {
             "Ongoing snapshot file should have been removed even after upload failure"
         );
     }
+
+    fn not_restorable_verifier() -> MockSnapshotRestorabilityVerifier {
+        let mut restorability_verifier = MockSnapshotRestorabilityVerifier::new();
+        restorability_verifier
+            .expect_verify_restorability()
+            .return_once(|_, _, _| Err(anyhow!("digest mismatch")))
+            .once();
+
+        restorability_verifier
+    }
+
+    #[tokio::test]
+    async fn do_not_upload_snapshot_archive_if_it_is_not_restorable() {
+        let mut snapshot_uploader = MockSnapshotUploader::new();
+        snapshot_uploader.expect_upload_snapshot().never();
+
+        let cardano_immutable_files_full_artifact_builder =
+            CardanoImmutableFilesFullArtifactBuilder::new(
+                &Version::parse("1.0.0").unwrap(),
+                Arc::new(DumbSnapshotter::new()),
+                Arc::new(snapshot_uploader),
+                CompressionAlgorithm::default(),
+            )
+            .with_restorability_verifier(Arc::new(not_restorable_verifier()));
+
+        cardano_immutable_files_full_artifact_builder
+            .compute_artifact(
+                fake_data::beacon(),
+                &fake_data::certificate("certificate-123".to_string()),
+            )
+            .await
+            .expect_err("Artifact computation should fail if the snapshot is not restorable");
+    }
+
+    #[tokio::test]
+    async fn remove_snapshot_archive_if_it_is_not_restorable() {
+        let file = NamedTempFile::new().unwrap();
+        let file_path = file.path();
+        let snapshot = OngoingSnapshot::new(file_path.to_path_buf(), 7331);
+
+        let cardano_immutable_files_full_artifact_builder =
+            CardanoImmutableFilesFullArtifactBuilder::new(
+                &Version::parse("1.0.0").unwrap(),
+                Arc::new(DumbSnapshotter::new()),
+                Arc::new(DumbSnapshotUploader::new()),
+                CompressionAlgorithm::default(),
+            )
+            .with_restorability_verifier(Arc::new(not_restorable_verifier()));
+
+        cardano_immutable_files_full_artifact_builder
+            .verify_snapshot_restorability(&fake_data::beacon(), &snapshot, "digest")
+            .await
+            .expect_err("Restorability verification should have failed");
+
+        assert!(
+            !file_path.exists(),
+            "Ongoing snapshot file should have been removed after restorability verification failure"
+        );
+    }
 }
@@ -0,0 +1,325 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use mithril_common::{
+    entities::{
+        CardanoUtxoSetSnapshot, Certificate, Epoch, ProtocolMessagePartKey, SignedEntityType,
+    },
+    signable_builder::UtxoSetRetriever,
+    StdResult,
+};
+use slog_scope::{debug, warn};
+
+use crate::http_server;
+
+use super::ArtifactBuilder;
+
+/// A [CardanoUtxoSetSnapshot] builder
+///
+/// The UTxO set file, with one JSON serialized UTxO per line in canonical order, is copied in the
+/// target directory and is served by the aggregator. Only the files of the last
+/// [KEPT_UTXO_SET_FILES][Self::KEPT_UTXO_SET_FILES] artifacts are kept, since a full UTxO set is
+/// written every epoch: the locations of the older artifacts are not downloadable anymore.
+pub struct CardanoUtxoSetArtifactBuilder {
+    utxo_set_retriever: Arc<dyn UtxoSetRetriever>,
+    target_directory: PathBuf,
+    server_url: String,
+}
+
+impl CardanoUtxoSetArtifactBuilder {
+    /// Number of UTxO set files kept in the target directory
+    pub const KEPT_UTXO_SET_FILES: usize = 3;
+
+    /// CardanoUtxoSet artifact builder factory
+    pub fn new(
+        utxo_set_retriever: Arc<dyn UtxoSetRetriever>,
+        target_directory: &Path,
+        server_url: String,
+    ) -> Self {
+        Self {
+            utxo_set_retriever,
+            target_directory: target_directory.to_path_buf(),
+            server_url,
+        }
+    }
+
+    fn compute_location(&self, file_name: &str) -> String {
+        format!(
+            "{}{}/cardano_utxo_set_download/{}",
+            self.server_url,
+            http_server::SERVER_BASE_PATH,
+            file_name
+        )
+    }
+
+    /// Remove the UTxO set files of the target directory but the most recently written ones
+    fn prune_utxo_set_files(&self) -> StdResult<()> {
+        let mut utxo_set_files = vec![];
+        for entry in std::fs::read_dir(&self.target_directory)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "jsonl")
+            {
+                utxo_set_files.push((entry.metadata()?.modified()?, path));
+            }
+        }
+        utxo_set_files.sort();
+
+        let pruned_files_count = utxo_set_files
+            .len()
+            .saturating_sub(Self::KEPT_UTXO_SET_FILES);
+        for (_, path) in utxo_set_files.into_iter().take(pruned_files_count) {
+            debug!(
+                "CardanoUtxoSetArtifactBuilder: prune UTxO set file: '{}'",
+                path.display()
+            );
+            std::fs::remove_file(&path)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ArtifactBuilder<Epoch, CardanoUtxoSetSnapshot> for CardanoUtxoSetArtifactBuilder {
+    async fn compute_artifact(
+        &self,
+        beacon: Epoch,
+        certificate: &Certificate,
+    ) -> StdResult<CardanoUtxoSetSnapshot> {
+        let signed_entity_type = SignedEntityType::CardanoUtxoSet(beacon);
+        let merkle_root = certificate
+            .protocol_message
+            .get_message_part(&ProtocolMessagePartKey::CardanoUtxoSetMerkleRoot)
+            .ok_or(anyhow!(
+                "Can not find CardanoUtxoSetMerkleRoot protocol message part in certificate"
+            ))
+            .with_context(|| {
+                format!(
+                    "Can not compute CardanoUtxoSetSnapshot artifact for signed_entity: {signed_entity_type:?}"
+                )
+            })?;
+
+        let utxo_set_file = self.utxo_set_retriever.retrieve_utxo_set(beacon).await?;
+        let (computed_merkle_root, utxos_count) = {
+            let utxo_set_file = utxo_set_file.clone();
+            tokio::task::spawn_blocking(move || utxo_set_file.compute_merkle_root()).await??
+        };
+        let computed_merkle_root = computed_merkle_root.to_hex();
+        if &computed_merkle_root != merkle_root {
+            return Err(anyhow!(
+                "The Merkle root of the UTxO set '{computed_merkle_root}' does not match the certified one '{merkle_root}'"
+            ))
+            .with_context(|| {
+                format!(
+                    "Can not compute CardanoUtxoSetSnapshot artifact for signed_entity: {signed_entity_type:?}"
+                )
+            });
+        }
+
+        let hash = CardanoUtxoSetSnapshot::compute_hash_from(merkle_root, beacon);
+        let file_name = format!("{hash}.jsonl");
+        let file_path = self.target_directory.join(&file_name);
+        tokio::fs::copy(utxo_set_file.path(), &file_path)
+            .await
+            .with_context(|| format!("Can not write UTxO set file: '{}'", file_path.display()))?;
+        if let Err(error) = self.prune_utxo_set_files() {
+            warn!("CardanoUtxoSetArtifactBuilder: UTxO set files pruning failure: {error:?}");
+        }
+
+        Ok(CardanoUtxoSetSnapshot::new(
+            merkle_root.to_string(),
+            beacon,
+            utxos_count,
+            vec![self.compute_location(&file_name)],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::{
+        chain_observer::FakeObserver,
+        crypto_helper::MKTree,
+        entities::{CardanoUtxo, ProtocolMessage, TimePoint},
+        signable_builder::EpochBoundaryUtxoSetRetriever,
+        test_utils::{fake_data, TempDir},
+    };
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn fake_utxo_set() -> Vec<CardanoUtxo> {
+        vec![
+            CardanoUtxo::new("tx-hash-1", 0, "output-1"),
+            CardanoUtxo::new("tx-hash-2", 1, "output-2"),
+        ]
+    }
+
+    fn certificate_with_merkle_root(merkle_root: &str) -> Certificate {
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoUtxoSetMerkleRoot,
+            merkle_root.to_string(),
+        );
+        Certificate {
+            protocol_message,
+            ..fake_data::certificate("certificate-123".to_string())
+        }
+    }
+
+    fn merkle_root_of(utxo_set: &[CardanoUtxo]) -> String {
+        MKTree::new(utxo_set)
+            .unwrap()
+            .compute_root()
+            .unwrap()
+            .to_hex()
+    }
+
+    async fn retriever_returning(
+        test_name: &str,
+        utxo_set: Vec<CardanoUtxo>,
+    ) -> Arc<dyn UtxoSetRetriever> {
+        let chain_observer = FakeObserver::new(Some(TimePoint::dummy()));
+        chain_observer.set_utxo_set(utxo_set).await;
+
+        Arc::new(EpochBoundaryUtxoSetRetriever::new(
+            Arc::new(chain_observer),
+            Path::new("../mithril-test-lab/test_data/immutable/"),
+            &TempDir::create("cardano_utxo_set_artifact_builder", test_name),
+            slog_scope::logger(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn should_compute_valid_artifact_and_write_utxo_set_file() {
+        let target_dir = tempdir().unwrap();
+        let epoch = TimePoint::dummy().epoch;
+        let merkle_root = merkle_root_of(&fake_utxo_set());
+        let artifact_builder = CardanoUtxoSetArtifactBuilder::new(
+            retriever_returning(
+                "should_compute_valid_artifact_and_write_utxo_set_file",
+                fake_utxo_set(),
+            )
+            .await,
+            target_dir.path(),
+            "http://aggregator:8080/".to_string(),
+        );
+
+        let artifact = artifact_builder
+            .compute_artifact(epoch, &certificate_with_merkle_root(&merkle_root))
+            .await
+            .unwrap();
+
+        let hash = CardanoUtxoSetSnapshot::compute_hash_from(&merkle_root, epoch);
+        assert_eq!(
+            CardanoUtxoSetSnapshot::new(
+                merkle_root,
+                epoch,
+                2,
+                vec![format!(
+                    "http://aggregator:8080/{}/cardano_utxo_set_download/{hash}.jsonl",
+                    http_server::SERVER_BASE_PATH
+                )]
+            ),
+            artifact
+        );
+        let written_utxo_set: Vec<CardanoUtxo> =
+            std::fs::read_to_string(target_dir.path().join(format!("{hash}.jsonl")))
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect();
+        assert_eq!(fake_utxo_set(), written_utxo_set);
+    }
+
+    #[tokio::test]
+    async fn should_keep_only_the_last_utxo_set_files() {
+        let target_dir = tempdir().unwrap();
+        let now = std::time::SystemTime::now();
+        for (index, file_name) in ["old-1.jsonl", "old-2.jsonl", "old-3.jsonl"]
+            .iter()
+            .enumerate()
+        {
+            let file = std::fs::File::create(target_dir.path().join(file_name)).unwrap();
+            file.set_modified(now - std::time::Duration::from_secs(60 * (3 - index as u64)))
+                .unwrap();
+        }
+        std::fs::write(target_dir.path().join("not-a-utxo-set.txt"), "content").unwrap();
+        let epoch = TimePoint::dummy().epoch;
+        let merkle_root = merkle_root_of(&fake_utxo_set());
+        let artifact_builder = CardanoUtxoSetArtifactBuilder::new(
+            retriever_returning("should_keep_only_the_last_utxo_set_files", fake_utxo_set()).await,
+            target_dir.path(),
+            "http://aggregator:8080/".to_string(),
+        );
+
+        artifact_builder
+            .compute_artifact(epoch, &certificate_with_merkle_root(&merkle_root))
+            .await
+            .unwrap();
+
+        let hash = CardanoUtxoSetSnapshot::compute_hash_from(&merkle_root, epoch);
+        let mut remaining_files: Vec<String> = std::fs::read_dir(target_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        remaining_files.sort();
+        let mut expected_files = vec![
+            format!("{hash}.jsonl"),
+            "not-a-utxo-set.txt".to_string(),
+            "old-2.jsonl".to_string(),
+            "old-3.jsonl".to_string(),
+        ];
+        expected_files.sort();
+        assert_eq!(expected_files, remaining_files);
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_compute_artifact_without_merkle_root() {
+        let target_dir = tempdir().unwrap();
+        let epoch = TimePoint::dummy().epoch;
+        let artifact_builder = CardanoUtxoSetArtifactBuilder::new(
+            retriever_returning(
+                "should_fail_to_compute_artifact_without_merkle_root",
+                fake_utxo_set(),
+            )
+            .await,
+            target_dir.path(),
+            "http://aggregator:8080/".to_string(),
+        );
+        let certificate_without_merkle_root = Certificate {
+            protocol_message: ProtocolMessage::new(),
+            ..fake_data::certificate("certificate-123".to_string())
+        };
+
+        artifact_builder
+            .compute_artifact(epoch, &certificate_without_merkle_root)
+            .await
+            .expect_err("The artifact building must fail since there is no CardanoUtxoSetMerkleRoot part in its message.");
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_compute_artifact_if_utxo_set_does_not_match_certified_merkle_root() {
+        let target_dir = tempdir().unwrap();
+        let epoch = TimePoint::dummy().epoch;
+        let artifact_builder = CardanoUtxoSetArtifactBuilder::new(
+            retriever_returning(
+                "should_fail_to_compute_artifact_if_utxo_set_does_not_match_certified_merkle_root",
+                fake_utxo_set(),
+            )
+            .await,
+            target_dir.path(),
+            "http://aggregator:8080/".to_string(),
+        );
+
+        artifact_builder
+            .compute_artifact(epoch, &certificate_with_merkle_root("another-merkle-root"))
+            .await
+            .expect_err("The artifact building must fail since the UTxO set does not match the certified Merkle root.");
+    }
+}
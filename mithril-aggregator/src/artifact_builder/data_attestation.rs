@@ -0,0 +1,85 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+
+use super::ArtifactBuilder;
+use mithril_common::{
+    entities::{Certificate, DataAttestation, DataAttestationBeacon, ProtocolMessagePartKey},
+    StdResult,
+};
+
+/// A [DataAttestation] builder
+#[derive(Default)]
+pub struct DataAttestationArtifactBuilder {}
+
+#[async_trait]
+impl ArtifactBuilder<DataAttestationBeacon, DataAttestation> for DataAttestationArtifactBuilder {
+    async fn compute_artifact(
+        &self,
+        beacon: DataAttestationBeacon,
+        certificate: &Certificate,
+    ) -> StdResult<DataAttestation> {
+        let certified_payload_hash = certificate
+            .protocol_message
+            .get_message_part(&ProtocolMessagePartKey::DataAttestationPayloadHash)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Can not find DataAttestationPayloadHash protocol message part in certificate"
+                )
+            })?;
+        if certified_payload_hash != &beacon.payload_hash {
+            return Err(anyhow!(
+                "Certified payload hash '{certified_payload_hash}' does not match the data attestation payload hash '{}'",
+                beacon.payload_hash
+            ));
+        }
+
+        Ok(DataAttestation::new(beacon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::{
+        entities::{Epoch, ProtocolMessage},
+        test_utils::fake_data,
+    };
+
+    use super::*;
+
+    fn certificate_with_payload_hash(payload_hash: &str) -> Certificate {
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::DataAttestationPayloadHash,
+            payload_hash.to_string(),
+        );
+
+        Certificate {
+            protocol_message,
+            ..fake_data::certificate("certificate-123".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn should_compute_valid_artifact() {
+        let beacon = DataAttestationBeacon::new(Epoch(1), "db-sync-snapshot", "abc123");
+        let certificate = certificate_with_payload_hash("abc123");
+
+        let artifact = DataAttestationArtifactBuilder::default()
+            .compute_artifact(beacon.clone(), &certificate)
+            .await
+            .unwrap();
+
+        assert_eq!(DataAttestation::new(beacon), artifact);
+    }
+
+    #[tokio::test]
+    async fn should_fail_if_certified_payload_hash_does_not_match() {
+        let beacon = DataAttestationBeacon::new(Epoch(1), "db-sync-snapshot", "abc123");
+        let certificate = certificate_with_payload_hash("def456");
+
+        DataAttestationArtifactBuilder::default()
+            .compute_artifact(beacon, &certificate)
+            .await
+            .expect_err("A mismatching certified payload hash should fail");
+    }
+}
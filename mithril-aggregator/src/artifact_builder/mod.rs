@@ -1,10 +1,14 @@
 //! The module used for building artifact
 mod cardano_immutable_files_full;
 mod cardano_transactions;
+mod cardano_utxo_set;
+mod data_attestation;
 mod interface;
 mod mithril_stake_distribution;
 
 pub use cardano_immutable_files_full::*;
 pub use cardano_transactions::*;
+pub use cardano_utxo_set::*;
+pub use data_attestation::*;
 pub use interface::*;
 pub use mithril_stake_distribution::*;
@@ -179,6 +179,35 @@ impl ServeCommand {
             }
         }
 
+        // Create a PeerReconciler only if the `peer_aggregator_endpoint` is provided in the config.
+        if let Some(peer_aggregator_endpoint) = config.peer_aggregator_endpoint {
+            match dependencies_builder
+                .create_peer_reconciler(&peer_aggregator_endpoint)
+                .await
+            {
+                Ok(service) => {
+                    join_set.spawn(async move {
+                        // Wait 5s to let the other services the time to start before running
+                        // the first reconciliation.
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                        service
+                            .run_forever(Duration::from_secs(
+                                // Reconciliation interval are in minutes
+                                config.peer_reconciliation_run_interval * 60,
+                            ))
+                            .await;
+                        Ok(())
+                    });
+                }
+                Err(error) => {
+                    warn!(
+                        "Failed to build the `PeerReconciler`:\n peer aggregator `{}`\n Error: {:?}",
+                        peer_aggregator_endpoint, error
+                    );
+                }
+            }
+        }
+
         join_set.spawn(async { tokio::signal::ctrl_c().await.map_err(|e| e.to_string()) });
         dependencies_builder.vanish().await;
 
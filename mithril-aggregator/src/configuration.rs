@@ -2,6 +2,7 @@ use anyhow::{anyhow, Context};
 use config::{ConfigError, Map, Source, Value, ValueKind};
 use mithril_common::chain_observer::ChainObserverType;
 use mithril_common::crypto_helper::ProtocolGenesisSigner;
+use mithril_common::digesters::ImmutableDigesterIoConfig;
 use mithril_common::era::adapters::EraReaderAdapterType;
 use mithril_doc::{Documenter, DocumenterDefault, StructDoc};
 use serde::{Deserialize, Serialize};
@@ -147,12 +148,28 @@ pub struct Configuration {
     #[example = "`{ level: 9, number_of_workers: 4 }`"]
     pub zstandard_parameters: Option<ZstandardCompressionParameters>,
 
+    /// If set, each snapshot archive is unpacked in a scratch directory and the digest of the
+    /// restored immutable files is checked before the archive is uploaded.
+    pub snapshot_restorability_check: bool,
+
+    /// IO parameters of the immutable files digester, to tune when the Cardano database is
+    /// stored on a network filesystem (ie: NFS).
+    #[example = "`{ read_ahead_size: 1048576, io_concurrency: 8 }`"]
+    pub immutable_digester_io_config: Option<ImmutableDigesterIoConfig>,
+
     /// Url to CExplorer list of pools to import as signer in the database.
     pub cexplorer_pools_url: Option<String>,
 
     /// Time interval at which the signers in [Self::cexplorer_pools_url] will be imported (in minutes).
     pub signer_importer_run_interval: u64,
 
+    /// Endpoint of a peer aggregator to reconcile the local certificate chain and artifacts with.
+    #[example = "`https://aggregator.pre-release-preview.api.mithril.network/aggregator`"]
+    pub peer_aggregator_endpoint: Option<String>,
+
+    /// Time interval at which the reconciliation with [Self::peer_aggregator_endpoint] runs (in minutes).
+    pub peer_reconciliation_run_interval: u64,
+
     /// If set no error is returned in case of unparsable block and an error log is written instead.
     ///
     /// Will be ignored on (pre)production networks.
@@ -170,6 +187,26 @@ pub struct Configuration {
 
     /// Maximum number of transactions hashes allowed by request to the prover
     pub cardano_transactions_prover_max_hashes_allowed_by_request: usize,
+
+    /// Operators allowed to submit data attestations, data attestations are rejected if not set.
+    #[example = "`[{ name: \"db-sync\", api_key_hash: \"<sha256 of the api key>\", allowed_namespaces: [\"db-sync-snapshot\"], max_attestations_per_epoch: 10 }]`"]
+    pub data_attestation_operators: Option<Vec<DataAttestationOperator>>,
+}
+
+/// Policy of an operator allowed to submit data attestations.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DataAttestationOperator {
+    /// Name of the operator, used in the logs.
+    pub name: String,
+
+    /// Hex encoded SHA256 hash of the API key that authenticates the operator.
+    pub api_key_hash: String,
+
+    /// Namespaces in which the operator is allowed to submit attestations.
+    pub allowed_namespaces: Vec<String>,
+
+    /// Maximum number of attestations the operator can submit in an epoch.
+    pub max_attestations_per_epoch: u32,
 }
 
 /// Uploader needed to copy the snapshot once computed.
@@ -239,8 +276,12 @@ impl Configuration {
             signed_entity_types: None,
             snapshot_compression_algorithm: CompressionAlgorithm::Zstandard,
             zstandard_parameters: Some(ZstandardCompressionParameters::default()),
+            snapshot_restorability_check: false,
+            immutable_digester_io_config: None,
             cexplorer_pools_url: None,
             signer_importer_run_interval: 1,
+            peer_aggregator_endpoint: None,
+            peer_reconciliation_run_interval: 1,
             allow_unparsable_block: false,
             cardano_transactions_prover_cache_pool_size: 3,
             cardano_transactions_database_connection_pool_size: 5,
@@ -249,6 +290,7 @@ impl Configuration {
                 step: 15,
             },
             cardano_transactions_prover_max_hashes_allowed_by_request: 100,
+            data_attestation_operators: None,
         }
     }
 
@@ -346,9 +388,15 @@ pub struct DefaultConfiguration {
     /// Use CDN domain to construct snapshot urls default setting (if snapshot_uploader_type is Gcp)
     pub snapshot_use_cdn_domain: String,
 
+    /// Snapshot restorability check default setting
+    pub snapshot_restorability_check: String,
+
     /// Signer importer run interval default setting
     pub signer_importer_run_interval: u64,
 
+    /// Peer reconciliation run interval default setting
+    pub peer_reconciliation_run_interval: u64,
+
     /// If set no error is returned in case of unparsable block and an error log is written instead.
     ///
     /// Will be ignored on (pre)production networks.
@@ -383,7 +431,9 @@ impl Default for DefaultConfiguration {
             disable_digests_cache: "false".to_string(),
             snapshot_compression_algorithm: "zstandard".to_string(),
             snapshot_use_cdn_domain: "false".to_string(),
+            snapshot_restorability_check: "false".to_string(),
             signer_importer_run_interval: 720,
+            peer_reconciliation_run_interval: 60,
             allow_unparsable_block: "false".to_string(),
             cardano_transactions_prover_cache_pool_size: 10,
             cardano_transactions_database_connection_pool_size: 10,
@@ -444,7 +494,9 @@ impl Source for DefaultConfiguration {
         insert_default_configuration!(result, myself.disable_digests_cache);
         insert_default_configuration!(result, myself.snapshot_compression_algorithm);
         insert_default_configuration!(result, myself.snapshot_use_cdn_domain);
+        insert_default_configuration!(result, myself.snapshot_restorability_check);
         insert_default_configuration!(result, myself.signer_importer_run_interval);
+        insert_default_configuration!(result, myself.peer_reconciliation_run_interval);
         insert_default_configuration!(result, myself.allow_unparsable_block);
         insert_default_configuration!(result, myself.cardano_transactions_prover_cache_pool_size);
         insert_default_configuration!(
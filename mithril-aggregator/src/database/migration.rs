@@ -752,5 +752,39 @@ pragma foreign_keys=true;
                 SignedEntityTypeDiscriminants::CardanoTransactions.index()
             ),
         ),
+        // Migration 26
+        // Add the `signed_entity_type` record for 'CardanoUtxoSet'
+        SqlMigration::new(
+            26,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (4, 'Cardano UTxO Set');
+"#,
+        ),
+        // Migration 27
+        // Add the `signed_entity_type` record for 'DataAttestation'
+        SqlMigration::new(
+            27,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name)
+    values  (5, 'Data Attestation');
+"#,
+        ),
+        // Migration 28
+        // Add the `data_attestation` table to persist the attestations submitted by the operators
+        SqlMigration::new(
+            28,
+            r#"
+create table data_attestation (
+    namespace       text        not null,
+    payload_hash    text        not null,
+    epoch           integer     not null,
+    operator        text        not null,
+    created_at      text        not null,
+    primary key (namespace, payload_hash)
+);
+create index data_attestation_operator_epoch_index on data_attestation(operator, epoch);
+"#,
+        ),
     ]
 }
@@ -2,7 +2,7 @@ use sqlite::Value;
 
 #[cfg(test)]
 use mithril_common::entities::Epoch;
-#[cfg(test)]
+use mithril_common::entities::SignedEntityType;
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
 
@@ -29,6 +29,18 @@ impl GetCertificateRecordQuery {
         }
     }
 
+    pub fn by_signed_entity_type(signed_entity_type: &SignedEntityType) -> StdResult<Self> {
+        Ok(Self {
+            condition: WhereCondition::new(
+                "signed_entity_type_id = ?* and signed_entity_beacon = ?*",
+                vec![
+                    Value::Integer(signed_entity_type.index() as i64),
+                    Value::String(signed_entity_type.get_json_beacon()?),
+                ],
+            ),
+        })
+    }
+
     #[cfg(test)]
     pub fn by_epoch(epoch: Epoch) -> StdResult<Self> {
         Ok(Self {
@@ -93,6 +105,40 @@ mod tests {
         assert_eq!(0, cursor.count());
     }
 
+    #[test]
+    fn test_get_certificate_records_by_signed_entity_type() {
+        let (certificates, _) = setup_certificate_chain(5, 2);
+        let signed_entity_type = certificates[1].signed_entity_type();
+
+        let connection = main_db_connection().unwrap();
+        insert_certificate_records(&connection, certificates.clone());
+
+        let certificate_records: Vec<CertificateRecord> = connection
+            .fetch_collect(
+                GetCertificateRecordQuery::by_signed_entity_type(&signed_entity_type).unwrap(),
+            )
+            .unwrap();
+        let expected_certificate_records: Vec<CertificateRecord> = certificates
+            .iter()
+            .filter_map(|c| {
+                (c.signed_entity_type() == signed_entity_type).then_some(c.to_owned().into())
+            })
+            .rev()
+            .collect();
+        assert!(!expected_certificate_records.is_empty());
+        assert_eq!(expected_certificate_records, certificate_records);
+
+        let cursor = connection
+            .fetch(
+                GetCertificateRecordQuery::by_signed_entity_type(
+                    &SignedEntityType::MithrilStakeDistribution(Epoch(50)),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(0, cursor.count());
+    }
+
     #[test]
     fn test_get_all_certificate_records() {
         let (certificates, _) = setup_certificate_chain(5, 2);
@@ -0,0 +1,180 @@
+use sqlite::Value;
+
+use mithril_common::entities::{Epoch, SignedEntityTypeDiscriminants};
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::DataAttestationRecord;
+
+/// Simple queries to retrieve [DataAttestationRecord] from the sqlite database.
+pub struct GetDataAttestationQuery {
+    condition: WhereCondition,
+}
+
+impl GetDataAttestationQuery {
+    /// Get the data attestation of the given payload hash in the given namespace.
+    pub fn by_namespace_and_payload_hash(namespace: &str, payload_hash: &str) -> Self {
+        let condition = WhereCondition::new(
+            "namespace = ?* and payload_hash = ?*",
+            vec![
+                Value::String(namespace.to_string()),
+                Value::String(payload_hash.to_string()),
+            ],
+        );
+
+        Self { condition }
+    }
+
+    /// Get the data attestations submitted by the given operator at the given epoch.
+    pub fn by_operator_and_epoch(operator: &str, epoch: Epoch) -> StdResult<Self> {
+        let condition = WhereCondition::new(
+            "operator = ?* and epoch = ?*",
+            vec![
+                Value::String(operator.to_string()),
+                Value::Integer(epoch.try_into()?),
+            ],
+        );
+
+        Ok(Self { condition })
+    }
+
+    /// Get the data attestations submitted up to the given epoch that do not have a signed
+    /// entity yet, ie: that are not certified, whatever the epoch of their certification.
+    pub fn uncertified_up_to_epoch(epoch: Epoch) -> StdResult<Self> {
+        let condition = WhereCondition::new(
+            r#"da.epoch <= ?* and not exists (
+    select 1 from signed_entity as se
+    where se.signed_entity_type_id = ?*
+        and json_extract(se.beacon, '$.namespace') = da.namespace
+        and json_extract(se.beacon, '$.payload_hash') = da.payload_hash
+)"#,
+            vec![
+                Value::Integer(epoch.try_into()?),
+                Value::Integer(SignedEntityTypeDiscriminants::DataAttestation.index() as i64),
+            ],
+        );
+
+        Ok(Self { condition })
+    }
+}
+
+impl Query for GetDataAttestationQuery {
+    type Entity = DataAttestationRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:data_attestation:}", "da")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("select {projection} from data_attestation as da where {condition} order by da.created_at asc, da.namespace asc, da.payload_hash asc")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{DataAttestationBeacon, SignedEntityType};
+    use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+
+    use crate::database::query::InsertDataAttestationQuery;
+    use crate::database::record::SignedEntityRecord;
+    use crate::database::test_helper::{insert_signed_entities, main_db_connection};
+
+    use super::*;
+
+    fn insert_data_attestations(connection: &SqliteConnection, records: &[DataAttestationRecord]) {
+        for record in records {
+            connection
+                .fetch_first(InsertDataAttestationQuery::one(record.clone()).unwrap())
+                .unwrap();
+        }
+    }
+
+    fn record(epoch: u64, payload_hash: &str, operator: &str) -> DataAttestationRecord {
+        DataAttestationRecord::new(
+            DataAttestationBeacon::new(Epoch(epoch), "namespace", payload_hash),
+            operator,
+        )
+    }
+
+    #[test]
+    fn test_get_data_attestation_by_namespace_and_payload_hash() {
+        let connection = main_db_connection().unwrap();
+        let records = vec![
+            record(5, "hash-1", "operator"),
+            record(5, "hash-2", "operator"),
+        ];
+        insert_data_attestations(&connection, &records);
+
+        let data_attestation = connection
+            .fetch_first(GetDataAttestationQuery::by_namespace_and_payload_hash(
+                "namespace",
+                "hash-2",
+            ))
+            .unwrap();
+        assert_eq!(Some(records[1].clone()), data_attestation);
+
+        let data_attestation = connection
+            .fetch_first(GetDataAttestationQuery::by_namespace_and_payload_hash(
+                "other-namespace",
+                "hash-1",
+            ))
+            .unwrap();
+        assert_eq!(None, data_attestation);
+    }
+
+    #[test]
+    fn test_get_data_attestations_by_operator_and_epoch() {
+        let connection = main_db_connection().unwrap();
+        let records = vec![
+            record(5, "hash-1", "operator"),
+            record(5, "hash-2", "another-operator"),
+            record(6, "hash-3", "operator"),
+        ];
+        insert_data_attestations(&connection, &records);
+
+        let data_attestations: Vec<DataAttestationRecord> = connection
+            .fetch_collect(
+                GetDataAttestationQuery::by_operator_and_epoch("operator", Epoch(5)).unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(vec![records[0].clone()], data_attestations);
+    }
+
+    #[test]
+    fn test_get_uncertified_data_attestations_up_to_epoch() {
+        let connection = main_db_connection().unwrap();
+        let records = vec![
+            record(4, "certified", "operator"),
+            record(4, "uncertified-1", "operator"),
+            record(5, "uncertified-2", "operator"),
+            record(6, "uncertified-3", "operator"),
+        ];
+        insert_data_attestations(&connection, &records);
+        // Certified at a later epoch than its submission
+        insert_signed_entities(
+            &connection,
+            vec![SignedEntityRecord {
+                signed_entity_type: SignedEntityType::DataAttestation(DataAttestationBeacon::new(
+                    Epoch(5),
+                    "namespace",
+                    "certified",
+                )),
+                ..SignedEntityRecord::fake_records(1).remove(0)
+            }],
+        )
+        .unwrap();
+
+        let data_attestations: Vec<DataAttestationRecord> = connection
+            .fetch_collect(GetDataAttestationQuery::uncertified_up_to_epoch(Epoch(5)).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            vec![records[1].clone(), records[2].clone()],
+            data_attestations
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use sqlite::Value;
+
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::DataAttestationRecord;
+
+/// Query to insert [DataAttestationRecord] in the sqlite database
+pub struct InsertDataAttestationQuery {
+    condition: WhereCondition,
+}
+
+impl InsertDataAttestationQuery {
+    pub fn one(record: DataAttestationRecord) -> StdResult<Self> {
+        let expression =
+            "(namespace, payload_hash, epoch, operator, created_at) values (?*, ?*, ?*, ?*, ?*)";
+        let parameters = vec![
+            Value::String(record.beacon.namespace),
+            Value::String(record.beacon.payload_hash),
+            Value::Integer(record.beacon.epoch.try_into()?),
+            Value::String(record.operator),
+            Value::String(record.created_at.to_rfc3339()),
+        ];
+
+        Ok(Self {
+            condition: WhereCondition::new(expression, parameters),
+        })
+    }
+}
+
+impl Query for InsertDataAttestationQuery {
+    type Entity = DataAttestationRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let aliases = SourceAlias::new(&[("{:data_attestation:}", "data_attestation")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("insert into data_attestation {condition} returning {projection}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{DataAttestationBeacon, Epoch};
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[test]
+    fn test_insert_data_attestation_record() {
+        let connection = main_db_connection().unwrap();
+        let record = DataAttestationRecord::new(
+            DataAttestationBeacon::new(Epoch(5), "namespace", "payload-hash"),
+            "operator",
+        );
+
+        let inserted_record = connection
+            .fetch_first(InsertDataAttestationQuery::one(record.clone()).unwrap())
+            .unwrap();
+
+        assert_eq!(Some(record), inserted_record);
+    }
+}
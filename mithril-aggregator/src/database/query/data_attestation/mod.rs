@@ -0,0 +1,5 @@
+mod get_data_attestation;
+mod insert_data_attestation;
+
+pub use get_data_attestation::*;
+pub use insert_data_attestation::*;
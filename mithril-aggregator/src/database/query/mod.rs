@@ -1,5 +1,6 @@
 //! Aggregator related database queries
 mod certificate;
+mod data_attestation;
 mod epoch_setting;
 mod open_message;
 mod signed_entity;
@@ -9,6 +10,7 @@ mod single_signature;
 mod stake_pool;
 
 pub use certificate::*;
+pub use data_attestation::*;
 pub use epoch_setting::*;
 pub use open_message::*;
 pub use signed_entity::*;
@@ -1,5 +1,6 @@
 use sqlite::Value;
 
+use chrono::{DateTime, Utc};
 use mithril_common::entities::{Epoch, SignedEntityType};
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
@@ -22,6 +23,16 @@ impl GetOpenMessageWithSingleSignaturesQuery {
         Ok(Self { condition })
     }
 
+    /// Get the open messages that are neither certified nor expired at the given date.
+    pub fn not_certified_nor_expired(now: DateTime<Utc>) -> Self {
+        let condition = WhereCondition::new(
+            "is_certified = false and is_expired = false and (expires_at is null or expires_at >= ?*)",
+            vec![Value::String(now.to_rfc3339())],
+        );
+
+        Self { condition }
+    }
+
     fn get_epoch_condition(epoch: Epoch) -> WhereCondition {
         WhereCondition::new("epoch_setting_id = ?*", vec![Value::Integer(*epoch as i64)])
     }
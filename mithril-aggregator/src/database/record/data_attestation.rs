@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+
+use mithril_common::entities::{DataAttestationBeacon, Epoch};
+use mithril_persistence::sqlite::{HydrationError, Projection, SqLiteEntity};
+
+/// Data attestation record is the representation of an attestation submitted by an operator.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DataAttestationRecord {
+    /// Beacon of the attestation.
+    pub beacon: DataAttestationBeacon,
+
+    /// Name of the operator that submitted the attestation.
+    pub operator: String,
+
+    /// Date and time when the attestation was submitted.
+    pub created_at: DateTime<Utc>,
+}
+
+impl DataAttestationRecord {
+    /// DataAttestationRecord factory
+    pub fn new<T: Into<String>>(beacon: DataAttestationBeacon, operator: T) -> Self {
+        Self {
+            beacon,
+            operator: operator.into(),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+impl SqLiteEntity for DataAttestationRecord {
+    fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
+    where
+        Self: Sized,
+    {
+        let epoch_int = row.read::<i64, _>(0);
+        let namespace = row.read::<&str, _>(1);
+        let payload_hash = row.read::<&str, _>(2);
+        let operator = row.read::<&str, _>(3).to_string();
+        let created_at = row.read::<&str, _>(4);
+
+        let epoch = Epoch(epoch_int.try_into().map_err(|e| {
+            HydrationError::InvalidData(format!(
+                "Could not cast i64 ({epoch_int}) to u64. Error: '{e}'"
+            ))
+        })?);
+        let data_attestation_record = Self {
+            beacon: DataAttestationBeacon::new(epoch, namespace, payload_hash),
+            operator,
+            created_at: DateTime::parse_from_rfc3339(created_at)
+                .map_err(|e| {
+                    HydrationError::InvalidData(format!(
+                        "Could not turn string '{created_at}' to rfc3339 Datetime. Error: {e}"
+                    ))
+                })?
+                .with_timezone(&Utc),
+        };
+
+        Ok(data_attestation_record)
+    }
+
+    fn get_projection() -> Projection {
+        let mut projection = Projection::default();
+        projection.add_field("epoch", "{:data_attestation:}.epoch", "integer");
+        projection.add_field("namespace", "{:data_attestation:}.namespace", "text");
+        projection.add_field("payload_hash", "{:data_attestation:}.payload_hash", "text");
+        projection.add_field("operator", "{:data_attestation:}.operator", "text");
+        projection.add_field("created_at", "{:data_attestation:}.created_at", "text");
+
+        projection
+    }
+}
@@ -1,6 +1,7 @@
 //! Aggregator related database records
 
 mod certificate;
+mod data_attestation;
 mod epoch_setting;
 mod open_message;
 mod open_message_with_single_signatures;
@@ -11,6 +12,7 @@ mod single_signature;
 mod stake_pool;
 
 pub use certificate::*;
+pub use data_attestation::*;
 pub use epoch_setting::*;
 pub use open_message::*;
 pub use open_message_with_single_signatures::*;
@@ -5,6 +5,7 @@ use mithril_common::crypto_helper::ProtocolParameters;
 use mithril_common::entities::{BlockNumber, Epoch, SignedEntity, SignedEntityType, Snapshot};
 use mithril_common::messages::{
     CardanoTransactionSnapshotListItemMessage, CardanoTransactionSnapshotMessage,
+    CardanoUtxoSetSnapshotListItemMessage, CardanoUtxoSetSnapshotMessage, DataAttestationMessage,
     MithrilStakeDistributionListItemMessage, MithrilStakeDistributionMessage,
     SignerWithStakeMessagePart, SnapshotListItemMessage, SnapshotMessage,
 };
@@ -113,6 +114,7 @@ impl TryFrom<SignedEntityRecord> for SnapshotMessage {
             locations: artifact.locations,
             compression_algorithm: Some(artifact.compression_algorithm),
             cardano_node_version: Some(artifact.cardano_node_version),
+            chunk_checksums: artifact.chunk_checksums,
         };
 
         Ok(snapshot_message)
@@ -213,6 +215,83 @@ impl TryFrom<SignedEntityRecord> for CardanoTransactionSnapshotListItemMessage {
     }
 }
 
+impl TryFrom<SignedEntityRecord> for CardanoUtxoSetSnapshotMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoUtxoSet {
+            merkle_root: String,
+            epoch: Epoch,
+            utxos_count: u64,
+            locations: Vec<String>,
+            hash: String,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoUtxoSet>(&value.artifact)?;
+        let message = CardanoUtxoSetSnapshotMessage {
+            merkle_root: artifact.merkle_root,
+            epoch: artifact.epoch,
+            utxos_count: artifact.utxos_count,
+            locations: artifact.locations,
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
+impl TryFrom<SignedEntityRecord> for DataAttestationMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpDataAttestation {
+            hash: String,
+            epoch: Epoch,
+            namespace: String,
+            payload_hash: String,
+        }
+        let artifact = serde_json::from_str::<TmpDataAttestation>(&value.artifact)?;
+        let message = DataAttestationMessage {
+            hash: artifact.hash,
+            epoch: artifact.epoch,
+            namespace: artifact.namespace,
+            payload_hash: artifact.payload_hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
+impl TryFrom<SignedEntityRecord> for CardanoUtxoSetSnapshotListItemMessage {
+    type Error = StdError;
+
+    fn try_from(value: SignedEntityRecord) -> Result<Self, Self::Error> {
+        #[derive(Deserialize)]
+        struct TmpCardanoUtxoSet {
+            merkle_root: String,
+            epoch: Epoch,
+            utxos_count: u64,
+            hash: String,
+        }
+        let artifact = serde_json::from_str::<TmpCardanoUtxoSet>(&value.artifact)?;
+        let message = CardanoUtxoSetSnapshotListItemMessage {
+            merkle_root: artifact.merkle_root,
+            epoch: artifact.epoch,
+            utxos_count: artifact.utxos_count,
+            hash: artifact.hash,
+            certificate_hash: value.certificate_id,
+            created_at: value.created_at,
+        };
+
+        Ok(message)
+    }
+}
+
 impl TryFrom<SignedEntityRecord> for SnapshotListItemMessage {
     type Error = StdError;
 
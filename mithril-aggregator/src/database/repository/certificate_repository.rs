@@ -5,7 +5,7 @@ use async_trait::async_trait;
 use sqlite::ConnectionThreadSafe;
 
 use mithril_common::certificate_chain::{CertificateRetriever, CertificateRetrieverError};
-use mithril_common::entities::{Certificate, Epoch};
+use mithril_common::entities::{Certificate, Epoch, SignedEntityType};
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::ConnectionExtensions;
 
@@ -38,6 +38,23 @@ impl CertificateRepository {
         Ok(record.map(|c| c.into()))
     }
 
+    /// Return the latest certificate of the given signed entity type if any.
+    pub async fn get_certificate_by_signed_entity_type<T>(
+        &self,
+        signed_entity_type: &SignedEntityType,
+    ) -> StdResult<Option<T>>
+    where
+        T: From<CertificateRecord>,
+    {
+        let record =
+            self.connection
+                .fetch_first(GetCertificateRecordQuery::by_signed_entity_type(
+                    signed_entity_type,
+                )?)?;
+
+        Ok(record.map(|c| c.into()))
+    }
+
     /// Return the latest certificates.
     pub async fn get_latest_certificates<T>(&self, last_n: usize) -> StdResult<Vec<T>>
     where
@@ -259,6 +276,37 @@ mod tests {
         assert_eq!(expected_hash, certificate.hash);
     }
 
+    #[tokio::test]
+    async fn repository_get_certificate_by_signed_entity_type() {
+        let (certificates, _) = setup_certificate_chain(5, 2);
+        let expected_certificate = certificates[0].clone();
+        let mut deps = DependenciesBuilder::new(Configuration::new_sample());
+        let connection = deps.get_sqlite_connection().await.unwrap();
+        insert_certificate_records(&connection, certificates.clone());
+
+        let repository: CertificateRepository = CertificateRepository::new(connection);
+        let certificate = repository
+            .get_certificate_by_signed_entity_type::<Certificate>(
+                &SignedEntityType::MithrilStakeDistribution(Epoch(50)),
+            )
+            .await
+            .unwrap();
+        assert!(certificate.is_none());
+
+        let certificate = repository
+            .get_certificate_by_signed_entity_type::<Certificate>(
+                &expected_certificate.signed_entity_type(),
+            )
+            .await
+            .unwrap()
+            .expect("The certificate exist and should be returned.");
+
+        assert_eq!(
+            expected_certificate.signed_entity_type(),
+            certificate.signed_entity_type()
+        );
+    }
+
     #[tokio::test]
     async fn repository_get_latest_certificates() {
         let (certificates, _) = setup_certificate_chain(5, 2);
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+
+use mithril_common::entities::Epoch;
+use mithril_common::StdResult;
+use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
+
+use crate::database::query::{GetDataAttestationQuery, InsertDataAttestationQuery};
+use crate::database::record::DataAttestationRecord;
+
+/// ## Data attestation repository
+///
+/// This is a business oriented layer to perform actions on the database through
+/// queries.
+pub struct DataAttestationRepository {
+    connection: Arc<SqliteConnection>,
+}
+
+impl DataAttestationRepository {
+    /// Instanciate service
+    pub fn new(connection: Arc<SqliteConnection>) -> Self {
+        Self { connection }
+    }
+
+    /// Create a new [DataAttestationRecord] in the database.
+    pub async fn create_data_attestation(
+        &self,
+        record: DataAttestationRecord,
+    ) -> StdResult<DataAttestationRecord> {
+        let beacon = record.beacon.clone();
+        self.connection
+            .fetch_first(InsertDataAttestationQuery::one(record)?)?
+            .with_context(|| format!("No entity returned by the persister, beacon = {beacon}"))
+    }
+
+    /// Return the [DataAttestationRecord] of the given payload hash in the given namespace if
+    /// it exists.
+    pub async fn get_data_attestation(
+        &self,
+        namespace: &str,
+        payload_hash: &str,
+    ) -> StdResult<Option<DataAttestationRecord>> {
+        self.connection
+            .fetch_first(GetDataAttestationQuery::by_namespace_and_payload_hash(
+                namespace,
+                payload_hash,
+            ))
+    }
+
+    /// Return the number of attestations submitted by the given operator at the given epoch.
+    pub async fn count_operator_data_attestations(
+        &self,
+        operator: &str,
+        epoch: Epoch,
+    ) -> StdResult<usize> {
+        Ok(self
+            .connection
+            .fetch(GetDataAttestationQuery::by_operator_and_epoch(
+                operator, epoch,
+            )?)?
+            .count())
+    }
+
+    /// Return the attestations submitted up to the given epoch that are not certified yet.
+    pub async fn get_uncertified_data_attestations(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<Vec<DataAttestationRecord>> {
+        self.connection
+            .fetch_collect(GetDataAttestationQuery::uncertified_up_to_epoch(epoch)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::DataAttestationBeacon;
+
+    use crate::database::test_helper::main_db_connection;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn create_and_count_data_attestations() {
+        let repository = DataAttestationRepository::new(Arc::new(main_db_connection().unwrap()));
+        let beacon = DataAttestationBeacon::new(Epoch(5), "namespace", "payload-hash");
+
+        let record = repository
+            .create_data_attestation(DataAttestationRecord::new(beacon.clone(), "operator"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some(record.clone()),
+            repository
+                .get_data_attestation("namespace", "payload-hash")
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            1,
+            repository
+                .count_operator_data_attestations("operator", Epoch(5))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            vec![record],
+            repository
+                .get_uncertified_data_attestations(Epoch(6))
+                .await
+                .unwrap()
+        );
+    }
+}
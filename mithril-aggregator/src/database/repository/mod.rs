@@ -1,6 +1,7 @@
 //! Aggregator related database repositories
 mod cardano_transaction_repository;
 mod certificate_repository;
+mod data_attestation_repository;
 mod epoch_setting_store;
 mod open_message_repository;
 mod signed_entity_store;
@@ -10,6 +11,7 @@ mod single_signature_repository;
 mod stake_pool_store;
 
 pub use certificate_repository::*;
+pub use data_attestation_repository::*;
 pub use epoch_setting_store::*;
 pub use open_message_repository::*;
 pub use signed_entity_store::*;
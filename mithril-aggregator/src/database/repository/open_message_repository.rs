@@ -51,6 +51,16 @@ impl OpenMessageRepository {
         )
     }
 
+    /// Return the open messages with their associated single signatures that are neither
+    /// certified nor expired.
+    pub async fn get_current_open_messages_with_single_signatures(
+        &self,
+    ) -> StdResult<Vec<OpenMessageWithSingleSignaturesRecord>> {
+        self.connection.fetch_collect(
+            GetOpenMessageWithSingleSignaturesQuery::not_certified_nor_expired(Utc::now()),
+        )
+    }
+
     /// Return the expired [OpenMessageRecord] for the given Epoch and [SignedEntityType] if it exists
     pub async fn get_expired_open_message(
         &self,
@@ -379,4 +389,54 @@ mod tests {
             .single_signatures
             .is_empty())
     }
+
+    #[tokio::test]
+    async fn repository_get_current_open_messages_with_single_signatures() {
+        let connection = get_connection().await;
+        let repository = OpenMessageRepository::new(connection.clone());
+        let epoch = Epoch(1);
+
+        let mut open_messages = vec![];
+        for signed_entity_type in [
+            SignedEntityType::MithrilStakeDistribution(epoch),
+            SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon::new("devnet", *epoch, 1)),
+            SignedEntityType::CardanoTransactions(epoch, 100),
+            SignedEntityType::CardanoStakeDistribution(epoch),
+        ] {
+            open_messages.push(
+                repository
+                    .create_open_message(epoch, &signed_entity_type, &ProtocolMessage::new())
+                    .await
+                    .unwrap(),
+            );
+        }
+        let certified = OpenMessageRecord {
+            is_certified: true,
+            ..open_messages[1].clone()
+        };
+        let expired = OpenMessageRecord {
+            is_expired: true,
+            ..open_messages[2].clone()
+        };
+        let past_expiration_date = OpenMessageRecord {
+            expires_at: Some(Utc::now() - chrono::Days::new(1)),
+            ..open_messages[3].clone()
+        };
+        for open_message in [certified, expired, past_expiration_date] {
+            repository.update_open_message(&open_message).await.unwrap();
+        }
+
+        let current_open_messages = repository
+            .get_current_open_messages_with_single_signatures()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![open_messages[0].signed_entity_type.clone()],
+            current_open_messages
+                .into_iter()
+                .map(|record| record.signed_entity_type)
+                .collect::<Vec<_>>()
+        );
+    }
 }
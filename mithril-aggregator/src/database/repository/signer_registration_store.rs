@@ -3,6 +3,8 @@ use std::sync::Arc;
 
 use anyhow::Context;
 use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
 
 use mithril_common::entities::{Epoch, PartyId, Signer, SignerWithStake};
 use mithril_common::StdResult;
@@ -16,6 +18,14 @@ use crate::database::query::{
 use crate::database::record::SignerRegistrationRecord;
 use crate::VerificationKeyStorer;
 
+/// Service to get [SignerRegistrationRecord].
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SignerRegistrationGetter: Sync + Send {
+    /// Return the records of the signers registered for the given recording [Epoch].
+    async fn get_by_epoch(&self, epoch: Epoch) -> StdResult<Vec<SignerRegistrationRecord>>;
+}
+
 /// Service to deal with signer_registration (read & write).
 pub struct SignerRegistrationStore {
     connection: Arc<SqliteConnection>,
@@ -110,8 +120,19 @@ impl VerificationKeyStorer for SignerRegistrationStore {
     }
 }
 
+#[async_trait]
+impl SignerRegistrationGetter for SignerRegistrationStore {
+    async fn get_by_epoch(&self, epoch: Epoch) -> StdResult<Vec<SignerRegistrationRecord>> {
+        self.connection
+            .fetch_collect(GetSignerRegistrationRecordQuery::by_epoch(epoch)?)
+            .with_context(|| format!("get signer registrations failure, epoch: {epoch}"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use mithril_common::test_utils::fake_data;
+
     use crate::database::test_helper::{insert_signer_registrations, main_db_connection};
     use crate::store::test_verification_key_storer;
 
@@ -150,6 +171,31 @@ mod tests {
             .expect("Signer registration should exist for this epoch");
     }
 
+    #[tokio::test]
+    async fn get_signer_registration_records_by_epoch() {
+        let connection = main_db_connection().unwrap();
+        let signers = fake_data::signers_with_stakes(3);
+        insert_signer_registrations(
+            &connection,
+            vec![
+                (Epoch(1), signers[0..2].to_vec()),
+                (Epoch(2), signers[2..].to_vec()),
+            ],
+        )
+        .unwrap();
+        let store = SignerRegistrationStore::new(Arc::new(connection));
+
+        let records = store.get_by_epoch(Epoch(1)).await.unwrap();
+
+        let mut party_ids: Vec<_> = records.into_iter().map(|r| r.signer_id).collect();
+        party_ids.sort();
+        let mut expected_party_ids: Vec<_> =
+            signers[0..2].iter().map(|s| s.party_id.clone()).collect();
+        expected_party_ids.sort();
+        assert_eq!(expected_party_ids, party_ids);
+        assert!(store.get_by_epoch(Epoch(3)).await.unwrap().is_empty());
+    }
+
     pub fn init_signer_registration_store(
         initial_data: Vec<(Epoch, HashMap<PartyId, SignerWithStake>)>,
     ) -> Arc<dyn VerificationKeyStorer> {
@@ -32,8 +32,10 @@ use mithril_common::{
     },
     signable_builder::{
         CardanoImmutableFilesFullSignableBuilder, CardanoTransactionsSignableBuilder,
-        MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
-        SignableBuilderService, TransactionsImporter,
+        CardanoUtxoSetSignableBuilder, DataAttestationSignableBuilder,
+        EpochBoundaryUtxoSetRetriever, MithrilSignableBuilderService,
+        MithrilStakeDistributionSignableBuilder, SignableBuilderService, TransactionsImporter,
+        UtxoSetRetriever,
     },
     signed_entity_type_lock::SignedEntityTypeLock,
     MithrilTickerService, TickerService,
@@ -47,28 +49,34 @@ use mithril_persistence::{
 use crate::{
     artifact_builder::{
         CardanoImmutableFilesFullArtifactBuilder, CardanoTransactionsArtifactBuilder,
+        CardanoUtxoSetArtifactBuilder, DataAttestationArtifactBuilder,
         MithrilStakeDistributionArtifactBuilder,
     },
     configuration::ExecutionEnvironment,
     database::repository::{
-        CertificateRepository, EpochSettingStore, OpenMessageRepository, SignedEntityStore,
-        SignedEntityStorer, SignerRegistrationStore, SignerStore, SingleSignatureRepository,
-        StakePoolStore,
+        CertificateRepository, DataAttestationRepository, EpochSettingStore, OpenMessageRepository,
+        SignedEntityStore, SignedEntityStorer, SignerRegistrationStore, SignerStore,
+        SingleSignatureRepository, StakePoolStore,
     },
     event_store::{EventMessage, EventStore, TransmitterService},
     http_server::routes::router,
     services::{
-        CardanoTransactionsImporter, CertifierService, MessageService, MithrilCertifierService,
-        MithrilEpochService, MithrilMessageService, MithrilProverService,
-        MithrilSignedEntityService, MithrilStakeDistributionService, ProverService,
-        SignedEntityService, StakeDistributionService,
+        CardanoTransactionsImporter, CertifierService, DataAttestationService, MessageService,
+        MithrilCertifierService, MithrilDataAttestationService, MithrilEpochService,
+        MithrilMessageService, MithrilProverService, MithrilSignedEntityService,
+        MithrilStakeDistributionService, ProverService, SignedEntityService,
+        StakeDistributionService,
+    },
+    tools::{
+        AggregatorHttpInventoryRetriever, CExplorerSignerRetriever, DatabaseLocalInventoryReader,
+        GcpFileUploader, GenesisToolsDependency, PeerReconciler, SignersImporter,
     },
-    tools::{CExplorerSignerRetriever, GcpFileUploader, GenesisToolsDependency, SignersImporter},
     AggregatorConfig, AggregatorRunner, AggregatorRuntime, CertificatePendingStore,
     CompressedArchiveSnapshotter, Configuration, DependencyContainer, DumbSnapshotUploader,
     DumbSnapshotter, LocalSnapshotUploader, MithrilSignerRegisterer, MultiSigner, MultiSignerImpl,
-    ProtocolParametersStorer, RemoteSnapshotUploader, SnapshotUploader, SnapshotUploaderType,
-    Snapshotter, SnapshotterCompressionAlgorithm, VerificationKeyStorer,
+    ProtocolParametersStorer, RemoteSnapshotUploader, SnapshotRestorabilityVerifier,
+    SnapshotUploader, SnapshotUploaderType, Snapshotter, SnapshotterCompressionAlgorithm,
+    UnpackingSnapshotRestorabilityVerifier, VerificationKeyStorer,
 };
 
 use super::{DependenciesBuilderError, EpochServiceWrapper, Result};
@@ -119,6 +127,9 @@ pub struct DependenciesBuilder {
     /// Open message repository.
     pub open_message_repository: Option<Arc<OpenMessageRepository>>,
 
+    /// Signer registration store.
+    pub signer_registration_store: Option<Arc<SignerRegistrationStore>>,
+
     /// Verification key store.
     pub verification_key_store: Option<Arc<dyn VerificationKeyStorer>>,
 
@@ -217,6 +228,12 @@ pub struct DependenciesBuilder {
 
     /// Transactions Importer
     pub transactions_importer: Option<Arc<dyn TransactionsImporter>>,
+
+    /// UTxO Set Retriever
+    pub utxo_set_retriever: Option<Arc<dyn UtxoSetRetriever>>,
+
+    /// Data attestation service
+    pub data_attestation_service: Option<Arc<dyn DataAttestationService>>,
 }
 
 impl DependenciesBuilder {
@@ -233,6 +250,7 @@ impl DependenciesBuilder {
             certificate_pending_store: None,
             certificate_repository: None,
             open_message_repository: None,
+            signer_registration_store: None,
             verification_key_store: None,
             protocol_parameters_store: None,
             cardano_cli_runner: None,
@@ -265,6 +283,8 @@ impl DependenciesBuilder {
             prover_service: None,
             signed_entity_type_lock: None,
             transactions_importer: None,
+            utxo_set_retriever: None,
+            data_attestation_service: None,
         }
     }
 
@@ -535,12 +555,25 @@ impl DependenciesBuilder {
         Ok(self.open_message_repository.as_ref().cloned().unwrap())
     }
 
-    async fn build_verification_key_store(&mut self) -> Result<Arc<dyn VerificationKeyStorer>> {
+    async fn build_signer_registration_store(&mut self) -> Result<Arc<SignerRegistrationStore>> {
         Ok(Arc::new(SignerRegistrationStore::new(
             self.get_sqlite_connection().await?,
         )))
     }
 
+    /// Get a configured [SignerRegistrationStore].
+    pub async fn get_signer_registration_store(&mut self) -> Result<Arc<SignerRegistrationStore>> {
+        if self.signer_registration_store.is_none() {
+            self.signer_registration_store = Some(self.build_signer_registration_store().await?);
+        }
+
+        Ok(self.signer_registration_store.as_ref().cloned().unwrap())
+    }
+
+    async fn build_verification_key_store(&mut self) -> Result<Arc<dyn VerificationKeyStorer>> {
+        Ok(self.get_signer_registration_store().await?)
+    }
+
     /// Get a configured [VerificationKeyStorer].
     pub async fn get_verification_key_store(&mut self) -> Result<Arc<dyn VerificationKeyStorer>> {
         if self.verification_key_store.is_none() {
@@ -756,7 +789,12 @@ impl DependenciesBuilder {
             ExecutionEnvironment::Production => Some(self.get_immutable_cache_provider().await?),
             _ => None,
         };
-        let digester = CardanoImmutableDigester::new(immutable_digester_cache, self.get_logger()?);
+        let digester = CardanoImmutableDigester::new(immutable_digester_cache, self.get_logger()?)
+            .with_io_config(
+                self.configuration
+                    .immutable_digester_io_config
+                    .unwrap_or_default(),
+            );
 
         Ok(Arc::new(digester))
     }
@@ -799,6 +837,33 @@ impl DependenciesBuilder {
         Ok(snapshotter)
     }
 
+    async fn build_snapshot_restorability_verifier(
+        &mut self,
+    ) -> Result<Option<Arc<dyn SnapshotRestorabilityVerifier>>> {
+        // Only the archives built in production can be restored
+        if !self.configuration.snapshot_restorability_check
+            || self.configuration.environment != ExecutionEnvironment::Production
+        {
+            return Ok(None);
+        }
+
+        // The digests cache is not used since the cached digests are the ones of the
+        // original immutable files
+        let digester = CardanoImmutableDigester::new(None, self.get_logger()?).with_io_config(
+            self.configuration
+                .immutable_digester_io_config
+                .unwrap_or_default(),
+        );
+
+        Ok(Some(Arc::new(UnpackingSnapshotRestorabilityVerifier::new(
+            self.configuration
+                .snapshot_directory
+                .join("restorability_check"),
+            self.configuration.snapshot_compression_algorithm,
+            Arc::new(digester),
+        ))))
+    }
+
     /// [Snapshotter] service.
     pub async fn get_snapshotter(&mut self) -> Result<Arc<dyn Snapshotter>> {
         if self.snapshotter.is_none() {
@@ -1077,10 +1142,16 @@ impl DependenciesBuilder {
             block_range_root_retriever,
             self.get_logger()?,
         ));
+        let cardano_utxo_set_builder = Arc::new(CardanoUtxoSetSignableBuilder::new(
+            self.get_utxo_set_retriever().await?,
+            self.get_logger()?,
+        ));
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             mithril_stake_distribution_builder,
             immutable_signable_builder,
             cardano_transactions_builder,
+            cardano_utxo_set_builder,
+            Arc::new(DataAttestationSignableBuilder::default()),
         ));
 
         Ok(signable_builder_service)
@@ -1107,21 +1178,49 @@ impl DependenciesBuilder {
         let cardano_node_version = Version::parse(&self.configuration.cardano_node_version)
             .map_err(|e| DependenciesBuilderError::Initialization { message: format!("Could not parse configuration setting 'cardano_node_version' value '{}' as Semver.", self.configuration.cardano_node_version), error: Some(e.into()) })?;
         let cardano_immutable_files_full_artifact_builder =
-            Arc::new(CardanoImmutableFilesFullArtifactBuilder::new(
+            CardanoImmutableFilesFullArtifactBuilder::new(
                 &cardano_node_version,
                 snapshotter,
                 snapshot_uploader,
                 self.configuration.snapshot_compression_algorithm,
-            ));
+            );
+        let cardano_immutable_files_full_artifact_builder =
+            Arc::new(match self.build_snapshot_restorability_verifier().await? {
+                Some(restorability_verifier) => cardano_immutable_files_full_artifact_builder
+                    .with_restorability_verifier(restorability_verifier),
+                None => cardano_immutable_files_full_artifact_builder,
+            });
         let prover_service = self.get_prover_service().await?;
         let cardano_transactions_artifact_builder = Arc::new(
             CardanoTransactionsArtifactBuilder::new(prover_service.clone()),
         );
+        let cardano_utxo_set_directory = self
+            .configuration
+            .snapshot_directory
+            .join("cardano_utxo_set");
+        if !cardano_utxo_set_directory.exists() {
+            std::fs::create_dir_all(&cardano_utxo_set_directory).map_err(|e| {
+                DependenciesBuilderError::Initialization {
+                    message: format!(
+                        "Could not create Cardano UTxO set directory: '{}'.",
+                        cardano_utxo_set_directory.display()
+                    ),
+                    error: Some(e.into()),
+                }
+            })?;
+        }
+        let cardano_utxo_set_artifact_builder = Arc::new(CardanoUtxoSetArtifactBuilder::new(
+            self.get_utxo_set_retriever().await?,
+            &cardano_utxo_set_directory,
+            self.configuration.get_server_url(),
+        ));
         let signed_entity_service = Arc::new(MithrilSignedEntityService::new(
             signed_entity_storer,
             mithril_stake_distribution_artifact_builder,
             cardano_immutable_files_full_artifact_builder,
             cardano_transactions_artifact_builder,
+            cardano_utxo_set_artifact_builder,
+            Arc::new(DataAttestationArtifactBuilder::default()),
         ));
 
         // Compute the cache pool for prover service
@@ -1218,6 +1317,56 @@ impl DependenciesBuilder {
         Ok(self.transactions_importer.as_ref().cloned().unwrap())
     }
 
+    async fn build_utxo_set_retriever(&mut self) -> Result<Arc<dyn UtxoSetRetriever>> {
+        let utxo_set_retriever = Arc::new(EpochBoundaryUtxoSetRetriever::new(
+            self.get_chain_observer().await?,
+            &self.configuration.db_directory,
+            &self
+                .configuration
+                .snapshot_directory
+                .join("cardano_utxo_set_snapshots"),
+            self.get_logger()?,
+        ));
+
+        Ok(utxo_set_retriever)
+    }
+
+    async fn get_utxo_set_retriever(&mut self) -> Result<Arc<dyn UtxoSetRetriever>> {
+        if self.utxo_set_retriever.is_none() {
+            self.utxo_set_retriever = Some(self.build_utxo_set_retriever().await?);
+        }
+
+        Ok(self.utxo_set_retriever.as_ref().cloned().unwrap())
+    }
+
+    async fn build_data_attestation_service(&mut self) -> Result<Arc<dyn DataAttestationService>> {
+        let data_attestation_service = Arc::new(MithrilDataAttestationService::new(
+            self.configuration
+                .data_attestation_operators
+                .clone()
+                .unwrap_or_default(),
+            self.get_signed_entity_config()?
+                .list_allowed_signed_entity_types_discriminants(),
+            self.get_era_checker().await?,
+            Arc::new(DataAttestationRepository::new(
+                self.get_sqlite_connection().await?,
+            )),
+        ));
+
+        Ok(data_attestation_service)
+    }
+
+    /// [DataAttestationService] service
+    pub async fn get_data_attestation_service(
+        &mut self,
+    ) -> Result<Arc<dyn DataAttestationService>> {
+        if self.data_attestation_service.is_none() {
+            self.data_attestation_service = Some(self.build_data_attestation_service().await?);
+        }
+
+        Ok(self.data_attestation_service.as_ref().cloned().unwrap())
+    }
+
     /// Return an unconfigured [DependencyContainer]
     pub async fn build_dependency_container(&mut self) -> Result<DependencyContainer> {
         let dependency_manager = DependencyContainer {
@@ -1256,11 +1405,13 @@ impl DependenciesBuilder {
             ticker_service: self.get_ticker_service().await?,
             signed_entity_storer: self.get_signed_entity_storer().await?,
             signer_getter: self.get_signer_store().await?,
+            signer_registration_getter: self.get_signer_registration_store().await?,
             message_service: self.get_message_service().await?,
             block_scanner: self.get_block_scanner().await?,
             transaction_store: self.get_transaction_repository().await?,
             prover_service: self.get_prover_service().await?,
             signed_entity_type_lock: self.get_signed_entity_lock().await?,
+            data_attestation_service: self.get_data_attestation_service().await?,
         };
 
         Ok(dependency_manager)
@@ -1352,6 +1503,28 @@ impl DependenciesBuilder {
         Ok(SignersImporter::new(Arc::new(retriever), persister))
     }
 
+    /// Create a [PeerReconciler] instance.
+    pub async fn create_peer_reconciler(
+        &mut self,
+        peer_aggregator_endpoint: &str,
+    ) -> Result<PeerReconciler> {
+        let peer_retriever = AggregatorHttpInventoryRetriever::new(
+            peer_aggregator_endpoint,
+            Some(Duration::from_secs(30)),
+        )?;
+        let local_reader = DatabaseLocalInventoryReader::new(
+            self.get_certificate_repository().await?,
+            self.get_signed_entity_storer().await?,
+        );
+
+        Ok(PeerReconciler::new(
+            peer_aggregator_endpoint,
+            Arc::new(peer_retriever),
+            Arc::new(local_reader),
+            self.get_event_transmitter().await?,
+        ))
+    }
+
     /// Create [TickerService] instance.
     pub async fn build_ticker_service(&mut self) -> Result<Arc<dyn TickerService>> {
         let chain_observer = self.get_chain_observer().await?;
@@ -22,13 +22,13 @@ use crate::{
     configuration::*,
     database::repository::{
         CertificateRepository, OpenMessageRepository, SignedEntityStorer, SignerGetter,
-        StakePoolStore,
+        SignerRegistrationGetter, StakePoolStore,
     },
     event_store::{EventMessage, TransmitterService},
     multi_signer::MultiSigner,
     services::{
-        CertifierService, EpochService, MessageService, ProverService, SignedEntityService,
-        StakeDistributionService, TransactionStore,
+        CertifierService, DataAttestationService, EpochService, MessageService, ProverService,
+        SignedEntityService, StakeDistributionService, TransactionStore,
     },
     signer_registerer::SignerRecorder,
     snapshot_uploaders::SnapshotUploader,
@@ -153,6 +153,9 @@ pub struct DependencyContainer {
     /// Signer getter service
     pub signer_getter: Arc<dyn SignerGetter>,
 
+    /// Signer registration getter service
+    pub signer_registration_getter: Arc<dyn SignerRegistrationGetter>,
+
     /// HTTP message service
     pub message_service: Arc<dyn MessageService>,
 
@@ -161,6 +164,9 @@ pub struct DependencyContainer {
 
     /// Signed Entity Type Lock
     pub signed_entity_type_lock: Arc<SignedEntityTypeLock>,
+
+    /// Data attestation service
+    pub data_attestation_service: Arc<dyn DataAttestationService>,
 }
 
 #[doc(hidden)]
@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use mithril_common::entities::{
+    PartyId, ProtocolParameters, SignedEntityType, SignerWithStake, Stake,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+use crate::entities::OpenMessage;
+
+/// Message structure of a certification dry run of an open message.
+///
+/// It reports if a certificate could be issued with the single signatures received so far,
+/// and which registered signers have not signed yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertificationDryRunMessage {
+    /// Type of the signed entity of the open message
+    pub signed_entity_type: SignedEntityType,
+
+    /// Is the quorum reached with the single signatures received so far
+    pub is_quorum_reached: bool,
+
+    /// Number of distinct lotteries won by the single signatures received so far
+    pub won_lotteries: u64,
+
+    /// Number of distinct lotteries to win to reach the quorum (the `k` protocol parameter)
+    pub quorum: u64,
+
+    /// Stake of the signers that sent a single signature
+    pub signed_stake: Stake,
+
+    /// Stake of all the registered signers
+    pub total_stake: Stake,
+
+    /// Has the open message already been certified
+    pub is_certified: bool,
+
+    /// Has the open message expired
+    pub is_expired: bool,
+
+    /// Date and time at which the open message expires, if it does
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Registered signers that have not sent a single signature, ordered by decreasing stake
+    pub missing_signers: Vec<CertificationDryRunMissingSignerMessage>,
+}
+
+/// Message structure of a registered signer missing in a certification dry run
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertificationDryRunMissingSignerMessage {
+    /// The registered signer party id
+    pub party_id: PartyId,
+
+    /// The registered signer stake
+    pub stake: Stake,
+}
+
+impl CertificationDryRunMessage {
+    /// Build a [CertificationDryRunMessage] from an open message and the signers registered
+    /// for its signing epoch.
+    pub fn new(
+        open_message: &OpenMessage,
+        protocol_parameters: &ProtocolParameters,
+        signers_with_stake: &[SignerWithStake],
+        is_quorum_reached: bool,
+    ) -> Self {
+        let signers_id = open_message.get_signers_id();
+        let won_lotteries = open_message
+            .single_signatures
+            .iter()
+            .flat_map(|signature| signature.won_indexes.iter())
+            .collect::<BTreeSet<_>>()
+            .len() as u64;
+        let (signers, mut missing_signers): (Vec<_>, Vec<_>) = signers_with_stake
+            .iter()
+            .partition(|signer| signers_id.contains(&signer.party_id));
+        missing_signers.sort_by(|a, b| {
+            b.stake
+                .cmp(&a.stake)
+                .then_with(|| a.party_id.cmp(&b.party_id))
+        });
+
+        Self {
+            signed_entity_type: open_message.signed_entity_type.clone(),
+            is_quorum_reached,
+            won_lotteries,
+            quorum: protocol_parameters.k,
+            signed_stake: signers.iter().map(|signer| signer.stake).sum(),
+            total_stake: signers_with_stake.iter().map(|signer| signer.stake).sum(),
+            is_certified: open_message.is_certified,
+            is_expired: open_message.is_expired,
+            expires_at: open_message.expires_at,
+            missing_signers: missing_signers
+                .into_iter()
+                .map(|signer| CertificationDryRunMissingSignerMessage {
+                    party_id: signer.party_id.clone(),
+                    stake: signer.stake,
+                })
+                .collect(),
+        }
+    }
+
+    #[cfg(test)]
+    /// Create a dumb CertificationDryRunMessage instance mainly for test purposes
+    pub fn dummy() -> Self {
+        use mithril_common::test_utils::fake_data;
+
+        Self::new(
+            &OpenMessage::dummy(),
+            &fake_data::protocol_parameters(),
+            &fake_data::signers_with_stakes(5),
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::SingleSignatures;
+    use mithril_common::test_utils::fake_data;
+
+    use super::*;
+
+    fn signer_with_stake(party_id: &str, stake: Stake) -> SignerWithStake {
+        SignerWithStake {
+            party_id: party_id.to_string(),
+            stake,
+            ..fake_data::signers_with_stakes(1)[0].clone()
+        }
+    }
+
+    fn single_signature(party_id: &str, won_indexes: Vec<u64>) -> SingleSignatures {
+        SingleSignatures {
+            party_id: party_id.to_string(),
+            won_indexes,
+            ..fake_data::single_signatures(vec![1])
+        }
+    }
+
+    #[test]
+    fn build_from_open_message_and_registered_signers() {
+        let open_message = OpenMessage {
+            single_signatures: vec![
+                single_signature("signer-1", vec![1, 3, 5]),
+                single_signature("signer-2", vec![3, 7]),
+            ],
+            ..OpenMessage::dummy()
+        };
+        let signers_with_stake = vec![
+            signer_with_stake("signer-1", 10),
+            signer_with_stake("signer-2", 20),
+            signer_with_stake("signer-3", 5),
+            signer_with_stake("signer-4", 50),
+            signer_with_stake("signer-5", 5),
+        ];
+
+        let message = CertificationDryRunMessage::new(
+            &open_message,
+            &ProtocolParameters::new(10, 100, 0.65),
+            &signers_with_stake,
+            false,
+        );
+
+        assert_eq!(
+            CertificationDryRunMessage {
+                signed_entity_type: open_message.signed_entity_type.clone(),
+                is_quorum_reached: false,
+                won_lotteries: 4,
+                quorum: 10,
+                signed_stake: 30,
+                total_stake: 90,
+                is_certified: open_message.is_certified,
+                is_expired: open_message.is_expired,
+                expires_at: open_message.expires_at,
+                missing_signers: vec![
+                    CertificationDryRunMissingSignerMessage {
+                        party_id: "signer-4".to_string(),
+                        stake: 50,
+                    },
+                    CertificationDryRunMissingSignerMessage {
+                        party_id: "signer-3".to_string(),
+                        stake: 5,
+                    },
+                    CertificationDryRunMissingSignerMessage {
+                        party_id: "signer-5".to_string(),
+                        stake: 5,
+                    },
+                ],
+            },
+            message
+        );
+    }
+}
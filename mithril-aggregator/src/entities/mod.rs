@@ -1,12 +1,12 @@
 //! Entities module
 //!
 //! This module provide domain entities for the services & state machine.
+mod certification_dry_run_message;
 mod open_message;
-mod signer_registration_message;
 mod signer_ticker_message;
 
-pub use open_message::OpenMessage;
-pub use signer_registration_message::{
-    SignerRegistrationsListItemMessage, SignerRegistrationsMessage,
+pub use certification_dry_run_message::{
+    CertificationDryRunMessage, CertificationDryRunMissingSignerMessage,
 };
+pub use open_message::OpenMessage;
 pub use signer_ticker_message::{SignerTickerListItemMessage, SignersTickersMessage};
@@ -1,5 +1,7 @@
-use anyhow::anyhow;
+use anyhow::{anyhow, Context};
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use std::{collections::HashMap, sync::Arc};
 
@@ -9,6 +11,8 @@ use mithril_persistence::sqlite::{
     SqliteConnection, WhereCondition,
 };
 
+use super::{EventPayload, EVENT_SCHEMA_VERSION};
+
 /// Event that is sent from a thread to be persisted.
 #[derive(Debug, Clone)]
 pub struct EventMessage {
@@ -18,12 +22,8 @@ pub struct EventMessage {
     /// `HTTP::register_signer`.
     pub source: String,
 
-    /// The action represent the action that is going to be declared and as such
-    /// represents the type of the JSON content.
-    pub action: String,
-
-    /// JSON content of the message, its type is declared in the action property.
-    pub content: String,
+    /// Typed payload of the message, its variant declares the action of the message.
+    pub payload: EventPayload,
 
     /// Headers
     pub headers: HashMap<String, String>,
@@ -31,15 +31,19 @@ pub struct EventMessage {
 
 impl EventMessage {
     /// Instanciate a new EventMessage.
-    pub fn new(source: &str, action: &str, content: &str) -> Self {
+    pub fn new(source: &str, payload: EventPayload) -> Self {
         Self {
             source: source.to_string(),
-            action: action.to_string(),
-            content: content.to_string(),
+            payload,
             headers: HashMap::new(),
         }
     }
 
+    /// The action of the message, given by its payload.
+    pub fn action(&self) -> &str {
+        self.payload.action()
+    }
+
     /// forge a new instance adding the given header
     pub fn add_header(mut self, name: &str, value: &str) -> Self {
         let _ = self.headers.insert(name.to_owned(), value.to_owned());
@@ -62,10 +66,43 @@ pub struct Event {
     /// the `action` of the original [EventMessage] this Event originates from.
     pub action: String,
 
-    /// the `content` of the original [EventMessage] this Event originates from.
+    /// the JSON [stored content][StoredEventContent] of the original [EventMessage] this Event
+    /// originates from.
     pub content: String,
 }
 
+/// JSON content of an [Event] as written in the database.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StoredEventContent {
+    /// Version of the schema of the payload, events written before the schemas were versioned
+    /// have none and are considered as version `0`.
+    #[serde(default)]
+    pub schema_version: u32,
+
+    /// Headers of the original [EventMessage]
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+
+    /// JSON content of the payload of the original [EventMessage]
+    pub content: Value,
+}
+
+impl Event {
+    /// Read the typed payload of the event, migrating it to the current schema version if it
+    /// was written with an older one.
+    pub fn payload(&self) -> StdResult<EventPayload> {
+        let stored_content: StoredEventContent = serde_json::from_str(&self.content)
+            .with_context(|| format!("Can not read the content of event '{}'", self.event_id))?;
+
+        EventPayload::from_stored(
+            &self.action,
+            stored_content.schema_version,
+            stored_content.content,
+            &stored_content.headers,
+        )
+    }
+}
+
 impl SqLiteEntity for Event {
     fn hydrate(row: sqlite::Row) -> Result<Self, HydrationError>
     where
@@ -108,16 +145,17 @@ struct InsertEventQuery {
 
 impl InsertEventQuery {
     fn one(message: EventMessage) -> StdResult<Self> {
+        let content = StoredEventContent {
+            schema_version: EVENT_SCHEMA_VERSION,
+            content: message.payload.content()?,
+            headers: message.headers,
+        };
         let condition = WhereCondition::new(
             "(source, action, content, created_at) values (?*, ?*, ?*, ?*)",
             vec![
                 sqlite::Value::String(message.source),
-                sqlite::Value::String(message.action),
-                sqlite::Value::String(format!(
-                    r#"{{"headers": {}, "content": {}}}"#,
-                    serde_json::to_string(&message.headers)?,
-                    message.content
-                )),
+                sqlite::Value::String(message.payload.action().to_string()),
+                sqlite::Value::String(serde_json::to_string(&content)?),
                 sqlite::Value::String(Utc::now().to_rfc3339()),
             ],
         );
@@ -180,9 +218,13 @@ impl EventPersister {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mithril_common::StdResult;
+    use mithril_common::{messages::SnapshotDownloadMessage, StdResult};
     use sqlite::Connection;
 
+    fn fake_payload() -> EventPayload {
+        EventPayload::SnapshotDownloaded(SnapshotDownloadMessage::dummy().into())
+    }
+
     #[test]
     fn event_projection() {
         let projection = Event::get_projection();
@@ -195,7 +237,7 @@ mod tests {
 
     #[test]
     fn provider_sql() {
-        let message = EventMessage::new("source", "action", "content");
+        let message = EventMessage::new("source", fake_payload());
         let (parameters, values) = InsertEventQuery::one(message).unwrap().filters().expand();
 
         assert_eq!(
@@ -209,9 +251,46 @@ mod tests {
     fn can_persist_event() -> StdResult<()> {
         let connection = Arc::new(Connection::open_thread_safe(":memory:").unwrap());
         let persister = EventPersister::new(connection);
-        let message = EventMessage::new("source", "action", "content");
+        let message = EventMessage::new("source", fake_payload());
 
         let _event = persister.persist(message)?;
         Ok(())
     }
+
+    #[test]
+    fn persisted_event_payload_can_be_read() -> StdResult<()> {
+        let connection = Arc::new(Connection::open_thread_safe(":memory:").unwrap());
+        let persister = EventPersister::new(connection);
+        let message = EventMessage::new("source", fake_payload()).add_header("epoch", "12");
+
+        let event = persister.persist(message)?;
+        let stored_content: StoredEventContent = serde_json::from_str(&event.content)?;
+
+        assert_eq!("snapshot_downloaded", event.action);
+        assert_eq!(EVENT_SCHEMA_VERSION, stored_content.schema_version);
+        assert_eq!(Some(&"12".to_string()), stored_content.headers.get("epoch"));
+        assert_eq!(fake_payload(), event.payload()?);
+        Ok(())
+    }
+
+    #[test]
+    fn event_payload_written_before_schema_versioning_is_migrated_on_read() -> StdResult<()> {
+        let message = SnapshotDownloadMessage::dummy();
+        let event = Event {
+            event_id: 1,
+            created_at: Utc::now(),
+            source: "HTTP::statistics".to_string(),
+            action: "snapshot_downloaded".to_string(),
+            content: format!(
+                r#"{{"headers": {{}}, "content": {}}}"#,
+                serde_json::to_string(&message)?
+            ),
+        };
+
+        assert_eq!(
+            EventPayload::SnapshotDownloaded(message.into()),
+            event.payload()?
+        );
+        Ok(())
+    }
 }
@@ -2,9 +2,14 @@
 //! This module proposes tools to send messages between processes and how to
 //! persist them in a separate database.
 mod event;
+mod payload;
 mod runner;
 mod transmitter_service;
 
-pub use event::{Event, EventMessage, EventPersister};
+pub use event::{Event, EventMessage, EventPersister, StoredEventContent};
+pub use payload::{
+    EventPayload, PeerDivergence, PeerDivergenceEventPayload, SignerRegistrationEventPayload,
+    SnapshotDownloadedEventPayload, EVENT_SCHEMA_VERSION,
+};
 pub use runner::EventStore;
 pub use transmitter_service::TransmitterService;
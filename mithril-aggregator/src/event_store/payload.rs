@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use mithril_common::{
+    entities::{
+        CardanoDbBeacon, CompressionAlgorithm, Epoch, PartyId, ProtocolMessage, SignedEntityType,
+        SignerWithStake, Stake,
+    },
+    messages::SnapshotDownloadMessage,
+    StdResult,
+};
+
+/// Version of the schemas of the [EventPayload] written in the event store.
+///
+/// It must be increased each time a payload schema changes, along with a migration of the
+/// previous version in [EventPayload::from_stored].
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Typed payload of an event, its variant gives the `action` of the event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", content = "content", rename_all = "snake_case")]
+pub enum EventPayload {
+    /// A signer has registered, or was already registered, to the aggregator.
+    RegisterSigner(SignerRegistrationEventPayload),
+
+    /// A snapshot has been downloaded by a client.
+    SnapshotDownloaded(SnapshotDownloadedEventPayload),
+
+    /// A divergence with a peer aggregator has been found by the reconciliation.
+    PeerDivergence(PeerDivergenceEventPayload),
+}
+
+/// Payload of a [EventPayload::RegisterSigner] event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignerRegistrationEventPayload {
+    /// The unique identifier of the signer
+    pub party_id: PartyId,
+
+    /// The stake of the signer
+    pub stake: Stake,
+
+    /// Current epoch of the aggregator when the signer registered
+    pub epoch: Option<Epoch>,
+
+    /// Version of the signer node
+    pub signer_node_version: Option<String>,
+}
+
+impl SignerRegistrationEventPayload {
+    /// SignerRegistrationEventPayload factory
+    pub fn new(
+        signer_with_stake: &SignerWithStake,
+        epoch: Option<Epoch>,
+        signer_node_version: Option<String>,
+    ) -> Self {
+        Self {
+            party_id: signer_with_stake.party_id.clone(),
+            stake: signer_with_stake.stake,
+            epoch,
+            signer_node_version,
+        }
+    }
+}
+
+/// Payload of a [EventPayload::SnapshotDownloaded] event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotDownloadedEventPayload {
+    /// Digest that is signed by the signer participants
+    pub digest: String,
+
+    /// Mithril beacon on the Cardano chain
+    pub beacon: CardanoDbBeacon,
+
+    /// Size of the snapshot file in Bytes
+    pub size: u64,
+
+    /// Compression algorithm of the snapshot archive
+    pub compression_algorithm: CompressionAlgorithm,
+
+    /// Cardano node version
+    pub cardano_node_version: String,
+}
+
+impl From<SnapshotDownloadMessage> for SnapshotDownloadedEventPayload {
+    fn from(message: SnapshotDownloadMessage) -> Self {
+        Self {
+            digest: message.digest,
+            beacon: message.beacon,
+            size: message.size,
+            compression_algorithm: message.compression_algorithm,
+            cardano_node_version: message.cardano_node_version,
+        }
+    }
+}
+
+/// A divergence between the local aggregator and a peer aggregator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PeerDivergence {
+    /// The peer has certified a signed entity type that the local aggregator has not certified.
+    MissingCertificate {
+        /// Signed entity type, with its beacon, certified by the peer
+        signed_entity_type: SignedEntityType,
+    },
+
+    /// The peer and the local aggregator have certified a different protocol message for the
+    /// same beacon.
+    DifferingCertificate {
+        /// Signed entity type, with its beacon, certified by the certificates
+        signed_entity_type: SignedEntityType,
+
+        /// Protocol message certified by the local aggregator
+        local_protocol_message: ProtocolMessage,
+
+        /// Protocol message certified by the peer
+        peer_protocol_message: ProtocolMessage,
+    },
+
+    /// The peer and the local aggregator have produced a different artifact for the same beacon.
+    DifferingArtifact {
+        /// Signed entity type, with its beacon, certified by the artifacts
+        signed_entity_type: SignedEntityType,
+
+        /// Id of the local artifact
+        local_artifact_id: String,
+
+        /// Id of the peer artifact
+        peer_artifact_id: String,
+    },
+}
+
+/// Payload of a [EventPayload::PeerDivergence] event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerDivergenceEventPayload {
+    /// Endpoint of the peer aggregator
+    pub peer_aggregator_endpoint: String,
+
+    /// Divergence found with the peer aggregator
+    pub divergence: PeerDivergence,
+}
+
+impl EventPayload {
+    /// Name of the action of the event
+    pub fn action(&self) -> &'static str {
+        match self {
+            Self::RegisterSigner(_) => "register_signer",
+            Self::SnapshotDownloaded(_) => "snapshot_downloaded",
+            Self::PeerDivergence(_) => "peer_divergence",
+        }
+    }
+
+    /// JSON content of the payload, without its action
+    pub fn content(&self) -> StdResult<Value> {
+        let mut value = serde_json::to_value(self)?;
+
+        value
+            .get_mut("content")
+            .map(Value::take)
+            .ok_or(anyhow!("Event payload '{}' has no content", self.action()))
+    }
+
+    /// Rebuild a payload from the `action` and the JSON `content` of a stored event written with
+    /// the given schema version, migrating it to the current schema version.
+    pub fn from_stored(
+        action: &str,
+        schema_version: u32,
+        content: Value,
+        headers: &HashMap<String, String>,
+    ) -> StdResult<Self> {
+        let content = match schema_version {
+            0 => Self::migrate_from_v0(action, content, headers),
+            EVENT_SCHEMA_VERSION => content,
+            _ => {
+                return Err(anyhow!(
+                    "Unsupported event schema version '{schema_version}', the latest supported is '{EVENT_SCHEMA_VERSION}'"
+                ))
+            }
+        };
+
+        serde_json::from_value(json!({ "action": action, "content": content })).with_context(
+            || format!("Can not read event payload of action '{action}' (schema version: '{schema_version}')"),
+        )
+    }
+
+    /// Before versioning, the signer epoch and node version were only sent as headers.
+    fn migrate_from_v0(
+        action: &str,
+        mut content: Value,
+        headers: &HashMap<String, String>,
+    ) -> Value {
+        if action == "register_signer" {
+            if let Some(content) = content.as_object_mut() {
+                content.insert(
+                    "epoch".to_string(),
+                    json!(headers
+                        .get("epoch")
+                        .and_then(|epoch| epoch.parse::<u64>().ok())),
+                );
+                content.insert(
+                    "signer_node_version".to_string(),
+                    json!(headers.get("signer-node-version")),
+                );
+            }
+        }
+
+        content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::fake_data;
+
+    use super::*;
+
+    fn fake_signer_registration() -> SignerRegistrationEventPayload {
+        SignerRegistrationEventPayload::new(
+            &fake_data::signers_with_stakes(1)[0],
+            Some(Epoch(12)),
+            Some("0.2.154".to_string()),
+        )
+    }
+
+    #[test]
+    fn payload_content_does_not_include_action() {
+        let payload = EventPayload::RegisterSigner(fake_signer_registration());
+
+        let content = payload.content().unwrap();
+
+        assert_eq!(
+            serde_json::to_value(fake_signer_registration()).unwrap(),
+            content
+        );
+    }
+
+    #[test]
+    fn read_stored_payload_of_current_schema_version() {
+        for payload in [
+            EventPayload::RegisterSigner(fake_signer_registration()),
+            EventPayload::SnapshotDownloaded(SnapshotDownloadMessage::dummy().into()),
+            EventPayload::PeerDivergence(PeerDivergenceEventPayload {
+                peer_aggregator_endpoint: "http://peer-aggregator".to_string(),
+                divergence: PeerDivergence::DifferingArtifact {
+                    signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(12)),
+                    local_artifact_id: "local-hash".to_string(),
+                    peer_artifact_id: "peer-hash".to_string(),
+                },
+            }),
+            EventPayload::PeerDivergence(PeerDivergenceEventPayload {
+                peer_aggregator_endpoint: "http://peer-aggregator".to_string(),
+                divergence: PeerDivergence::DifferingCertificate {
+                    signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(12)),
+                    local_protocol_message: ProtocolMessage::new(),
+                    peer_protocol_message: ProtocolMessage::new(),
+                },
+            }),
+        ] {
+            let read_payload = EventPayload::from_stored(
+                payload.action(),
+                EVENT_SCHEMA_VERSION,
+                payload.content().unwrap(),
+                &HashMap::new(),
+            )
+            .unwrap();
+
+            assert_eq!(payload, read_payload);
+        }
+    }
+
+    #[test]
+    fn migrate_v0_signer_registration_using_its_headers() {
+        let signer_with_stake = fake_data::signers_with_stakes(1)[0].clone();
+        let headers = HashMap::from([
+            ("epoch".to_string(), "12".to_string()),
+            ("signer-node-version".to_string(), "0.2.154".to_string()),
+        ]);
+
+        let payload = EventPayload::from_stored(
+            "register_signer",
+            0,
+            serde_json::to_value(&signer_with_stake).unwrap(),
+            &headers,
+        )
+        .unwrap();
+
+        assert_eq!(
+            EventPayload::RegisterSigner(SignerRegistrationEventPayload::new(
+                &signer_with_stake,
+                Some(Epoch(12)),
+                Some("0.2.154".to_string()),
+            )),
+            payload
+        );
+    }
+
+    #[test]
+    fn migrate_v0_signer_registration_without_headers() {
+        let signer_with_stake = fake_data::signers_with_stakes(1)[0].clone();
+
+        let payload = EventPayload::from_stored(
+            "register_signer",
+            0,
+            serde_json::to_value(&signer_with_stake).unwrap(),
+            &HashMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            EventPayload::RegisterSigner(SignerRegistrationEventPayload::new(
+                &signer_with_stake,
+                None,
+                None,
+            )),
+            payload
+        );
+    }
+
+    #[test]
+    fn migrate_v0_snapshot_downloaded() {
+        let message = SnapshotDownloadMessage::dummy();
+
+        let payload = EventPayload::from_stored(
+            "snapshot_downloaded",
+            0,
+            serde_json::to_value(&message).unwrap(),
+            &HashMap::new(),
+        )
+        .unwrap();
+
+        assert_eq!(EventPayload::SnapshotDownloaded(message.into()), payload);
+    }
+
+    #[test]
+    fn fail_to_read_stored_payload_of_a_newer_schema_version() {
+        let payload = EventPayload::RegisterSigner(fake_signer_registration());
+
+        EventPayload::from_stored(
+            payload.action(),
+            EVENT_SCHEMA_VERSION + 1,
+            payload.content().unwrap(),
+            &HashMap::new(),
+        )
+        .expect_err("A payload of an unknown schema version should not be read");
+    }
+
+    #[test]
+    fn fail_to_read_stored_payload_of_an_unknown_action() {
+        EventPayload::from_stored(
+            "unknown_action",
+            EVENT_SCHEMA_VERSION,
+            json!({}),
+            &HashMap::new(),
+        )
+        .expect_err("A payload of an unknown action should not be read");
+    }
+}
@@ -1,10 +1,9 @@
 use std::fmt::Debug;
 
-use serde::Serialize;
 use slog_scope::warn;
 use tokio::sync::mpsc::UnboundedSender;
 
-use super::EventMessage;
+use super::{EventMessage, EventPayload};
 
 /// The transmitter service is used to allow inter process channel
 /// communication. This service is used to create multiple transmitters.
@@ -31,30 +30,19 @@ where
 }
 
 impl TransmitterService<EventMessage> {
-    /// Craft and send an [EventMessage] given the serializable data.
+    /// Craft and send an [EventMessage] given its typed payload.
     /// This method is done in a way to make as simple as possible to send a
     /// message and make any error not to cause a business failure. A warning is
     /// issued so the resulting error may be discarded.
-    pub fn send_event_message<T>(
+    pub fn send_event_message(
         &self,
         source: &str,
-        action: &str,
-        content: &T,
+        payload: EventPayload,
         headers: Vec<(&str, &str)>,
-    ) -> Result<(), String>
-    where
-        T: Serialize,
-    {
-        let content = serde_json::to_string(content).map_err(|e| {
-            let error_msg = format!("Serialization error while forging event message: {e}");
-            warn!("Event message error => «{error_msg}»");
-
-            error_msg
-        })?;
+    ) -> Result<(), String> {
         let message = EventMessage {
             source: source.to_string(),
-            action: action.to_string(),
-            content,
+            payload,
             headers: headers
                 .into_iter()
                 .map(|(h, v)| (h.to_string(), v.to_string()))
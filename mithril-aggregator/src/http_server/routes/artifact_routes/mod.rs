@@ -1,4 +1,6 @@
 pub mod cardano_transaction;
+pub mod cardano_utxo_set;
+pub mod data_attestation;
 pub mod mithril_stake_distribution;
 pub mod snapshot;
 
@@ -0,0 +1,293 @@
+use std::sync::Arc;
+
+use warp::Filter;
+
+use crate::http_server::routes::middlewares;
+use crate::DependencyContainer;
+
+const AUTHORIZATION_HEADER: &str = "authorization";
+
+pub fn routes(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    register_attestation(dependency_manager)
+}
+
+/// POST /attestations
+fn register_attestation(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("attestations")
+        .and(warp::post())
+        .and(warp::header::optional::<String>(AUTHORIZATION_HEADER))
+        .and(warp::body::json())
+        .and(middlewares::with_data_attestation_service(
+            dependency_manager,
+        ))
+        .and_then(handlers::register_attestation)
+}
+
+mod handlers {
+    use mithril_common::entities::ClientError;
+    use mithril_common::messages::{
+        RegisterDataAttestationMessage, RegisterDataAttestationResponseMessage,
+    };
+    use slog_scope::{debug, warn};
+    use std::convert::Infallible;
+    use std::sync::Arc;
+    use warp::http::StatusCode;
+
+    use crate::http_server::routes::reply;
+    use crate::services::{DataAttestationService, DataAttestationServiceError};
+
+    /// Register a data attestation
+    pub async fn register_attestation(
+        authorization: Option<String>,
+        message: RegisterDataAttestationMessage,
+        data_attestation_service: Arc<dyn DataAttestationService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!("⇄ HTTP SERVER: register_attestation/{:?}", message);
+
+        let api_key = match authorization
+            .as_deref()
+            .and_then(|value| value.strip_prefix("Bearer "))
+        {
+            Some(api_key) => api_key.trim(),
+            None => {
+                warn!("register_attestation::missing_api_key");
+                return Ok(reply::json(
+                    &ClientError::new(
+                        "Unauthorized",
+                        "An 'Authorization: Bearer <api key>' header is expected",
+                    ),
+                    StatusCode::UNAUTHORIZED,
+                ));
+            }
+        };
+
+        match data_attestation_service
+            .submit_attestation(api_key, &message.namespace, &message.payload_hash)
+            .await
+        {
+            Ok(data_attestation) => Ok(reply::json(
+                &RegisterDataAttestationResponseMessage::from(data_attestation),
+                StatusCode::ACCEPTED,
+            )),
+            Err(err) => match err.downcast_ref::<DataAttestationServiceError>() {
+                Some(service_error) => {
+                    warn!("register_attestation::rejected"; "error" => ?service_error);
+                    let (label, status_code) = match service_error {
+                        DataAttestationServiceError::NotEnabled
+                        | DataAttestationServiceError::EraNotSupported(_) => {
+                            ("Data attestations not available", StatusCode::FORBIDDEN)
+                        }
+                        DataAttestationServiceError::Unauthorized => {
+                            ("Unauthorized", StatusCode::UNAUTHORIZED)
+                        }
+                        DataAttestationServiceError::NamespaceNotAllowed { .. } => {
+                            ("Namespace not allowed", StatusCode::FORBIDDEN)
+                        }
+                        DataAttestationServiceError::InvalidPayloadHash(_) => {
+                            ("Invalid payload hash", StatusCode::BAD_REQUEST)
+                        }
+                        DataAttestationServiceError::RateLimited { .. } => {
+                            ("Rate limited", StatusCode::TOO_MANY_REQUESTS)
+                        }
+                    };
+
+                    Ok(reply::json(
+                        &ClientError::new(label, service_error.to_string()),
+                        status_code,
+                    ))
+                }
+                None => {
+                    warn!("register_attestation::error"; "error" => ?err);
+                    Ok(reply::internal_server_error(err.to_string()))
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use mithril_common::entities::{DataAttestation, DataAttestationBeacon, Epoch};
+    use mithril_common::messages::RegisterDataAttestationMessage;
+    use mithril_common::test_utils::apispec::APISpec;
+    use warp::{
+        http::{Method, StatusCode},
+        test::request,
+    };
+
+    use crate::{
+        http_server::SERVER_BASE_PATH,
+        initialize_dependencies,
+        services::{DataAttestationServiceError, MockDataAttestationService},
+    };
+
+    use super::*;
+
+    fn setup_router(
+        dependency_manager: Arc<DependencyContainer>,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        let cors = warp::cors()
+            .allow_any_origin()
+            .allow_headers(vec!["content-type", "authorization"])
+            .allow_methods(vec![Method::GET, Method::POST, Method::OPTIONS]);
+
+        warp::any()
+            .and(warp::path(SERVER_BASE_PATH))
+            .and(routes(dependency_manager).with(cors))
+    }
+
+    async fn post_attestation(
+        data_attestation_service: MockDataAttestationService,
+        authorization: Option<&str>,
+    ) -> (
+        RegisterDataAttestationMessage,
+        warp::http::Response<warp::hyper::body::Bytes>,
+    ) {
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.data_attestation_service = Arc::new(data_attestation_service);
+        let message = RegisterDataAttestationMessage::dummy();
+
+        let mut request = request()
+            .method(Method::POST.as_str())
+            .path(&format!("/{SERVER_BASE_PATH}/attestations"))
+            .json(&message);
+        if let Some(authorization) = authorization {
+            request = request.header(AUTHORIZATION_HEADER, authorization);
+        }
+        let response = request
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        (message, response)
+    }
+
+    fn mock_submission_error(error: DataAttestationServiceError) -> MockDataAttestationService {
+        let mut data_attestation_service = MockDataAttestationService::new();
+        data_attestation_service
+            .expect_submit_attestation()
+            .return_once(|_, _, _| Err(error.into()));
+        data_attestation_service
+    }
+
+    #[tokio::test]
+    async fn test_register_attestation_post_accepted() {
+        let mut data_attestation_service = MockDataAttestationService::new();
+        data_attestation_service
+            .expect_submit_attestation()
+            .withf(|api_key, _, _| api_key == "api-key")
+            .return_once(|_, namespace, payload_hash| {
+                Ok(DataAttestation::new(DataAttestationBeacon::new(
+                    Epoch(5),
+                    namespace,
+                    payload_hash,
+                )))
+            });
+
+        let (message, response) =
+            post_attestation(data_attestation_service, Some("Bearer api-key")).await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            Method::POST.as_str(),
+            "/attestations",
+            "application/json",
+            &message,
+            &response,
+            &StatusCode::ACCEPTED,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_register_attestation_post_unauthorized_without_api_key() {
+        let mut data_attestation_service = MockDataAttestationService::new();
+        data_attestation_service.expect_submit_attestation().never();
+
+        let (message, response) = post_attestation(data_attestation_service, None).await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            Method::POST.as_str(),
+            "/attestations",
+            "application/json",
+            &message,
+            &response,
+            &StatusCode::UNAUTHORIZED,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_register_attestation_post_rejected_by_the_operator_policy() {
+        for (error, status_code) in [
+            (
+                DataAttestationServiceError::Unauthorized,
+                StatusCode::UNAUTHORIZED,
+            ),
+            (
+                DataAttestationServiceError::NamespaceNotAllowed {
+                    operator: "operator".to_string(),
+                    namespace: "namespace".to_string(),
+                },
+                StatusCode::FORBIDDEN,
+            ),
+            (
+                DataAttestationServiceError::InvalidPayloadHash("invalid".to_string()),
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                DataAttestationServiceError::RateLimited {
+                    operator: "operator".to_string(),
+                    limit: 1,
+                    epoch: Epoch(5),
+                },
+                StatusCode::TOO_MANY_REQUESTS,
+            ),
+            (
+                DataAttestationServiceError::NotEnabled,
+                StatusCode::FORBIDDEN,
+            ),
+        ] {
+            let (message, response) =
+                post_attestation(mock_submission_error(error), Some("Bearer api-key")).await;
+
+            APISpec::verify_conformity(
+                APISpec::get_all_spec_files(),
+                Method::POST.as_str(),
+                "/attestations",
+                "application/json",
+                &message,
+                &response,
+                &status_code,
+            )
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_attestation_post_ko_500() {
+        let mut data_attestation_service = MockDataAttestationService::new();
+        data_attestation_service
+            .expect_submit_attestation()
+            .return_once(|_, _, _| Err(anyhow!("an error")));
+
+        let (message, response) =
+            post_attestation(data_attestation_service, Some("Bearer api-key")).await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            Method::POST.as_str(),
+            "/attestations",
+            "application/json",
+            &message,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
+}
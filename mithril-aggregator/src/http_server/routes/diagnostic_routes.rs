@@ -0,0 +1,164 @@
+use crate::http_server::routes::middlewares;
+use crate::DependencyContainer;
+use std::sync::Arc;
+use warp::Filter;
+
+pub fn routes(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    certification_dry_run(dependency_manager)
+}
+
+/// GET /diagnostic/certification-dry-run
+fn certification_dry_run(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("diagnostic" / "certification-dry-run")
+        .and(warp::get())
+        .and(middlewares::with_certifier_service(dependency_manager))
+        .and_then(handlers::certification_dry_run)
+}
+
+mod handlers {
+    use crate::{http_server::routes::reply, services::CertifierService};
+
+    use slog_scope::{debug, warn};
+    use std::convert::Infallible;
+    use std::sync::Arc;
+    use warp::http::StatusCode;
+
+    /// Certification dry run of the open messages neither certified nor expired
+    pub async fn certification_dry_run(
+        certifier_service: Arc<dyn CertifierService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!("⇄ HTTP SERVER: diagnostic/certification-dry-run");
+
+        match certifier_service.dry_run_current_certificates().await {
+            Ok(messages) => Ok(reply::json(&messages, StatusCode::OK)),
+            Err(err) => {
+                warn!("certification_dry_run::error"; "error" => ?err);
+                Ok(reply::internal_server_error(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use mithril_common::test_utils::apispec::APISpec;
+    use serde_json::Value::Null;
+    use warp::{
+        http::{Method, StatusCode},
+        test::request,
+    };
+
+    use crate::{
+        entities::CertificationDryRunMessage, http_server::SERVER_BASE_PATH,
+        initialize_dependencies, services::MockCertifierService,
+    };
+
+    use super::*;
+
+    fn setup_router(
+        dependency_manager: Arc<DependencyContainer>,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        let cors = warp::cors()
+            .allow_any_origin()
+            .allow_headers(vec!["content-type"])
+            .allow_methods(vec![Method::GET, Method::POST, Method::OPTIONS]);
+
+        warp::any()
+            .and(warp::path(SERVER_BASE_PATH))
+            .and(routes(dependency_manager).with(cors))
+    }
+
+    #[tokio::test]
+    async fn test_certification_dry_run_get_ok_200() {
+        let method = Method::GET.as_str();
+        let path = "/diagnostic/certification-dry-run";
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_dry_run_current_certificates()
+            .return_once(|| Ok(vec![CertificationDryRunMessage::dummy()]))
+            .once();
+        dependency_manager.certifier_service = Arc::new(mock_certifier_service);
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_certification_dry_run_without_open_message_get_ok_200() {
+        let method = Method::GET.as_str();
+        let path = "/diagnostic/certification-dry-run";
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_dry_run_current_certificates()
+            .return_once(|| Ok(vec![]));
+        dependency_manager.certifier_service = Arc::new(mock_certifier_service);
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        assert_eq!("[]", response.body());
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_certification_dry_run_get_ko_500() {
+        let method = Method::GET.as_str();
+        let path = "/diagnostic/certification-dry-run";
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_dry_run_current_certificates()
+            .return_once(|| Err(anyhow!("an error")));
+        dependency_manager.certifier_service = Arc::new(mock_certifier_service);
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
+}
@@ -6,7 +6,7 @@ use warp::Filter;
 pub fn routes(
     dependency_manager: Arc<DependencyContainer>,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
-    epoch_settings(dependency_manager)
+    epoch_settings(dependency_manager.clone()).or(epoch_schedule(dependency_manager))
 }
 
 /// GET /epoch-settings
@@ -19,11 +19,21 @@ fn epoch_settings(
         .and_then(handlers::epoch_settings)
 }
 
+/// GET /epoch-settings/schedule
+fn epoch_schedule(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("epoch-settings" / "schedule")
+        .and(warp::get())
+        .and(middlewares::with_epoch_service(dependency_manager))
+        .and_then(handlers::epoch_schedule)
+}
+
 mod handlers {
     use crate::dependency_injection::EpochServiceWrapper;
     use crate::http_server::routes::reply;
-    use crate::ToEpochSettingsMessageAdapter;
-    use mithril_common::entities::EpochSettings;
+    use crate::{ToEpochScheduleMessageAdapter, ToEpochSettingsMessageAdapter};
+    use mithril_common::entities::{EpochSettings, EpochSettingsCalculator};
     use mithril_common::messages::ToMessageAdapter;
     use slog_scope::{debug, warn};
     use std::convert::Infallible;
@@ -56,12 +66,36 @@ mod handlers {
             }
         }
     }
+
+    /// Epoch schedule
+    pub async fn epoch_schedule(
+        epoch_service: EpochServiceWrapper,
+    ) -> Result<impl warp::Reply, Infallible> {
+        debug!("⇄ HTTP SERVER: epoch_schedule");
+        let epoch_service = epoch_service.read().await;
+
+        match epoch_service.epoch_of_current_data().and_then(|epoch| {
+            EpochSettingsCalculator::default()
+                .compute_schedule(epoch)
+                .map_err(|e| e.into())
+        }) {
+            Ok(epoch_schedule) => {
+                let epoch_schedule_message = ToEpochScheduleMessageAdapter::adapt(epoch_schedule);
+                Ok(reply::json(&epoch_schedule_message, StatusCode::OK))
+            }
+            Err(err) => {
+                warn!("epoch_schedule::error"; "error" => ?err);
+                Ok(reply::internal_server_error(err))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use mithril_common::{
         entities::Epoch,
+        messages::EpochScheduleMessage,
         test_utils::{apispec::APISpec, MithrilFixtureBuilder},
     };
     use serde_json::Value::Null;
@@ -138,4 +172,59 @@ mod tests {
         )
         .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_epoch_schedule_get_ok() {
+        let method = Method::GET.as_str();
+        let path = "/epoch-settings/schedule";
+        let mut dependency_manager = initialize_dependencies().await;
+        let fixture = MithrilFixtureBuilder::default().with_signers(5).build();
+        let epoch_service = FakeEpochService::from_fixture(Epoch(5), &fixture);
+        dependency_manager.epoch_service = Arc::new(RwLock::new(epoch_service));
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        let message: EpochScheduleMessage = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(Epoch(5), message.epoch);
+        assert_eq!(Epoch(6), message.signer_registration_epoch);
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_epoch_schedule_get_ko_500() {
+        let method = Method::GET.as_str();
+        let path = "/epoch-settings/schedule";
+        let dependency_manager = initialize_dependencies().await;
+
+        let response = request()
+            .method(method)
+            .path(&format!("/{SERVER_BASE_PATH}{path}"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
 }
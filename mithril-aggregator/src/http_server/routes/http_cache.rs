@@ -0,0 +1,358 @@
+use std::{convert::Infallible, io::Write};
+
+use flate2::{write::GzEncoder, Compression};
+use sha2::{Digest, Sha256};
+use slog_scope::warn;
+use warp::http::{header, HeaderValue, Method, StatusCode};
+use warp::hyper::{body, Body};
+use warp::reply::Response;
+use warp::{Filter, Rejection, Reply};
+
+/// Responses smaller than this size (in bytes) are not worth compressing.
+const MIN_COMPRESSION_SIZE: usize = 1024;
+
+/// Content encoding supported for the JSON responses, in order of preference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContentEncoding {
+    Zstd,
+    Gzip,
+}
+
+impl ContentEncoding {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Zstd => "zstd",
+            Self::Gzip => "gzip",
+        }
+    }
+
+    /// Select the preferred encoding accepted by the client given its `Accept-Encoding` header.
+    fn select(accept_encoding: &str) -> Option<Self> {
+        let accepted: Vec<&str> = accept_encoding
+            .split(',')
+            .filter_map(|coding| {
+                let mut parts = coding.split(';').map(str::trim);
+                let name = parts.next()?;
+                let refused = parts.any(|param| {
+                    param
+                        .strip_prefix("q=")
+                        .and_then(|q| q.parse::<f32>().ok())
+                        .is_some_and(|q| q == 0.0)
+                });
+
+                (!refused).then_some(name)
+            })
+            .collect();
+
+        [Self::Zstd, Self::Gzip]
+            .into_iter()
+            .find(|encoding| accepted.contains(&encoding.name()))
+    }
+
+    fn encode(&self, content: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Zstd => zstd::encode_all(content, zstd::DEFAULT_COMPRESSION_LEVEL),
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(content)?;
+                encoder.finish()
+            }
+        }
+    }
+}
+
+/// Add an `ETag` to the JSON responses of the given routes, answer `304 Not Modified` to the
+/// requests whose `If-None-Match` header matches it, and compress them with `zstd` or `gzip`
+/// if the client accepts it.
+///
+/// The `ETag` is computed on the uncompressed content and shared by all its encodings, so it is
+/// a weak validator and the responses vary on `Accept-Encoding`.
+///
+/// Only successful `GET` responses with a JSON body are handled, responses that already carry a
+/// cache validator (ie: downloaded files) are left untouched.
+pub fn with_etag_and_compression<F, R>(
+    routes: F,
+) -> impl Filter<Extract = (Response,), Error = Rejection> + Clone
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone + Send + Sync + 'static,
+    R: Reply,
+{
+    warp::method()
+        .and(warp::header::optional::<String>(
+            header::IF_NONE_MATCH.as_str(),
+        ))
+        .and(warp::header::optional::<String>(
+            header::ACCEPT_ENCODING.as_str(),
+        ))
+        .and(routes)
+        .and_then(
+            |method: Method,
+             if_none_match: Option<String>,
+             accept_encoding: Option<String>,
+             reply: R| async move {
+                Ok::<_, Infallible>(
+                    cache_and_encode(
+                        method,
+                        if_none_match,
+                        accept_encoding,
+                        reply.into_response(),
+                    )
+                    .await,
+                )
+            },
+        )
+}
+
+fn is_cacheable(method: &Method, response: &Response) -> bool {
+    let headers = response.headers();
+
+    method == Method::GET
+        && response.status() == StatusCode::OK
+        && headers
+            .get(header::CONTENT_TYPE)
+            .is_some_and(|content_type| content_type.as_bytes().starts_with(b"application/json"))
+        && !headers.contains_key(header::ETAG)
+        && !headers.contains_key(header::LAST_MODIFIED)
+        && !headers.contains_key(header::CONTENT_ENCODING)
+}
+
+fn compute_etag(content: &[u8]) -> String {
+    format!(r#"W/"{}""#, hex::encode(Sha256::digest(content)))
+}
+
+/// Check if one of the entity tags of an `If-None-Match` header matches the given `etag`, using
+/// the weak comparison.
+fn if_none_match_matches(if_none_match: &str, etag: &str) -> bool {
+    if_none_match.split(',').map(str::trim).any(|tag| {
+        tag == "*"
+            || tag.strip_prefix("W/").unwrap_or(tag) == etag.strip_prefix("W/").unwrap_or(etag)
+    })
+}
+
+async fn cache_and_encode(
+    method: Method,
+    if_none_match: Option<String>,
+    accept_encoding: Option<String>,
+    response: Response,
+) -> Response {
+    if !is_cacheable(&method, &response) {
+        return response;
+    }
+
+    let (mut parts, response_body) = response.into_parts();
+    let content = match body::to_bytes(response_body).await {
+        Ok(content) => content,
+        Err(err) => {
+            warn!("http_cache::read_body_error"; "error" => ?err);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let etag = compute_etag(&content);
+    parts.headers.insert(
+        header::ETAG,
+        HeaderValue::from_str(&etag).expect("An hex encoded ETag is a valid header value"),
+    );
+    parts.headers.insert(
+        header::VARY,
+        HeaderValue::from_static(header::ACCEPT_ENCODING.as_str()),
+    );
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    if if_none_match.is_some_and(|if_none_match| if_none_match_matches(&if_none_match, &etag)) {
+        parts.status = StatusCode::NOT_MODIFIED;
+        parts.headers.remove(header::CONTENT_TYPE);
+        return Response::from_parts(parts, Body::empty());
+    }
+
+    let encoding = accept_encoding
+        .as_deref()
+        .and_then(ContentEncoding::select)
+        .filter(|_| content.len() >= MIN_COMPRESSION_SIZE);
+    let Some(encoding) = encoding else {
+        return Response::from_parts(parts, Body::from(content));
+    };
+    // Compression is CPU bound, it must not block the executor
+    let encoding_content = content.clone();
+    let encoded_content =
+        tokio::task::spawn_blocking(move || encoding.encode(&encoding_content)).await;
+    match encoded_content {
+        Ok(Ok(encoded_content)) => {
+            parts.headers.insert(
+                header::CONTENT_ENCODING,
+                HeaderValue::from_static(encoding.name()),
+            );
+            Response::from_parts(parts, Body::from(encoded_content))
+        }
+        Ok(Err(err)) => {
+            warn!("http_cache::encoding_error"; "encoding" => encoding.name(), "error" => ?err);
+            Response::from_parts(parts, Body::from(content))
+        }
+        Err(err) => {
+            warn!("http_cache::encoding_task_error"; "encoding" => encoding.name(), "error" => ?err);
+            Response::from_parts(parts, Body::from(content))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+    use serde_json::json;
+    use warp::test::request;
+
+    use super::*;
+
+    fn large_json() -> serde_json::Value {
+        json!({ "items": vec!["certificate-hash"; 200] })
+    }
+
+    fn json_route(
+        value: serde_json::Value,
+    ) -> impl Filter<Extract = (Response,), Error = Rejection> + Clone {
+        with_etag_and_compression(
+            warp::path!("json").map(move || warp::reply::json(&value).into_response()),
+        )
+    }
+
+    #[test]
+    fn select_preferred_accepted_encoding() {
+        assert_eq!(None, ContentEncoding::select("identity"));
+        assert_eq!(Some(ContentEncoding::Gzip), ContentEncoding::select("gzip"));
+        assert_eq!(
+            Some(ContentEncoding::Zstd),
+            ContentEncoding::select("gzip, deflate, br, zstd")
+        );
+        assert_eq!(
+            Some(ContentEncoding::Gzip),
+            ContentEncoding::select("zstd;q=0, gzip;q=0.5")
+        );
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison() {
+        assert!(if_none_match_matches(r#""abc""#, r#""abc""#));
+        assert!(if_none_match_matches(r#"W/"abc""#, r#""abc""#));
+        assert!(if_none_match_matches(r#""xyz", "abc""#, r#""abc""#));
+        assert!(if_none_match_matches("*", r#""abc""#));
+        assert!(!if_none_match_matches(r#""xyz""#, r#""abc""#));
+    }
+
+    #[tokio::test]
+    async fn add_etag_to_json_response() {
+        let value = json!({ "hash": "123" });
+        let response = request()
+            .method("GET")
+            .path("/json")
+            .reply(&json_route(value.clone()))
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            compute_etag(&serde_json::to_vec(&value).unwrap()),
+            response.headers()[header::ETAG]
+        );
+        assert!(response.headers()[header::ETAG]
+            .to_str()
+            .unwrap()
+            .starts_with("W/"));
+        assert_eq!(
+            header::ACCEPT_ENCODING.as_str(),
+            response.headers()[header::VARY]
+        );
+        assert_eq!(
+            serde_json::to_vec(&value).unwrap(),
+            response.body().to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn reply_not_modified_if_none_match_the_etag() {
+        let value = json!({ "hash": "123" });
+        let etag = compute_etag(&serde_json::to_vec(&value).unwrap());
+        let response = request()
+            .method("GET")
+            .path("/json")
+            .header(header::IF_NONE_MATCH, &etag)
+            .reply(&json_route(value))
+            .await;
+
+        assert_eq!(StatusCode::NOT_MODIFIED, response.status());
+        assert_eq!(etag, response.headers()[header::ETAG]);
+        assert!(response.body().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reply_content_if_none_match_another_etag() {
+        let response = request()
+            .method("GET")
+            .path("/json")
+            .header(header::IF_NONE_MATCH, r#""another-etag""#)
+            .reply(&json_route(json!({ "hash": "123" })))
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+    }
+
+    #[tokio::test]
+    async fn compress_large_json_response_with_gzip() {
+        let response = request()
+            .method("GET")
+            .path("/json")
+            .header(header::ACCEPT_ENCODING, "gzip")
+            .reply(&json_route(large_json()))
+            .await;
+
+        assert_eq!("gzip", response.headers()[header::CONTENT_ENCODING]);
+        let mut decoded = Vec::new();
+        GzDecoder::new(response.body().as_ref())
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(serde_json::to_vec(&large_json()).unwrap(), decoded);
+    }
+
+    #[tokio::test]
+    async fn compress_large_json_response_with_zstd() {
+        let response = request()
+            .method("GET")
+            .path("/json")
+            .header(header::ACCEPT_ENCODING, "gzip, zstd")
+            .reply(&json_route(large_json()))
+            .await;
+
+        assert_eq!("zstd", response.headers()[header::CONTENT_ENCODING]);
+        let decoded = zstd::decode_all(response.body().as_ref()).unwrap();
+        assert_eq!(serde_json::to_vec(&large_json()).unwrap(), decoded);
+    }
+
+    #[tokio::test]
+    async fn do_not_compress_small_json_response() {
+        let response = request()
+            .method("GET")
+            .path("/json")
+            .header(header::ACCEPT_ENCODING, "gzip, zstd")
+            .reply(&json_route(json!({ "hash": "123" })))
+            .await;
+
+        assert!(!response.headers().contains_key(header::CONTENT_ENCODING));
+    }
+
+    #[tokio::test]
+    async fn leave_non_json_response_untouched() {
+        let route = with_etag_and_compression(
+            warp::path!("file")
+                .map(|| warp::reply::with_header("content", "content-type", "application/gzip")),
+        );
+        let response = request()
+            .method("GET")
+            .path("/file")
+            .header(header::ACCEPT_ENCODING, "gzip, zstd")
+            .reply(&route)
+            .await;
+
+        assert!(!response.headers().contains_key(header::ETAG));
+        assert!(!response.headers().contains_key(header::CONTENT_ENCODING));
+    }
+}
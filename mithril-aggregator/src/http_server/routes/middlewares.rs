@@ -6,14 +6,13 @@ use warp::Filter;
 use mithril_common::entities::SignedEntityConfig;
 use mithril_common::{api_version::APIVersionProvider, TickerService};
 
-use crate::database::repository::SignerGetter;
+use crate::database::repository::{SignerGetter, SignerRegistrationGetter};
 use crate::dependency_injection::EpochServiceWrapper;
 use crate::event_store::{EventMessage, TransmitterService};
-use crate::services::{CertifierService, MessageService, ProverService, SignedEntityService};
-use crate::{
-    CertificatePendingStore, Configuration, DependencyContainer, SignerRegisterer,
-    VerificationKeyStorer,
+use crate::services::{
+    CertifierService, DataAttestationService, MessageService, ProverService, SignedEntityService,
 };
+use crate::{CertificatePendingStore, Configuration, DependencyContainer, SignerRegisterer};
 
 /// With certificate pending store
 pub(crate) fn with_certificate_pending_store(
@@ -36,6 +35,13 @@ pub fn with_signer_getter(
     warp::any().map(move || dependency_manager.signer_getter.clone())
 }
 
+/// With signer registration getter middleware
+pub fn with_signer_registration_getter(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (Arc<dyn SignerRegistrationGetter>,), Error = Infallible> + Clone {
+    warp::any().map(move || dependency_manager.signer_registration_getter.clone())
+}
+
 /// With config middleware
 pub fn with_config(
     dependency_manager: Arc<DependencyContainer>,
@@ -85,13 +91,6 @@ pub fn with_signed_entity_service(
     warp::any().map(move || dependency_manager.signed_entity_service.clone())
 }
 
-/// With verification key store
-pub fn with_verification_key_store(
-    dependency_manager: Arc<DependencyContainer>,
-) -> impl Filter<Extract = (Arc<dyn VerificationKeyStorer>,), Error = Infallible> + Clone {
-    warp::any().map(move || dependency_manager.verification_key_store.clone())
-}
-
 /// With API version provider
 pub fn with_api_version_provider(
     dependency_manager: Arc<DependencyContainer>,
@@ -113,6 +112,13 @@ pub fn with_prover_service(
     warp::any().map(move || dependency_manager.prover_service.clone())
 }
 
+/// With data attestation service
+pub fn with_data_attestation_service(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (Arc<dyn DataAttestationService>,), Error = Infallible> + Clone {
+    warp::any().map(move || dependency_manager.data_attestation_service.clone())
+}
+
 pub mod validators {
     use crate::http_server::validators::ProverTransactionsHashValidator;
 
@@ -1,6 +1,9 @@
 mod artifact_routes;
+mod attestation_routes;
 mod certificate_routes;
+mod diagnostic_routes;
 mod epoch_routes;
+mod http_cache;
 mod middlewares;
 mod proof_routes;
 pub(crate) mod reply;
@@ -1,11 +1,12 @@
 use crate::http_server::routes::{
-    artifact_routes, certificate_routes, epoch_routes, root_routes, signatures_routes,
-    signer_routes, statistics_routes,
+    artifact_routes, attestation_routes, certificate_routes, diagnostic_routes, epoch_routes,
+    http_cache, reply, root_routes, signatures_routes, signer_routes, statistics_routes,
 };
 use crate::http_server::SERVER_BASE_PATH;
 use crate::DependencyContainer;
 
 use mithril_common::api_version::APIVersionProvider;
+use mithril_common::entities::ApiVersionMismatchError;
 use mithril_common::MITHRIL_API_VERSION_HEADER;
 
 use slog_scope::warn;
@@ -18,12 +19,12 @@ use warp::{Filter, Rejection, Reply};
 use super::{middlewares, proof_routes};
 
 #[derive(Debug)]
-pub struct VersionMismatchError;
+pub struct VersionMismatchError(pub ApiVersionMismatchError);
 
 impl Reject for VersionMismatchError {}
 
 #[derive(Debug)]
-pub struct VersionParseError;
+pub struct VersionParseError(pub String);
 
 impl Reject for VersionParseError {}
 
@@ -33,7 +34,13 @@ pub fn routes(
 ) -> impl Filter<Extract = (impl Reply,), Error = warp::Rejection> + Clone {
     let cors = warp::cors()
         .allow_any_origin()
-        .allow_headers(vec!["content-type", MITHRIL_API_VERSION_HEADER])
+        .allow_headers(vec![
+            "content-type",
+            "if-none-match",
+            "authorization",
+            MITHRIL_API_VERSION_HEADER,
+        ])
+        .expose_headers(vec!["etag"])
         .allow_methods(vec![Method::GET, Method::POST, Method::OPTIONS]);
 
     warp::any()
@@ -42,23 +49,33 @@ pub fn routes(
         ))
         .and(warp::path(SERVER_BASE_PATH))
         .and(
-            certificate_routes::routes(dependency_manager.clone())
-                .or(artifact_routes::snapshot::routes(
-                    dependency_manager.clone(),
-                ))
-                .or(artifact_routes::mithril_stake_distribution::routes(
-                    dependency_manager.clone(),
-                ))
-                .or(artifact_routes::cardano_transaction::routes(
-                    dependency_manager.clone(),
-                ))
-                .or(proof_routes::routes(dependency_manager.clone()))
-                .or(signer_routes::routes(dependency_manager.clone()))
-                .or(signatures_routes::routes(dependency_manager.clone()))
-                .or(epoch_routes::routes(dependency_manager.clone()))
-                .or(statistics_routes::routes(dependency_manager.clone()))
-                .or(root_routes::routes(dependency_manager.clone()))
-                .with(cors),
+            http_cache::with_etag_and_compression(
+                certificate_routes::routes(dependency_manager.clone())
+                    .or(artifact_routes::snapshot::routes(
+                        dependency_manager.clone(),
+                    ))
+                    .or(artifact_routes::mithril_stake_distribution::routes(
+                        dependency_manager.clone(),
+                    ))
+                    .or(artifact_routes::cardano_transaction::routes(
+                        dependency_manager.clone(),
+                    ))
+                    .or(artifact_routes::cardano_utxo_set::routes(
+                        dependency_manager.clone(),
+                    ))
+                    .or(artifact_routes::data_attestation::routes(
+                        dependency_manager.clone(),
+                    ))
+                    .or(proof_routes::routes(dependency_manager.clone()))
+                    .or(signer_routes::routes(dependency_manager.clone()))
+                    .or(signatures_routes::routes(dependency_manager.clone()))
+                    .or(attestation_routes::routes(dependency_manager.clone()))
+                    .or(epoch_routes::routes(dependency_manager.clone()))
+                    .or(statistics_routes::routes(dependency_manager.clone()))
+                    .or(diagnostic_routes::routes(dependency_manager.clone()))
+                    .or(root_routes::routes(dependency_manager.clone())),
+            )
+            .with(cors),
         )
         .recover(handle_custom)
         .and(middlewares::with_api_version_provider(dependency_manager))
@@ -85,18 +102,30 @@ fn header_must_be(
                 match maybe_header {
                     None => Ok(()),
                     Some(version) => match semver::Version::parse(&version) {
-                        Ok(version)
-                            if (api_version_provider
-                                .compute_current_version_requirement().unwrap()
-                                .matches(&version))
-                            .to_owned() =>
-                        {
-                            Ok(())
+                        Ok(version) => {
+                            let version_requirement = api_version_provider
+                                .compute_current_version_requirement()
+                                .unwrap();
+                            if version_requirement.matches(&version) {
+                                Ok(())
+                            } else {
+                                Err(warp::reject::custom(VersionMismatchError(
+                                    ApiVersionMismatchError::new(
+                                        version.to_string(),
+                                        api_version_provider
+                                            .compute_current_version()
+                                            .unwrap()
+                                            .to_string(),
+                                        version_requirement.to_string(),
+                                    ),
+                                )))
+                            }
                         }
-                        Ok(_version) => Err(warp::reject::custom(VersionMismatchError)),
                         Err(err) => {
                             warn!("⇄ HTTP SERVER::api_version_check::parse_error"; "error" => ?err);
-                            Err(warp::reject::custom(VersionParseError))
+                            Err(warp::reject::custom(VersionParseError(format!(
+                                "Can not parse API version '{version}': {err}"
+                            ))))
                         }
                     },
                 }
@@ -106,8 +135,13 @@ fn header_must_be(
 }
 
 pub async fn handle_custom(reject: Rejection) -> Result<impl Reply, Rejection> {
-    if reject.find::<VersionMismatchError>().is_some() {
-        Ok(StatusCode::PRECONDITION_FAILED)
+    if let Some(VersionMismatchError(error)) = reject.find::<VersionMismatchError>() {
+        Ok(reply::json(error, StatusCode::PRECONDITION_FAILED))
+    } else if let Some(VersionParseError(message)) = reject.find::<VersionParseError>() {
+        Ok(reply::bad_request(
+            "api_version_parse_error".to_string(),
+            message.clone(),
+        ))
     } else {
         Err(reject)
     }
@@ -185,4 +219,46 @@ mod tests {
             .await
             .expect(r#"request with the good version "0.1.2" should not be rejected"#);
     }
+
+    #[tokio::test]
+    async fn reply_structured_error_on_version_mismatch() {
+        let era_checker = EraChecker::new(SupportedEra::dummy(), Epoch(1));
+        let mut version_provider = APIVersionProvider::new(Arc::new(era_checker));
+        let mut open_api_versions = HashMap::new();
+        open_api_versions.insert("openapi.yaml".to_string(), Version::new(0, 2, 1));
+        version_provider.update_open_api_versions(open_api_versions);
+        let filters = header_must_be(Arc::new(version_provider))
+            .map(warp::reply)
+            .recover(handle_custom);
+
+        let response = warp::test::request()
+            .header(MITHRIL_API_VERSION_HEADER, "0.1.2")
+            .path("/aggregator/whatever")
+            .reply(&filters)
+            .await;
+
+        assert_eq!(StatusCode::PRECONDITION_FAILED, response.status());
+        let error: ApiVersionMismatchError = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(
+            ApiVersionMismatchError::new("0.1.2", "0.2.1", "=0.2"),
+            error
+        );
+    }
+
+    #[tokio::test]
+    async fn reply_bad_request_on_version_parse_error() {
+        let era_checker = EraChecker::new(SupportedEra::dummy(), Epoch(1));
+        let api_version_provider = Arc::new(APIVersionProvider::new(Arc::new(era_checker)));
+        let filters = header_must_be(api_version_provider)
+            .map(warp::reply)
+            .recover(handle_custom);
+
+        let response = warp::test::request()
+            .header(MITHRIL_API_VERSION_HEADER, "not_a_version")
+            .path("/aggregator/whatever")
+            .reply(&filters)
+            .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
 }
@@ -3,10 +3,17 @@ use crate::DependencyContainer;
 use std::sync::Arc;
 use warp::Filter;
 
+/// Maximum size of the body of a signatures batch, a larger body is rejected before being read.
+const MAX_BATCH_BODY_LENGTH: u64 = 16 * 1024 * 1024;
+
+/// Maximum number of signatures in a batch, the following signatures are not registered.
+const MAX_BATCH_SIGNATURES: usize = 256;
+
 pub fn routes(
     dependency_manager: Arc<DependencyContainer>,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
-    register_signatures(dependency_manager)
+    register_signatures(dependency_manager.clone())
+        .or(register_signatures_batch(dependency_manager))
 }
 
 /// POST /register-signatures
@@ -24,15 +31,40 @@ fn register_signatures(
         .and_then(handlers::register_signatures)
 }
 
+/// POST /register-signatures/batch
+fn register_signatures_batch(
+    dependency_manager: Arc<DependencyContainer>,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("register-signatures" / "batch")
+        .and(warp::post())
+        .and(warp::body::content_length_limit(MAX_BATCH_BODY_LENGTH))
+        .and(warp::body::stream())
+        .and(middlewares::with_certifier_service(
+            dependency_manager.clone(),
+        ))
+        .and(middlewares::with_ticker_service(dependency_manager.clone()))
+        .and(middlewares::with_signed_entity_config(dependency_manager))
+        .and_then(handlers::register_signatures_batch)
+}
+
 mod handlers {
+    use anyhow::anyhow;
+    use futures::{Stream, StreamExt, TryStreamExt};
     use slog_scope::{debug, trace, warn};
     use std::convert::Infallible;
+    use std::io;
     use std::sync::Arc;
+    use tokio_util::codec::{FramedRead, LinesCodec, LinesCodecError};
+    use tokio_util::io::StreamReader;
     use warp::http::StatusCode;
+    use warp::hyper::body::Buf;
 
     use mithril_common::entities::{SignedEntityConfig, SignedEntityTypeDiscriminants};
-    use mithril_common::messages::{RegisterSignatureMessage, TryFromMessageAdapter};
-    use mithril_common::TickerService;
+    use mithril_common::messages::{
+        RegisterSignatureMessage, RegisterSignaturesBatchItemMessage,
+        RegisterSignaturesBatchResponseMessage, TryFromMessageAdapter,
+    };
+    use mithril_common::{StdError, TickerService};
 
     use crate::{
         http_server::routes::reply,
@@ -40,6 +72,116 @@ mod handlers {
         services::{CertifierService, CertifierServiceError},
     };
 
+    use super::MAX_BATCH_SIGNATURES;
+
+    /// Maximum size of a single signature line of a batch, a longer line is rejected
+    /// without being buffered.
+    const MAX_BATCH_LINE_LENGTH: usize = 1024 * 1024;
+
+    /// Outcome of the registration of a single signature
+    enum RegistrationOutcome {
+        Registered,
+        DecodingError(StdError),
+        AlreadyCertified,
+        NotFound,
+        BatchTooLarge,
+        Error(StdError),
+    }
+
+    impl RegistrationOutcome {
+        fn status_code(&self) -> StatusCode {
+            match self {
+                Self::Registered => StatusCode::CREATED,
+                Self::DecodingError(_) => StatusCode::BAD_REQUEST,
+                Self::AlreadyCertified => StatusCode::GONE,
+                Self::NotFound => StatusCode::NOT_FOUND,
+                Self::BatchTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+                Self::Error(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            }
+        }
+
+        fn into_batch_item(self, index: usize) -> RegisterSignaturesBatchItemMessage {
+            let status = self.status_code().as_u16();
+            let message = match self {
+                Self::Registered => None,
+                Self::DecodingError(err) => {
+                    Some(format!("Could not decode signature payload: {err}"))
+                }
+                Self::AlreadyCertified => Some("open message already certified".to_string()),
+                Self::NotFound => Some("open message not found".to_string()),
+                Self::BatchTooLarge => Some(format!(
+                    "signature not registered, a batch can not contain more than {MAX_BATCH_SIGNATURES} signatures"
+                )),
+                Self::Error(err) => Some(err.to_string()),
+            };
+
+            RegisterSignaturesBatchItemMessage {
+                index,
+                status,
+                message,
+            }
+        }
+    }
+
+    async fn register_signature(
+        message: RegisterSignatureMessage,
+        certifier_service: &dyn CertifierService,
+        ticker_service: &dyn TickerService,
+        signed_entity_config: &SignedEntityConfig,
+    ) -> RegistrationOutcome {
+        let signed_entity_type = match message.signed_entity_type.clone() {
+            Some(signed_entity_type) => signed_entity_type,
+            None => match ticker_service
+                .get_current_time_point()
+                .await
+                .and_then(|time_point| {
+                    signed_entity_config
+                        .time_point_to_signed_entity(
+                            SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                            &time_point,
+                        )
+                        .ok_or_else(|| {
+                            anyhow!("Can not derive a signed entity type from the time point")
+                        })
+                }) {
+                Ok(signed_entity_type) => signed_entity_type,
+                Err(err) => {
+                    warn!("register_signatures::cant_retrieve_signed_entity_type"; "error" => ?err);
+                    return RegistrationOutcome::Error(err);
+                }
+            },
+        };
+
+        let signatures = match FromRegisterSingleSignatureAdapter::try_adapt(message) {
+            Ok(signature) => signature,
+            Err(err) => {
+                warn!("register_signatures::payload decoding error"; "error" => ?err);
+                return RegistrationOutcome::DecodingError(err);
+            }
+        };
+
+        match certifier_service
+            .register_single_signature(&signed_entity_type, &signatures)
+            .await
+        {
+            Err(err) => match err.downcast_ref::<CertifierServiceError>() {
+                Some(CertifierServiceError::AlreadyCertified(signed_entity_type)) => {
+                    debug!("register_signatures::open_message_already_certified"; "signed_entity_type" => ?signed_entity_type);
+                    RegistrationOutcome::AlreadyCertified
+                }
+                Some(CertifierServiceError::NotFound(signed_entity_type)) => {
+                    debug!("register_signatures::not_found"; "signed_entity_type" => ?signed_entity_type);
+                    RegistrationOutcome::NotFound
+                }
+                Some(_) | None => {
+                    warn!("register_signatures::error"; "error" => ?err);
+                    RegistrationOutcome::Error(err)
+                }
+            },
+            Ok(()) => RegistrationOutcome::Registered,
+        }
+    }
+
     /// Register Signatures
     pub async fn register_signatures(
         message: RegisterSignatureMessage,
@@ -50,67 +192,106 @@ mod handlers {
         debug!("⇄ HTTP SERVER: register_signatures/{:?}", message);
         trace!("⇄ HTTP SERVER: register_signatures"; "complete_message" => #?message );
 
-        let signed_entity_type = match message.signed_entity_type.clone() {
-            Some(signed_entity_type) => Ok(signed_entity_type),
-            None => ticker_service.get_current_time_point().await.map(|t| {
-                signed_entity_config.time_point_to_signed_entity(
-                    SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
-                    &t,
-                )
-            }),
-        };
+        let outcome = register_signature(
+            message,
+            certifier_service.as_ref(),
+            ticker_service.as_ref(),
+            &signed_entity_config,
+        )
+        .await;
+
+        match outcome {
+            RegistrationOutcome::Registered
+            | RegistrationOutcome::AlreadyCertified
+            | RegistrationOutcome::NotFound
+            | RegistrationOutcome::BatchTooLarge => Ok(reply::empty(outcome.status_code())),
+            RegistrationOutcome::DecodingError(err) => Ok(reply::bad_request(
+                "Could not decode signature payload".to_string(),
+                err.to_string(),
+            )),
+            RegistrationOutcome::Error(err) => Ok(reply::internal_server_error(err)),
+        }
+    }
 
-        match signed_entity_type {
-            Ok(signed_entity_type) => {
-                let signatures = match FromRegisterSingleSignatureAdapter::try_adapt(message) {
-                    Ok(signature) => signature,
-                    Err(err) => {
-                        warn!("register_signatures::payload decoding error"; "error" => ?err);
-
-                        return Ok(reply::bad_request(
-                            "Could not decode signature payload".to_string(),
-                            err.to_string(),
-                        ));
+    /// Register a batch of Signatures
+    ///
+    /// The body is a stream of newline delimited JSON [RegisterSignatureMessage], each line is
+    /// deserialized and registered as soon as it is received so the whole body is never buffered.
+    ///
+    /// The index of a reported signature is the index of its line in the body, blank lines are
+    /// skipped but still counted.
+    pub async fn register_signatures_batch<S, B>(
+        body: S,
+        certifier_service: Arc<dyn CertifierService>,
+        ticker_service: Arc<dyn TickerService>,
+        signed_entity_config: SignedEntityConfig,
+    ) -> Result<impl warp::Reply, Infallible>
+    where
+        S: Stream<Item = Result<B, warp::Error>> + Send + 'static,
+        B: Buf + Send,
+    {
+        debug!("⇄ HTTP SERVER: register_signatures_batch");
+
+        let reader = StreamReader::new(Box::pin(body.map_err(io::Error::other)));
+        let mut lines = FramedRead::new(
+            reader,
+            LinesCodec::new_with_max_length(MAX_BATCH_LINE_LENGTH),
+        );
+        let mut items = vec![];
+        let mut index = 0;
+
+        while let Some(line) = lines.next().await {
+            let outcome = match line {
+                Ok(line) if line.trim().is_empty() => {
+                    index += 1;
+                    continue;
+                }
+                Ok(_) if items.len() >= MAX_BATCH_SIGNATURES => RegistrationOutcome::BatchTooLarge,
+                Ok(line) => match serde_json::from_str::<RegisterSignatureMessage>(&line) {
+                    Ok(message) => {
+                        trace!("⇄ HTTP SERVER: register_signatures_batch"; "index" => index, "complete_message" => #?message);
+                        register_signature(
+                            message,
+                            certifier_service.as_ref(),
+                            ticker_service.as_ref(),
+                            &signed_entity_config,
+                        )
+                        .await
                     }
-                };
-
-                match certifier_service
-                    .register_single_signature(&signed_entity_type, &signatures)
-                    .await
-                {
-                    Err(err) => match err.downcast_ref::<CertifierServiceError>() {
-                        Some(CertifierServiceError::AlreadyCertified(signed_entity_type)) => {
-                            debug!("register_signatures::open_message_already_certified"; "signed_entity_type" => ?signed_entity_type);
-                            Ok(reply::empty(StatusCode::GONE))
-                        }
-                        Some(CertifierServiceError::NotFound(signed_entity_type)) => {
-                            debug!("register_signatures::not_found"; "signed_entity_type" => ?signed_entity_type);
-                            Ok(reply::empty(StatusCode::NOT_FOUND))
-                        }
-                        Some(_) | None => {
-                            warn!("register_signatures::error"; "error" => ?err);
-                            Ok(reply::internal_server_error(err))
-                        }
-                    },
-                    Ok(()) => Ok(reply::empty(StatusCode::CREATED)),
+                    Err(err) => RegistrationOutcome::DecodingError(err.into()),
+                },
+                Err(LinesCodecError::MaxLineLengthExceeded) => RegistrationOutcome::DecodingError(
+                    anyhow!("signature payload exceeds {MAX_BATCH_LINE_LENGTH} bytes"),
+                ),
+                Err(LinesCodecError::Io(err)) => {
+                    warn!("register_signatures_batch::body_read_error"; "error" => ?err);
+                    return Ok(reply::bad_request(
+                        "Could not read signatures batch".to_string(),
+                        err.to_string(),
+                    ));
                 }
-            }
-            Err(err) => {
-                warn!("register_signatures::cant_retrieve_signed_entity_type"; "error" => ?err);
-                Ok(reply::internal_server_error(err))
-            }
+            };
+            items.push(outcome.into_batch_item(index));
+            index += 1;
         }
+
+        Ok(reply::json(
+            &RegisterSignaturesBatchResponseMessage { items },
+            StatusCode::MULTI_STATUS,
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use anyhow::anyhow;
+    use mockall::Sequence;
     use warp::http::{Method, StatusCode};
     use warp::test::request;
 
     use mithril_common::{
-        entities::SignedEntityType, messages::RegisterSignatureMessage,
+        entities::SignedEntityType,
+        messages::{RegisterSignatureMessage, RegisterSignaturesBatchResponseMessage},
         test_utils::apispec::APISpec,
     };
 
@@ -304,4 +485,168 @@ mod tests {
         )
         .unwrap();
     }
+
+    fn to_ndjson(lines: &[String]) -> String {
+        lines.join("\n")
+    }
+
+    #[tokio::test]
+    async fn test_register_signatures_batch_post_207_report_each_signature_status() {
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_register_single_signature()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.certifier_service = Arc::new(mock_certifier_service);
+
+        let mut message_with_invalid_signature = RegisterSignatureMessage::dummy();
+        message_with_invalid_signature.signature = "invalid-signature".to_string();
+        let body = to_ndjson(&[
+            serde_json::to_string(&RegisterSignatureMessage::dummy()).unwrap(),
+            "not a json".to_string(),
+            String::new(),
+            serde_json::to_string(&message_with_invalid_signature).unwrap(),
+        ]);
+
+        let response = request()
+            .method(Method::POST.as_str())
+            .path(&format!("/{SERVER_BASE_PATH}/register-signatures/batch"))
+            .header("content-type", "application/x-ndjson")
+            .body(body)
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        assert_eq!(StatusCode::MULTI_STATUS, response.status());
+        let response_message: RegisterSignaturesBatchResponseMessage =
+            serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(
+            vec![(0, 201), (1, 400), (3, 400)],
+            response_message
+                .items
+                .iter()
+                .map(|item| (item.index, item.status))
+                .collect::<Vec<_>>()
+        );
+        assert!(response_message.items[0].message.is_none());
+        assert!(response_message.items[1].message.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_register_signatures_batch_post_207_report_certifier_errors() {
+        let signed_entity_type = SignedEntityType::dummy();
+        let mut seq = Sequence::new();
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_register_single_signature()
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_once(move |_, _| {
+                Err(CertifierServiceError::AlreadyCertified(signed_entity_type).into())
+            });
+        mock_certifier_service
+            .expect_register_single_signature()
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_once(move |_, _| Err(anyhow!("an error occurred")));
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.certifier_service = Arc::new(mock_certifier_service);
+
+        let message = serde_json::to_string(&RegisterSignatureMessage::dummy()).unwrap();
+        let body = to_ndjson(&[message.clone(), message]);
+
+        let response = request()
+            .method(Method::POST.as_str())
+            .path(&format!("/{SERVER_BASE_PATH}/register-signatures/batch"))
+            .header("content-type", "application/x-ndjson")
+            .body(body)
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        assert_eq!(StatusCode::MULTI_STATUS, response.status());
+        let response_message: RegisterSignaturesBatchResponseMessage =
+            serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(
+            vec![(0, 410), (1, 500)],
+            response_message
+                .items
+                .iter()
+                .map(|item| (item.index, item.status))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_signatures_batch_post_207_do_not_register_signatures_over_the_limit() {
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_register_single_signature()
+            .times(MAX_BATCH_SIGNATURES)
+            .returning(|_, _| Ok(()));
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.certifier_service = Arc::new(mock_certifier_service);
+
+        let message = serde_json::to_string(&RegisterSignatureMessage::dummy()).unwrap();
+        let body = to_ndjson(&vec![message; MAX_BATCH_SIGNATURES + 2]);
+
+        let response = request()
+            .method(Method::POST.as_str())
+            .path(&format!("/{SERVER_BASE_PATH}/register-signatures/batch"))
+            .header("content-type", "application/x-ndjson")
+            .body(body)
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        assert_eq!(StatusCode::MULTI_STATUS, response.status());
+        let response_message: RegisterSignaturesBatchResponseMessage =
+            serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(MAX_BATCH_SIGNATURES + 2, response_message.items.len());
+        assert_eq!(
+            vec![(MAX_BATCH_SIGNATURES, 413), (MAX_BATCH_SIGNATURES + 1, 413)],
+            response_message.items[MAX_BATCH_SIGNATURES..]
+                .iter()
+                .map(|item| (item.index, item.status))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_signatures_batch_post_413_if_the_body_is_too_large() {
+        let mut mock_certifier_service = MockCertifierService::new();
+        mock_certifier_service
+            .expect_register_single_signature()
+            .never();
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.certifier_service = Arc::new(mock_certifier_service);
+
+        let response = request()
+            .method(Method::POST.as_str())
+            .path(&format!("/{SERVER_BASE_PATH}/register-signatures/batch"))
+            .header("content-type", "application/x-ndjson")
+            .body("")
+            // Set after the body, which sets the content length to its own size
+            .header("content-length", MAX_BATCH_BODY_LENGTH + 1)
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_register_signatures_batch_post_207_with_empty_body() {
+        let dependency_manager = initialize_dependencies().await;
+
+        let response = request()
+            .method(Method::POST.as_str())
+            .path(&format!("/{SERVER_BASE_PATH}/register-signatures/batch"))
+            .header("content-type", "application/x-ndjson")
+            .body("")
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        assert_eq!(StatusCode::MULTI_STATUS, response.status());
+        let response_message: RegisterSignaturesBatchResponseMessage =
+            serde_json::from_slice(response.body()).unwrap();
+        assert!(response_message.items.is_empty());
+    }
 }
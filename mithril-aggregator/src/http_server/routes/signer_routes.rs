@@ -52,24 +52,31 @@ fn registered_signers(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("signers" / "registered" / String)
         .and(warp::get())
-        .and(middlewares::with_verification_key_store(dependency_manager))
+        .and(middlewares::with_signer_registration_getter(
+            dependency_manager.clone(),
+        ))
+        .and(middlewares::with_signer_getter(dependency_manager))
         .and_then(handlers::registered_signers)
 }
 
 mod handlers {
-    use crate::database::repository::SignerGetter;
-    use crate::entities::{
-        SignerRegistrationsMessage, SignerTickerListItemMessage, SignersTickersMessage,
+    use crate::database::repository::{SignerGetter, SignerRegistrationGetter};
+    use crate::entities::{SignerTickerListItemMessage, SignersTickersMessage};
+    use crate::event_store::{
+        EventMessage, EventPayload, SignerRegistrationEventPayload, TransmitterService,
     };
-    use crate::event_store::{EventMessage, TransmitterService};
+    use crate::FromRegisterSignerAdapter;
     use crate::{
         http_server::routes::reply, Configuration, SignerRegisterer, SignerRegistrationError,
     };
-    use crate::{FromRegisterSignerAdapter, VerificationKeyStorer};
-    use mithril_common::entities::Epoch;
-    use mithril_common::messages::{RegisterSignerMessage, TryFromMessageAdapter};
+    use mithril_common::entities::{Epoch, PartyId};
+    use mithril_common::messages::{
+        RegisterSignerMessage, SignerRegistrationsListItemMessage, SignerRegistrationsMessage,
+        TryFromMessageAdapter,
+    };
     use mithril_common::TickerService;
     use slog_scope::{debug, trace, warn};
+    use std::collections::HashMap;
     use std::convert::Infallible;
     use std::sync::Arc;
     use warp::http::StatusCode;
@@ -119,14 +126,15 @@ mod handlers {
             None => Vec::new(),
         };
 
-        let epoch_str = match ticker_service.get_current_epoch().await {
-            Ok(epoch) => format!("{epoch}"),
+        let current_epoch = match ticker_service.get_current_epoch().await {
+            Ok(epoch) => Some(epoch),
             Err(e) => {
                 warn!("Could not read epoch to add in event: {e}");
-                String::new()
+                None
             }
         };
-        if !epoch_str.is_empty() {
+        let epoch_str = current_epoch.map(|epoch| format!("{epoch}"));
+        if let Some(epoch_str) = epoch_str.as_ref() {
             headers.push(("epoch", epoch_str.as_str()));
         }
 
@@ -137,8 +145,11 @@ mod handlers {
             Ok(signer_with_stake) => {
                 let _ = event_transmitter.send_event_message(
                     "HTTP::signer_register",
-                    "register_signer",
-                    &signer_with_stake,
+                    EventPayload::RegisterSigner(SignerRegistrationEventPayload::new(
+                        &signer_with_stake,
+                        current_epoch,
+                        signer_node_version.clone(),
+                    )),
                     headers,
                 );
 
@@ -148,8 +159,11 @@ mod handlers {
                 debug!("register_signer::already_registered");
                 let _ = event_transmitter.send_event_message(
                     "HTTP::signer_register",
-                    "register_signer",
-                    &signer_with_stake,
+                    EventPayload::RegisterSigner(SignerRegistrationEventPayload::new(
+                        &signer_with_stake,
+                        current_epoch,
+                        signer_node_version.clone(),
+                    )),
                     headers,
                 );
                 Ok(reply::empty(StatusCode::CREATED))
@@ -177,7 +191,8 @@ mod handlers {
     /// Get Registered Signers for a given epoch
     pub async fn registered_signers(
         registered_at: String,
-        verification_key_store: Arc<dyn VerificationKeyStorer>,
+        signer_registration_getter: Arc<dyn SignerRegistrationGetter>,
+        signer_getter: Arc<dyn SignerGetter>,
     ) -> Result<impl warp::Reply, Infallible> {
         debug!("⇄ HTTP SERVER: signers/registered/{:?}", registered_at);
 
@@ -194,23 +209,46 @@ mod handlers {
 
         // The given epoch is the epoch at which the signer registered, the store works on
         // the recording epoch so we need to offset.
-        match verification_key_store
-            .get_signers(registered_at.offset_to_recording_epoch())
+        let records = match signer_registration_getter
+            .get_by_epoch(registered_at.offset_to_recording_epoch())
             .await
         {
-            Ok(Some(signers)) => {
-                let message = SignerRegistrationsMessage::new(registered_at, signers);
-                Ok(reply::json(&message, StatusCode::OK))
-            }
-            Ok(None) => {
+            Ok(records) if records.is_empty() => {
                 warn!("registered_signers::not_found");
-                Ok(reply::empty(StatusCode::NOT_FOUND))
+                return Ok(reply::empty(StatusCode::NOT_FOUND));
             }
+            Ok(records) => records,
             Err(err) => {
                 warn!("registered_signers::error"; "error" => ?err);
-                Ok(reply::internal_server_error(err))
+                return Ok(reply::internal_server_error(err));
             }
-        }
+        };
+
+        // Pool tickers are informative only, the registrations are returned without them if
+        // they can't be read.
+        let pool_tickers: HashMap<PartyId, String> = match signer_getter.get_all().await {
+            Ok(signers) => signers
+                .into_iter()
+                .filter_map(|signer| signer.pool_ticker.map(|ticker| (signer.signer_id, ticker)))
+                .collect(),
+            Err(err) => {
+                warn!("registered_signers::pool_tickers_error"; "error" => ?err);
+                HashMap::new()
+            }
+        };
+
+        let registrations = records
+            .into_iter()
+            .map(|record| SignerRegistrationsListItemMessage {
+                pool_ticker: pool_tickers.get(&record.signer_id).cloned(),
+                party_id: record.signer_id,
+                stake: record.stake.unwrap_or_default(),
+                registration_time: Some(record.created_at),
+            })
+            .collect();
+        let message = SignerRegistrationsMessage::new(registered_at, registrations);
+
+        Ok(reply::json(&message, StatusCode::OK))
     }
 
     pub async fn signers_tickers(
@@ -256,22 +294,31 @@ mod tests {
     use mithril_common::entities::Epoch;
     use mithril_common::{
         crypto_helper::ProtocolRegistrationError,
-        messages::RegisterSignerMessage,
+        messages::{RegisterSignerMessage, SignerRegistrationsMessage},
         test_utils::{apispec::APISpec, fake_data},
     };
     use mithril_persistence::store::adapter::AdapterError;
 
     use crate::{
-        database::{record::SignerRecord, repository::MockSignerGetter},
+        database::{
+            record::{SignerRecord, SignerRegistrationRecord},
+            repository::{MockSignerGetter, MockSignerRegistrationGetter},
+        },
         http_server::SERVER_BASE_PATH,
         initialize_dependencies,
         signer_registerer::MockSignerRegisterer,
-        store::MockVerificationKeyStorer,
         SignerRegistrationError,
     };
 
     use super::*;
 
+    fn fake_signer_registration_records(epoch: Epoch) -> Vec<SignerRegistrationRecord> {
+        fake_data::signers_with_stakes(3)
+            .into_iter()
+            .map(|signer| SignerRegistrationRecord::from_signer_with_stake(signer, epoch))
+            .collect()
+    }
+
     fn setup_router(
         dependency_manager: Arc<DependencyContainer>,
     ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
@@ -481,14 +528,14 @@ mod tests {
     async fn test_registered_signers_get_offset_given_epoch_to_registration_epoch() {
         let asked_epoch = Epoch(1);
         let expected_retrieval_epoch = asked_epoch.offset_to_recording_epoch();
-        let mut mock_verification_key_store = MockVerificationKeyStorer::new();
-        mock_verification_key_store
-            .expect_get_signers()
+        let mut mock_signer_registration_getter = MockSignerRegistrationGetter::new();
+        mock_signer_registration_getter
+            .expect_get_by_epoch()
             .with(eq(expected_retrieval_epoch))
-            .return_once(|_| Ok(Some(fake_data::signers_with_stakes(3))))
+            .return_once(move |_| Ok(fake_signer_registration_records(expected_retrieval_epoch)))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
-        dependency_manager.verification_key_store = Arc::new(mock_verification_key_store);
+        dependency_manager.signer_registration_getter = Arc::new(mock_signer_registration_getter);
 
         let method = Method::GET.as_str();
         let base_path = "/signers/registered";
@@ -507,13 +554,13 @@ mod tests {
 
     #[tokio::test]
     async fn test_registered_signers_get_ok() {
-        let mut mock_verification_key_store = MockVerificationKeyStorer::new();
-        mock_verification_key_store
-            .expect_get_signers()
-            .return_once(|_| Ok(Some(fake_data::signers_with_stakes(3))))
+        let mut mock_signer_registration_getter = MockSignerRegistrationGetter::new();
+        mock_signer_registration_getter
+            .expect_get_by_epoch()
+            .return_once(|epoch| Ok(fake_signer_registration_records(epoch)))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
-        dependency_manager.verification_key_store = Arc::new(mock_verification_key_store);
+        dependency_manager.signer_registration_getter = Arc::new(mock_signer_registration_getter);
 
         let base_path = "/signers/registered";
         let method = Method::GET.as_str();
@@ -538,13 +585,13 @@ mod tests {
 
     #[tokio::test]
     async fn test_registered_signers_returns_404_not_found_when_no_registration() {
-        let mut mock_verification_key_store = MockVerificationKeyStorer::new();
-        mock_verification_key_store
-            .expect_get_signers()
-            .return_once(|_| Ok(None))
+        let mut mock_signer_registration_getter = MockSignerRegistrationGetter::new();
+        mock_signer_registration_getter
+            .expect_get_by_epoch()
+            .return_once(|_| Ok(vec![]))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
-        dependency_manager.verification_key_store = Arc::new(mock_verification_key_store);
+        dependency_manager.signer_registration_getter = Arc::new(mock_signer_registration_getter);
 
         let method = Method::GET.as_str();
         let base_path = "/signers/registered";
@@ -569,12 +616,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_registered_signers_get_ko() {
-        let mut mock_verification_key_store = MockVerificationKeyStorer::new();
-        mock_verification_key_store
-            .expect_get_signers()
+        let mut mock_signer_registration_getter = MockSignerRegistrationGetter::new();
+        mock_signer_registration_getter
+            .expect_get_by_epoch()
             .return_once(|_| Err(AdapterError::GeneralError(anyhow!("invalid query")).into()));
         let mut dependency_manager = initialize_dependencies().await;
-        dependency_manager.verification_key_store = Arc::new(mock_verification_key_store);
+        dependency_manager.signer_registration_getter = Arc::new(mock_signer_registration_getter);
 
         let method = Method::GET.as_str();
         let base_path = "/signers/registered";
@@ -597,6 +644,55 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_registered_signers_get_include_pool_ticker_and_registration_time() {
+        let records = fake_signer_registration_records(Epoch(4));
+        let party_id_with_ticker = records[0].signer_id.clone();
+        let registration_time = records[0].created_at;
+        let mut mock_signer_registration_getter = MockSignerRegistrationGetter::new();
+        mock_signer_registration_getter
+            .expect_get_by_epoch()
+            .return_once(|_| Ok(records))
+            .once();
+        let mut mock_signer_getter = MockSignerGetter::new();
+        let signer_id = party_id_with_ticker.clone();
+        mock_signer_getter
+            .expect_get_all()
+            .return_once(|| {
+                Ok(vec![SignerRecord {
+                    signer_id,
+                    pool_ticker: Some("[Pool_Name]".to_string()),
+                    created_at: Default::default(),
+                    updated_at: Default::default(),
+                    last_registered_at: None,
+                }])
+            })
+            .once();
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.signer_registration_getter = Arc::new(mock_signer_registration_getter);
+        dependency_manager.signer_getter = Arc::new(mock_signer_getter);
+
+        let response = request()
+            .method(Method::GET.as_str())
+            .path(&format!("/{SERVER_BASE_PATH}/signers/registered/3"))
+            .reply(&setup_router(Arc::new(dependency_manager)))
+            .await;
+
+        let message: SignerRegistrationsMessage = serde_json::from_slice(response.body()).unwrap();
+        let registration = message
+            .registrations
+            .iter()
+            .find(|registration| registration.party_id == party_id_with_ticker)
+            .unwrap();
+        assert_eq!(Some("[Pool_Name]".to_string()), registration.pool_ticker);
+        assert_eq!(Some(registration_time), registration.registration_time);
+        assert!(message
+            .registrations
+            .iter()
+            .filter(|registration| registration.party_id != party_id_with_ticker)
+            .all(|registration| registration.pool_ticker.is_none()));
+    }
+
     #[tokio::test]
     async fn test_signers_tickers_get_ok() {
         let mut mock_signer_getter = MockSignerGetter::new();
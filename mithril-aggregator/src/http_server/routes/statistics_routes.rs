@@ -29,7 +29,7 @@ mod handlers {
     use mithril_common::messages::SnapshotDownloadMessage;
     use warp::http::StatusCode;
 
-    use crate::event_store::{EventMessage, TransmitterService};
+    use crate::event_store::{EventMessage, EventPayload, TransmitterService};
     use crate::http_server::routes::reply;
 
     pub async fn post_snapshot_statistics(
@@ -40,8 +40,7 @@ mod handlers {
 
         match event_transmitter.send_event_message(
             "HTTP::statistics",
-            "snapshot_downloaded",
-            &snapshot_download_message,
+            EventPayload::SnapshotDownloaded(snapshot_download_message.into()),
             headers,
         ) {
             Err(e) => Ok(reply::internal_server_error(e)),
@@ -25,20 +25,22 @@ mod runtime;
 pub mod services;
 mod signer_registerer;
 mod snapshot_uploaders;
+mod snapshot_verifier;
 mod snapshotter;
 mod store;
 mod tools;
 
 pub use crate::artifact_builder::ArtifactBuilder;
 pub use crate::configuration::{
-    Configuration, DefaultConfiguration, ExecutionEnvironment, SnapshotUploaderType,
-    ZstandardCompressionParameters,
+    Configuration, DataAttestationOperator, DefaultConfiguration, ExecutionEnvironment,
+    SnapshotUploaderType, ZstandardCompressionParameters,
 };
 pub use crate::multi_signer::{MultiSigner, MultiSignerImpl};
 pub use commands::{CommandType, MainOpts};
 pub use dependency_injection::DependencyContainer;
 pub use message_adapters::{
-    FromRegisterSignerAdapter, ToCertificatePendingMessageAdapter, ToEpochSettingsMessageAdapter,
+    FromRegisterSignerAdapter, ToCertificatePendingMessageAdapter, ToEpochScheduleMessageAdapter,
+    ToEpochSettingsMessageAdapter,
 };
 pub use runtime::{
     AggregatorConfig, AggregatorRunner, AggregatorRunnerTrait, AggregatorRuntime, RuntimeError,
@@ -50,6 +52,9 @@ pub use signer_registerer::{
 pub use snapshot_uploaders::{
     DumbSnapshotUploader, LocalSnapshotUploader, RemoteSnapshotUploader, SnapshotUploader,
 };
+pub use snapshot_verifier::{
+    SnapshotRestorabilityVerifier, UnpackingSnapshotRestorabilityVerifier,
+};
 pub use snapshotter::{
     CompressedArchiveSnapshotter, DumbSnapshotter, SnapshotError, Snapshotter,
     SnapshotterCompressionAlgorithm,
@@ -3,7 +3,10 @@ mod from_register_signer;
 mod to_cardano_transaction_list_message;
 mod to_cardano_transaction_message;
 mod to_cardano_transactions_proof_message;
+mod to_cardano_utxo_set_list_message;
+mod to_cardano_utxo_set_message;
 mod to_certificate_pending_message;
+mod to_epoch_schedule_message;
 mod to_epoch_settings_message;
 mod to_mithril_stake_distribution_list_message;
 mod to_mithril_stake_distribution_message;
@@ -17,7 +20,12 @@ pub use to_cardano_transaction_list_message::ToCardanoTransactionListMessageAdap
 #[cfg(test)]
 pub use to_cardano_transaction_message::ToCardanoTransactionMessageAdapter;
 pub use to_cardano_transactions_proof_message::ToCardanoTransactionsProofsMessageAdapter;
+#[cfg(test)]
+pub use to_cardano_utxo_set_list_message::ToCardanoUtxoSetListMessageAdapter;
+#[cfg(test)]
+pub use to_cardano_utxo_set_message::ToCardanoUtxoSetMessageAdapter;
 pub use to_certificate_pending_message::ToCertificatePendingMessageAdapter;
+pub use to_epoch_schedule_message::ToEpochScheduleMessageAdapter;
 pub use to_epoch_settings_message::ToEpochSettingsMessageAdapter;
 #[cfg(test)]
 pub use to_mithril_stake_distribution_list_message::ToMithrilStakeDistributionListMessageAdapter;
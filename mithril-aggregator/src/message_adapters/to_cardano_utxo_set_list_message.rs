@@ -0,0 +1,55 @@
+use mithril_common::entities::{CardanoUtxoSetSnapshot, SignedEntity};
+use mithril_common::messages::{
+    CardanoUtxoSetSnapshotListItemMessage, CardanoUtxoSetSnapshotListMessage, ToMessageAdapter,
+};
+
+/// Adapter to convert a list of [CardanoUtxoSetSnapshot] to [CardanoUtxoSetSnapshotListMessage] instances
+#[allow(dead_code)]
+pub struct ToCardanoUtxoSetListMessageAdapter;
+
+impl ToMessageAdapter<Vec<SignedEntity<CardanoUtxoSetSnapshot>>, CardanoUtxoSetSnapshotListMessage>
+    for ToCardanoUtxoSetListMessageAdapter
+{
+    /// Method to trigger the conversion
+    fn adapt(
+        snapshots: Vec<SignedEntity<CardanoUtxoSetSnapshot>>,
+    ) -> CardanoUtxoSetSnapshotListMessage {
+        snapshots
+            .into_iter()
+            .map(|entity| CardanoUtxoSetSnapshotListItemMessage {
+                merkle_root: entity.artifact.merkle_root,
+                epoch: entity.artifact.epoch,
+                utxos_count: entity.artifact.utxos_count,
+                hash: entity.artifact.hash,
+                certificate_hash: entity.certificate_id,
+                created_at: entity.created_at,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adapt_ok() {
+        let signed_entity = SignedEntity::<CardanoUtxoSetSnapshot>::dummy();
+        let cardano_utxo_set_list_message_expected = vec![CardanoUtxoSetSnapshotListItemMessage {
+            merkle_root: signed_entity.artifact.merkle_root.clone(),
+            epoch: signed_entity.artifact.epoch,
+            utxos_count: signed_entity.artifact.utxos_count,
+            hash: signed_entity.artifact.hash.clone(),
+            certificate_hash: signed_entity.certificate_id.clone(),
+            created_at: signed_entity.created_at,
+        }];
+
+        let cardano_utxo_set_list_message =
+            ToCardanoUtxoSetListMessageAdapter::adapt(vec![signed_entity]);
+
+        assert_eq!(
+            cardano_utxo_set_list_message_expected,
+            cardano_utxo_set_list_message
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use mithril_common::entities::{CardanoUtxoSetSnapshot, SignedEntity};
+use mithril_common::messages::{CardanoUtxoSetSnapshotMessage, ToMessageAdapter};
+
+/// Adapter to convert [CardanoUtxoSetSnapshot] to [CardanoUtxoSetSnapshotMessage] instances
+#[allow(dead_code)]
+pub struct ToCardanoUtxoSetMessageAdapter;
+
+impl ToMessageAdapter<SignedEntity<CardanoUtxoSetSnapshot>, CardanoUtxoSetSnapshotMessage>
+    for ToCardanoUtxoSetMessageAdapter
+{
+    /// Method to trigger the conversion
+    fn adapt(from: SignedEntity<CardanoUtxoSetSnapshot>) -> CardanoUtxoSetSnapshotMessage {
+        CardanoUtxoSetSnapshotMessage {
+            merkle_root: from.artifact.merkle_root,
+            epoch: from.artifact.epoch,
+            utxos_count: from.artifact.utxos_count,
+            locations: from.artifact.locations,
+            hash: from.artifact.hash,
+            certificate_hash: from.certificate_id,
+            created_at: from.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adapt_ok() {
+        let signed_entity = SignedEntity::<CardanoUtxoSetSnapshot>::dummy();
+        let cardano_utxo_set_message_expected = CardanoUtxoSetSnapshotMessage {
+            merkle_root: signed_entity.artifact.merkle_root.clone(),
+            epoch: signed_entity.artifact.epoch,
+            utxos_count: signed_entity.artifact.utxos_count,
+            locations: signed_entity.artifact.locations.clone(),
+            hash: signed_entity.artifact.hash.clone(),
+            certificate_hash: signed_entity.certificate_id.clone(),
+            created_at: signed_entity.created_at,
+        };
+
+        let cardano_utxo_set_message = ToCardanoUtxoSetMessageAdapter::adapt(signed_entity);
+
+        assert_eq!(cardano_utxo_set_message_expected, cardano_utxo_set_message);
+    }
+}
@@ -0,0 +1,46 @@
+use mithril_common::entities::EpochSchedule;
+use mithril_common::messages::{EpochScheduleMessage, ToMessageAdapter};
+
+/// Adapter to spawn [EpochScheduleMessage] from [EpochSchedule] instances.
+pub struct ToEpochScheduleMessageAdapter;
+
+impl ToMessageAdapter<EpochSchedule, EpochScheduleMessage> for ToEpochScheduleMessageAdapter {
+    /// Turn an entity instance into message.
+    fn adapt(epoch_schedule: EpochSchedule) -> EpochScheduleMessage {
+        EpochScheduleMessage {
+            epoch: epoch_schedule.current_epoch,
+            signer_retrieval_epoch: epoch_schedule.signer_retrieval_epoch,
+            next_signer_retrieval_epoch: epoch_schedule.next_signer_retrieval_epoch,
+            signer_registration_epoch: epoch_schedule.signer_registration_epoch,
+            signer_signing_epoch: epoch_schedule.signer_signing_epoch,
+            protocol_parameters_recording_epoch: epoch_schedule.protocol_parameters_recording_epoch,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{Epoch, EpochSettingsCalculator};
+
+    use super::*;
+
+    #[test]
+    fn test_simple_message() {
+        let epoch_schedule = EpochSettingsCalculator::default()
+            .compute_schedule(Epoch(10))
+            .unwrap();
+        let message = ToEpochScheduleMessageAdapter::adapt(epoch_schedule);
+
+        assert_eq!(
+            EpochScheduleMessage {
+                epoch: Epoch(10),
+                signer_retrieval_epoch: Epoch(9),
+                next_signer_retrieval_epoch: Epoch(10),
+                signer_registration_epoch: Epoch(11),
+                signer_signing_epoch: Epoch(13),
+                protocol_parameters_recording_epoch: Epoch(12),
+            },
+            message
+        );
+    }
+}
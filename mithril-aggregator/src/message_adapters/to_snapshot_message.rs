@@ -17,6 +17,7 @@ impl ToMessageAdapter<SignedEntity<Snapshot>, SnapshotMessage> for ToSnapshotMes
             locations: signed_entity.artifact.locations,
             compression_algorithm: Some(signed_entity.artifact.compression_algorithm),
             cardano_node_version: Some(signed_entity.artifact.cardano_node_version),
+            chunk_checksums: signed_entity.artifact.chunk_checksums,
         }
     }
 }
@@ -145,10 +145,21 @@ impl AggregatorRunner {
         &self,
         time_point: &TimePoint,
     ) -> Vec<SignedEntityType> {
-        let signed_entity_types = self
+        let mut signed_entity_types = self
             .dependencies
             .signed_entity_config
             .list_allowed_signed_entity_types(time_point);
+        match self
+            .dependencies
+            .data_attestation_service
+            .list_pending_attestations(time_point.epoch)
+            .await
+        {
+            Ok(pending_attestations) => signed_entity_types.extend(pending_attestations),
+            Err(error) => {
+                warn!("RUNNER: could not list pending data attestations"; "error" => ?error)
+            }
+        }
         self.dependencies
             .signed_entity_type_lock
             .filter_unlocked_entries(signed_entity_types)
@@ -494,12 +505,16 @@ pub mod tests {
         entities::OpenMessage,
         initialize_dependencies,
         runtime::{AggregatorRunner, AggregatorRunnerTrait},
-        services::{MithrilStakeDistributionService, MockCertifierService},
+        services::{
+            MithrilStakeDistributionService, MockCertifierService, MockDataAttestationService,
+        },
         DependencyContainer, MithrilSignerRegisterer, SignerRegistrationRound,
     };
     use async_trait::async_trait;
     use chrono::{DateTime, Utc};
-    use mithril_common::entities::{ChainPoint, SignedEntityTypeDiscriminants};
+    use mithril_common::entities::{
+        ChainPoint, DataAttestationBeacon, SignedEntityTypeDiscriminants,
+    };
     use mithril_common::signed_entity_type_lock::SignedEntityTypeLock;
     use mithril_common::{
         chain_observer::FakeObserver,
@@ -1180,10 +1195,37 @@ pub mod tests {
             signed_entities,
             SignedEntityTypeDiscriminants::all()
                 .into_iter()
+                // Data attestations are only listed once submitted by an operator
+                .filter(|d| d != &SignedEntityTypeDiscriminants::DataAttestation)
                 .collect::<Vec<_>>()
         );
     }
 
+    #[tokio::test]
+    async fn list_available_signed_entity_types_include_pending_data_attestations() {
+        let time_point = TimePoint::dummy();
+        let data_attestation = SignedEntityType::DataAttestation(DataAttestationBeacon::new(
+            time_point.epoch,
+            "namespace",
+            "payload-hash",
+        ));
+        let runner = {
+            let mut dependencies = initialize_dependencies().await;
+            let mut data_attestation_service = MockDataAttestationService::new();
+            let pending_attestations = vec![data_attestation.clone()];
+            data_attestation_service
+                .expect_list_pending_attestations()
+                .with(eq(time_point.epoch))
+                .return_once(|_| Ok(pending_attestations));
+            dependencies.data_attestation_service = Arc::new(data_attestation_service);
+            AggregatorRunner::new(Arc::new(dependencies))
+        };
+
+        let signed_entities = runner.list_available_signed_entity_types(&time_point).await;
+
+        assert_eq!(Some(&data_attestation), signed_entities.last());
+    }
+
     #[tokio::test]
     async fn list_available_signed_entity_types_exclude_locked_entities() {
         let signed_entity_type_lock = Arc::new(SignedEntityTypeLock::default());
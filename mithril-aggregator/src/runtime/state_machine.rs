@@ -233,16 +233,18 @@ impl AggregatorRuntime {
                     .as_ref()
                     .map(|om| om.is_expired)
                     .unwrap_or(false);
-                let exists_newer_open_message = {
-                    let new_signed_entity_type = self
-                        .config
-                        .signed_entity_config
-                        .time_point_to_signed_entity(
-                            &state.open_message.signed_entity_type,
-                            &last_time_point,
-                        );
-                    new_signed_entity_type != state.open_message.signed_entity_type
-                };
+                // Signed entity types that are not derived from the time point, such as data
+                // attestations, are never superseded by a newer open message
+                let exists_newer_open_message = self
+                    .config
+                    .signed_entity_config
+                    .time_point_to_signed_entity(
+                        &state.open_message.signed_entity_type,
+                        &last_time_point,
+                    )
+                    .is_some_and(|new_signed_entity_type| {
+                        new_signed_entity_type != state.open_message.signed_entity_type
+                    });
 
                 if state.current_time_point.epoch < last_time_point.epoch {
                     // SIGNING > IDLE
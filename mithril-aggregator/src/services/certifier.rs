@@ -19,6 +19,7 @@ use mithril_common::{
 };
 use slog::Logger;
 use slog_scope::{debug, error, info, trace, warn};
+use std::collections::HashMap;
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::RwLock;
@@ -28,7 +29,7 @@ use crate::{
     database::repository::{
         CertificateRepository, OpenMessageRepository, SingleSignatureRepository,
     },
-    entities::OpenMessage,
+    entities::{CertificationDryRunMessage, OpenMessage},
     MultiSigner,
 };
 
@@ -132,6 +133,18 @@ pub trait CertifierService: Sync + Send {
         signed_entity_type: &SignedEntityType,
     ) -> StdResult<Option<Certificate>>;
 
+    /// Check, without creating any certificate, if the single signatures received so far for
+    /// the open message at the given beacon reach the quorum, and report the registered
+    /// signers that have not signed it yet. If the open message does not exist, None is returned.
+    async fn dry_run_certificate(
+        &self,
+        signed_entity_type: &SignedEntityType,
+    ) -> StdResult<Option<CertificationDryRunMessage>>;
+
+    /// Dry run the certification of all the open messages that are neither certified nor
+    /// expired, see [dry_run_certificate][CertifierService::dry_run_certificate].
+    async fn dry_run_current_certificates(&self) -> StdResult<Vec<CertificationDryRunMessage>>;
+
     /// Returns a certificate from its hash.
     async fn get_certificate_by_hash(&self, hash: &str) -> StdResult<Option<Certificate>>;
 
@@ -156,6 +169,9 @@ pub struct MithrilCertifierService {
     // todo: should be removed after removing immutable file number from the certificate metadata
     ticker_service: Arc<dyn TickerService>,
     epoch_service: EpochServiceWrapper,
+    // Outcome of the latest multi-signature dry run of each open message, along with the
+    // number of single signatures it was computed with.
+    dry_run_cache: RwLock<HashMap<SignedEntityType, (usize, bool)>>,
     _logger: Logger,
 }
 
@@ -184,6 +200,7 @@ impl MithrilCertifierService {
             genesis_verifier,
             ticker_service,
             epoch_service,
+            dry_run_cache: RwLock::new(HashMap::new()),
             _logger: logger,
         }
     }
@@ -204,6 +221,57 @@ impl MithrilCertifierService {
 
         Ok(open_message_with_single_signatures)
     }
+
+    /// Check if the quorum is reached for the given open message.
+    ///
+    /// The multi-signature aggregation is only run again if single signatures were received
+    /// since the previous dry run of the open message.
+    async fn is_quorum_reached(&self, open_message: &OpenMessage) -> StdResult<bool> {
+        let signed_entity_type = &open_message.signed_entity_type;
+        let nb_single_signatures = open_message.single_signatures.len();
+        if let Some((nb_cached_signatures, is_quorum_reached)) =
+            self.dry_run_cache.read().await.get(signed_entity_type)
+        {
+            if *nb_cached_signatures == nb_single_signatures {
+                return Ok(*is_quorum_reached);
+            }
+        }
+
+        let is_quorum_reached = self
+            .multi_signer
+            .read()
+            .await
+            .create_multi_signature(open_message)
+            .await
+            .with_context(|| {
+                format!("CertifierService can not dry run multi-signature for signed_entity_type: '{signed_entity_type}'")
+            })?
+            .is_some();
+        self.dry_run_cache.write().await.insert(
+            signed_entity_type.clone(),
+            (nb_single_signatures, is_quorum_reached),
+        );
+
+        Ok(is_quorum_reached)
+    }
+
+    async fn dry_run_open_message(
+        &self,
+        open_message: &OpenMessage,
+    ) -> StdResult<CertificationDryRunMessage> {
+        let is_quorum_reached = self.is_quorum_reached(open_message).await?;
+
+        let epoch_service = self.epoch_service.read().await;
+        let protocol_parameters = epoch_service.current_protocol_parameters()?;
+        let signers_with_stake = epoch_service.current_signers_with_stake()?;
+
+        Ok(CertificationDryRunMessage::new(
+            open_message,
+            protocol_parameters,
+            signers_with_stake,
+            is_quorum_reached,
+        ))
+    }
 }
 
 #[async_trait]
@@ -218,6 +286,10 @@ impl CertifierService for MithrilCertifierService {
                 format!("Certifier can not clean open messages from epoch '{epoch}'")
             })?;
         info!("MithrilCertifierService: Informed of a new Epoch: {epoch:?}. Cleaned {nb} open messages along with their single signatures.");
+        self.dry_run_cache
+            .write()
+            .await
+            .retain(|signed_entity_type, _| signed_entity_type.get_epoch() >= epoch);
 
         Ok(())
     }
@@ -449,6 +521,37 @@ impl CertifierService for MithrilCertifierService {
         Ok(Some(certificate))
     }
 
+    async fn dry_run_certificate(
+        &self,
+        signed_entity_type: &SignedEntityType,
+    ) -> StdResult<Option<CertificationDryRunMessage>> {
+        debug!("CertifierService::dry_run_certificate(signed_entity_type: {signed_entity_type:?})");
+
+        let open_message = match self.get_open_message(signed_entity_type).await.with_context(|| format!("CertifierService can not get open message for signed_entity_type: '{signed_entity_type}'"))? {
+            Some(open_message) => open_message,
+            None => return Ok(None),
+        };
+        Ok(Some(self.dry_run_open_message(&open_message).await?))
+    }
+
+    async fn dry_run_current_certificates(&self) -> StdResult<Vec<CertificationDryRunMessage>> {
+        debug!("CertifierService::dry_run_current_certificates");
+
+        let open_messages = self
+            .open_message_repository
+            .get_current_open_messages_with_single_signatures()
+            .await
+            .with_context(|| {
+                "Certifier can not get current open messages with single signatures"
+            })?;
+        let mut dry_runs = Vec::with_capacity(open_messages.len());
+        for record in open_messages {
+            dry_runs.push(self.dry_run_open_message(&record.into()).await?);
+        }
+
+        Ok(dry_runs)
+    }
+
     async fn get_certificate_by_hash(&self, hash: &str) -> StdResult<Option<Certificate>> {
         self.certificate_repository.get_certificate(hash).await
     }
@@ -501,6 +604,7 @@ mod tests {
         entities::{CardanoDbBeacon, ProtocolMessagePartKey},
         test_utils::{fake_data, MithrilFixture, MithrilFixtureBuilder},
     };
+    use std::collections::HashSet;
 
     use super::*;
 
@@ -889,6 +993,222 @@ mod tests {
         assert!(!latest_certificates.is_empty());
     }
 
+    #[tokio::test]
+    async fn dry_run_certificate_report_missing_signers_without_creating_certificate() {
+        let network = fake_data::network();
+        let beacon = CardanoDbBeacon::new(network.to_string(), 3, 1);
+        let signed_entity_type = SignedEntityType::CardanoImmutableFilesFull(beacon.clone());
+        let protocol_message = ProtocolMessage::new();
+        let epochs_with_signers = (1..=3).map(Epoch).collect::<Vec<_>>();
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let certifier_service = setup_certifier_service_with_network(
+            network,
+            &fixture,
+            &epochs_with_signers,
+            Some(beacon.epoch),
+        )
+        .await;
+        certifier_service
+            .create_open_message(&signed_entity_type, &protocol_message)
+            .await
+            .unwrap();
+        let first_signature = fixture.sign_all(&protocol_message).remove(0);
+        certifier_service
+            .register_single_signature(&signed_entity_type, &first_signature)
+            .await
+            .unwrap();
+
+        let dry_run = certifier_service
+            .dry_run_certificate(&signed_entity_type)
+            .await
+            .unwrap()
+            .expect("A dry run should be returned for an existing open message");
+
+        assert!(!dry_run.missing_signers.is_empty());
+        assert!(!dry_run
+            .missing_signers
+            .iter()
+            .any(|signer| signer.party_id == first_signature.party_id));
+        assert_eq!(
+            fixture
+                .signers_with_stake()
+                .iter()
+                .map(|s| s.stake)
+                .sum::<u64>(),
+            dry_run.total_stake
+        );
+        let open_message = certifier_service
+            .get_open_message(&signed_entity_type)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!open_message.is_certified);
+    }
+
+    #[tokio::test]
+    async fn dry_run_certificate_report_quorum_reached_when_all_signers_signed() {
+        let network = fake_data::network();
+        let beacon = CardanoDbBeacon::new(network.to_string(), 3, 1);
+        let signed_entity_type = SignedEntityType::CardanoImmutableFilesFull(beacon.clone());
+        let protocol_message = ProtocolMessage::new();
+        let epochs_with_signers = (1..=3).map(Epoch).collect::<Vec<_>>();
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let certifier_service = setup_certifier_service_with_network(
+            network,
+            &fixture,
+            &epochs_with_signers,
+            Some(beacon.epoch),
+        )
+        .await;
+        certifier_service
+            .create_open_message(&signed_entity_type, &protocol_message)
+            .await
+            .unwrap();
+        for signature in fixture.sign_all(&protocol_message) {
+            certifier_service
+                .register_single_signature(&signed_entity_type, &signature)
+                .await
+                .unwrap();
+        }
+
+        let dry_run = certifier_service
+            .dry_run_certificate(&signed_entity_type)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(dry_run.is_quorum_reached);
+        assert_eq!(fixture.protocol_parameters().k, dry_run.quorum);
+    }
+
+    #[tokio::test]
+    async fn dry_run_certificate_for_open_message_not_created() {
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 1);
+        let signed_entity_type = SignedEntityType::CardanoImmutableFilesFull(beacon.clone());
+        let epochs_with_signers = (1..=5).map(Epoch).collect::<Vec<_>>();
+        let fixture = MithrilFixtureBuilder::default().with_signers(5).build();
+        let certifier_service = setup_certifier_service(&fixture, &epochs_with_signers, None).await;
+
+        let dry_run = certifier_service
+            .dry_run_certificate(&signed_entity_type)
+            .await
+            .unwrap();
+
+        assert_eq!(None, dry_run);
+    }
+
+    #[tokio::test]
+    async fn dry_run_current_certificates_report_all_open_messages_neither_certified_nor_expired() {
+        let network = fake_data::network();
+        let epoch = Epoch(3);
+        let epochs_with_signers = (1..=3).map(Epoch).collect::<Vec<_>>();
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let certifier_service = setup_certifier_service_with_network(
+            network,
+            &fixture,
+            &epochs_with_signers,
+            Some(epoch),
+        )
+        .await;
+        let signed_entity_types = vec![
+            SignedEntityType::MithrilStakeDistribution(epoch),
+            SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon::new(
+                network.to_string(),
+                *epoch,
+                1,
+            )),
+            SignedEntityType::CardanoStakeDistribution(epoch),
+        ];
+        for signed_entity_type in &signed_entity_types {
+            certifier_service
+                .create_open_message(signed_entity_type, &ProtocolMessage::new())
+                .await
+                .unwrap();
+        }
+        let mut certified_open_message = certifier_service
+            .open_message_repository
+            .get_open_message(&signed_entity_types[2])
+            .await
+            .unwrap()
+            .unwrap();
+        certified_open_message.is_certified = true;
+        certifier_service
+            .open_message_repository
+            .update_open_message(&certified_open_message)
+            .await
+            .unwrap();
+
+        let dry_runs = certifier_service
+            .dry_run_current_certificates()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            signed_entity_types[0..2]
+                .iter()
+                .cloned()
+                .collect::<HashSet<_>>(),
+            dry_runs
+                .into_iter()
+                .map(|dry_run| dry_run.signed_entity_type)
+                .collect::<HashSet<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn dry_run_certificate_only_computes_multi_signature_again_when_signatures_are_received()
+    {
+        let mut mock_multi_signer = MockMultiSigner::new();
+        mock_multi_signer
+            .expect_verify_single_signature()
+            .returning(|_, _| Ok(()));
+        mock_multi_signer
+            .expect_create_multi_signature()
+            .returning(|_| Ok(None))
+            .times(2);
+        let network = fake_data::network();
+        let beacon = CardanoDbBeacon::new(network.to_string(), 3, 1);
+        let signed_entity_type = SignedEntityType::CardanoImmutableFilesFull(beacon.clone());
+        let protocol_message = ProtocolMessage::new();
+        let epochs_with_signers = (1..=3).map(Epoch).collect::<Vec<_>>();
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let mut certifier_service = setup_certifier_service_with_network(
+            network,
+            &fixture,
+            &epochs_with_signers,
+            Some(beacon.epoch),
+        )
+        .await;
+        certifier_service.multi_signer = Arc::new(RwLock::new(mock_multi_signer));
+        certifier_service
+            .create_open_message(&signed_entity_type, &protocol_message)
+            .await
+            .unwrap();
+        let mut signatures = fixture.sign_all(&protocol_message);
+
+        certifier_service
+            .register_single_signature(&signed_entity_type, &signatures.remove(0))
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            certifier_service
+                .dry_run_certificate(&signed_entity_type)
+                .await
+                .unwrap();
+        }
+
+        certifier_service
+            .register_single_signature(&signed_entity_type, &signatures.remove(0))
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            certifier_service
+                .dry_run_certificate(&signed_entity_type)
+                .await
+                .unwrap();
+        }
+    }
+
     #[tokio::test]
     async fn should_not_create_certificate_for_open_message_not_created() {
         let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 1);
@@ -0,0 +1,471 @@
+//! ## DataAttestationService
+//!
+//! This service accepts the payload hashes that authenticated operators submit for
+//! certification, and persists them until they are certified.
+//!
+//! An attestation that is not certified by the end of an epoch is carried to the next epoch, and
+//! is then certified with the beacon of that epoch.
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use slog_scope::{debug, info};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use mithril_common::entities::{
+    DataAttestation, DataAttestationBeacon, Epoch, SignedEntityType, SignedEntityTypeDiscriminants,
+};
+use mithril_common::era::{EraChecker, SupportedEra};
+use mithril_common::StdResult;
+
+use crate::database::record::DataAttestationRecord;
+use crate::database::repository::DataAttestationRepository;
+use crate::DataAttestationOperator;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Minimum length of a hex encoded payload hash (32 bytes).
+const PAYLOAD_HASH_MIN_LENGTH: usize = 64;
+
+/// Maximum length of a hex encoded payload hash (64 bytes).
+const PAYLOAD_HASH_MAX_LENGTH: usize = 128;
+
+/// Errors dedicated to the [DataAttestationService].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DataAttestationServiceError {
+    /// Data attestations are not in the signed entity types of the aggregator.
+    #[error("Data attestations are not enabled on this aggregator")]
+    NotEnabled,
+
+    /// Data attestations can not be signed in the current era.
+    #[error("Data attestations can not be signed in era '{0}'")]
+    EraNotSupported(SupportedEra),
+
+    /// The given API key does not match any operator.
+    #[error("Unknown operator API key")]
+    Unauthorized,
+
+    /// The operator is not allowed to submit attestations in the namespace.
+    #[error(
+        "Operator '{operator}' is not allowed to submit attestations in namespace '{namespace}'"
+    )]
+    NamespaceNotAllowed {
+        /// Name of the operator
+        operator: String,
+        /// Namespace of the rejected attestation
+        namespace: String,
+    },
+
+    /// The payload hash is not an hex encoded hash.
+    #[error("Invalid payload hash '{0}', an hex encoded hash of 32 to 64 bytes is expected")]
+    InvalidPayloadHash(String),
+
+    /// The operator has reached its maximum number of attestations for the epoch.
+    #[error(
+        "Operator '{operator}' has reached its limit of {limit} attestations at epoch {epoch}"
+    )]
+    RateLimited {
+        /// Name of the operator
+        operator: String,
+        /// Maximum number of attestations of the operator in an epoch
+        limit: u32,
+        /// Epoch of the rejected attestation
+        epoch: Epoch,
+    },
+}
+
+/// Service that handles the data attestations submitted by the operators.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DataAttestationService: Sync + Send {
+    /// Submit a payload hash to certify on behalf of the operator authenticated by the API key.
+    ///
+    /// Submitting again an attestation that was already submitted returns it without counting it
+    /// in the operator limit.
+    async fn submit_attestation(
+        &self,
+        api_key: &str,
+        namespace: &str,
+        payload_hash: &str,
+    ) -> StdResult<DataAttestation>;
+
+    /// List the signed entity types, at the given epoch, of the attestations submitted up to
+    /// this epoch that are not certified yet.
+    async fn list_pending_attestations(&self, epoch: Epoch) -> StdResult<Vec<SignedEntityType>>;
+}
+
+/// Mithril implementation of the [DataAttestationService], submitted attestations are
+/// persisted in the database.
+pub struct MithrilDataAttestationService {
+    operators: Vec<DataAttestationOperator>,
+    allowed_discriminants: BTreeSet<SignedEntityTypeDiscriminants>,
+    era_checker: Arc<EraChecker>,
+    data_attestation_repository: Arc<DataAttestationRepository>,
+    /// Serialize the submissions so the operators limits can not be exceeded by concurrent calls
+    submission_lock: Mutex<()>,
+}
+
+impl MithrilDataAttestationService {
+    /// [MithrilDataAttestationService] factory
+    pub fn new(
+        operators: Vec<DataAttestationOperator>,
+        allowed_discriminants: BTreeSet<SignedEntityTypeDiscriminants>,
+        era_checker: Arc<EraChecker>,
+        data_attestation_repository: Arc<DataAttestationRepository>,
+    ) -> Self {
+        Self {
+            operators,
+            allowed_discriminants,
+            era_checker,
+            data_attestation_repository,
+            submission_lock: Mutex::new(()),
+        }
+    }
+
+    fn check_is_signable(&self) -> Result<(), DataAttestationServiceError> {
+        if !self
+            .allowed_discriminants
+            .contains(&SignedEntityTypeDiscriminants::DataAttestation)
+        {
+            return Err(DataAttestationServiceError::NotEnabled);
+        }
+
+        let current_era = self.era_checker.current_era();
+        if !SignedEntityTypeDiscriminants::DataAttestation.is_supported_in_era(current_era) {
+            return Err(DataAttestationServiceError::EraNotSupported(current_era));
+        }
+
+        Ok(())
+    }
+
+    fn authenticate(
+        &self,
+        api_key: &str,
+    ) -> Result<&DataAttestationOperator, DataAttestationServiceError> {
+        let api_key_hash = Sha256::digest(api_key.as_bytes());
+
+        // The hashes are compared in constant time to not leak how much of a hash matches
+        self.operators
+            .iter()
+            .find(|operator| {
+                hex::decode(&operator.api_key_hash).is_ok_and(|operator_key_hash| {
+                    bool::from(operator_key_hash.ct_eq(&api_key_hash))
+                })
+            })
+            .ok_or(DataAttestationServiceError::Unauthorized)
+    }
+
+    fn check_payload_hash(payload_hash: &str) -> Result<(), DataAttestationServiceError> {
+        let is_valid = (PAYLOAD_HASH_MIN_LENGTH..=PAYLOAD_HASH_MAX_LENGTH)
+            .contains(&payload_hash.len())
+            && hex::decode(payload_hash).is_ok();
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(DataAttestationServiceError::InvalidPayloadHash(
+                payload_hash.to_string(),
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl DataAttestationService for MithrilDataAttestationService {
+    async fn submit_attestation(
+        &self,
+        api_key: &str,
+        namespace: &str,
+        payload_hash: &str,
+    ) -> StdResult<DataAttestation> {
+        self.check_is_signable()?;
+        let operator = self.authenticate(api_key)?;
+        if !operator.allowed_namespaces.iter().any(|n| n == namespace) {
+            return Err(DataAttestationServiceError::NamespaceNotAllowed {
+                operator: operator.name.clone(),
+                namespace: namespace.to_string(),
+            }
+            .into());
+        }
+        Self::check_payload_hash(payload_hash)?;
+
+        let epoch = self.era_checker.current_epoch();
+        let beacon = DataAttestationBeacon::new(epoch, namespace, payload_hash.to_lowercase());
+        let _submission_lock = self.submission_lock.lock().await;
+        if let Some(record) = self
+            .data_attestation_repository
+            .get_data_attestation(&beacon.namespace, &beacon.payload_hash)
+            .await?
+        {
+            // The attestation of the first submission is returned, a resubmission at a later
+            // epoch must not yield a beacon that will never be certified
+            debug!("DataAttestationService: attestation already submitted"; "beacon" => %record.beacon);
+            return Ok(DataAttestation::new(record.beacon));
+        }
+
+        let submissions = self
+            .data_attestation_repository
+            .count_operator_data_attestations(&operator.name, epoch)
+            .await?;
+        if submissions >= operator.max_attestations_per_epoch as usize {
+            return Err(DataAttestationServiceError::RateLimited {
+                operator: operator.name.clone(),
+                limit: operator.max_attestations_per_epoch,
+                epoch,
+            }
+            .into());
+        }
+        self.data_attestation_repository
+            .create_data_attestation(DataAttestationRecord::new(beacon.clone(), &operator.name))
+            .await?;
+        info!(
+            "DataAttestationService: attestation submitted";
+            "operator" => &operator.name, "beacon" => %beacon
+        );
+
+        Ok(DataAttestation::new(beacon))
+    }
+
+    async fn list_pending_attestations(&self, epoch: Epoch) -> StdResult<Vec<SignedEntityType>> {
+        if self.check_is_signable().is_err() {
+            return Ok(vec![]);
+        }
+
+        let pending_attestations = self
+            .data_attestation_repository
+            .get_uncertified_data_attestations(epoch)
+            .await?;
+
+        Ok(pending_attestations
+            .into_iter()
+            .map(|record| {
+                SignedEntityType::DataAttestation(DataAttestationBeacon {
+                    epoch,
+                    ..record.beacon
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_persistence::sqlite::SqliteConnection;
+
+    use crate::database::record::SignedEntityRecord;
+    use crate::database::test_helper::{insert_signed_entities, main_db_connection};
+
+    use super::*;
+
+    const API_KEY: &str = "operator-api-key";
+    const NAMESPACE: &str = "db-sync-snapshot";
+    const PAYLOAD_HASH: &str = "b4e1d4b4c3a2f7e9d2c9b8a7f6e5d4c3b2a1f0e9d8c7b6a5f4e3d2c1b0a9f8e7";
+
+    fn operator(max_attestations_per_epoch: u32) -> DataAttestationOperator {
+        DataAttestationOperator {
+            name: "db-sync".to_string(),
+            api_key_hash: hex::encode(Sha256::digest(API_KEY.as_bytes())),
+            allowed_namespaces: vec![NAMESPACE.to_string()],
+            max_attestations_per_epoch,
+        }
+    }
+
+    fn build_service(era: SupportedEra, epoch: Epoch) -> MithrilDataAttestationService {
+        build_service_with_connection(era, epoch, Arc::new(main_db_connection().unwrap()))
+    }
+
+    fn build_service_with_connection(
+        era: SupportedEra,
+        epoch: Epoch,
+        connection: Arc<SqliteConnection>,
+    ) -> MithrilDataAttestationService {
+        MithrilDataAttestationService::new(
+            vec![operator(2)],
+            BTreeSet::from([SignedEntityTypeDiscriminants::DataAttestation]),
+            Arc::new(EraChecker::new(era, epoch)),
+            Arc::new(DataAttestationRepository::new(connection)),
+        )
+    }
+
+    fn payload_hash(index: u8) -> String {
+        format!("{index:02x}{}", &PAYLOAD_HASH[2..])
+    }
+
+    async fn submit_error(
+        service: &MithrilDataAttestationService,
+        api_key: &str,
+        namespace: &str,
+        payload_hash: &str,
+    ) -> DataAttestationServiceError {
+        service
+            .submit_attestation(api_key, namespace, payload_hash)
+            .await
+            .expect_err("submit_attestation should fail")
+            .downcast::<DataAttestationServiceError>()
+            .expect("the error should be a DataAttestationServiceError")
+    }
+
+    #[tokio::test]
+    async fn submitted_attestation_is_pending_until_it_is_certified() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        let service =
+            build_service_with_connection(SupportedEra::Pythagoras, Epoch(5), connection.clone());
+
+        let data_attestation = service
+            .submit_attestation(API_KEY, NAMESPACE, PAYLOAD_HASH)
+            .await
+            .unwrap();
+
+        let beacon = DataAttestationBeacon::new(Epoch(5), NAMESPACE, PAYLOAD_HASH);
+        let signed_entity_type = SignedEntityType::DataAttestation(beacon.clone());
+        assert_eq!(DataAttestation::new(beacon), data_attestation);
+        assert_eq!(
+            vec![signed_entity_type.clone()],
+            service.list_pending_attestations(Epoch(5)).await.unwrap()
+        );
+
+        // Still pending, at the next epoch, after a restart
+        let service =
+            build_service_with_connection(SupportedEra::Pythagoras, Epoch(6), connection.clone());
+        let signed_entity_type = SignedEntityType::DataAttestation(DataAttestationBeacon::new(
+            Epoch(6),
+            NAMESPACE,
+            PAYLOAD_HASH,
+        ));
+        assert_eq!(
+            vec![signed_entity_type.clone()],
+            service.list_pending_attestations(Epoch(6)).await.unwrap()
+        );
+
+        insert_signed_entities(
+            &connection,
+            vec![SignedEntityRecord {
+                signed_entity_type,
+                ..SignedEntityRecord::fake_records(1).remove(0)
+            }],
+        )
+        .unwrap();
+        assert!(service
+            .list_pending_attestations(Epoch(6))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn resubmitted_attestation_keep_the_epoch_of_its_first_submission() {
+        let service = build_service(SupportedEra::Pythagoras, Epoch(5));
+        let data_attestation = service
+            .submit_attestation(API_KEY, NAMESPACE, PAYLOAD_HASH)
+            .await
+            .unwrap();
+
+        service
+            .era_checker
+            .change_era(SupportedEra::Pythagoras, Epoch(6));
+        let resubmitted_data_attestation = service
+            .submit_attestation(API_KEY, NAMESPACE, PAYLOAD_HASH)
+            .await
+            .unwrap();
+
+        assert_eq!(data_attestation, resubmitted_data_attestation);
+        assert_eq!(Epoch(5), resubmitted_data_attestation.epoch);
+    }
+
+    #[tokio::test]
+    async fn submit_attestation_fails_if_not_enabled_or_before_pythagoras_era() {
+        let service = MithrilDataAttestationService::new(
+            vec![operator(2)],
+            BTreeSet::from([SignedEntityTypeDiscriminants::MithrilStakeDistribution]),
+            Arc::new(EraChecker::new(SupportedEra::Pythagoras, Epoch(5))),
+            Arc::new(DataAttestationRepository::new(Arc::new(
+                main_db_connection().unwrap(),
+            ))),
+        );
+        assert_eq!(
+            DataAttestationServiceError::NotEnabled,
+            submit_error(&service, API_KEY, NAMESPACE, PAYLOAD_HASH).await
+        );
+
+        let service = build_service(SupportedEra::Thales, Epoch(5));
+        assert_eq!(
+            DataAttestationServiceError::EraNotSupported(SupportedEra::Thales),
+            submit_error(&service, API_KEY, NAMESPACE, PAYLOAD_HASH).await
+        );
+        assert!(service
+            .list_pending_attestations(Epoch(5))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn submit_attestation_fails_if_the_operator_policy_is_not_respected() {
+        let service = build_service(SupportedEra::Pythagoras, Epoch(5));
+
+        assert_eq!(
+            DataAttestationServiceError::Unauthorized,
+            submit_error(&service, "unknown-api-key", NAMESPACE, PAYLOAD_HASH).await
+        );
+        assert_eq!(
+            DataAttestationServiceError::NamespaceNotAllowed {
+                operator: "db-sync".to_string(),
+                namespace: "other-namespace".to_string(),
+            },
+            submit_error(&service, API_KEY, "other-namespace", PAYLOAD_HASH).await
+        );
+        for invalid_payload_hash in ["", "not-an-hex-hash", &PAYLOAD_HASH[1..]] {
+            assert_eq!(
+                DataAttestationServiceError::InvalidPayloadHash(invalid_payload_hash.to_string()),
+                submit_error(&service, API_KEY, NAMESPACE, invalid_payload_hash).await
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn submit_attestation_is_rate_limited_by_operator_and_epoch() {
+        let service = build_service(SupportedEra::Pythagoras, Epoch(5));
+        service
+            .submit_attestation(API_KEY, NAMESPACE, &payload_hash(1))
+            .await
+            .unwrap();
+        service
+            .submit_attestation(API_KEY, NAMESPACE, &payload_hash(2))
+            .await
+            .unwrap();
+
+        // Already pending attestations are not counted
+        service
+            .submit_attestation(API_KEY, NAMESPACE, &payload_hash(2))
+            .await
+            .unwrap();
+        assert_eq!(
+            DataAttestationServiceError::RateLimited {
+                operator: "db-sync".to_string(),
+                limit: 2,
+                epoch: Epoch(5),
+            },
+            submit_error(&service, API_KEY, NAMESPACE, &payload_hash(3)).await
+        );
+        assert_eq!(
+            2,
+            service
+                .list_pending_attestations(Epoch(5))
+                .await
+                .unwrap()
+                .len()
+        );
+
+        service
+            .era_checker
+            .change_era(SupportedEra::Pythagoras, Epoch(6));
+        service
+            .submit_attestation(API_KEY, NAMESPACE, &payload_hash(3))
+            .await
+            .unwrap();
+    }
+}
@@ -9,7 +9,8 @@ use mithril_common::{
     entities::SignedEntityTypeDiscriminants,
     messages::{
         CardanoTransactionSnapshotListMessage, CardanoTransactionSnapshotMessage,
-        CertificateListMessage, CertificateMessage, MithrilStakeDistributionListMessage,
+        CardanoUtxoSetSnapshotListMessage, CardanoUtxoSetSnapshotMessage, CertificateListMessage,
+        CertificateMessage, DataAttestationMessage, MithrilStakeDistributionListMessage,
         MithrilStakeDistributionMessage, SnapshotListMessage, SnapshotMessage,
     },
     StdResult,
@@ -74,6 +75,24 @@ pub trait MessageService: Sync + Send {
         &self,
         limit: usize,
     ) -> StdResult<CardanoTransactionSnapshotListMessage>;
+
+    /// Return the information regarding the Cardano UTxO set for the given identifier.
+    async fn get_cardano_utxo_set_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoUtxoSetSnapshotMessage>>;
+
+    /// Return the list of the last Cardano UTxO set message
+    async fn get_cardano_utxo_set_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoUtxoSetSnapshotListMessage>;
+
+    /// Return the information regarding the data attestation for the given identifier.
+    async fn get_data_attestation_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<DataAttestationMessage>>;
 }
 
 /// Implementation of the [MessageService]
@@ -186,6 +205,43 @@ impl MessageService for MithrilMessageService {
 
         entities.into_iter().map(|i| i.try_into()).collect()
     }
+
+    async fn get_cardano_utxo_set_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<CardanoUtxoSetSnapshotMessage>> {
+        let signed_entity = self
+            .signed_entity_storer
+            .get_signed_entity(signed_entity_id)
+            .await?;
+
+        signed_entity.map(|v| v.try_into()).transpose()
+    }
+
+    async fn get_cardano_utxo_set_list_message(
+        &self,
+        limit: usize,
+    ) -> StdResult<CardanoUtxoSetSnapshotListMessage> {
+        let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoUtxoSet;
+        let entities = self
+            .signed_entity_storer
+            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .await?;
+
+        entities.into_iter().map(|i| i.try_into()).collect()
+    }
+
+    async fn get_data_attestation_message(
+        &self,
+        signed_entity_id: &str,
+    ) -> StdResult<Option<DataAttestationMessage>> {
+        let signed_entity = self
+            .signed_entity_storer
+            .get_signed_entity(signed_entity_id)
+            .await?;
+
+        signed_entity.map(|v| v.try_into()).transpose()
+    }
 }
 
 #[cfg(test)]
@@ -193,8 +249,9 @@ mod tests {
     use std::sync::Arc;
 
     use mithril_common::entities::{
-        CardanoTransactionsSnapshot, Certificate, Epoch, MithrilStakeDistribution, SignedEntity,
-        SignedEntityType, Snapshot,
+        CardanoTransactionsSnapshot, CardanoUtxoSetSnapshot, Certificate, DataAttestation,
+        DataAttestationBeacon, Epoch, MithrilStakeDistribution, SignedEntity, SignedEntityType,
+        Snapshot,
     };
     use mithril_common::messages::ToMessageAdapter;
     use mithril_common::test_utils::MithrilFixtureBuilder;
@@ -204,6 +261,7 @@ mod tests {
     use crate::dependency_injection::DependenciesBuilder;
     use crate::message_adapters::{
         ToCardanoTransactionListMessageAdapter, ToCardanoTransactionMessageAdapter,
+        ToCardanoUtxoSetListMessageAdapter, ToCardanoUtxoSetMessageAdapter,
         ToMithrilStakeDistributionListMessageAdapter, ToMithrilStakeDistributionMessageAdapter,
         ToSnapshotListMessageAdapter, ToSnapshotMessageAdapter,
     };
@@ -499,4 +557,91 @@ mod tests {
 
         assert_eq!(message, response);
     }
+
+    #[tokio::test]
+    async fn get_cardano_utxo_set() {
+        let entity = SignedEntity::<CardanoUtxoSetSnapshot>::dummy();
+        let record = SignedEntityRecord {
+            signed_entity_id: entity.signed_entity_id.clone(),
+            signed_entity_type: SignedEntityType::CardanoUtxoSet(entity.artifact.epoch),
+            certificate_id: entity.certificate_id.clone(),
+            artifact: serde_json::to_string(&entity.artifact).unwrap(),
+            created_at: entity.created_at,
+        };
+        let message = ToCardanoUtxoSetMessageAdapter::adapt(entity);
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        let mut storer = MockSignedEntityStorer::new();
+        storer
+            .expect_get_signed_entity()
+            .return_once(|_| Ok(Some(record)))
+            .once();
+        dep_builder.signed_entity_storer = Some(Arc::new(storer));
+        let service = dep_builder.get_message_service().await.unwrap();
+        let response = service
+            .get_cardano_utxo_set_message("whatever")
+            .await
+            .unwrap()
+            .expect("A CardanoUtxoSetSnapshotMessage was expected.");
+
+        assert_eq!(message, response);
+    }
+
+    #[tokio::test]
+    async fn get_cardano_utxo_set_list_message() {
+        let entity = SignedEntity::<CardanoUtxoSetSnapshot>::dummy();
+        let records = vec![SignedEntityRecord {
+            signed_entity_id: entity.signed_entity_id.clone(),
+            signed_entity_type: SignedEntityType::CardanoUtxoSet(entity.artifact.epoch),
+            certificate_id: entity.certificate_id.clone(),
+            artifact: serde_json::to_string(&entity.artifact).unwrap(),
+            created_at: entity.created_at,
+        }];
+        let message = ToCardanoUtxoSetListMessageAdapter::adapt(vec![entity]);
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        let mut storer = MockSignedEntityStorer::new();
+        storer
+            .expect_get_last_signed_entities_by_type()
+            .return_once(|_, _| Ok(records))
+            .once();
+        dep_builder.signed_entity_storer = Some(Arc::new(storer));
+        let service = dep_builder.get_message_service().await.unwrap();
+        let response = service.get_cardano_utxo_set_list_message(10).await.unwrap();
+
+        assert_eq!(message, response);
+    }
+
+    #[tokio::test]
+    async fn get_data_attestation() {
+        let beacon = DataAttestationBeacon::new(Epoch(5), "namespace", "payload-hash");
+        let data_attestation = DataAttestation::new(beacon.clone());
+        let record = SignedEntityRecord {
+            signed_entity_id: data_attestation.hash.clone(),
+            signed_entity_type: SignedEntityType::DataAttestation(beacon),
+            certificate_id: "certificate-hash".to_string(),
+            artifact: serde_json::to_string(&data_attestation).unwrap(),
+            created_at: Default::default(),
+        };
+        let configuration = Configuration::new_sample();
+        let mut dep_builder = DependenciesBuilder::new(configuration);
+        let mut storer = MockSignedEntityStorer::new();
+        storer
+            .expect_get_signed_entity()
+            .return_once(|_| Ok(Some(record)))
+            .once();
+        dep_builder.signed_entity_storer = Some(Arc::new(storer));
+        let service = dep_builder.get_message_service().await.unwrap();
+        let response = service
+            .get_data_attestation_message("whatever")
+            .await
+            .unwrap()
+            .expect("A DataAttestationMessage was expected.");
+
+        assert_eq!(data_attestation.hash, response.hash);
+        assert_eq!(Epoch(5), response.epoch);
+        assert_eq!("namespace", response.namespace);
+        assert_eq!("payload-hash", response.payload_hash);
+        assert_eq!("certificate-hash", response.certificate_hash);
+    }
 }
@@ -6,11 +6,13 @@
 //! * StakeEntity: fetches Cardano stake distribution information
 //! * Certifier: registers signers and create certificates once ready
 //! * SignedEntity: provides information about signed entities.
+//! * DataAttestation: accepts the payload hashes submitted by operators for certification.
 //!
 //! Each service is defined by a public API (a trait) that is used in the controllers (runtimes).
 
 mod cardano_transactions_importer;
 mod certifier;
+mod data_attestation;
 mod epoch_service;
 mod message;
 mod prover;
@@ -19,6 +21,7 @@ mod stake_distribution;
 
 pub use cardano_transactions_importer::*;
 pub use certifier::*;
+pub use data_attestation::*;
 pub use epoch_service::*;
 pub use message::*;
 pub use prover::*;
@@ -10,9 +10,9 @@ use std::sync::Arc;
 
 use mithril_common::{
     entities::{
-        BlockNumber, CardanoDbBeacon, CardanoTransactionsSnapshot, Certificate, Epoch,
-        MithrilStakeDistribution, SignedEntity, SignedEntityType, SignedEntityTypeDiscriminants,
-        Snapshot,
+        BlockNumber, CardanoDbBeacon, CardanoTransactionsSnapshot, CardanoUtxoSetSnapshot,
+        Certificate, DataAttestation, DataAttestationBeacon, Epoch, MithrilStakeDistribution,
+        SignedEntity, SignedEntityType, SignedEntityTypeDiscriminants, Snapshot,
     },
     signable_builder::Artifact,
     StdResult,
@@ -77,6 +77,9 @@ pub struct MithrilSignedEntityService {
         Arc<dyn ArtifactBuilder<CardanoDbBeacon, Snapshot>>,
     cardano_transactions_artifact_builder:
         Arc<dyn ArtifactBuilder<BlockNumber, CardanoTransactionsSnapshot>>,
+    cardano_utxo_set_artifact_builder: Arc<dyn ArtifactBuilder<Epoch, CardanoUtxoSetSnapshot>>,
+    data_attestation_artifact_builder:
+        Arc<dyn ArtifactBuilder<DataAttestationBeacon, DataAttestation>>,
 }
 
 impl MithrilSignedEntityService {
@@ -92,12 +95,18 @@ impl MithrilSignedEntityService {
         cardano_transactions_artifact_builder: Arc<
             dyn ArtifactBuilder<BlockNumber, CardanoTransactionsSnapshot>,
         >,
+        cardano_utxo_set_artifact_builder: Arc<dyn ArtifactBuilder<Epoch, CardanoUtxoSetSnapshot>>,
+        data_attestation_artifact_builder: Arc<
+            dyn ArtifactBuilder<DataAttestationBeacon, DataAttestation>,
+        >,
     ) -> Self {
         Self {
             signed_entity_storer,
             mithril_stake_distribution_artifact_builder,
             cardano_immutable_files_full_artifact_builder,
             cardano_transactions_artifact_builder,
+            cardano_utxo_set_artifact_builder,
+            data_attestation_artifact_builder,
         }
     }
 
@@ -139,6 +148,26 @@ impl MithrilSignedEntityService {
                         )
                     })?,
             )),
+            SignedEntityType::CardanoUtxoSet(epoch) => Ok(Arc::new(
+                self.cardano_utxo_set_artifact_builder
+                    .compute_artifact(epoch, certificate)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Signed Entity Service can not compute artifact for entity type: '{signed_entity_type}'"
+                        )
+                    })?,
+            )),
+            SignedEntityType::DataAttestation(beacon) => Ok(Arc::new(
+                self.data_attestation_artifact_builder
+                    .compute_artifact(beacon, certificate)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Signed Entity Service can not compute artifact for entity type: '{signed_entity_type}'"
+                        )
+                    })?,
+            )),
         }
     }
 
@@ -339,6 +368,9 @@ mod tests {
             MockArtifactBuilder<CardanoDbBeacon, Snapshot>,
         mock_cardano_transactions_artifact_builder:
             MockArtifactBuilder<BlockNumber, CardanoTransactionsSnapshot>,
+        mock_cardano_utxo_set_artifact_builder: MockArtifactBuilder<Epoch, CardanoUtxoSetSnapshot>,
+        mock_data_attestation_artifact_builder:
+            MockArtifactBuilder<DataAttestationBeacon, DataAttestation>,
     }
 
     impl MockDependencyInjector {
@@ -357,6 +389,14 @@ mod tests {
                     BlockNumber,
                     CardanoTransactionsSnapshot,
                 >::new(),
+                mock_cardano_utxo_set_artifact_builder: MockArtifactBuilder::<
+                    Epoch,
+                    CardanoUtxoSetSnapshot,
+                >::new(),
+                mock_data_attestation_artifact_builder: MockArtifactBuilder::<
+                    DataAttestationBeacon,
+                    DataAttestation,
+                >::new(),
             }
         }
 
@@ -366,6 +406,8 @@ mod tests {
                 Arc::new(self.mock_mithril_stake_distribution_artifact_builder),
                 Arc::new(self.mock_cardano_immutable_files_full_artifact_builder),
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
+                Arc::new(self.mock_cardano_utxo_set_artifact_builder),
+                Arc::new(self.mock_data_attestation_artifact_builder),
             )
         }
     }
@@ -481,6 +523,58 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn build_cardano_utxo_set_snapshot_artifact_when_given_cardano_utxo_set_type() {
+        let mut mock_container = MockDependencyInjector::new();
+
+        let expected = CardanoUtxoSetSnapshot::new("merkle_root".to_string(), Epoch(3), 12, vec![]);
+
+        mock_container
+            .mock_cardano_utxo_set_artifact_builder
+            .expect_compute_artifact()
+            .times(1)
+            .returning(|_, _| {
+                Ok(CardanoUtxoSetSnapshot::new(
+                    "merkle_root".to_string(),
+                    Epoch(3),
+                    12,
+                    vec![],
+                ))
+            });
+
+        let artifact_builder_service = mock_container.build_artifact_builder_service();
+
+        let certificate = fake_data::certificate("hash".to_string());
+        let signed_entity_type = SignedEntityType::CardanoUtxoSet(Epoch(3));
+        let artifact = artifact_builder_service
+            .compute_artifact(signed_entity_type.clone(), &certificate)
+            .await
+            .unwrap();
+
+        assert_expected(&expected, &artifact);
+    }
+
+    #[tokio::test]
+    async fn should_store_the_artifact_when_creating_artifact_for_cardano_utxo_set() {
+        generic_test_that_the_artifact_is_stored(
+            SignedEntityType::CardanoUtxoSet(Epoch(3)),
+            CardanoUtxoSetSnapshot::new("merkle_root".to_string(), Epoch(3), 12, vec![]),
+            &|mock_injector| &mut mock_injector.mock_cardano_utxo_set_artifact_builder,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn should_store_the_artifact_when_creating_artifact_for_data_attestation() {
+        let beacon = DataAttestationBeacon::new(Epoch(3), "db-sync-snapshot", "abc123");
+        generic_test_that_the_artifact_is_stored(
+            SignedEntityType::DataAttestation(beacon.clone()),
+            DataAttestation::new(beacon),
+            &|mock_injector| &mut mock_injector.mock_data_attestation_artifact_builder,
+        )
+        .await;
+    }
+
     async fn generic_test_that_the_artifact_is_stored<
         T: Artifact + Clone + Serialize + 'static,
         U: signable_builder::Beacon,
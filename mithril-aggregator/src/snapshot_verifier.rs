@@ -0,0 +1,264 @@
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use flate2::read::GzDecoder;
+use mithril_common::{
+    digesters::ImmutableDigester,
+    entities::{CardanoDbBeacon, CompressionAlgorithm},
+    StdResult,
+};
+use slog_scope::{info, warn};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tar::Archive;
+use zstd::Decoder;
+
+use crate::snapshotter::{OngoingSnapshot, SnapshotError};
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Define the ability to verify that a snapshot archive can be restored before publishing it.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SnapshotRestorabilityVerifier: Sync + Send {
+    /// Verify that the given snapshot archive can be restored and that the restored Cardano
+    /// database has the expected digest.
+    async fn verify_restorability(
+        &self,
+        ongoing_snapshot: &OngoingSnapshot,
+        beacon: &CardanoDbBeacon,
+        expected_digest: &str,
+    ) -> StdResult<()>;
+}
+
+/// A [SnapshotRestorabilityVerifier] that unpacks the archive in a scratch directory and
+/// computes the digest of the restored immutable files, as a client would.
+pub struct UnpackingSnapshotRestorabilityVerifier {
+    /// Directory where the archives are unpacked
+    scratch_directory: PathBuf,
+
+    /// Compression algorithm of the archives
+    compression_algorithm: CompressionAlgorithm,
+
+    /// Digester of the restored immutable files, it must not use a digests cache since the
+    /// cached digests would be the ones of the original files.
+    digester: Arc<dyn ImmutableDigester>,
+}
+
+impl UnpackingSnapshotRestorabilityVerifier {
+    /// UnpackingSnapshotRestorabilityVerifier factory
+    pub fn new(
+        scratch_directory: PathBuf,
+        compression_algorithm: CompressionAlgorithm,
+        digester: Arc<dyn ImmutableDigester>,
+    ) -> Self {
+        Self {
+            scratch_directory,
+            compression_algorithm,
+            digester,
+        }
+    }
+
+    fn unpack_archive(
+        archive_path: &Path,
+        compression_algorithm: CompressionAlgorithm,
+        unpack_directory: &Path,
+    ) -> StdResult<()> {
+        let archive_file = File::open(archive_path)
+            .map_err(|e| SnapshotError::InvalidArchiveError(e.to_string()))?;
+        let mut archive: Archive<Box<dyn Read>> = match compression_algorithm {
+            CompressionAlgorithm::Gzip => Archive::new(Box::new(GzDecoder::new(archive_file))),
+            CompressionAlgorithm::Zstandard => Archive::new(Box::new(Decoder::new(archive_file)?)),
+        };
+        archive.unpack(unpack_directory).map_err(|e| {
+            SnapshotError::InvalidArchiveError(format!("can't unpack archive with error: '{e:?}'"))
+        })?;
+
+        Ok(())
+    }
+
+    async fn unpack_and_compute_digest(
+        &self,
+        ongoing_snapshot: &OngoingSnapshot,
+        beacon: &CardanoDbBeacon,
+        unpack_directory: &Path,
+    ) -> StdResult<String> {
+        let archive_path = ongoing_snapshot.get_file_path().to_path_buf();
+        let compression_algorithm = self.compression_algorithm;
+        let directory = unpack_directory.to_path_buf();
+        // spawn a separate thread to prevent blocking
+        tokio::task::spawn_blocking(move || {
+            Self::unpack_archive(&archive_path, compression_algorithm, &directory)
+        })
+        .await??;
+
+        let digest = self
+            .digester
+            .compute_digest(unpack_directory, beacon)
+            .await
+            .with_context(|| "Can not compute the digest of the unpacked snapshot")?;
+
+        Ok(digest)
+    }
+}
+
+#[async_trait]
+impl SnapshotRestorabilityVerifier for UnpackingSnapshotRestorabilityVerifier {
+    async fn verify_restorability(
+        &self,
+        ongoing_snapshot: &OngoingSnapshot,
+        beacon: &CardanoDbBeacon,
+        expected_digest: &str,
+    ) -> StdResult<()> {
+        let archive_path = ongoing_snapshot.get_file_path();
+        info!(
+            "verifying restorability of archive: {}",
+            archive_path.display()
+        );
+        let unpack_directory = self.scratch_directory.join(archive_path.file_name().ok_or(
+            SnapshotError::VerifyArchiveError(format!(
+                "Could not append archive name to scratch directory: archive `{}`",
+                archive_path.display(),
+            )),
+        )?);
+        std::fs::create_dir_all(&unpack_directory).map_err(|e| {
+            SnapshotError::VerifyArchiveError(format!(
+                "Could not create directory `{}`: {e}",
+                unpack_directory.display(),
+            ))
+        })?;
+
+        let digest = self
+            .unpack_and_compute_digest(ongoing_snapshot, beacon, &unpack_directory)
+            .await;
+
+        // Always remove the scratch directory
+        if let Err(error) = std::fs::remove_dir_all(&unpack_directory) {
+            warn!(
+                " > Post restorability verification, could not remove scratch directory at path: path:{}, err: {}",
+                unpack_directory.display(),
+                error
+            );
+        }
+
+        let digest = digest.with_context(|| {
+            format!(
+                "Snapshot archive is not restorable: '{}'",
+                archive_path.display()
+            )
+        })?;
+        if digest != expected_digest {
+            return Err(anyhow!(SnapshotError::VerifyArchiveError(format!(
+                "digest of the restored snapshot '{digest}' does not match the expected digest '{expected_digest}'"
+            ))));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::digesters::{CardanoImmutableDigester, DummyImmutablesDbBuilder};
+    use mithril_common::test_utils::TempDir;
+
+    use crate::{CompressedArchiveSnapshotter, Snapshotter, SnapshotterCompressionAlgorithm};
+
+    use super::*;
+
+    struct TestSetup {
+        ongoing_snapshot: OngoingSnapshot,
+        beacon: CardanoDbBeacon,
+        digest: String,
+        verifier: UnpackingSnapshotRestorabilityVerifier,
+        scratch_directory: PathBuf,
+    }
+
+    async fn setup(test_name: &str) -> TestSetup {
+        let test_dir = TempDir::create("snapshot_verifier", test_name);
+        let db_directory = test_dir.join("db");
+        let scratch_directory = test_dir.join("scratch");
+        DummyImmutablesDbBuilder::new(db_directory.as_os_str().to_str().unwrap())
+            .with_immutables(&[1, 2, 3])
+            .append_immutable_trio()
+            .build();
+        let digester = Arc::new(CardanoImmutableDigester::new(None, slog_scope::logger()));
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 3);
+        let digest = digester
+            .compute_digest(&db_directory, &beacon)
+            .await
+            .unwrap();
+
+        let snapshotter = CompressedArchiveSnapshotter::new(
+            db_directory,
+            test_dir.join("pending_snapshot"),
+            SnapshotterCompressionAlgorithm::Gzip,
+        )
+        .unwrap();
+        let ongoing_snapshot = snapshotter.snapshot("archive.tar.gz").unwrap();
+
+        TestSetup {
+            ongoing_snapshot,
+            beacon,
+            digest,
+            verifier: UnpackingSnapshotRestorabilityVerifier::new(
+                scratch_directory.clone(),
+                CompressionAlgorithm::Gzip,
+                digester,
+            ),
+            scratch_directory,
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_restorable_snapshot_with_expected_digest() {
+        let setup = setup("verify_restorable_snapshot_with_expected_digest").await;
+
+        setup
+            .verifier
+            .verify_restorability(&setup.ongoing_snapshot, &setup.beacon, &setup.digest)
+            .await
+            .expect("Restorability verification should not fail");
+
+        assert_eq!(
+            0,
+            std::fs::read_dir(&setup.scratch_directory).unwrap().count(),
+            "Scratch directory should have been cleaned"
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_fail_if_restored_snapshot_digest_mismatch() {
+        let setup = setup("verify_fail_if_restored_snapshot_digest_mismatch").await;
+
+        setup
+            .verifier
+            .verify_restorability(&setup.ongoing_snapshot, &setup.beacon, "another-digest")
+            .await
+            .expect_err("Restorability verification should fail");
+
+        assert_eq!(
+            0,
+            std::fs::read_dir(&setup.scratch_directory).unwrap().count(),
+            "Scratch directory should have been cleaned"
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_fail_if_archive_is_corrupted() {
+        let setup = setup("verify_fail_if_archive_is_corrupted").await;
+        std::fs::write(
+            setup.ongoing_snapshot.get_file_path(),
+            b"not a gzip tar archive",
+        )
+        .unwrap();
+
+        setup
+            .verifier
+            .verify_restorability(&setup.ongoing_snapshot, &setup.beacon, &setup.digest)
+            .await
+            .expect_err("Restorability verification should fail");
+    }
+}
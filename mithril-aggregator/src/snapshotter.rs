@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Context};
 use flate2::Compression;
 use flate2::{read::GzDecoder, write::GzEncoder};
-use mithril_common::StdResult;
+use mithril_common::{archive::DeterministicTarBuilder, StdResult};
 use slog_scope::{info, warn};
 use std::fs::{self, File};
 use std::io::{self, Read, Seek, SeekFrom};
@@ -167,10 +167,9 @@ impl CompressedArchiveSnapshotter {
         match self.compression_algorithm {
             SnapshotterCompressionAlgorithm::Gzip => {
                 let enc = GzEncoder::new(tar_file, Compression::default());
-                let mut tar = tar::Builder::new(enc);
+                let mut tar = DeterministicTarBuilder::new(enc);
 
                 tar.append_dir_all(".", &self.db_directory)
-                    .map_err(SnapshotError::CreateArchiveError)
                     .with_context(|| {
                         format!(
                             "GzEncoder Builder can not add directory: '{}' to the archive",
@@ -180,7 +179,6 @@ impl CompressedArchiveSnapshotter {
 
                 let mut gz = tar
                     .into_inner()
-                    .map_err(SnapshotError::CreateArchiveError)
                     .with_context(|| "GzEncoder Builder can not write the archive")?;
                 gz.try_finish()
                     .map_err(SnapshotError::CreateArchiveError)
@@ -190,10 +188,9 @@ impl CompressedArchiveSnapshotter {
                 let mut enc = Encoder::new(tar_file, params.level)?;
                 enc.multithread(params.number_of_workers)
                     .map_err(SnapshotError::CreateArchiveError)?;
-                let mut tar = tar::Builder::new(enc);
+                let mut tar = DeterministicTarBuilder::new(enc);
 
                 tar.append_dir_all(".", &self.db_directory)
-                    .map_err(SnapshotError::CreateArchiveError)
                     .with_context(|| {
                         format!(
                             "ZstandardEncoder Builder can not add directory: '{}' to the archive",
@@ -203,7 +200,6 @@ impl CompressedArchiveSnapshotter {
 
                 let zstd = tar
                     .into_inner()
-                    .map_err(SnapshotError::CreateArchiveError)
                     .with_context(|| "ZstandardEncoder Builder can not write the archive")?;
                 zstd.finish()
                     .map_err(SnapshotError::CreateArchiveError)
@@ -569,4 +565,31 @@ mod tests {
             .snapshot(pending_snapshot_archive_file)
             .expect("Snapshotter::snapshot should not fail.");
     }
+
+    #[test]
+    fn should_create_identical_archives_of_the_same_db() {
+        let test_dir = get_test_directory("should_create_identical_archives_of_the_same_db");
+        let pending_snapshot_directory = test_dir.join("pending_snapshot");
+        let db_directory = test_dir.join("db");
+
+        DummyImmutablesDbBuilder::new(db_directory.as_os_str().to_str().unwrap())
+            .with_immutables(&[1, 2, 3])
+            .append_immutable_trio()
+            .build();
+
+        let snapshotter = CompressedArchiveSnapshotter::new(
+            db_directory,
+            pending_snapshot_directory,
+            SnapshotterCompressionAlgorithm::Gzip,
+        )
+        .unwrap();
+
+        let first_snapshot = snapshotter.snapshot("first.tar.gz").unwrap();
+        let second_snapshot = snapshotter.snapshot("second.tar.gz").unwrap();
+
+        assert_eq!(
+            fs::read(first_snapshot.get_file_path()).unwrap(),
+            fs::read(second_snapshot.get_file_path()).unwrap()
+        );
+    }
 }
@@ -249,7 +249,8 @@ mod test {
             time_point.epoch,
             time_point.immutable_file_number,
             SignedEntityConfig::dummy()
-                .time_point_to_signed_entity(signed_entity_type, &time_point),
+                .time_point_to_signed_entity(signed_entity_type, &time_point)
+                .unwrap(),
         );
 
         certificate.into()
@@ -275,6 +276,12 @@ mod test {
                     SignedEntityType::CardanoTransactions(epoch, block_number) => {
                         format!("cardano-transactions-{epoch}-{block_number}",)
                     }
+                    SignedEntityType::CardanoUtxoSet(epoch) => {
+                        format!("cardano-utxo-set-{epoch}")
+                    }
+                    SignedEntityType::DataAttestation(beacon) => {
+                        format!("data-attestation-{}-{}", beacon.epoch, beacon.payload_hash)
+                    }
                 };
 
                 let signed_entity_record = SignedEntityRecord {
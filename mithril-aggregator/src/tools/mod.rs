@@ -4,6 +4,7 @@ mod era;
 mod genesis;
 #[cfg(test)]
 pub mod mocks;
+mod peer_reconciler;
 mod remote_file_uploader;
 mod signer_importer;
 
@@ -11,6 +12,9 @@ pub use certificates_hash_migrator::CertificatesHashMigrator;
 pub use digest_helpers::extract_digest_from_path;
 pub use era::EraTools;
 pub use genesis::{GenesisTools, GenesisToolsDependency};
+pub use peer_reconciler::{
+    AggregatorHttpInventoryRetriever, DatabaseLocalInventoryReader, PeerReconciler,
+};
 pub use remote_file_uploader::{GcpFileUploader, RemoteFileUploader};
 pub use signer_importer::{
     CExplorerSignerRetriever, SignersImporter, SignersImporterPersister, SignersImporterRetriever,
@@ -0,0 +1,672 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use reqwest::{IntoUrl, Url};
+use serde::de::DeserializeOwned;
+use slog_scope::{info, warn};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use mithril_common::entities::{
+    Certificate, ProtocolMessage, SignedEntityType, SignedEntityTypeDiscriminants,
+};
+use mithril_common::messages::{
+    CardanoTransactionSnapshotListMessage, CardanoUtxoSetSnapshotListMessage,
+    CertificateListMessage, MithrilStakeDistributionListMessage, SnapshotListMessage,
+};
+use mithril_common::StdResult;
+
+use crate::database::repository::{CertificateRepository, SignedEntityStorer};
+use crate::event_store::{
+    EventMessage, EventPayload, PeerDivergence, PeerDivergenceEventPayload, TransmitterService,
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Number of the latest certificates and artifacts of each type compared with the peer, it
+/// matches the number of items listed by the aggregator API.
+const INVENTORY_SIZE: usize = 20;
+
+/// Latest certificates and artifacts of an aggregator.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AggregatorInventory {
+    /// Protocol messages of the latest certificates, by the signed entity type they certify
+    pub certificate_protocol_messages: HashMap<SignedEntityType, ProtocolMessage>,
+
+    /// Ids of the latest artifacts, by the signed entity type they certify
+    pub artifact_ids: HashMap<SignedEntityType, String>,
+}
+
+/// Tool that compares the local certificate chain and artifacts against the ones of a peer
+/// aggregator, and reports the divergences in the event store.
+///
+/// Certificates of two aggregators are signed by different signatures, so they are compared by
+/// the protocol message they certify for a signed entity type rather than by their hash.
+pub struct PeerReconciler {
+    peer_aggregator_endpoint: String,
+    peer_retriever: Arc<dyn PeerInventoryRetriever>,
+    local_reader: Arc<dyn LocalInventoryReader>,
+    event_transmitter: Arc<TransmitterService<EventMessage>>,
+    reported_divergences: Mutex<Vec<PeerDivergence>>,
+    divergences_since_startup_counter: AtomicU64,
+}
+
+impl PeerReconciler {
+    /// [PeerReconciler] factory
+    pub(crate) fn new(
+        peer_aggregator_endpoint: &str,
+        peer_retriever: Arc<dyn PeerInventoryRetriever>,
+        local_reader: Arc<dyn LocalInventoryReader>,
+        event_transmitter: Arc<TransmitterService<EventMessage>>,
+    ) -> Self {
+        Self {
+            peer_aggregator_endpoint: peer_aggregator_endpoint.to_string(),
+            peer_retriever,
+            local_reader,
+            event_transmitter,
+            reported_divergences: Mutex::new(vec![]),
+            divergences_since_startup_counter: AtomicU64::new(0),
+        }
+    }
+
+    /// Compare the local inventory against the peer one and report the divergences that were
+    /// not found by the previous run.
+    ///
+    /// A certificate that the peer has just produced may be reported missing until the local
+    /// aggregator produces it too.
+    pub async fn run(&self) -> StdResult<Vec<PeerDivergence>> {
+        info!("🔧 Peer Reconciler: starting"; "peer_aggregator_endpoint" => &self.peer_aggregator_endpoint);
+        let peer_inventory = self
+            .peer_retriever
+            .retrieve()
+            .await
+            .with_context(|| "Failed to retrieve the inventory of the peer aggregator")?;
+        let divergences = self.compute_divergences(peer_inventory).await?;
+        let divergences = self.keep_new_divergences(divergences);
+        self.divergences_since_startup_counter
+            .fetch_add(divergences.len() as u64, Ordering::Relaxed);
+
+        for divergence in &divergences {
+            warn!("🔧 Peer Reconciler: divergence found"; "divergence" => ?divergence);
+            let _ = self.event_transmitter.send_event_message(
+                "PeerReconciler",
+                EventPayload::PeerDivergence(PeerDivergenceEventPayload {
+                    peer_aggregator_endpoint: self.peer_aggregator_endpoint.clone(),
+                    divergence: divergence.clone(),
+                }),
+                vec![],
+            );
+        }
+        info!(
+            "🔧 Peer Reconciler: finished";
+            "number_of_new_divergences" => divergences.len(),
+            "divergences_since_startup" => self.divergences_since_startup_counter_get()
+        );
+
+        Ok(divergences)
+    }
+
+    /// Number of divergences reported since the startup of the aggregator.
+    pub fn divergences_since_startup_counter_get(&self) -> u64 {
+        self.divergences_since_startup_counter
+            .load(Ordering::Relaxed)
+    }
+
+    /// Keep the divergences that were not found by the previous run, a divergence that is
+    /// resolved and then found again is reported again.
+    fn keep_new_divergences(&self, divergences: Vec<PeerDivergence>) -> Vec<PeerDivergence> {
+        let mut reported_divergences = self.reported_divergences.lock().unwrap();
+        let new_divergences = divergences
+            .iter()
+            .filter(|divergence| !reported_divergences.contains(divergence))
+            .cloned()
+            .collect();
+        *reported_divergences = divergences;
+
+        new_divergences
+    }
+
+    async fn compute_divergences(
+        &self,
+        peer_inventory: AggregatorInventory,
+    ) -> StdResult<Vec<PeerDivergence>> {
+        let mut divergences = vec![];
+
+        for (signed_entity_type, peer_protocol_message) in
+            peer_inventory.certificate_protocol_messages
+        {
+            match self
+                .local_reader
+                .get_certificate_protocol_message(&signed_entity_type)
+                .await?
+            {
+                None => {
+                    divergences.push(PeerDivergence::MissingCertificate { signed_entity_type });
+                }
+                Some(local_protocol_message) if local_protocol_message != peer_protocol_message => {
+                    divergences.push(PeerDivergence::DifferingCertificate {
+                        signed_entity_type,
+                        local_protocol_message,
+                        peer_protocol_message,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        let local_artifact_ids = self.local_reader.get_latest_artifact_ids().await?;
+        for (signed_entity_type, peer_artifact_id) in peer_inventory.artifact_ids {
+            match local_artifact_ids.get(&signed_entity_type) {
+                Some(local_artifact_id) if local_artifact_id != &peer_artifact_id => {
+                    divergences.push(PeerDivergence::DifferingArtifact {
+                        signed_entity_type,
+                        local_artifact_id: local_artifact_id.clone(),
+                        peer_artifact_id,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(divergences)
+    }
+
+    /// Start a loop that call [run][Self::run] at the given time interval.
+    pub async fn run_forever(&self, run_interval: Duration) {
+        let mut interval = tokio::time::interval(run_interval);
+
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.run().await {
+                warn!("Peer reconciliation failed: Error: «{:?}».", error);
+            }
+            info!(
+                "🔧 Peer Reconciler: Cycle finished, Sleeping for {} min",
+                run_interval.as_secs() / 60
+            );
+        }
+    }
+}
+
+/// Trait that define how a [PeerReconciler] retrieve the inventory of the peer aggregator.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait PeerInventoryRetriever: Sync + Send {
+    /// Retrieve the latest certificates and artifacts of the peer.
+    async fn retrieve(&self) -> StdResult<AggregatorInventory>;
+}
+
+/// Trait that define how a [PeerReconciler] read the local inventory.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait LocalInventoryReader: Sync + Send {
+    /// Get the protocol message of the local certificate of the given signed entity type if any.
+    async fn get_certificate_protocol_message(
+        &self,
+        signed_entity_type: &SignedEntityType,
+    ) -> StdResult<Option<ProtocolMessage>>;
+
+    /// Get the ids of the latest local artifacts, by the signed entity type they certify.
+    async fn get_latest_artifact_ids(&self) -> StdResult<HashMap<SignedEntityType, String>>;
+}
+
+/// A [PeerInventoryRetriever] fetching the inventory from the API of the peer aggregator.
+pub struct AggregatorHttpInventoryRetriever {
+    aggregator_endpoint: Url,
+    client: reqwest::Client,
+}
+
+impl AggregatorHttpInventoryRetriever {
+    /// Create a new [AggregatorHttpInventoryRetriever] fetching the aggregator at the given url.
+    pub(crate) fn new<T: IntoUrl>(
+        aggregator_endpoint: T,
+        timeout: Option<Duration>,
+    ) -> StdResult<Self> {
+        let aggregator_endpoint = aggregator_endpoint
+            .into_url()
+            .with_context(|| "Given `aggregator_endpoint` is not a valid Url")?;
+        let client_builder = reqwest::Client::builder();
+        let client = match timeout {
+            None => client_builder,
+            Some(timeout) => client_builder.timeout(timeout),
+        }
+        .build()
+        .with_context(|| "Http Client build failed")?;
+
+        Ok(Self {
+            aggregator_endpoint,
+            client,
+        })
+    }
+
+    async fn get<T: DeserializeOwned>(&self, route: &str) -> StdResult<T> {
+        let url = format!(
+            "{}/{route}",
+            self.aggregator_endpoint.as_str().trim_end_matches('/')
+        );
+        self.client
+            .get(&url)
+            .send()
+            .await
+            .with_context(|| format!("Retrieving of '{url}' failed"))?
+            .error_for_status()
+            .with_context(|| format!("Data fetching of '{url}' failed"))?
+            .json::<T>()
+            .await
+            .with_context(|| format!("Failed to deserialize the data retrieved from '{url}'"))
+    }
+}
+
+#[async_trait]
+impl PeerInventoryRetriever for AggregatorHttpInventoryRetriever {
+    async fn retrieve(&self) -> StdResult<AggregatorInventory> {
+        let certificates: CertificateListMessage = self.get("certificates").await?;
+        let snapshots: SnapshotListMessage = self.get("artifact/snapshots").await?;
+        let mithril_stake_distributions: MithrilStakeDistributionListMessage =
+            self.get("artifact/mithril-stake-distributions").await?;
+        let cardano_transactions: CardanoTransactionSnapshotListMessage =
+            self.get("artifact/cardano-transactions").await?;
+        let cardano_utxo_sets: CardanoUtxoSetSnapshotListMessage =
+            self.get("artifact/cardano-utxo-sets").await?;
+
+        let artifact_ids = snapshots
+            .into_iter()
+            .map(|item| {
+                (
+                    SignedEntityType::CardanoImmutableFilesFull(item.beacon),
+                    item.digest,
+                )
+            })
+            .chain(mithril_stake_distributions.into_iter().map(|item| {
+                (
+                    SignedEntityType::MithrilStakeDistribution(item.epoch),
+                    item.hash,
+                )
+            }))
+            .chain(cardano_transactions.into_iter().map(|item| {
+                (
+                    SignedEntityType::CardanoTransactions(item.epoch, item.block_number),
+                    item.hash,
+                )
+            }))
+            .chain(
+                cardano_utxo_sets
+                    .into_iter()
+                    .map(|item| (SignedEntityType::CardanoUtxoSet(item.epoch), item.hash)),
+            )
+            .collect();
+
+        let mut certificate_protocol_messages = HashMap::new();
+        // Certificates are sorted from the newest, only keep the latest certificate of a beacon
+        for item in certificates {
+            certificate_protocol_messages
+                .entry(item.signed_entity_type)
+                .or_insert(item.protocol_message);
+        }
+
+        Ok(AggregatorInventory {
+            certificate_protocol_messages,
+            artifact_ids,
+        })
+    }
+}
+
+/// A [LocalInventoryReader] reading the local certificate chain and artifacts from the database.
+pub struct DatabaseLocalInventoryReader {
+    certificate_repository: Arc<CertificateRepository>,
+    signed_entity_storer: Arc<dyn SignedEntityStorer>,
+}
+
+impl DatabaseLocalInventoryReader {
+    /// [DatabaseLocalInventoryReader] factory
+    pub fn new(
+        certificate_repository: Arc<CertificateRepository>,
+        signed_entity_storer: Arc<dyn SignedEntityStorer>,
+    ) -> Self {
+        Self {
+            certificate_repository,
+            signed_entity_storer,
+        }
+    }
+}
+
+#[async_trait]
+impl LocalInventoryReader for DatabaseLocalInventoryReader {
+    async fn get_certificate_protocol_message(
+        &self,
+        signed_entity_type: &SignedEntityType,
+    ) -> StdResult<Option<ProtocolMessage>> {
+        let certificate = self
+            .certificate_repository
+            .get_certificate_by_signed_entity_type::<Certificate>(signed_entity_type)
+            .await?;
+
+        Ok(certificate.map(|c| c.protocol_message))
+    }
+
+    async fn get_latest_artifact_ids(&self) -> StdResult<HashMap<SignedEntityType, String>> {
+        let mut artifact_ids = HashMap::new();
+
+        for discriminant in [
+            SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+            SignedEntityTypeDiscriminants::MithrilStakeDistribution,
+            SignedEntityTypeDiscriminants::CardanoTransactions,
+            SignedEntityTypeDiscriminants::CardanoUtxoSet,
+        ] {
+            let records = self
+                .signed_entity_storer
+                .get_last_signed_entities_by_type(&discriminant, INVENTORY_SIZE)
+                .await?;
+            // Records are sorted from the newest, only keep the latest artifact of a beacon
+            for record in records {
+                artifact_ids
+                    .entry(record.signed_entity_type)
+                    .or_insert(record.signed_entity_id);
+            }
+        }
+
+        Ok(artifact_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::{CardanoDbBeacon, Epoch, ProtocolMessagePartKey};
+    use mithril_common::messages::{
+        CertificateListItemMessage, MithrilStakeDistributionListItemMessage,
+        SnapshotListItemMessage,
+    };
+    use mithril_common::test_utils::test_http_server::test_http_server;
+    use tokio::sync::mpsc::unbounded_channel;
+    use warp::Filter;
+
+    use crate::database::record::{CertificateRecord, SignedEntityRecord};
+    use crate::database::repository::SignedEntityStore;
+    use crate::database::test_helper::{
+        insert_certificate_records, insert_signed_entities, main_db_connection,
+    };
+
+    use super::*;
+
+    fn msd_type(epoch: u64) -> SignedEntityType {
+        SignedEntityType::MithrilStakeDistribution(Epoch(epoch))
+    }
+
+    fn protocol_message(digest: &str) -> ProtocolMessage {
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message
+            .set_message_part(ProtocolMessagePartKey::SnapshotDigest, digest.to_string());
+
+        protocol_message
+    }
+
+    fn build_reconciler(
+        peer_inventory: AggregatorInventory,
+        local_reader: MockLocalInventoryReader,
+    ) -> (
+        PeerReconciler,
+        tokio::sync::mpsc::UnboundedReceiver<EventMessage>,
+    ) {
+        let mut peer_retriever = MockPeerInventoryRetriever::new();
+        peer_retriever
+            .expect_retrieve()
+            .returning(move || Ok(peer_inventory.clone()));
+        let (tx, rx) = unbounded_channel();
+        let reconciler = PeerReconciler::new(
+            "http://peer-aggregator",
+            Arc::new(peer_retriever),
+            Arc::new(local_reader),
+            Arc::new(TransmitterService::new(tx)),
+        );
+
+        (reconciler, rx)
+    }
+
+    #[tokio::test]
+    async fn no_divergence_when_local_inventory_matches_the_peer() {
+        let mut local_reader = MockLocalInventoryReader::new();
+        local_reader
+            .expect_get_certificate_protocol_message()
+            .returning(|_| Ok(Some(protocol_message("digest-5"))));
+        local_reader
+            .expect_get_latest_artifact_ids()
+            .returning(|| Ok(HashMap::from([(msd_type(5), "hash-5".to_string())])));
+        let (reconciler, mut rx) = build_reconciler(
+            AggregatorInventory {
+                certificate_protocol_messages: HashMap::from([(
+                    msd_type(5),
+                    protocol_message("digest-5"),
+                )]),
+                artifact_ids: HashMap::from([(msd_type(5), "hash-5".to_string())]),
+            },
+            local_reader,
+        );
+
+        let divergences = reconciler.run().await.unwrap();
+
+        assert!(divergences.is_empty());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn report_missing_and_differing_certificates_and_differing_artifacts() {
+        let mut local_reader = MockLocalInventoryReader::new();
+        local_reader
+            .expect_get_certificate_protocol_message()
+            .returning(|signed_entity_type| match signed_entity_type {
+                t if t == &msd_type(5) => Ok(Some(protocol_message("digest-5"))),
+                t if t == &msd_type(6) => Ok(Some(protocol_message("local-digest-6"))),
+                _ => Ok(None),
+            });
+        local_reader.expect_get_latest_artifact_ids().returning(|| {
+            Ok(HashMap::from([
+                (msd_type(5), "hash-5".to_string()),
+                (msd_type(6), "local-hash-6".to_string()),
+            ]))
+        });
+        let (reconciler, mut rx) = build_reconciler(
+            AggregatorInventory {
+                certificate_protocol_messages: HashMap::from([
+                    (msd_type(5), protocol_message("digest-5")),
+                    (msd_type(6), protocol_message("peer-digest-6")),
+                    (msd_type(7), protocol_message("digest-7")),
+                ]),
+                artifact_ids: HashMap::from([
+                    (msd_type(5), "hash-5".to_string()),
+                    (msd_type(6), "peer-hash-6".to_string()),
+                    // Not produced locally yet: only reported as a missing certificate
+                    (msd_type(7), "hash-7".to_string()),
+                ]),
+            },
+            local_reader,
+        );
+
+        let divergences = reconciler.run().await.unwrap();
+
+        let expected_divergences = vec![
+            PeerDivergence::MissingCertificate {
+                signed_entity_type: msd_type(7),
+            },
+            PeerDivergence::DifferingCertificate {
+                signed_entity_type: msd_type(6),
+                local_protocol_message: protocol_message("local-digest-6"),
+                peer_protocol_message: protocol_message("peer-digest-6"),
+            },
+            PeerDivergence::DifferingArtifact {
+                signed_entity_type: msd_type(6),
+                local_artifact_id: "local-hash-6".to_string(),
+                peer_artifact_id: "peer-hash-6".to_string(),
+            },
+        ];
+        assert_eq!(expected_divergences.len(), divergences.len());
+        for expected_divergence in &expected_divergences {
+            assert!(
+                divergences.contains(expected_divergence),
+                "divergence {expected_divergence:?} should be reported, got: {divergences:?}"
+            );
+        }
+        for divergence in divergences {
+            let message = rx.try_recv().unwrap();
+            assert_eq!(
+                EventPayload::PeerDivergence(PeerDivergenceEventPayload {
+                    peer_aggregator_endpoint: "http://peer-aggregator".to_string(),
+                    divergence,
+                }),
+                message.payload
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn only_report_divergences_not_found_by_the_previous_run() {
+        let mut local_reader = MockLocalInventoryReader::new();
+        let mut number_of_calls = 0;
+        local_reader
+            .expect_get_certificate_protocol_message()
+            .returning(move |_| {
+                number_of_calls += 1;
+                // Missing for the two first runs, resolved at the third one and missing again after
+                match number_of_calls {
+                    3 => Ok(Some(protocol_message("digest-5"))),
+                    _ => Ok(None),
+                }
+            });
+        local_reader
+            .expect_get_latest_artifact_ids()
+            .returning(|| Ok(HashMap::new()));
+        let (reconciler, mut rx) = build_reconciler(
+            AggregatorInventory {
+                certificate_protocol_messages: HashMap::from([(
+                    msd_type(5),
+                    protocol_message("digest-5"),
+                )]),
+                artifact_ids: HashMap::new(),
+            },
+            local_reader,
+        );
+        let missing_certificate = PeerDivergence::MissingCertificate {
+            signed_entity_type: msd_type(5),
+        };
+
+        assert_eq!(
+            vec![missing_certificate.clone()],
+            reconciler.run().await.unwrap()
+        );
+        assert_eq!(
+            Vec::<PeerDivergence>::new(),
+            reconciler.run().await.unwrap()
+        );
+        assert_eq!(
+            Vec::<PeerDivergence>::new(),
+            reconciler.run().await.unwrap()
+        );
+        assert_eq!(vec![missing_certificate], reconciler.run().await.unwrap());
+
+        assert_eq!(2, reconciler.divergences_since_startup_counter_get());
+        assert!(rx.try_recv().is_ok());
+        assert!(rx.try_recv().is_ok());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn http_retriever_build_the_inventory_from_the_aggregator_lists() {
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 3, 45);
+        let snapshot = SnapshotListItemMessage {
+            digest: "digest-45".to_string(),
+            beacon: beacon.clone(),
+            ..SnapshotListItemMessage::dummy()
+        };
+        let msd = MithrilStakeDistributionListItemMessage {
+            epoch: Epoch(3),
+            hash: "msd-hash-3".to_string(),
+            ..MithrilStakeDistributionListItemMessage::dummy()
+        };
+        let certificates = vec![
+            CertificateListItemMessage {
+                hash: "certificate-2".to_string(),
+                signed_entity_type: msd_type(3),
+                protocol_message: protocol_message("digest-3"),
+                ..CertificateListItemMessage::dummy()
+            },
+            // Older certificate of the same beacon, superseded by the newest one
+            CertificateListItemMessage {
+                hash: "certificate-1".to_string(),
+                signed_entity_type: msd_type(3),
+                protocol_message: protocol_message("older-digest-3"),
+                ..CertificateListItemMessage::dummy()
+            },
+        ];
+        let server = test_http_server(
+            warp::path("certificates")
+                .map(move || warp::reply::json(&certificates))
+                .or(warp::path!("artifact" / "snapshots")
+                    .map(move || warp::reply::json(&vec![snapshot.clone()])))
+                .or(warp::path!("artifact" / "mithril-stake-distributions")
+                    .map(move || warp::reply::json(&vec![msd.clone()])))
+                .or(warp::path!("artifact" / "cardano-transactions")
+                    .map(|| warp::reply::json(&Vec::<String>::new())))
+                .or(warp::path!("artifact" / "cardano-utxo-sets")
+                    .map(|| warp::reply::json(&Vec::<String>::new()))),
+        );
+        let retriever = AggregatorHttpInventoryRetriever::new(server.url(), None).unwrap();
+
+        let inventory = retriever.retrieve().await.unwrap();
+
+        assert_eq!(
+            AggregatorInventory {
+                certificate_protocol_messages: HashMap::from([(
+                    msd_type(3),
+                    protocol_message("digest-3")
+                )]),
+                artifact_ids: HashMap::from([
+                    (
+                        SignedEntityType::CardanoImmutableFilesFull(beacon),
+                        "digest-45".to_string()
+                    ),
+                    (msd_type(3), "msd-hash-3".to_string()),
+                ]),
+            },
+            inventory
+        );
+    }
+
+    #[tokio::test]
+    async fn database_reader_read_local_certificates_and_artifacts() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        let certificate_record = CertificateRecord {
+            protocol_message: protocol_message("digest-1"),
+            ..CertificateRecord::dummy_genesis("certificate-1", Epoch(1), 1)
+        };
+        insert_certificate_records(&connection, vec![certificate_record.clone()]);
+        let signed_entities = SignedEntityRecord::fake_records(2);
+        insert_signed_entities(&connection, signed_entities.clone()).unwrap();
+        let reader = DatabaseLocalInventoryReader::new(
+            Arc::new(CertificateRepository::new(connection.clone())),
+            Arc::new(SignedEntityStore::new(connection)),
+        );
+
+        assert_eq!(
+            Some(protocol_message("digest-1")),
+            reader
+                .get_certificate_protocol_message(&certificate_record.signed_entity_type)
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            None,
+            reader
+                .get_certificate_protocol_message(&msd_type(50))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            signed_entities
+                .into_iter()
+                .map(|record| (record.signed_entity_type, record.signed_entity_id))
+                .collect::<HashMap<_, _>>(),
+            reader.get_latest_artifact_ids().await.unwrap()
+        );
+    }
+}
@@ -75,8 +75,8 @@ impl AggregatorObserver {
             .await
             .with_context(|| "Querying the current beacon should not fail")?;
 
-        Ok(self
-            .signed_entity_config
-            .time_point_to_signed_entity(discriminant, &time_point))
+        self.signed_entity_config
+            .time_point_to_signed_entity(discriminant, &time_point)
+            .ok_or_else(|| anyhow!("No signed entity type for discriminant: '{discriminant}'"))
     }
 }
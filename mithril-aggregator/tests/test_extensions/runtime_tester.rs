@@ -157,10 +157,10 @@ impl RuntimeTester {
                 &message.source
             );
         }
-        if action != message.action {
+        if action != message.action() {
             error_message.push_str(&format!(
                 "The action of the message ({}) is NOT what was expected ({action}).",
-                &message.action
+                message.action()
             ));
         }
 
@@ -0,0 +1,141 @@
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+
+use mithril_client::aggregator_client::{
+    AggregatorClient, AggregatorClientError, AggregatorRequest,
+};
+use mithril_client::{MithrilCertificate, MithrilResult};
+
+/// [AggregatorClient] serving the certificates of an offline certificate chain bundle.
+///
+/// A bundle is a JSON file holding the array of the certificates of a chain, as returned by the
+/// `/certificate/{hash}` route of an aggregator, from the certificate to verify down to its
+/// genesis certificate.
+pub struct CertificateChainBundleClient {
+    certificates: HashMap<String, String>,
+}
+
+impl CertificateChainBundleClient {
+    /// Constructs a new `CertificateChainBundleClient` from the given certificates.
+    pub fn new(certificates: Vec<MithrilCertificate>) -> MithrilResult<Self> {
+        let certificates = certificates
+            .into_iter()
+            .map(|certificate| {
+                let hash = certificate.hash.clone();
+                serde_json::to_string(&certificate).map(|json| (hash, json))
+            })
+            .collect::<Result<_, _>>()
+            .with_context(|| "Could not serialize the certificates of the chain bundle")?;
+
+        Ok(Self { certificates })
+    }
+
+    /// Constructs a new `CertificateChainBundleClient` from the bundle file at the given path.
+    pub fn from_file(path: &Path) -> MithrilResult<Self> {
+        let content = std::fs::read_to_string(path).with_context(|| {
+            format!(
+                "Could not read certificate chain bundle: '{}'",
+                path.display()
+            )
+        })?;
+        let certificates: Vec<MithrilCertificate> =
+            serde_json::from_str(&content).with_context(|| {
+                format!(
+                    "Could not parse certificate chain bundle: '{}'",
+                    path.display()
+                )
+            })?;
+
+        Self::new(certificates)
+    }
+}
+
+#[async_trait]
+impl AggregatorClient for CertificateChainBundleClient {
+    async fn get_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        match request {
+            AggregatorRequest::GetCertificate { hash } => {
+                self.certificates.get(&hash).cloned().ok_or_else(|| {
+                    AggregatorClientError::RemoteServerLogical(anyhow!(
+                        "Certificate '{hash}' is not in the chain bundle"
+                    ))
+                })
+            }
+            request => Err(AggregatorClientError::SubsystemError(anyhow!(
+                "Request not supported by an offline certificate chain bundle: {request:?}"
+            ))),
+        }
+    }
+
+    async fn post_content(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<String, AggregatorClientError> {
+        Err(AggregatorClientError::SubsystemError(anyhow!(
+            "Request not supported by an offline certificate chain bundle: {request:?}"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn certificate(hash: &str) -> MithrilCertificate {
+        MithrilCertificate {
+            hash: hash.to_string(),
+            ..MithrilCertificate::dummy()
+        }
+    }
+
+    #[tokio::test]
+    async fn serve_certificates_of_the_bundle() {
+        let client =
+            CertificateChainBundleClient::new(vec![certificate("hash-1"), certificate("hash-2")])
+                .unwrap();
+
+        let content = client
+            .get_content(AggregatorRequest::GetCertificate {
+                hash: "hash-2".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            certificate("hash-2"),
+            serde_json::from_str::<MithrilCertificate>(&content).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_certificate_is_a_logical_error() {
+        let client = CertificateChainBundleClient::new(vec![certificate("hash-1")]).unwrap();
+
+        let error = client
+            .get_content(AggregatorRequest::GetCertificate {
+                hash: "hash-2".to_string(),
+            })
+            .await
+            .expect_err("Getting a certificate missing from the bundle should fail");
+
+        assert!(
+            matches!(error, AggregatorClientError::RemoteServerLogical(_)),
+            "Unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn other_requests_are_not_supported() {
+        let client = CertificateChainBundleClient::new(vec![certificate("hash-1")]).unwrap();
+
+        client
+            .get_content(AggregatorRequest::ListSnapshots)
+            .await
+            .expect_err("Listing snapshots from a bundle should fail");
+    }
+}
@@ -0,0 +1,186 @@
+//! Minimal verification of a Cardano db directory against a Mithril certificate.
+//!
+//! Meant for container entrypoints and boot-time integrity checks: it does not read any
+//! configuration file, does not display any progress and prints a single line result.
+//! The exit code is `0` if the Cardano db matches the certificate and its certificate chain is
+//! valid, `1` otherwise.
+
+mod chain_bundle;
+
+use anyhow::{anyhow, Context};
+use clap::{ArgGroup, Parser};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use mithril_client::{ClientBuilder, MessageBuilder, MithrilResult};
+
+use crate::chain_bundle::CertificateChainBundleClient;
+
+#[derive(Parser, Debug, Clone)]
+#[clap(name = "mithril-verify")]
+#[clap(
+    about = "Verify that a Cardano db directory matches a Mithril certificate, exit with 0 if it does, 1 otherwise.",
+    long_about = None
+)]
+#[command(version)]
+#[clap(group(ArgGroup::new("certificate_source").required(true).args(["aggregator_endpoint", "chain_bundle"])))]
+pub struct Args {
+    /// Directory of the Cardano db to verify, ie: the directory that contains the `immutable`
+    /// directory.
+    #[clap(long, env = "DB_DIRECTORY")]
+    db_dir: PathBuf,
+
+    /// Hash of the certificate of the Cardano db.
+    #[clap(long, env = "CERTIFICATE_HASH")]
+    certificate_hash: String,
+
+    /// Aggregator endpoint URL used to fetch the certificate chain.
+    #[clap(long, env = "AGGREGATOR_ENDPOINT")]
+    aggregator_endpoint: Option<String>,
+
+    /// Offline certificate chain bundle: a JSON file holding the array of the certificates
+    /// from the certificate to verify down to its genesis certificate, as returned by the
+    /// aggregator `/certificate/{hash}` route.
+    #[clap(long, env = "CERTIFICATE_CHAIN_BUNDLE")]
+    chain_bundle: Option<PathBuf>,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: String,
+}
+
+impl Args {
+    fn client_builder(&self) -> MithrilResult<ClientBuilder> {
+        match (&self.aggregator_endpoint, &self.chain_bundle) {
+            (_, Some(chain_bundle)) => Ok(ClientBuilder::new(&self.genesis_verification_key)
+                .with_aggregator_client(Arc::new(CertificateChainBundleClient::from_file(
+                    chain_bundle,
+                )?))),
+            (Some(aggregator_endpoint), None) => Ok(ClientBuilder::aggregator(
+                aggregator_endpoint,
+                &self.genesis_verification_key,
+            )),
+            (None, None) => Err(anyhow!(
+                "Either an aggregator endpoint or a certificate chain bundle must be given"
+            )),
+        }
+    }
+
+    async fn verify(&self) -> MithrilResult<()> {
+        if !self.db_dir.join("immutable").is_dir() {
+            return Err(anyhow!(
+                "'{}' is not a Cardano db directory: it has no 'immutable' directory",
+                self.db_dir.display()
+            ));
+        }
+
+        let client = self.client_builder()?.build()?;
+        let certificate = client
+            .certificate()
+            .verify_chain(&self.certificate_hash)
+            .await?;
+        let message = MessageBuilder::new()
+            .compute_snapshot_message(&certificate, &self.db_dir)
+            .await
+            .with_context(|| "Can not compute the Cardano db message")?;
+
+        if !certificate.match_message(&message) {
+            return Err(anyhow!(
+                "Cardano db does not match certificate '{}'",
+                self.certificate_hash
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = Args::parse();
+
+    #[cfg(feature = "bundle_openssl")]
+    openssl_probe::init_ssl_cert_env_vars();
+
+    match args.verify().await {
+        Ok(()) => {
+            println!(
+                "OK: Cardano db '{}' matches certificate '{}'",
+                args.db_dir.display(),
+                args.certificate_hash
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            println!("FAILED: {error:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn fail_if_no_certificate_source_is_given() {
+        Args::try_parse_from([
+            "mithril-verify",
+            "--db-dir",
+            "db",
+            "--certificate-hash",
+            "hash",
+            "--genesis-verification-key",
+            "key",
+        ])
+        .expect_err("Parsing should fail without aggregator endpoint nor chain bundle");
+    }
+
+    #[test]
+    fn fail_if_both_certificate_sources_are_given() {
+        Args::try_parse_from([
+            "mithril-verify",
+            "--db-dir",
+            "db",
+            "--certificate-hash",
+            "hash",
+            "--genesis-verification-key",
+            "key",
+            "--aggregator-endpoint",
+            "http://aggregator",
+            "--chain-bundle",
+            "bundle.json",
+        ])
+        .expect_err("Parsing should fail with both aggregator endpoint and chain bundle");
+    }
+
+    #[tokio::test]
+    async fn fail_if_db_dir_has_no_immutable_directory() {
+        let db_dir = TempDir::create(
+            "mithril-verify",
+            "fail_if_db_dir_has_no_immutable_directory",
+        );
+        let args = Args::try_parse_from([
+            "mithril-verify",
+            "--db-dir",
+            db_dir.to_str().unwrap(),
+            "--certificate-hash",
+            "hash",
+            "--genesis-verification-key",
+            "key",
+            "--aggregator-endpoint",
+            "http://aggregator",
+        ])
+        .unwrap();
+
+        let error = args
+            .verify()
+            .await
+            .expect_err("Verification should fail without immutable directory");
+
+        assert!(error.to_string().contains("no 'immutable' directory"));
+    }
+}
@@ -14,12 +14,15 @@ use crate::{
     commands::client_builder,
     configuration::ConfigParameters,
     utils::{
-        CardanoDbDownloadChecker, CardanoDbUtils, ExpanderUtils, IndicatifFeedbackReceiver,
-        ProgressOutputType, ProgressPrinter,
+        CardanoDbDownloadChecker, CardanoDbUtils, DownloadMeasureFeedbackReceiver,
+        ExecutionSummary, ExpanderUtils, IndicatifFeedbackReceiver, ProgressOutputType,
+        ProgressPrinter,
     },
 };
 use mithril_client::{
-    common::ProtocolMessage, Client, MessageBuilder, MithrilCertificate, MithrilResult, Snapshot,
+    common::ProtocolMessage, feedback::FeedbackReceiver,
+    snapshot_entries_filter::SnapshotEntriesFilter, Client, MessageBuilder, MithrilCertificate,
+    MithrilResult, Snapshot,
 };
 
 /// Clap command to download a Cardano db and verify its associated certificate.
@@ -43,6 +46,32 @@ pub struct CardanoDbDownloadCommand {
     /// Genesis Verification Key to check the certificate chain.
     #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
     genesis_verification_key: Option<String>,
+
+    /// Hash of a trusted certificate to verify the cardano db against, instead of the
+    /// certificate hash given by the aggregator in the cardano db message.
+    #[clap(long)]
+    certificate_hash: Option<String>,
+
+    /// Glob pattern of the files to extract from the cardano db archive, relative to the
+    /// cardano db root (ie: `immutable/`). Can be repeated, all files are extracted if not set.
+    ///
+    /// The immutable files must be extracted for the cardano db to be verified.
+    #[clap(long)]
+    include: Vec<String>,
+
+    /// Glob pattern of the files to skip when extracting the cardano db archive, relative to
+    /// the cardano db root (ie: `ledger/`). Can be repeated, has precedence over `--include`.
+    #[clap(long)]
+    exclude: Vec<String>,
+
+    /// Do not send the download statistics of the cardano db.
+    #[clap(long)]
+    no_statistics: bool,
+
+    /// Endpoint of an alternate collector to send the download statistics to, instead of the
+    /// aggregator.
+    #[clap(long, env = "STATISTICS_ENDPOINT")]
+    statistics_endpoint: Option<String>,
 }
 
 impl CardanoDbDownloadCommand {
@@ -55,8 +84,11 @@ impl CardanoDbDownloadCommand {
     pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
         let config = config_builder.add_source(self.clone()).build()?;
         let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let mut summary = ExecutionSummary::new();
         let download_dir: &String = &params.require("download_dir")?;
         let db_dir = Path::new(download_dir).join("db");
+        let entries_filter = SnapshotEntriesFilter::new(&self.include, &self.exclude)?;
+        Self::check_entries_filter(&entries_filter)?;
 
         let progress_output_type = if self.json {
             ProgressOutputType::JsonReporter
@@ -64,10 +96,15 @@ impl CardanoDbDownloadCommand {
             ProgressOutputType::Tty
         };
         let progress_printer = ProgressPrinter::new(progress_output_type, 5);
-        let client = client_builder(&params)?
-            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
-                progress_output_type,
-            )))
+        let feedback_receiver = Arc::new(
+            IndicatifFeedbackReceiver::new(progress_output_type)
+                .with_multi_progress((*progress_printer).clone()),
+        );
+        let download_measure_receiver = Arc::new(DownloadMeasureFeedbackReceiver::new());
+        let client = client_builder(&params)
+            .await?
+            .add_feedback_receiver(feedback_receiver.clone())
+            .add_feedback_receiver(download_measure_receiver.clone())
             .build()?;
 
         let get_list_of_artifact_ids = || async {
@@ -90,45 +127,92 @@ impl CardanoDbDownloadCommand {
             .await?
             .with_context(|| format!("Can not get the cardano db for digest: '{}'", self.digest))?;
 
-        Self::check_local_disk_info(1, &progress_printer, &db_dir, &cardano_db_message)?;
+        summary
+            .time_step("local_disk_check", async {
+                Self::check_local_disk_info(
+                    1,
+                    &progress_printer,
+                    &db_dir,
+                    &cardano_db_message,
+                    &entries_filter,
+                )
+            })
+            .await?;
 
-        let certificate = Self::fetch_certificate_and_verifying_chain(
-            2,
-            &progress_printer,
-            &client,
-            &cardano_db_message.certificate_hash,
-        )
-        .await?;
+        let certificate = summary
+            .time_step(
+                "certificate_chain_verification",
+                Self::fetch_certificate_and_verifying_chain(
+                    2,
+                    &progress_printer,
+                    &client,
+                    &cardano_db_message,
+                    self.certificate_hash.as_deref(),
+                ),
+            )
+            .await?;
 
-        Self::download_and_unpack_cardano_db(
-            3,
-            &progress_printer,
-            &client,
-            &cardano_db_message,
-            &db_dir,
-        )
-        .await
-        .with_context(|| {
-            format!(
-                "Can not get download and unpack cardano db for digest: '{}'",
-                self.digest
+        summary
+            .time_download_and_unpack_steps(
+                &download_measure_receiver,
+                Self::download_and_unpack_cardano_db(
+                    3,
+                    &progress_printer,
+                    &client,
+                    &cardano_db_message,
+                    &db_dir,
+                    &entries_filter,
+                ),
             )
-        })?;
+            .await
+            .with_context(|| {
+                format!(
+                    "Can not get download and unpack cardano db for digest: '{}'",
+                    self.digest
+                )
+            })?;
+        Self::complete_cardano_db_download(&params, &client, &cardano_db_message, &db_dir).await;
+
+        let message = summary
+            .time_step(
+                "digest_computation",
+                Self::compute_cardano_db_message(
+                    4,
+                    &progress_printer,
+                    feedback_receiver.clone(),
+                    &certificate,
+                    &db_dir,
+                ),
+            )
+            .await?;
 
-        let message =
-            Self::compute_cardano_db_message(4, &progress_printer, &certificate, &db_dir).await?;
+        summary
+            .time_step(
+                "signature_verification",
+                Self::verify_cardano_db_signature(
+                    5,
+                    &progress_printer,
+                    &certificate,
+                    &message,
+                    &cardano_db_message,
+                    &db_dir,
+                ),
+            )
+            .await?;
 
-        Self::verify_cardano_db_signature(
-            5,
-            &progress_printer,
-            &certificate,
-            &message,
-            &cardano_db_message,
-            &db_dir,
-        )
-        .await?;
+        Self::log_download_information(&db_dir, &cardano_db_message, &summary, self.json)?;
 
-        Self::log_download_information(&db_dir, &cardano_db_message, self.json)?;
+        Ok(())
+    }
+
+    /// The cardano db can't be verified if its immutable files are not extracted, fail before
+    /// downloading it.
+    fn check_entries_filter(entries_filter: &SnapshotEntriesFilter) -> MithrilResult<()> {
+        if !entries_filter.selects_immutable_files() {
+            return Err(anyhow!(
+                "The immutable files must be extracted to verify the cardano db, the given `--include` and `--exclude` patterns exclude files of the `immutable/` directory"
+            ));
+        }
 
         Ok(())
     }
@@ -138,6 +222,7 @@ impl CardanoDbDownloadCommand {
         progress_printer: &ProgressPrinter,
         db_dir: &Path,
         cardano_db: &Snapshot,
+        entries_filter: &SnapshotEntriesFilter,
     ) -> MithrilResult<()> {
         progress_printer.report_step(step_number, "Checking local disk info…")?;
 
@@ -146,6 +231,7 @@ impl CardanoDbDownloadCommand {
             db_dir,
             cardano_db.size,
             cardano_db.compression_algorithm.unwrap_or_default(),
+            entries_filter,
         ) {
             progress_printer
                 .report_step(step_number, &CardanoDbUtils::check_disk_space_error(e)?)?;
@@ -158,22 +244,39 @@ impl CardanoDbDownloadCommand {
         step_number: u16,
         progress_printer: &ProgressPrinter,
         client: &Client,
-        certificate_hash: &str,
+        cardano_db: &Snapshot,
+        pinned_certificate_hash: Option<&str>,
     ) -> MithrilResult<MithrilCertificate> {
         progress_printer.report_step(
             step_number,
             "Fetching the certificate and verifying the certificate chain…",
         )?;
-        let certificate = client
-            .certificate()
-            .verify_chain(certificate_hash)
-            .await
-            .with_context(|| {
-                format!(
-                    "Can not verify the certificate chain from certificate_hash: '{}'",
-                    certificate_hash
-                )
-            })?;
+        let certificate = match pinned_certificate_hash {
+            Some(certificate_hash) => {
+                if certificate_hash != cardano_db.certificate_hash {
+                    warn!(
+                        "The certificate hash given by the aggregator '{}' differs from the pinned certificate hash '{certificate_hash}'",
+                        cardano_db.certificate_hash
+                    );
+                }
+                client
+                    .certificate()
+                    .verify_snapshot_certificate(cardano_db, certificate_hash)
+                    .await
+            }
+            None => {
+                client
+                    .certificate()
+                    .verify_chain(&cardano_db.certificate_hash)
+                    .await
+            }
+        }
+        .with_context(|| {
+            format!(
+                "Can not verify the certificate chain from certificate_hash: '{}'",
+                pinned_certificate_hash.unwrap_or(&cardano_db.certificate_hash)
+            )
+        })?;
 
         Ok(certificate)
     }
@@ -184,16 +287,23 @@ impl CardanoDbDownloadCommand {
         client: &Client,
         cardano_db: &Snapshot,
         db_dir: &Path,
+        entries_filter: &SnapshotEntriesFilter,
     ) -> MithrilResult<()> {
         progress_printer.report_step(step_number, "Downloading and unpacking the cardano db")?;
         client
             .snapshot()
-            .download_unpack(cardano_db, db_dir)
-            .await?;
+            .download_unpack_selection(cardano_db, db_dir, entries_filter)
+            .await
+    }
 
+    async fn complete_cardano_db_download(
+        params: &ConfigParameters,
+        client: &Client,
+        cardano_db: &Snapshot,
+        db_dir: &Path,
+    ) {
         // The cardano db download does not fail if the statistic call fails.
-        // It would be nice to implement tests to verify the behavior of `add_statistics`
-        if let Err(e) = client.snapshot().add_statistics(cardano_db).await {
+        if let Err(e) = CardanoDbUtils::add_statistics(params, client, cardano_db).await {
             warn!("Could not increment cardano db download statistics: {e:?}");
         }
 
@@ -204,28 +314,26 @@ impl CardanoDbDownloadCommand {
                 db_dir.display()
             );
         };
-
-        Ok(())
     }
 
     async fn compute_cardano_db_message(
         step_number: u16,
         progress_printer: &ProgressPrinter,
+        feedback_receiver: Arc<dyn FeedbackReceiver>,
         certificate: &MithrilCertificate,
         db_dir: &Path,
     ) -> MithrilResult<ProtocolMessage> {
         progress_printer.report_step(step_number, "Computing the cardano db message")?;
-        let message = CardanoDbUtils::wait_spinner(
-            progress_printer,
-            MessageBuilder::new().compute_snapshot_message(certificate, db_dir),
-        )
-        .await
-        .with_context(|| {
-            format!(
-                "Can not compute the cardano db message from the directory: '{:?}'",
-                db_dir
-            )
-        })?;
+        let message = MessageBuilder::new()
+            .add_feedback_receiver(feedback_receiver)
+            .compute_snapshot_message(certificate, db_dir)
+            .await
+            .with_context(|| {
+                format!(
+                    "Can not compute the cardano db message from the directory: '{:?}'",
+                    db_dir
+                )
+            })?;
 
         Ok(message)
     }
@@ -258,6 +366,7 @@ impl CardanoDbDownloadCommand {
     fn log_download_information(
         db_dir: &Path,
         cardano_db: &Snapshot,
+        summary: &ExecutionSummary,
         json_output: bool,
     ) -> MithrilResult<()> {
         let canonicalized_filepath = &db_dir.canonicalize().with_context(|| {
@@ -269,9 +378,12 @@ impl CardanoDbDownloadCommand {
 
         if json_output {
             println!(
-                r#"{{"timestamp": "{}", "db_directory": "{}"}}"#,
-                Utc::now().to_rfc3339(),
-                canonicalized_filepath.display()
+                "{}",
+                serde_json::json!({
+                    "timestamp": Utc::now().to_rfc3339(),
+                    "db_directory": canonicalized_filepath,
+                    "summary": summary,
+                })
             );
         } else {
             let cardano_node_version = cardano_db
@@ -287,13 +399,15 @@ impl CardanoDbDownloadCommand {
     
     docker run -v cardano-node-ipc:/ipc -v cardano-node-data:/data --mount type=bind,source="{}",target=/data/db/ -e NETWORK={} ghcr.io/intersectmbo/cardano-node:{}
     
-    "###,
+{}
+"###,
                 cardano_db.digest,
                 db_dir.display(),
                 cardano_node_version,
                 canonicalized_filepath.display(),
                 cardano_db.beacon.network,
-                cardano_node_version
+                cardano_node_version,
+                summary.to_text()
             );
         }
 
@@ -332,6 +446,20 @@ impl Source for CardanoDbDownloadCommand {
             );
         }
 
+        if self.no_statistics {
+            map.insert(
+                "no_statistics".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(true)),
+            );
+        }
+
+        if let Some(statistics_endpoint) = self.statistics_endpoint.clone() {
+            map.insert(
+                "statistics_endpoint".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(statistics_endpoint)),
+            );
+        }
+
         Ok(map)
     }
 }
@@ -339,10 +467,9 @@ impl Source for CardanoDbDownloadCommand {
 #[cfg(test)]
 mod tests {
     use mithril_client::{
-        common::{CardanoDbBeacon, ProtocolMessagePartKey},
+        common::{CardanoDbBeacon, ProtocolMessagePartKey, SignedEntityType},
         MithrilCertificateMetadata,
     };
-    use mithril_common::entities::SignedEntityType;
     use mithril_common::test_utils::TempDir;
 
     use super::*;
@@ -375,6 +502,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn check_entries_filter_should_fail_if_the_immutable_files_are_not_selected() {
+        CardanoDbDownloadCommand::check_entries_filter(&SnapshotEntriesFilter::default())
+            .expect("Selecting every file should be accepted");
+        CardanoDbDownloadCommand::check_entries_filter(
+            &SnapshotEntriesFilter::new(&["immutable"], &["ledger"]).unwrap(),
+        )
+        .expect("Selecting the immutable files should be accepted");
+
+        CardanoDbDownloadCommand::check_entries_filter(
+            &SnapshotEntriesFilter::new(&["ledger"], &[]).unwrap(),
+        )
+        .expect_err("Not selecting the immutable files should fail");
+        CardanoDbDownloadCommand::check_entries_filter(
+            &SnapshotEntriesFilter::new(&[], &["immutable/"]).unwrap(),
+        )
+        .expect_err("Excluding the immutable files should fail");
+    }
+
     #[tokio::test]
     async fn verify_cardano_db_signature_should_remove_db_dir_if_messages_mismatch() {
         let progress_printer = ProgressPrinter::new(ProgressOutputType::Tty, 1);
@@ -41,10 +41,12 @@ impl CardanoTransactionsCertifyCommand {
             ProgressOutputType::Tty
         };
         let progress_printer = ProgressPrinter::new(progress_output_type, 4);
-        let client = client_builder(&params)?
-            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
-                progress_output_type,
-            )))
+        let client = client_builder(&params)
+            .await?
+            .add_feedback_receiver(Arc::new(
+                IndicatifFeedbackReceiver::new(progress_output_type)
+                    .with_multi_progress((*progress_printer).clone()),
+            ))
             .build()?;
 
         progress_printer.report_step(1, "Fetching a proof for the given transactions…")?;
@@ -0,0 +1,52 @@
+use clap::{Command, Parser};
+use clap_complete::Shell;
+use std::io::Write;
+
+use mithril_client::MithrilResult;
+
+/// Generate a shell completion script for the given shell on the standard output.
+#[derive(Parser, Debug, Clone)]
+pub struct GenerateCompletionCommand {
+    /// Shell for which the completion script is generated
+    #[clap(value_enum)]
+    shell: Shell,
+}
+
+impl GenerateCompletionCommand {
+    /// Main command execution
+    pub fn execute(&self, cmd: &mut Command) -> MithrilResult<()> {
+        self.write_completion(cmd, &mut std::io::stdout())
+    }
+
+    fn write_completion(&self, cmd: &mut Command, writer: &mut dyn Write) -> MithrilResult<()> {
+        let bin_name = cmd.get_name().to_string();
+        clap_complete::generate(self.shell, cmd, bin_name, writer);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_command() -> Command {
+        Command::new("mithril-client").subcommand(Command::new("cardano-db"))
+    }
+
+    #[test]
+    fn generate_completion_script_for_each_supported_shell() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            let mut output = Vec::new();
+            GenerateCompletionCommand { shell }
+                .write_completion(&mut test_command(), &mut output)
+                .unwrap();
+
+            let script = String::from_utf8(output).unwrap();
+            assert!(
+                script.contains("cardano-db"),
+                "{shell} completion script should complete the subcommands:\n{script}"
+            );
+        }
+    }
+}
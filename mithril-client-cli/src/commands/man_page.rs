@@ -0,0 +1,102 @@
+use anyhow::Context;
+use clap::{Command, Parser};
+use clap_mangen::Man;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use mithril_client::MithrilResult;
+
+/// Generate the man pages of the command line.
+///
+/// Without an output directory, only the main man page is written on the standard output.
+#[derive(Parser, Debug, Clone)]
+pub struct GenerateManCommand {
+    /// Directory where a man page is written for the command and each of its subcommands
+    #[clap(long)]
+    output_dir: Option<PathBuf>,
+}
+
+impl GenerateManCommand {
+    /// Main command execution
+    pub fn execute(&self, cmd: Command) -> MithrilResult<()> {
+        match &self.output_dir {
+            Some(output_dir) => {
+                std::fs::create_dir_all(output_dir).with_context(|| {
+                    format!(
+                        "Can not create man pages directory: '{}'",
+                        output_dir.display()
+                    )
+                })?;
+                let generated_files = Self::write_man_pages(cmd, output_dir)?;
+                println!(
+                    "{} man pages generated in directory `{}`",
+                    generated_files.len(),
+                    output_dir.display()
+                );
+
+                Ok(())
+            }
+            None => Man::new(cmd)
+                .render(&mut std::io::stdout())
+                .with_context(|| "Can not write man page to the standard output"),
+        }
+    }
+
+    /// Write a man page for the command and, recursively, for each of its visible subcommands.
+    ///
+    /// Subcommand pages are named after the full command path, ie: `mithril-client-cardano-db.1`.
+    fn write_man_pages(cmd: Command, output_dir: &Path) -> MithrilResult<Vec<PathBuf>> {
+        let mut generated_files = vec![];
+        let name = cmd.get_name().to_string();
+        let man = Man::new(cmd.clone());
+        let filepath = output_dir.join(man.get_filename());
+        let mut file = File::create(&filepath)
+            .with_context(|| format!("Can not create man page: '{}'", filepath.display()))?;
+        man.render(&mut file)
+            .with_context(|| format!("Can not write man page: '{}'", filepath.display()))?;
+        file.flush()?;
+        generated_files.push(filepath);
+
+        for subcommand in cmd.get_subcommands().filter(|s| !s.is_hide_set()) {
+            let subcommand_name = format!("{name}-{}", subcommand.get_name());
+            generated_files.extend(Self::write_man_pages(
+                subcommand.clone().name(subcommand_name),
+                output_dir,
+            )?);
+        }
+
+        Ok(generated_files)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn generate_a_man_page_for_each_visible_subcommand() {
+        let output_dir = TempDir::create(
+            "client-cli-man-page",
+            "generate_a_man_page_for_each_visible_subcommand",
+        );
+        let cmd = Command::new("mithril-client")
+            .subcommand(Command::new("cardano-db").subcommand(Command::new("download")))
+            .subcommand(Command::new("generate-man").hide(true));
+
+        let mut generated_files = GenerateManCommand::write_man_pages(cmd, &output_dir).unwrap();
+        generated_files.sort();
+
+        assert_eq!(
+            vec![
+                output_dir.join("mithril-client-cardano-db-download.1"),
+                output_dir.join("mithril-client-cardano-db.1"),
+                output_dir.join("mithril-client.1"),
+            ],
+            generated_files
+        );
+        assert!(generated_files.iter().all(|f| f.exists()));
+    }
+}
@@ -5,26 +5,89 @@
 
 pub mod cardano_db;
 pub mod cardano_transaction;
+mod completion;
 mod deprecation;
+mod man_page;
 pub mod mithril_stake_distribution;
+pub mod signer;
 
+pub use completion::GenerateCompletionCommand;
 pub use deprecation::{DeprecatedCommand, Deprecation};
+pub use man_page::GenerateManCommand;
 
-use mithril_client::{ClientBuilder, MithrilResult};
+use anyhow::anyhow;
+use mithril_client::{
+    genesis_verification_key_discovery::GenesisVerificationKeyDiscoverer, ClientBuilder,
+    MithrilResult,
+};
 use slog_scope::logger;
+use std::path::PathBuf;
 
-use crate::configuration::ConfigParameters;
+use crate::configuration::{ConfigError, ConfigParameters};
 
-pub(crate) fn client_builder(params: &ConfigParameters) -> MithrilResult<ClientBuilder> {
+pub(crate) async fn client_builder(params: &ConfigParameters) -> MithrilResult<ClientBuilder> {
     let builder = ClientBuilder::aggregator(
         &params.require("aggregator_endpoint")?,
-        &params.require("genesis_verification_key")?,
+        &genesis_verification_key(params).await?,
     )
     .with_logger(logger());
 
     Ok(builder)
 }
 
+/// Return the genesis verification key given by the `genesis_verification_key` parameter or,
+/// if it is not set, discover it at the `genesis_verification_key_url` parameter and verify it
+/// against the `genesis_root_verification_key` parameter or, if it is not set, against the root
+/// verification key pinned for the `cardano_network` parameter.
+pub(crate) async fn genesis_verification_key(params: &ConfigParameters) -> MithrilResult<String> {
+    if let Some(genesis_verification_key) = params.get("genesis_verification_key") {
+        return Ok(genesis_verification_key);
+    }
+
+    match params.get("genesis_verification_key_url") {
+        Some(url) => {
+            let discoverer = match (
+                params.get("genesis_root_verification_key"),
+                params.get("cardano_network"),
+            ) {
+                (Some(root_verification_key), _) => {
+                    GenesisVerificationKeyDiscoverer::new(&url, &root_verification_key)?
+                }
+                (None, Some(cardano_network)) => {
+                    GenesisVerificationKeyDiscoverer::for_network(&url, &cardano_network)?
+                }
+                (None, None) => {
+                    return Err(anyhow!(
+                        "Parameter 'genesis_root_verification_key' or 'cardano_network' is mandatory to discover the genesis verification key"
+                    ));
+                }
+            };
+            let cache_file = params
+                .get("genesis_verification_key_cache_file")
+                .map(PathBuf::from)
+                .or_else(default_genesis_verification_key_cache_file);
+
+            match cache_file {
+                Some(cache_file) => discoverer.with_cache_file(&cache_file),
+                None => discoverer,
+            }
+            .with_logger(logger())
+            .discover()
+            .await
+        }
+        None => Err(ConfigError::Required("genesis_verification_key".to_string()).into()),
+    }
+}
+
+/// Cache file of the discovered genesis verification key in the cache directory of the user,
+/// if the platform has one.
+fn default_genesis_verification_key_cache_file() -> Option<PathBuf> {
+    dirs_next::cache_dir().map(|dir| {
+        dir.join("mithril-client")
+            .join("genesis_verification_key.json")
+    })
+}
+
 pub(crate) fn client_builder_with_fallback_genesis_key(
     params: &ConfigParameters,
 ) -> MithrilResult<ClientBuilder> {
@@ -45,3 +108,73 @@ pub(crate) fn client_builder_with_fallback_genesis_key(
 
     Ok(builder)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn explicit_genesis_verification_key_overrides_discovery() {
+        let params = ConfigParameters::build(&[
+            ("genesis_verification_key", "explicit-key"),
+            (
+                "genesis_verification_key_url",
+                "http://unreachable.invalid/genesis",
+            ),
+        ]);
+
+        let genesis_verification_key = genesis_verification_key(&params).await.unwrap();
+
+        assert_eq!("explicit-key", genesis_verification_key);
+    }
+
+    #[tokio::test]
+    async fn genesis_verification_key_is_required_without_discovery_url() {
+        let params = ConfigParameters::build(&[]);
+
+        genesis_verification_key(&params)
+            .await
+            .expect_err("Without a genesis verification key nor a discovery url it should fail");
+    }
+
+    #[tokio::test]
+    async fn root_verification_key_or_cardano_network_is_required_to_discover_the_genesis_verification_key(
+    ) {
+        let params = ConfigParameters::build(&[(
+            "genesis_verification_key_url",
+            "http://unreachable.invalid/genesis",
+        )]);
+
+        let error = genesis_verification_key(&params)
+            .await
+            .expect_err("Discovery without a root verification key should fail");
+
+        assert!(
+            error.to_string().contains("genesis_root_verification_key")
+                && error.to_string().contains("cardano_network"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn discovery_fails_for_a_cardano_network_without_pinned_root_verification_key() {
+        let params = ConfigParameters::build(&[
+            (
+                "genesis_verification_key_url",
+                "http://unreachable.invalid/genesis",
+            ),
+            ("cardano_network", "unknown"),
+        ]);
+
+        let error = genesis_verification_key(&params)
+            .await
+            .expect_err("Discovery for a network without pinned root key should fail");
+
+        assert!(
+            error
+                .to_string()
+                .contains("No root verification key is pinned"),
+            "unexpected error: {error}"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use clap::Parser;
+use cli_table::{format::Justify, print_stdout, Cell, Table};
+use config::{builder::DefaultState, ConfigBuilder};
+use std::collections::HashMap;
+
+use super::epoch_or_current;
+use crate::{commands::client_builder_with_fallback_genesis_key, configuration::ConfigParameters};
+use mithril_client::MithrilResult;
+
+/// Signer LIST command
+#[derive(Parser, Debug, Clone)]
+pub struct SignerListCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Epoch at which the signers registered, the current epoch of the aggregator if not set.
+    #[clap(long)]
+    epoch: Option<u64>,
+}
+
+impl SignerListCommand {
+    /// Main command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let client = client_builder_with_fallback_genesis_key(&params)?.build()?;
+        let epoch = epoch_or_current(&client, self.epoch).await?;
+        let signer_registrations = client.signer().list_registered(epoch).await?;
+
+        if self.json {
+            println!("{}", serde_json::to_string(&signer_registrations)?);
+        } else {
+            let lines = signer_registrations
+                .registrations
+                .into_iter()
+                .map(|item| {
+                    vec![
+                        item.party_id.cell(),
+                        item.pool_ticker.unwrap_or_default().cell(),
+                        format!("{}", item.stake).cell().justify(Justify::Right),
+                        item.registration_time
+                            .map(|time| time.to_string())
+                            .unwrap_or_default()
+                            .cell(),
+                    ]
+                })
+                .collect::<Vec<_>>()
+                .table()
+                .title(vec![
+                    "Party Id".cell(),
+                    "Pool Ticker".cell(),
+                    "Stake".cell().justify(Justify::Right),
+                    "Registered".cell(),
+                ]);
+            println!(
+                "Signers registered at epoch {} (signing at epoch {}):",
+                signer_registrations.registered_at, signer_registrations.signing_at
+            );
+            print_stdout(lines)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+//! Commands for the signers registered to the aggregator
+mod list;
+mod show;
+
+pub use list::*;
+pub use show::*;
+
+use clap::Subcommand;
+use config::{builder::DefaultState, ConfigBuilder};
+use mithril_client::{common::Epoch, Client, MithrilResult};
+
+/// Registered signers exploration
+#[derive(Subcommand, Debug, Clone)]
+pub enum SignerCommands {
+    /// List the signers registered at an epoch
+    #[clap(arg_required_else_help = false)]
+    List(SignerListCommand),
+
+    /// Show the registration of the given signer at an epoch
+    #[clap(arg_required_else_help = true)]
+    Show(SignerShowCommand),
+}
+
+impl SignerCommands {
+    /// Execute signer command
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        match self {
+            Self::List(cmd) => cmd.execute(config_builder).await,
+            Self::Show(cmd) => cmd.execute(config_builder).await,
+        }
+    }
+}
+
+/// Return the given epoch or, if it is not set, the current epoch of the aggregator.
+async fn epoch_or_current(client: &Client, epoch: Option<u64>) -> MithrilResult<Epoch> {
+    match epoch {
+        Some(epoch) => Ok(Epoch(epoch)),
+        None => client.signer().get_current_epoch().await,
+    }
+}
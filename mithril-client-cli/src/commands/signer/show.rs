@@ -0,0 +1,70 @@
+use anyhow::anyhow;
+use clap::Parser;
+use cli_table::{print_stdout, Cell, Table};
+use config::{builder::DefaultState, ConfigBuilder};
+use std::collections::HashMap;
+
+use super::epoch_or_current;
+use crate::{commands::client_builder_with_fallback_genesis_key, configuration::ConfigParameters};
+use mithril_client::MithrilResult;
+
+/// Signer SHOW command
+#[derive(Parser, Debug, Clone)]
+pub struct SignerShowCommand {
+    /// Enable JSON output.
+    #[clap(long)]
+    json: bool,
+
+    /// Epoch at which the signer registered, the current epoch of the aggregator if not set.
+    #[clap(long)]
+    epoch: Option<u64>,
+
+    /// Party id of the signer.
+    party_id: String,
+}
+
+impl SignerShowCommand {
+    /// Main command execution
+    pub async fn execute(&self, config_builder: ConfigBuilder<DefaultState>) -> MithrilResult<()> {
+        let config = config_builder.build()?;
+        let params = ConfigParameters::new(config.try_deserialize::<HashMap<String, String>>()?);
+        let client = client_builder_with_fallback_genesis_key(&params)?.build()?;
+        let epoch = epoch_or_current(&client, self.epoch).await?;
+        let registration = client
+            .signer()
+            .get_registered(epoch, &self.party_id)
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "Signer '{}' is not registered at epoch {epoch}",
+                    self.party_id
+                )
+            })?;
+
+        if self.json {
+            println!("{}", serde_json::to_string(&registration)?);
+        } else {
+            let registration_table = vec![
+                vec!["Party Id".cell(), registration.party_id.cell()],
+                vec![
+                    "Pool Ticker".cell(),
+                    registration.pool_ticker.unwrap_or_default().cell(),
+                ],
+                vec!["Stake".cell(), format!("{}", registration.stake).cell()],
+                vec!["Registration Epoch".cell(), format!("{epoch}").cell()],
+                vec![
+                    "Registered".cell(),
+                    registration
+                        .registration_time
+                        .map(|time| time.to_string())
+                        .unwrap_or_default()
+                        .cell(),
+                ],
+            ]
+            .table();
+            print_stdout(registration_table)?;
+        }
+
+        Ok(())
+    }
+}
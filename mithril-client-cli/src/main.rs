@@ -11,12 +11,13 @@ use std::io::Write;
 use std::sync::Arc;
 use std::{fs::File, path::PathBuf};
 
-use mithril_client::MithrilResult;
+use mithril_client::{common::ApiVersionMismatchError, MithrilError, MithrilResult};
 use mithril_doc::{Documenter, GenerateDocCommands, StructDoc};
 
 use mithril_client_cli::commands::{
     cardano_db::CardanoDbCommands, cardano_transaction::CardanoTransactionCommands,
-    mithril_stake_distribution::MithrilStakeDistributionCommands, DeprecatedCommand, Deprecation,
+    mithril_stake_distribution::MithrilStakeDistributionCommands, signer::SignerCommands,
+    DeprecatedCommand, Deprecation, GenerateCompletionCommand, GenerateManCommand,
 };
 use mithril_client_cli::ClapError;
 
@@ -69,6 +70,27 @@ pub struct Args {
     #[example = "`https://aggregator.pre-release-preview.api.mithril.network/aggregator`"]
     aggregator_endpoint: Option<String>,
 
+    /// URL of a genesis verification key document, used to discover the genesis verification
+    /// key when it is not given explicitly.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY_URL")]
+    genesis_verification_key_url: Option<String>,
+
+    /// Root verification key that must sign the chain of the discovered genesis verification key,
+    /// overrides the root verification key pinned for the Cardano network.
+    #[clap(long, env = "GENESIS_ROOT_VERIFICATION_KEY")]
+    genesis_root_verification_key: Option<String>,
+
+    /// Cardano network whose pinned root verification key must sign the chain of the discovered
+    /// genesis verification key.
+    #[clap(long, env = "CARDANO_NETWORK")]
+    #[example = "`mainnet` or `preprod` or `preview`"]
+    cardano_network: Option<String>,
+
+    /// File where the discovered genesis verification key is cached.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY_CACHE_FILE")]
+    #[example = "`./genesis_verification_key.json`"]
+    genesis_verification_key_cache_file: Option<PathBuf>,
+
     /// Enable JSON output for logs displayed according to verbosity level
     #[clap(long)]
     log_format_json: bool,
@@ -175,6 +197,43 @@ impl Source for Args {
             );
         }
 
+        if let Some(genesis_verification_key_url) = self.genesis_verification_key_url.clone() {
+            map.insert(
+                "genesis_verification_key_url".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(genesis_verification_key_url),
+                ),
+            );
+        }
+
+        if let Some(genesis_root_verification_key) = self.genesis_root_verification_key.clone() {
+            map.insert(
+                "genesis_root_verification_key".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(genesis_root_verification_key),
+                ),
+            );
+        }
+
+        if let Some(cardano_network) = self.cardano_network.clone() {
+            map.insert(
+                "cardano_network".to_string(),
+                Value::new(Some(&namespace), ValueKind::from(cardano_network)),
+            );
+        }
+
+        if let Some(cache_file) = &self.genesis_verification_key_cache_file {
+            map.insert(
+                "genesis_verification_key_cache_file".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(format!("{}", cache_file.display())),
+                ),
+            );
+        }
+
         Ok(map)
     }
 }
@@ -190,8 +249,17 @@ enum ArtifactCommands {
     #[clap(subcommand, alias("ctx"))]
     CardanoTransaction(CardanoTransactionCommands),
 
+    #[clap(subcommand)]
+    Signer(SignerCommands),
+
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
+
+    #[clap(alias("completion"))]
+    GenerateCompletion(GenerateCompletionCommand),
+
+    #[clap(alias("man"), hide(true))]
+    GenerateMan(GenerateManCommand),
 }
 
 impl ArtifactCommands {
@@ -215,9 +283,12 @@ impl ArtifactCommands {
                     ctx.execute(config_builder).await
                 }
             }
+            Self::Signer(cmd) => cmd.execute(config_builder).await,
             Self::GenerateDoc(cmd) => cmd
                 .execute(&mut Args::command())
                 .map_err(|message| anyhow!(message)),
+            Self::GenerateCompletion(cmd) => cmd.execute(&mut Args::command()),
+            Self::GenerateMan(cmd) => cmd.execute(Args::command()),
         }
     }
 }
@@ -236,7 +307,33 @@ async fn main() -> MithrilResult<()> {
     #[cfg(feature = "bundle_openssl")]
     openssl_probe::init_ssl_cert_env_vars();
 
-    args.execute().await
+    args.execute().await.map_err(with_upgrade_hint)
+}
+
+/// Add an upgrade hint to the errors caused by an API version mismatch with the aggregator.
+fn with_upgrade_hint(error: MithrilError) -> MithrilError {
+    let hint = match error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<ApiVersionMismatchError>())
+    {
+        Some(mismatch) => match mismatch.is_client_outdated() {
+            Some(true) => format!(
+                "This mithril-client (API version '{}') is too old for the aggregator (API version '{}'), please upgrade mithril-client to a version supporting API version '{}'.",
+                mismatch.client_version, mismatch.server_version, mismatch.server_version_requirement
+            ),
+            Some(false) => format!(
+                "The aggregator (API version '{}') is older than this mithril-client (API version '{}'), please use a mithril-client release compatible with the aggregator or contact its operator.",
+                mismatch.server_version, mismatch.client_version
+            ),
+            None => format!(
+                "This mithril-client (API version '{}') is not compatible with the aggregator (API version '{}').",
+                mismatch.client_version, mismatch.server_version
+            ),
+        },
+        None => return error,
+    };
+
+    error.context(hint)
 }
 
 #[cfg(test)]
@@ -253,4 +350,24 @@ mod tests {
             .await
             .expect_err("Should fail if unstable flag missing");
     }
+
+    #[test]
+    fn add_upgrade_hint_to_api_version_mismatch_errors() {
+        let error = with_upgrade_hint(
+            anyhow!(ApiVersionMismatchError::new("0.1.0", "0.2.1", "=0.2"))
+                .context("Can not get the list of snapshots"),
+        );
+
+        assert!(
+            error.to_string().contains("please upgrade mithril-client"),
+            "Unexpected error: {error:?}"
+        );
+    }
+
+    #[test]
+    fn do_not_add_upgrade_hint_to_other_errors() {
+        let error = with_upgrade_hint(anyhow!("Can not get the list of snapshots"));
+
+        assert_eq!("Can not get the list of snapshots", error.to_string());
+    }
 }
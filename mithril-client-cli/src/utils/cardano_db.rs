@@ -1,10 +1,9 @@
-use anyhow::anyhow;
-use futures::Future;
-use indicatif::{MultiProgress, ProgressBar};
-use std::time::Duration;
+use anyhow::{anyhow, Context};
+use slog_scope::debug;
 
 use super::CardanoDbDownloadCheckerError;
-use mithril_client::{MithrilError, MithrilResult};
+use crate::configuration::ConfigParameters;
+use mithril_client::{Client, MithrilError, MithrilResult, Snapshot};
 
 /// Utility functions for to the CardanoDb commands
 pub struct CardanoDbUtils;
@@ -12,11 +11,18 @@ pub struct CardanoDbUtils;
 impl CardanoDbUtils {
     /// Handle the error return by `check_prerequisites`
     pub fn check_disk_space_error(error: MithrilError) -> MithrilResult<String> {
-        if let Some(CardanoDbDownloadCheckerError::NotEnoughSpace {
-            left_space: _,
-            pathdir: _,
-            archive_size: _,
-        }) = error.downcast_ref::<CardanoDbDownloadCheckerError>()
+        if let Some(
+            CardanoDbDownloadCheckerError::NotEnoughSpace {
+                left_space: _,
+                pathdir: _,
+                archive_size: _,
+            }
+            | CardanoDbDownloadCheckerError::NotEnoughSpaceForSelection {
+                left_space: _,
+                pathdir: _,
+                archive_size: _,
+            },
+        ) = error.downcast_ref::<CardanoDbDownloadCheckerError>()
         {
             Ok(format!("Warning: {}", error))
         } else {
@@ -24,30 +30,71 @@ impl CardanoDbUtils {
         }
     }
 
-    /// Display a spinner while waiting for the result of a future
-    pub async fn wait_spinner<T>(
-        progress_bar: &MultiProgress,
-        future: impl Future<Output = MithrilResult<T>>,
-    ) -> MithrilResult<T> {
-        let pb = progress_bar.add(ProgressBar::new_spinner());
-        let spinner = async move {
-            loop {
-                pb.tick();
-                tokio::time::sleep(Duration::from_millis(50)).await;
+    /// Increments the download statistics of the given cardano db, unless the `no_statistics`
+    /// parameter is set.
+    ///
+    /// The statistics are sent to the aggregator of the given client, or POSTed to the collector
+    /// at the `statistics_endpoint` parameter if it is set, which must expose the same
+    /// `/statistics/snapshot` route as an aggregator.
+    pub async fn add_statistics(
+        params: &ConfigParameters,
+        client: &Client,
+        cardano_db: &Snapshot,
+    ) -> MithrilResult<()> {
+        let no_statistics = params
+            .get_or("no_statistics", "false")
+            .parse::<bool>()
+            .with_context(|| "Parameter 'no_statistics' must be a boolean")?;
+        if no_statistics {
+            debug!("Cardano db download statistics disabled, skipping.");
+            return Ok(());
+        }
+
+        match params.get("statistics_endpoint") {
+            Some(statistics_endpoint) => {
+                Self::post_statistics(&statistics_endpoint, cardano_db).await
             }
-        };
+            None => client.snapshot().add_statistics(cardano_db).await,
+        }
+    }
+
+    async fn post_statistics(
+        statistics_endpoint: &str,
+        cardano_db: &Snapshot,
+    ) -> MithrilResult<()> {
+        let url = format!(
+            "{}/statistics/snapshot",
+            statistics_endpoint.trim_end_matches('/')
+        );
+        let response = reqwest::Client::new()
+            .post(&url)
+            .json(cardano_db)
+            .send()
+            .await
+            .with_context(|| format!("Could not send the download statistics to '{url}'"))?;
 
-        tokio::select! {
-            _ = spinner => Err(anyhow!("timeout")),
-            res = future => res,
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "Could not send the download statistics to '{url}', status: '{}'",
+                response.status()
+            ))
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::*;
     use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use warp::Filter;
+
+    use mithril_client::ClientBuilder;
+    use mithril_common::test_utils::fake_keys;
+    use mithril_common::test_utils::test_http_server::test_http_server;
+
+    use super::*;
 
     #[test]
     fn check_disk_space_error_should_return_warning_message_if_error_is_not_enough_space() {
@@ -80,4 +127,83 @@ mod test {
             error
         );
     }
+
+    fn unreachable_aggregator_client() -> Client {
+        ClientBuilder::aggregator(
+            "http://127.0.0.1:1",
+            fake_keys::genesis_verification_key()[0],
+        )
+        .build()
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn add_statistics_does_not_send_anything_if_disabled() {
+        let params = ConfigParameters::build(&[("no_statistics", "true")]);
+
+        CardanoDbUtils::add_statistics(
+            &params,
+            &unreachable_aggregator_client(),
+            &Snapshot::dummy(),
+        )
+        .await
+        .expect("Statistics should not be sent to the unreachable aggregator");
+    }
+
+    #[tokio::test]
+    async fn add_statistics_fails_if_no_statistics_is_not_a_boolean() {
+        let params = ConfigParameters::build(&[("no_statistics", "maybe")]);
+
+        CardanoDbUtils::add_statistics(
+            &params,
+            &unreachable_aggregator_client(),
+            &Snapshot::dummy(),
+        )
+        .await
+        .expect_err("Invalid 'no_statistics' parameter should fail");
+    }
+
+    #[tokio::test]
+    async fn add_statistics_fails_if_the_statistics_endpoint_is_not_an_url() {
+        let params = ConfigParameters::build(&[("statistics_endpoint", "not a url")]);
+
+        let error = CardanoDbUtils::add_statistics(
+            &params,
+            &unreachable_aggregator_client(),
+            &Snapshot::dummy(),
+        )
+        .await
+        .expect_err("Statistics endpoint is not a valid url");
+
+        assert!(
+            format!("{error:?}").contains("not a url"),
+            "Unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn add_statistics_posts_the_cardano_db_to_the_statistics_endpoint_if_set() {
+        let posted_body = Arc::new(Mutex::new(None));
+        let server = {
+            let posted_body = posted_body.clone();
+            test_http_server(
+                warp::path!("statistics" / "snapshot")
+                    .and(warp::post())
+                    .and(warp::body::json())
+                    .map(move |body: Snapshot| {
+                        *posted_body.lock().unwrap() = Some(body);
+                        warp::reply::with_status(warp::reply(), warp::http::StatusCode::CREATED)
+                    }),
+            )
+        };
+        // No genesis verification key is needed to send the statistics
+        let params = ConfigParameters::build(&[("statistics_endpoint", &server.url())]);
+        let cardano_db = Snapshot::dummy();
+
+        CardanoDbUtils::add_statistics(&params, &unreachable_aggregator_client(), &cardano_db)
+            .await
+            .expect("Statistics should be sent to the statistics endpoint");
+
+        assert_eq!(Some(cardano_db), posted_body.lock().unwrap().clone());
+    }
 }
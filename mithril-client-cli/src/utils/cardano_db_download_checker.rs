@@ -8,7 +8,10 @@ use anyhow::Context;
 use human_bytes::human_bytes;
 use thiserror::Error;
 
-use mithril_client::{common::CompressionAlgorithm, MithrilError, MithrilResult};
+use mithril_client::{
+    common::CompressionAlgorithm, snapshot_entries_filter::SnapshotEntriesFilter, MithrilError,
+    MithrilResult,
+};
 
 /// Checks to apply before downloading a Cardano Db archive to a given directory.
 pub struct CardanoDbDownloadChecker;
@@ -31,6 +34,21 @@ pub enum CardanoDbDownloadCheckerError {
         archive_size: f64,
     },
 
+    /// Not enough space on the disk to unpack the whole archive while only some of its files
+    /// are selected. The size of the selected files is only known while unpacking, where the
+    /// available space is checked before unpacking each of them.
+    #[error("There is only {} remaining in directory '{}', which is not enough to unpack the whole {} large archive: the selected files may still fit, the space they need is checked while unpacking them.", human_bytes(*left_space), pathdir.display(), human_bytes(*archive_size))]
+    NotEnoughSpaceForSelection {
+        /// Left space on device
+        left_space: f64,
+
+        /// Specified location
+        pathdir: PathBuf,
+
+        /// Packed cardano db size
+        archive_size: f64,
+    },
+
     /// The directory where the files from cardano db are expanded is not empty.
     /// An error is raised to let the user handle what it wants to do with those
     /// files.
@@ -63,10 +81,11 @@ impl CardanoDbDownloadChecker {
         pathdir: &Path,
         size: u64,
         compression_algorithm: CompressionAlgorithm,
+        entries_filter: &SnapshotEntriesFilter,
     ) -> MithrilResult<()> {
         Self::check_path_is_an_empty_dir(pathdir)?;
         Self::check_dir_writable(pathdir)?;
-        Self::check_disk_space(pathdir, size, compression_algorithm)
+        Self::check_disk_space(pathdir, size, compression_algorithm, entries_filter)
     }
 
     fn check_path_is_an_empty_dir(pathdir: &Path) -> MithrilResult<()> {
@@ -117,13 +136,23 @@ impl CardanoDbDownloadChecker {
         pathdir: &Path,
         size: u64,
         compression_algorithm: CompressionAlgorithm,
+        entries_filter: &SnapshotEntriesFilter,
     ) -> MithrilResult<()> {
         let free_space = fs2::available_space(pathdir)? as f64;
         if free_space < compression_algorithm.free_space_snapshot_ratio() * size as f64 {
-            return Err(CardanoDbDownloadCheckerError::NotEnoughSpace {
-                left_space: free_space,
-                pathdir: pathdir.to_owned(),
-                archive_size: size as f64,
+            let (left_space, pathdir, archive_size) = (free_space, pathdir.to_owned(), size as f64);
+            return Err(if entries_filter.selects_everything() {
+                CardanoDbDownloadCheckerError::NotEnoughSpace {
+                    left_space,
+                    pathdir,
+                    archive_size,
+                }
+            } else {
+                CardanoDbDownloadCheckerError::NotEnoughSpaceForSelection {
+                    left_space,
+                    pathdir,
+                    archive_size,
+                }
             }
             .into());
         }
@@ -162,6 +191,7 @@ mod test {
             &pathdir,
             12,
             CompressionAlgorithm::default(),
+            &SnapshotEntriesFilter::default(),
         )
         .expect_err("check_prerequisites should fail");
     }
@@ -176,6 +206,7 @@ mod test {
             &pathdir,
             12,
             CompressionAlgorithm::default(),
+            &SnapshotEntriesFilter::default(),
         )
         .expect("check_prerequisites should not fail");
     }
@@ -190,6 +221,7 @@ mod test {
             &pathdir,
             12,
             CompressionAlgorithm::default(),
+            &SnapshotEntriesFilter::default(),
         )
         .expect_err("check_prerequisites should fail");
 
@@ -214,6 +246,7 @@ mod test {
             &pathdir,
             archive_size,
             CompressionAlgorithm::default(),
+            &SnapshotEntriesFilter::default(),
         )
         .expect_err("check_prerequisites should fail");
 
@@ -231,6 +264,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn return_selection_error_if_not_enough_available_space_with_a_selection() {
+        let pathdir = create_temporary_empty_directory("enough_available_space_with_selection")
+            .join("target_directory");
+        fs::create_dir_all(&pathdir).unwrap();
+        let archive_size = u64::MAX;
+
+        let error = CardanoDbDownloadChecker::check_prerequisites(
+            &pathdir,
+            archive_size,
+            CompressionAlgorithm::default(),
+            &SnapshotEntriesFilter::new(&["immutable"], &[]).unwrap(),
+        )
+        .expect_err("check_prerequisites should fail");
+
+        assert!(
+            matches!(
+                error.downcast_ref::<CardanoDbDownloadCheckerError>(),
+                Some(CardanoDbDownloadCheckerError::NotEnoughSpaceForSelection { .. })
+            ),
+            "Unexpected error: {:?}",
+            error
+        );
+    }
+
     // Those test are not on Windows because `set_readonly` is ignored for directories on Windows 7+
     // https://doc.rust-lang.org/std/fs/struct.Permissions.html#method.set_readonly
     #[cfg(not(target_os = "windows"))]
@@ -278,6 +336,7 @@ mod test {
                 &pathdir,
                 12,
                 CompressionAlgorithm::default(),
+                &SnapshotEntriesFilter::default(),
             )
             .expect_err("check_prerequisites should fail");
 
@@ -0,0 +1,272 @@
+use async_trait::async_trait;
+use human_bytes::human_bytes;
+use serde::Serialize;
+use std::{
+    future::Future,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use mithril_client::feedback::{FeedbackReceiver, MithrilEvent};
+
+/// [FeedbackReceiver] measuring a snapshot download from its progress events: the number of
+/// bytes actually received and when the last of them was received.
+#[derive(Default)]
+pub struct DownloadMeasureFeedbackReceiver {
+    measure: Mutex<DownloadMeasure>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct DownloadMeasure {
+    downloaded_bytes: u64,
+    last_received_at: Option<Instant>,
+}
+
+impl DownloadMeasureFeedbackReceiver {
+    /// [DownloadMeasureFeedbackReceiver] constructor
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn measure(&self) -> DownloadMeasure {
+        self.measure
+            .lock()
+            .map(|measure| *measure)
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl FeedbackReceiver for DownloadMeasureFeedbackReceiver {
+    async fn handle_event(&self, event: MithrilEvent) {
+        if let MithrilEvent::SnapshotDownloadProgress {
+            downloaded_bytes, ..
+        } = event
+        {
+            if let Ok(mut measure) = self.measure.lock() {
+                measure.downloaded_bytes = downloaded_bytes;
+                measure.last_received_at = Some(Instant::now());
+            }
+        }
+    }
+}
+
+/// Duration of a step of a command
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StepDuration {
+    /// Name of the step
+    pub step: String,
+
+    /// Duration of the step, in seconds
+    pub seconds: f64,
+}
+
+/// Summary of a command execution, printed when the command ends so users can report
+/// performance issues with actual numbers.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExecutionSummary {
+    /// Duration of each step, in execution order
+    pub steps: Vec<StepDuration>,
+
+    /// Total duration of the command, in seconds
+    pub total_seconds: f64,
+
+    /// Number of bytes transferred from the network
+    pub bytes_transferred: u64,
+
+    /// Average throughput of the transfer, in bytes per second
+    pub throughput_bytes_per_second: f64,
+
+    #[serde(skip)]
+    started_at: Instant,
+
+    #[serde(skip)]
+    transfer_duration: Duration,
+}
+
+impl ExecutionSummary {
+    /// Create a new summary, the total duration is measured from now.
+    pub fn new() -> Self {
+        Self {
+            steps: vec![],
+            total_seconds: 0.0,
+            bytes_transferred: 0,
+            throughput_bytes_per_second: 0.0,
+            started_at: Instant::now(),
+            transfer_duration: Duration::ZERO,
+        }
+    }
+
+    /// Run the given future and record its duration as a step of the summary.
+    pub async fn time_step<T, F: Future<Output = T>>(&mut self, step: &str, future: F) -> T {
+        let started_at = Instant::now();
+        let output = future.await;
+        self.add_step(step, started_at.elapsed());
+
+        output
+    }
+
+    /// Run the given future, that downloads and simultaneously unpacks a snapshot, and record
+    /// two steps:
+    /// * `download`: until the last byte is received, the bytes received and the duration of
+    ///   this step are used to compute the average throughput,
+    /// * `unpack`: the time the unpacking of the received bytes lasted after the download.
+    ///
+    /// The download is measured by the given receiver that must be registered to the client
+    /// doing the download.
+    pub async fn time_download_and_unpack_steps<T, F: Future<Output = T>>(
+        &mut self,
+        download_measure_receiver: &DownloadMeasureFeedbackReceiver,
+        future: F,
+    ) -> T {
+        let started_at = Instant::now();
+        let output = future.await;
+        let ended_at = Instant::now();
+        let measure = download_measure_receiver.measure();
+        let download_ended_at = measure
+            .last_received_at
+            .unwrap_or(started_at)
+            .clamp(started_at, ended_at);
+        let download_duration = download_ended_at - started_at;
+        self.add_step("download", download_duration);
+        self.add_transfer(measure.downloaded_bytes, download_duration);
+        self.add_step("unpack", ended_at - download_ended_at);
+
+        output
+    }
+
+    fn add_step(&mut self, step: &str, duration: Duration) {
+        self.steps.push(StepDuration {
+            step: step.to_string(),
+            seconds: duration.as_secs_f64(),
+        });
+        self.total_seconds = self.started_at.elapsed().as_secs_f64();
+    }
+
+    fn add_transfer(&mut self, bytes: u64, duration: Duration) {
+        self.bytes_transferred += bytes;
+        self.transfer_duration += duration;
+        self.throughput_bytes_per_second = if self.transfer_duration.is_zero() {
+            0.0
+        } else {
+            self.bytes_transferred as f64 / self.transfer_duration.as_secs_f64()
+        };
+    }
+
+    /// Format the summary for a human reader.
+    pub fn to_text(&self) -> String {
+        let mut text = "Execution summary:\n".to_string();
+        for step in &self.steps {
+            text.push_str(&format!("    {:<30} {:>10.3}s\n", step.step, step.seconds));
+        }
+        text.push_str(&format!(
+            "    {:<30} {:>10.3}s\n",
+            "total", self.total_seconds
+        ));
+        text.push_str(&format!(
+            "    {} transferred at {}/s on average",
+            human_bytes(self.bytes_transferred as f64),
+            human_bytes(self.throughput_bytes_per_second)
+        ));
+
+        text
+    }
+}
+
+impl Default for ExecutionSummary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_throughput_from_transfer_steps_only() {
+        let mut summary = ExecutionSummary::new();
+        summary.add_step("verification", Duration::from_secs(10));
+        summary.add_step("download", Duration::from_secs(4));
+        summary.add_transfer(1000, Duration::from_secs(4));
+        summary.add_transfer(600, Duration::from_secs(4));
+
+        assert_eq!(
+            vec![
+                StepDuration {
+                    step: "verification".to_string(),
+                    seconds: 10.0
+                },
+                StepDuration {
+                    step: "download".to_string(),
+                    seconds: 4.0
+                },
+            ],
+            summary.steps
+        );
+        assert_eq!(1600, summary.bytes_transferred);
+        assert_eq!(200.0, summary.throughput_bytes_per_second);
+    }
+
+    #[tokio::test]
+    async fn time_download_and_unpack_steps_record_the_bytes_received() {
+        let mut summary = ExecutionSummary::new();
+        let download_measure_receiver = DownloadMeasureFeedbackReceiver::new();
+
+        let output = summary
+            .time_download_and_unpack_steps(&download_measure_receiver, async {
+                for downloaded_bytes in [400, 1000] {
+                    download_measure_receiver
+                        .handle_event(MithrilEvent::SnapshotDownloadProgress {
+                            download_id: "download_id".to_string(),
+                            downloaded_bytes,
+                            size: 5000,
+                        })
+                        .await;
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                42
+            })
+            .await;
+
+        assert_eq!(42, output);
+        assert_eq!(
+            vec!["download", "unpack"],
+            summary
+                .steps
+                .iter()
+                .map(|step| step.step.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(summary.steps[1].seconds >= 0.02);
+        assert_eq!(1000, summary.bytes_transferred);
+    }
+
+    #[tokio::test]
+    async fn time_step_return_the_future_output() {
+        let mut summary = ExecutionSummary::new();
+
+        let output = summary.time_step("step", async { 42 }).await;
+
+        assert_eq!(42, output);
+        assert_eq!(1, summary.steps.len());
+        assert_eq!(0, summary.bytes_transferred);
+    }
+
+    #[test]
+    fn json_summary_contains_machine_readable_values() {
+        let mut summary = ExecutionSummary::new();
+        summary.add_step("download", Duration::from_millis(2500));
+        summary.add_transfer(5000, Duration::from_millis(2500));
+
+        let json = serde_json::to_value(&summary).unwrap();
+
+        assert_eq!(
+            serde_json::json!([{"step": "download", "seconds": 2.5}]),
+            json["steps"]
+        );
+        assert_eq!(5000, json["bytes_transferred"]);
+        assert_eq!(2000.0, json["throughput_bytes_per_second"]);
+        assert!(json.get("started_at").is_none());
+    }
+}
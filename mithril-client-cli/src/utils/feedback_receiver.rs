@@ -1,6 +1,6 @@
 use async_trait::async_trait;
-use indicatif::{ProgressBar, ProgressDrawTarget, ProgressState, ProgressStyle};
-use std::fmt::Write;
+use indicatif::{MultiProgress, ProgressBar, ProgressState, ProgressStyle};
+use std::{collections::HashMap, fmt::Write};
 use tokio::sync::RwLock;
 
 use super::{DownloadProgressReporter, ProgressOutputType};
@@ -9,9 +9,16 @@ use mithril_client::feedback::{FeedbackReceiver, MithrilEvent};
 
 /// Custom [FeedbackReceiver] for Cardano DB to handle events sent
 /// by the `mithril-client` library
+///
+/// Each operation (download, digest computation, certificate chain validation) has its own
+/// progress bar, identified by the id of the operation, so concurrent operations do not
+/// interleave their output. The progress bars are displayed under a single [MultiProgress],
+/// in the order their operations were started.
 pub struct IndicatifFeedbackReceiver {
-    download_progress_reporter: RwLock<Option<DownloadProgressReporter>>,
-    certificate_validation_pb: RwLock<Option<ProgressBar>>,
+    multi_progress: MultiProgress,
+    download_progress_reporters: RwLock<HashMap<String, DownloadProgressReporter>>,
+    certificate_validation_pbs: RwLock<HashMap<String, ProgressBar>>,
+    digest_computation_pbs: RwLock<HashMap<String, ProgressBar>>,
     output_type: ProgressOutputType,
 }
 
@@ -19,11 +26,24 @@ impl IndicatifFeedbackReceiver {
     /// [IndicatifFeedbackReceiver] constructor
     pub fn new(output_type: ProgressOutputType) -> Self {
         Self {
-            download_progress_reporter: RwLock::new(None),
-            certificate_validation_pb: RwLock::new(None),
+            multi_progress: MultiProgress::with_draw_target(output_type.into()),
+            download_progress_reporters: RwLock::new(HashMap::new()),
+            certificate_validation_pbs: RwLock::new(HashMap::new()),
+            digest_computation_pbs: RwLock::new(HashMap::new()),
             output_type,
         }
     }
+
+    /// Display the progress bars under the given [MultiProgress], ie: the one of a
+    /// [ProgressPrinter][super::ProgressPrinter] so its steps are printed above the bars.
+    pub fn with_multi_progress(mut self, multi_progress: MultiProgress) -> Self {
+        self.multi_progress = multi_progress;
+        self
+    }
+
+    fn add_progress_bar(&self, progress_bar: ProgressBar) -> ProgressBar {
+        self.multi_progress.add(progress_bar)
+    }
 }
 
 #[async_trait]
@@ -32,69 +52,189 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
         match event {
             MithrilEvent::SnapshotDownloadStarted {
                 digest: _,
-                download_id: _,
+                download_id,
                 size,
             } => {
-                let pb = if self.output_type == ProgressOutputType::Tty {
-                    ProgressBar::new(size)
-                } else {
-                    ProgressBar::with_draw_target(Some(size), ProgressDrawTarget::hidden())
-                };
+                let pb = self.add_progress_bar(ProgressBar::new(size));
                 pb.set_style(ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} ({eta})")
                     .unwrap()
                     .with_key("eta", |state : &ProgressState, w: &mut dyn Write| write!(w, "{:.1}s", state.eta().as_secs_f64()).unwrap())
                     .progress_chars("#>-"));
-                let mut download_progress_reporter = self.download_progress_reporter.write().await;
-                *download_progress_reporter =
-                    Some(DownloadProgressReporter::new(pb, self.output_type));
+                let mut download_progress_reporters =
+                    self.download_progress_reporters.write().await;
+                download_progress_reporters.insert(
+                    download_id,
+                    DownloadProgressReporter::new(pb, self.output_type),
+                );
             }
             MithrilEvent::SnapshotDownloadProgress {
-                download_id: _,
+                download_id,
                 downloaded_bytes,
                 size: _,
             } => {
-                let download_progress_reporter = self.download_progress_reporter.read().await;
-                if let Some(progress_reporter) = download_progress_reporter.as_ref() {
+                let download_progress_reporters = self.download_progress_reporters.read().await;
+                if let Some(progress_reporter) = download_progress_reporters.get(&download_id) {
                     progress_reporter.report(downloaded_bytes);
                 }
             }
-            MithrilEvent::SnapshotDownloadCompleted { download_id: _ } => {
-                let mut download_progress_reporter = self.download_progress_reporter.write().await;
-                if let Some(progress_reporter) = download_progress_reporter.as_ref() {
+            MithrilEvent::SnapshotDownloadCompleted { download_id } => {
+                let mut download_progress_reporters =
+                    self.download_progress_reporters.write().await;
+                if let Some(progress_reporter) = download_progress_reporters.remove(&download_id) {
                     progress_reporter.finish("Cardano DB download completed");
                 }
-                *download_progress_reporter = None;
+            }
+            MithrilEvent::SnapshotDigestComputationStarted { computation_id } => {
+                let pb = self.add_progress_bar(ProgressBar::new(0));
+                pb.set_style(ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {pos}/{len} immutable files hashed ({eta})")
+                    .unwrap()
+                    .with_key("eta", |state : &ProgressState, w: &mut dyn Write| write!(w, "{:.1}s", state.eta().as_secs_f64()).unwrap())
+                    .progress_chars("#>-"));
+                let mut digest_computation_pbs = self.digest_computation_pbs.write().await;
+                digest_computation_pbs.insert(computation_id, pb);
+            }
+            MithrilEvent::SnapshotDigestComputationProgress {
+                computation_id,
+                hashed_files,
+                total_files,
+            } => {
+                let digest_computation_pbs = self.digest_computation_pbs.read().await;
+                if let Some(progress_bar) = digest_computation_pbs.get(&computation_id) {
+                    progress_bar.set_length(total_files);
+                    progress_bar.set_position(hashed_files);
+                }
+            }
+            MithrilEvent::SnapshotDigestComputationCompleted { computation_id } => {
+                let mut digest_computation_pbs = self.digest_computation_pbs.write().await;
+                if let Some(progress_bar) = digest_computation_pbs.remove(&computation_id) {
+                    progress_bar.finish();
+                }
             }
             MithrilEvent::CertificateChainValidationStarted {
-                certificate_chain_validation_id: _,
+                certificate_chain_validation_id,
             } => {
-                let pb = if self.output_type == ProgressOutputType::Tty {
-                    ProgressBar::new_spinner()
-                } else {
-                    ProgressBar::hidden()
-                };
-                let mut certificate_validation_pb = self.certificate_validation_pb.write().await;
-                *certificate_validation_pb = Some(pb);
+                let pb = self.add_progress_bar(ProgressBar::new_spinner());
+                let mut certificate_validation_pbs = self.certificate_validation_pbs.write().await;
+                certificate_validation_pbs.insert(certificate_chain_validation_id, pb);
             }
             MithrilEvent::CertificateValidated {
-                certificate_chain_validation_id: _,
+                certificate_chain_validation_id,
                 certificate_hash,
             } => {
-                let certificate_validation_pb = self.certificate_validation_pb.read().await;
-                if let Some(progress_bar) = certificate_validation_pb.as_ref() {
+                let certificate_validation_pbs = self.certificate_validation_pbs.read().await;
+                if let Some(progress_bar) =
+                    certificate_validation_pbs.get(&certificate_chain_validation_id)
+                {
                     progress_bar.set_message(format!("Certificate '{certificate_hash}' is valid"));
                     progress_bar.inc(1);
                 }
             }
             MithrilEvent::CertificateChainValidated {
-                certificate_chain_validation_id: _,
+                certificate_chain_validation_id,
             } => {
-                let mut certificate_validation_pb = self.certificate_validation_pb.write().await;
-                if let Some(progress_bar) = certificate_validation_pb.as_ref() {
+                let mut certificate_validation_pbs = self.certificate_validation_pbs.write().await;
+                if let Some(progress_bar) =
+                    certificate_validation_pbs.remove(&certificate_chain_validation_id)
+                {
                     progress_bar.finish_with_message("Certificate chain validated");
                 }
-                *certificate_validation_pb = None;
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn download_started(download_id: &str, size: u64) -> MithrilEvent {
+        MithrilEvent::SnapshotDownloadStarted {
+            digest: "digest".to_string(),
+            download_id: download_id.to_string(),
+            size,
+        }
+    }
+
+    fn download_progress(download_id: &str, downloaded_bytes: u64) -> MithrilEvent {
+        MithrilEvent::SnapshotDownloadProgress {
+            download_id: download_id.to_string(),
+            downloaded_bytes,
+            size: 100,
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_downloads_have_their_own_progress_bar() {
+        let receiver = IndicatifFeedbackReceiver::new(ProgressOutputType::Hidden);
+
+        receiver
+            .handle_event(download_started("download-1", 100))
+            .await;
+        receiver
+            .handle_event(download_started("download-2", 100))
+            .await;
+        receiver
+            .handle_event(download_progress("download-1", 10))
+            .await;
+        receiver
+            .handle_event(download_progress("download-2", 60))
+            .await;
+
+        let reporters = receiver.download_progress_reporters.read().await;
+        assert_eq!(2, reporters.len());
+        assert_eq!(10, reporters["download-1"].position());
+        assert_eq!(60, reporters["download-2"].position());
+    }
+
+    #[tokio::test]
+    async fn completed_download_does_not_finish_other_downloads() {
+        let receiver = IndicatifFeedbackReceiver::new(ProgressOutputType::Hidden);
+
+        receiver
+            .handle_event(download_started("download-1", 100))
+            .await;
+        receiver
+            .handle_event(download_started("download-2", 100))
+            .await;
+        receiver
+            .handle_event(MithrilEvent::SnapshotDownloadCompleted {
+                download_id: "download-1".to_string(),
+            })
+            .await;
+        receiver
+            .handle_event(download_progress("download-2", 60))
+            .await;
+
+        let reporters = receiver.download_progress_reporters.read().await;
+        assert!(!reporters.contains_key("download-1"));
+        assert_eq!(60, reporters["download-2"].position());
+    }
+
+    #[tokio::test]
+    async fn concurrent_certificate_chain_validations_have_their_own_progress_bar() {
+        let receiver = IndicatifFeedbackReceiver::new(ProgressOutputType::Hidden);
+
+        for validation_id in ["validation-1", "validation-2"] {
+            receiver
+                .handle_event(MithrilEvent::CertificateChainValidationStarted {
+                    certificate_chain_validation_id: validation_id.to_string(),
+                })
+                .await;
+        }
+        receiver
+            .handle_event(MithrilEvent::CertificateValidated {
+                certificate_chain_validation_id: "validation-2".to_string(),
+                certificate_hash: "certificate-hash".to_string(),
+            })
+            .await;
+        receiver
+            .handle_event(MithrilEvent::CertificateChainValidated {
+                certificate_chain_validation_id: "validation-1".to_string(),
+            })
+            .await;
+
+        let progress_bars = receiver.certificate_validation_pbs.read().await;
+        assert_eq!(1, progress_bars.len());
+        assert_eq!(1, progress_bars["validation-2"].position());
+    }
+}
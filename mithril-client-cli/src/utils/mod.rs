@@ -3,12 +3,14 @@
 
 mod cardano_db;
 mod cardano_db_download_checker;
+mod execution_summary;
 mod expander;
 mod feedback_receiver;
 mod progress_reporter;
 
 pub use cardano_db::*;
 pub use cardano_db_download_checker::*;
+pub use execution_summary::*;
 pub use expander::*;
 pub use feedback_receiver::*;
 pub use progress_reporter::*;
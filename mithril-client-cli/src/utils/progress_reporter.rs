@@ -147,6 +147,12 @@ impl DownloadProgressReporter {
         };
     }
 
+    /// Current position of the progress
+    #[cfg(test)]
+    pub fn position(&self) -> u64 {
+        self.progress_bar.position()
+    }
+
     /// Report that the current download is finished and print the given message.
     pub fn finish(&self, message: &str) {
         self.progress_bar.finish_with_message(message.to_string());
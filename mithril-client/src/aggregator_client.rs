@@ -10,9 +10,11 @@
 use anyhow::{anyhow, Context};
 use async_recursion::async_recursion;
 use async_trait::async_trait;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
 use reqwest::{Response, StatusCode, Url};
 use semver::Version;
 use slog::{debug, Logger};
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::RwLock;
@@ -20,6 +22,8 @@ use tokio::sync::RwLock;
 #[cfg(test)]
 use mockall::automock;
 
+use mithril_common::api_version::APIVersionProvider;
+use mithril_common::entities::{ApiVersionMismatchError, Epoch};
 use mithril_common::MITHRIL_API_VERSION_HEADER;
 
 use crate::{MithrilError, MithrilResult};
@@ -36,6 +40,9 @@ pub enum AggregatorClientError {
     RemoteServerLogical(#[source] MithrilError),
 
     /// Error raised when the server API version mismatch the client API version.
+    ///
+    /// Its source is an [ApiVersionMismatchError][crate::common::ApiVersionMismatchError] if the
+    /// aggregator sent its API version.
     #[error("API version mismatch")]
     ApiVersionMismatch(#[source] MithrilError),
 
@@ -69,6 +76,15 @@ pub enum AggregatorRequest {
     /// Lists the aggregator [snapshots][crate::Snapshot]
     ListSnapshots,
 
+    /// Get the settings of the current epoch of the aggregator
+    GetEpochSettings,
+
+    /// Lists the [signers registrations][crate::SignerRegistrations] sent at an epoch
+    ListRegisteredSigners {
+        /// Epoch at which the signers registered
+        epoch: Epoch,
+    },
+
     /// Increments the aggregator snapshot download statistics
     IncrementSnapshotStatistic {
         /// Snapshot as HTTP request body
@@ -112,6 +128,10 @@ impl AggregatorRequest {
                 format!("artifact/snapshot/{}", digest)
             }
             AggregatorRequest::ListSnapshots => "artifact/snapshots".to_string(),
+            AggregatorRequest::GetEpochSettings => "epoch-settings".to_string(),
+            AggregatorRequest::ListRegisteredSigners { epoch } => {
+                format!("signers/registered/{epoch}")
+            }
             AggregatorRequest::IncrementSnapshotStatistic { snapshot: _ } => {
                 "statistics/snapshot".to_string()
             }
@@ -161,11 +181,55 @@ pub trait AggregatorClient: Sync + Send {
     ) -> Result<String, AggregatorClientError>;
 }
 
+/// Maximum number of responses kept in the response cache of an [AggregatorHTTPClient].
+const RESPONSE_CACHE_CAPACITY: usize = 64;
+
+/// Response of the aggregator with an `ETag`, kept to send conditional requests.
+struct CachedResponse {
+    etag: String,
+    content: String,
+}
+
+/// Small cache of the aggregator responses, the oldest responses are evicted first when it is
+/// full.
+#[derive(Default)]
+struct ResponseCache {
+    responses: HashMap<Url, CachedResponse>,
+    insertion_order: VecDeque<Url>,
+}
+
+impl ResponseCache {
+    fn get(&self, url: &Url) -> Option<&CachedResponse> {
+        self.responses.get(url)
+    }
+
+    fn insert(&mut self, url: Url, etag: String, content: String) {
+        if self
+            .responses
+            .insert(url.clone(), CachedResponse { etag, content })
+            .is_none()
+        {
+            self.insertion_order.push_back(url);
+        }
+
+        while self.insertion_order.len() > RESPONSE_CACHE_CAPACITY {
+            if let Some(evicted_url) = self.insertion_order.pop_front() {
+                self.responses.remove(&evicted_url);
+            }
+        }
+    }
+}
+
 /// Responsible for HTTP transport and API version check.
+///
+/// The responses of the `GET` requests that have an `ETag` are cached, and the following
+/// requests to the same url are conditional so the aggregator can reply without a body if the
+/// content has not changed.
 pub struct AggregatorHTTPClient {
     http_client: reqwest::Client,
     aggregator_endpoint: Url,
     api_versions: Arc<RwLock<Vec<Version>>>,
+    response_cache: RwLock<ResponseCache>,
     logger: Logger,
 }
 
@@ -195,6 +259,7 @@ impl AggregatorHTTPClient {
             http_client,
             aggregator_endpoint,
             api_versions: Arc::new(RwLock::new(api_versions)),
+            response_cache: RwLock::new(ResponseCache::default()),
             logger,
         })
     }
@@ -224,11 +289,17 @@ impl AggregatorHTTPClient {
     }
 
     /// Perform a HTTP GET request on the Aggregator and return the given JSON
+    ///
+    /// If an `etag` is given the request is conditional, and the response may be a
+    /// `304 Not Modified` without body.
     #[cfg_attr(target_family = "wasm", async_recursion(?Send))]
     #[cfg_attr(not(target_family = "wasm"), async_recursion)]
-    async fn get(&self, url: Url) -> Result<Response, AggregatorClientError> {
+    async fn get(&self, url: Url, etag: Option<String>) -> Result<Response, AggregatorClientError> {
         debug!(self.logger, "GET url='{url}'.");
-        let request_builder = self.http_client.get(url.clone());
+        let mut request_builder = self.http_client.get(url.clone());
+        if let Some(etag) = etag.as_ref() {
+            request_builder = request_builder.header(IF_NONE_MATCH, etag);
+        }
         let current_api_version = self
             .compute_current_api_version()
             .await
@@ -247,15 +318,15 @@ impl AggregatorHTTPClient {
         })?;
 
         match response.status() {
-            StatusCode::OK => Ok(response),
+            StatusCode::OK | StatusCode::NOT_MODIFIED => Ok(response),
             StatusCode::PRECONDITION_FAILED => {
                 if self.discard_current_api_version().await.is_some()
                     && !self.api_versions.read().await.is_empty()
                 {
-                    return self.get(url).await;
+                    return self.get(url, etag).await;
                 }
 
-                Err(self.handle_api_error(&response).await)
+                Err(self.handle_api_error(response).await)
             }
             StatusCode::NOT_FOUND => Err(AggregatorClientError::RemoteServerLogical(anyhow!(
                 "Url='{url} not found"
@@ -298,7 +369,7 @@ impl AggregatorHTTPClient {
                     return self.post(url, json).await;
                 }
 
-                Err(self.handle_api_error(&response).await)
+                Err(self.handle_api_error(response).await)
             }
             StatusCode::NOT_FOUND => Err(AggregatorClientError::RemoteServerLogical(anyhow!(
                 "Url='{url} not found"
@@ -310,18 +381,37 @@ impl AggregatorHTTPClient {
     }
 
     /// API version error handling
-    async fn handle_api_error(&self, response: &Response) -> AggregatorClientError {
-        if let Some(version) = response.headers().get(MITHRIL_API_VERSION_HEADER) {
-            AggregatorClientError::ApiVersionMismatch(anyhow!(
-                "server version: '{}', signer version: '{}'",
-                version.to_str().unwrap(),
-                self.compute_current_api_version().await.unwrap()
-            ))
-        } else {
-            AggregatorClientError::ApiVersionMismatch(anyhow!(
-                "version precondition failed, sent version '{}'.",
-                self.compute_current_api_version().await.unwrap()
-            ))
+    ///
+    /// The aggregator describes the mismatch in the response body, older aggregators only send
+    /// their version in the response headers.
+    async fn handle_api_error(&self, response: Response) -> AggregatorClientError {
+        let client_version = self.compute_current_api_version().await.unwrap();
+        let server_version = response
+            .headers()
+            .get(MITHRIL_API_VERSION_HEADER)
+            .and_then(|version| version.to_str().ok())
+            .and_then(|version| Version::parse(version).ok());
+
+        match response.json::<ApiVersionMismatchError>().await {
+            Ok(error) => AggregatorClientError::ApiVersionMismatch(anyhow!(error)),
+            Err(_) => match server_version {
+                Some(server_version) => {
+                    let server_version_requirement =
+                        APIVersionProvider::compute_version_requirement(&server_version)
+                            .map(|requirement| requirement.to_string())
+                            .unwrap_or_default();
+                    AggregatorClientError::ApiVersionMismatch(anyhow!(
+                        ApiVersionMismatchError::new(
+                            client_version.to_string(),
+                            server_version.to_string(),
+                            server_version_requirement,
+                        )
+                    ))
+                }
+                None => AggregatorClientError::ApiVersionMismatch(anyhow!(
+                    "version precondition failed, sent version '{client_version}'."
+                )),
+            },
         }
     }
 
@@ -346,14 +436,47 @@ impl AggregatorClient for AggregatorHTTPClient {
         &self,
         request: AggregatorRequest,
     ) -> Result<String, AggregatorClientError> {
-        let response = self.get(self.get_url_for_route(&request.route())?).await?;
-        let content = format!("{response:?}");
+        let url = self.get_url_for_route(&request.route())?;
+        let cached_etag = self
+            .response_cache
+            .read()
+            .await
+            .get(&url)
+            .map(|cached_response| cached_response.etag.clone());
+        let mut response = self.get(url.clone(), cached_etag).await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(cached_response) = self.response_cache.read().await.get(&url) {
+                debug!(
+                    self.logger,
+                    "Content not modified, using cached response for url='{url}'."
+                );
+                return Ok(cached_response.content.clone());
+            }
+            // The cached response was evicted meanwhile, the content must be fetched again.
+            response = self.get(url.clone(), None).await?;
+        }
 
-        response.text().await.map_err(|e| {
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(|etag| etag.to_string());
+        let response_debug = format!("{response:?}");
+        let content = response.text().await.map_err(|e| {
             AggregatorClientError::SubsystemError(anyhow!(e).context(format!(
-                "Could not find a JSON body in the response '{content}'."
+                "Could not find a JSON body in the response '{response_debug}'."
             )))
-        })
+        })?;
+
+        if let Some(etag) = etag {
+            self.response_cache
+                .write()
+                .await
+                .insert(url, etag, content.clone());
+        }
+
+        Ok(content)
     }
 
     async fn post_content(
@@ -432,6 +555,16 @@ mod tests {
             AggregatorRequest::ListMithrilStakeDistributions.route()
         );
 
+        assert_eq!(
+            "epoch-settings".to_string(),
+            AggregatorRequest::GetEpochSettings.route()
+        );
+
+        assert_eq!(
+            "signers/registered/42".to_string(),
+            AggregatorRequest::ListRegisteredSigners { epoch: Epoch(42) }.route()
+        );
+
         assert_eq!(
             "artifact/snapshot/abc".to_string(),
             AggregatorRequest::GetSnapshot {
@@ -482,4 +615,211 @@ mod tests {
             );
         }
     }
+
+    mod conditional_requests {
+        use httpmock::{prelude::HttpMockRequest, MockServer};
+
+        use super::*;
+
+        fn is_not_conditional(request: &HttpMockRequest) -> bool {
+            !request
+                .headers
+                .iter()
+                .flatten()
+                .any(|(name, _)| name.eq_ignore_ascii_case(IF_NONE_MATCH.as_str()))
+        }
+
+        fn build_client(server: &MockServer) -> AggregatorHTTPClient {
+            AggregatorHTTPClient::new(
+                Url::parse(&server.url("/")).unwrap(),
+                vec![Version::new(0, 1, 0)],
+                crate::test_utils::test_logger(),
+            )
+            .unwrap()
+        }
+
+        #[cfg(feature = "fs")]
+        #[tokio::test]
+        async fn accept_and_decode_compressed_responses() {
+            use flate2::{write::GzEncoder, Compression};
+            use std::io::Write;
+
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(br#"["certificate-1"]"#).unwrap();
+            let compressed_content = encoder.finish().unwrap();
+            let server = MockServer::start();
+            server.mock(|when, then| {
+                when.path("/certificates").matches(|request| {
+                    request.headers.iter().flatten().any(|(name, value)| {
+                        name.eq_ignore_ascii_case("accept-encoding")
+                            && value.contains("gzip")
+                            && value.contains("zstd")
+                    })
+                });
+                then.status(200)
+                    .header("content-encoding", "gzip")
+                    .body(compressed_content);
+            });
+            let client = build_client(&server);
+
+            let content = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+
+            assert_eq!(r#"["certificate-1"]"#, content);
+        }
+
+        #[tokio::test]
+        async fn return_cached_content_if_not_modified() {
+            let server = MockServer::start();
+            let first_response = server.mock(|when, then| {
+                when.path("/certificates").matches(is_not_conditional);
+                then.status(200)
+                    .header("etag", r#""etag-1""#)
+                    .body(r#"["certificate-1"]"#);
+            });
+            let conditional_response = server.mock(|when, then| {
+                when.path("/certificates")
+                    .header("if-none-match", r#""etag-1""#);
+                then.status(304).header("etag", r#""etag-1""#);
+            });
+            let client = build_client(&server);
+
+            let first_content = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+            let second_content = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+
+            first_response.assert();
+            conditional_response.assert();
+            assert_eq!(r#"["certificate-1"]"#, first_content);
+            assert_eq!(first_content, second_content);
+        }
+
+        #[tokio::test]
+        async fn return_new_content_and_update_cache_if_modified() {
+            let server = MockServer::start();
+            server.mock(|when, then| {
+                when.path("/certificates").matches(is_not_conditional);
+                then.status(200)
+                    .header("etag", r#""etag-1""#)
+                    .body(r#"["certificate-1"]"#);
+            });
+            server.mock(|when, then| {
+                when.path("/certificates")
+                    .header("if-none-match", r#""etag-1""#);
+                then.status(200)
+                    .header("etag", r#""etag-2""#)
+                    .body(r#"["certificate-2","certificate-1"]"#);
+            });
+            let client = build_client(&server);
+
+            client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+            let content = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .unwrap();
+
+            assert_eq!(r#"["certificate-2","certificate-1"]"#, content);
+            let url = client.get_url_for_route("certificates").unwrap();
+            assert_eq!(
+                Some(r#""etag-2""#.to_string()),
+                client
+                    .response_cache
+                    .read()
+                    .await
+                    .get(&url)
+                    .map(|cached_response| cached_response.etag.clone())
+            );
+        }
+
+        #[test]
+        fn response_cache_evict_oldest_responses_when_full() {
+            let mut cache = ResponseCache::default();
+            let url = |index: usize| Url::parse(&format!("http://aggregator/{index}")).unwrap();
+
+            for index in 0..=RESPONSE_CACHE_CAPACITY {
+                cache.insert(url(index), format!("etag-{index}"), "content".to_string());
+            }
+
+            assert!(cache.get(&url(0)).is_none());
+            assert!(cache.get(&url(1)).is_some());
+            assert!(cache.get(&url(RESPONSE_CACHE_CAPACITY)).is_some());
+            assert_eq!(RESPONSE_CACHE_CAPACITY, cache.responses.len());
+        }
+    }
+
+    mod api_version_mismatch {
+        use httpmock::MockServer;
+
+        use super::*;
+
+        fn build_client(server: &MockServer) -> AggregatorHTTPClient {
+            AggregatorHTTPClient::new(
+                Url::parse(&server.url("/")).unwrap(),
+                vec![Version::new(0, 1, 0)],
+                crate::test_utils::test_logger(),
+            )
+            .unwrap()
+        }
+
+        fn find_api_version_mismatch_error(
+            error: AggregatorClientError,
+        ) -> Option<ApiVersionMismatchError> {
+            anyhow!(error)
+                .chain()
+                .find_map(|error| error.downcast_ref::<ApiVersionMismatchError>())
+                .cloned()
+        }
+
+        #[tokio::test]
+        async fn read_structured_error_sent_by_the_aggregator() {
+            let server = MockServer::start();
+            server.mock(|when, then| {
+                when.path("/certificates");
+                then.status(412)
+                    .header(MITHRIL_API_VERSION_HEADER, "0.2.1")
+                    .json_body_obj(&ApiVersionMismatchError::new("0.1.0", "0.2.1", "=0.2"));
+            });
+            let client = build_client(&server);
+
+            let error = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .expect_err("an API version mismatch should make the request fail");
+
+            assert_eq!(
+                Some(ApiVersionMismatchError::new("0.1.0", "0.2.1", "=0.2")),
+                find_api_version_mismatch_error(error)
+            );
+        }
+
+        #[tokio::test]
+        async fn build_structured_error_from_the_version_header_of_older_aggregators() {
+            let server = MockServer::start();
+            server.mock(|when, then| {
+                when.path("/certificates");
+                then.status(412).header(MITHRIL_API_VERSION_HEADER, "0.2.1");
+            });
+            let client = build_client(&server);
+
+            let error = client
+                .get_content(AggregatorRequest::ListCertificates)
+                .await
+                .expect_err("an API version mismatch should make the request fail");
+
+            assert_eq!(
+                Some(ApiVersionMismatchError::new("0.1.0", "0.2.1", "=0.2")),
+                find_api_version_mismatch_error(error)
+            );
+        }
+    }
 }
@@ -4,6 +4,8 @@
 //!  - [get][CertificateClient::get]: get a certificate data from its hash
 //!  - [list][CertificateClient::list]: get the list of available certificates
 //!  - [verify_chain][CertificateClient::verify_chain]: verify a certificate chain
+//!  - [verify_snapshot_certificate][CertificateClient::verify_snapshot_certificate]: verify the
+//!    certificate chain of a snapshot using a pinned certificate
 //!
 //! # Get a certificate
 //!
@@ -54,16 +56,39 @@
 //! #    Ok(())
 //! # }
 //! ```
+//!
+//! # Verify a snapshot against a pinned certificate
+//!
+//! To verify that a snapshot is certified by a certificate that the user trusts, instead of the
+//! certificate hash given by the aggregator in the snapshot message, using the
+//! [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let snapshot = client.snapshot().get("SNAPSHOT_DIGEST").await?.unwrap();
+//! let certificate = client
+//!     .certificate()
+//!     .verify_snapshot_certificate(&snapshot, "PINNED_CERTIFICATE_HASH")
+//!     .await?;
+//!
+//! println!("Snapshot digest={} is certified by certificate hash={}", snapshot.digest, certificate.hash);
+//! #    Ok(())
+//! # }
+//! ```
 
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use slog::{crit, debug, Logger};
+use thiserror::Error;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
 use crate::feedback::{FeedbackSender, MithrilEvent};
-use crate::{MithrilCertificate, MithrilCertificateListItem, MithrilResult};
+use crate::{MithrilCertificate, MithrilCertificateListItem, MithrilResult, Snapshot};
 use mithril_common::crypto_helper::ProtocolGenesisVerificationKey;
 use mithril_common::{
     certificate_chain::{
@@ -71,13 +96,27 @@ use mithril_common::{
         CertificateVerifier as CommonCertificateVerifier,
         MithrilCertificateVerifier as CommonMithrilCertificateVerifier,
     },
-    entities::Certificate,
+    entities::{Certificate, ProtocolMessagePartKey, SignedEntityType},
     messages::CertificateMessage,
 };
 
 #[cfg(test)]
 use mockall::automock;
 
+/// Error for the Certificate client
+#[derive(Error, Debug)]
+pub enum CertificateClientError {
+    /// The certificate returned by the aggregator is not the one that was requested
+    #[error("The certificate returned for the hash '{expected_hash}' has another hash: '{actual_hash}'.")]
+    CertificateHashMismatch {
+        /// hash of the requested certificate
+        expected_hash: String,
+
+        /// hash of the certificate returned by the aggregator
+        actual_hash: String,
+    },
+}
+
 /// Aggregator client for the Certificate
 pub struct CertificateClient {
     aggregator_client: Arc<dyn AggregatorClient>,
@@ -134,12 +173,21 @@ impl CertificateClient {
     /// Validate the chain starting with the certificate with given `certificate_hash`, return the certificate if
     /// the chain is valid.
     ///
-    /// This method will fail if no certificate exists for the given `certificate_hash`.
+    /// This method will fail if no certificate exists for the given `certificate_hash` or if the
+    /// certificate returned by the aggregator has another hash.
     pub async fn verify_chain(&self, certificate_hash: &str) -> MithrilResult<MithrilCertificate> {
         let certificate = self.retriever.get(certificate_hash).await?.ok_or(anyhow!(
             "No certificate exist for hash '{certificate_hash}'"
         ))?;
 
+        if certificate.hash != certificate_hash {
+            return Err(CertificateClientError::CertificateHashMismatch {
+                expected_hash: certificate_hash.to_string(),
+                actual_hash: certificate.hash,
+            }
+            .into());
+        }
+
         self.verifier
             .verify_chain(&certificate)
             .await
@@ -149,6 +197,41 @@ impl CertificateClient {
 
         Ok(certificate)
     }
+
+    /// Validate the chain starting with the certificate with the given pinned `certificate_hash`,
+    /// and check that this certificate certifies the given snapshot, return the certificate if
+    /// both checks succeed.
+    ///
+    /// The `certificate_hash` of the snapshot, given by the aggregator, is ignored: this protects
+    /// against an aggregator that would swap the snapshot message.
+    pub async fn verify_snapshot_certificate(
+        &self,
+        snapshot: &Snapshot,
+        certificate_hash: &str,
+    ) -> MithrilResult<MithrilCertificate> {
+        let certificate = self.verify_chain(certificate_hash).await?;
+
+        if certificate.signed_entity_type
+            != SignedEntityType::CardanoImmutableFilesFull(snapshot.beacon.clone())
+        {
+            return Err(anyhow!(
+                "Certificate '{certificate_hash}' does not certify the snapshot beacon '{}', it certifies '{:?}'",
+                snapshot.beacon,
+                certificate.signed_entity_type
+            ));
+        }
+
+        match certificate
+            .protocol_message
+            .get_message_part(&ProtocolMessagePartKey::SnapshotDigest)
+        {
+            Some(digest) if digest == &snapshot.digest => Ok(certificate),
+            _ => Err(anyhow!(
+                "Certificate '{certificate_hash}' does not certify the snapshot digest '{}'",
+                snapshot.digest
+            )),
+        }
+    }
 }
 
 /// Internal type to implement the [InternalCertificateRetriever] trait and avoid a circular
@@ -516,4 +599,169 @@ mod tests {
 
         assert_eq!(certificate.hash, last_certificate_hash);
     }
+
+    #[tokio::test]
+    async fn verify_chain_fail_if_returned_certificate_has_another_hash() {
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        let message = serde_json::to_string(&MithrilCertificate {
+            hash: "another-certificate-hash".to_string(),
+            ..MithrilCertificate::dummy()
+        })
+        .unwrap();
+        aggregator_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetCertificate {
+                hash: "requested-certificate-hash".to_string(),
+            }))
+            .return_once(move |_| Ok(message));
+        let mut verifier = MockCertificateVerifier::new();
+        verifier.expect_verify_chain().never();
+        let certificate_client =
+            build_client(Arc::new(aggregator_client), Some(Arc::new(verifier)));
+
+        let error = certificate_client
+            .verify_chain("requested-certificate-hash")
+            .await
+            .expect_err("Certificate with another hash than the requested one should be rejected");
+
+        assert!(
+            matches!(
+                error.downcast_ref::<CertificateClientError>(),
+                Some(CertificateClientError::CertificateHashMismatch { .. })
+            ),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    mod verify_snapshot_certificate {
+        use mithril_common::entities::{CardanoDbBeacon, ProtocolMessage};
+
+        use super::*;
+
+        fn certified_snapshot() -> Snapshot {
+            Snapshot {
+                digest: "digest-123".to_string(),
+                beacon: CardanoDbBeacon::new("devnet".to_string(), 8, 120),
+                certificate_hash: "certificate-hash-given-by-aggregator".to_string(),
+                ..Snapshot::dummy()
+            }
+        }
+
+        fn certificate_for(
+            certificate_hash: &str,
+            signed_entity_type: SignedEntityType,
+            digest: &str,
+        ) -> MithrilCertificate {
+            let mut protocol_message = ProtocolMessage::new();
+            protocol_message
+                .set_message_part(ProtocolMessagePartKey::SnapshotDigest, digest.to_string());
+
+            MithrilCertificate {
+                hash: certificate_hash.to_string(),
+                signed_entity_type,
+                protocol_message,
+                ..MithrilCertificate::dummy()
+            }
+        }
+
+        fn build_client_returning(certificate: MithrilCertificate) -> CertificateClient {
+            let requested_hash = certificate.hash.clone();
+            build_client_returning_for_hash(&requested_hash, certificate)
+        }
+
+        fn build_client_returning_for_hash(
+            requested_hash: &str,
+            certificate: MithrilCertificate,
+        ) -> CertificateClient {
+            let mut aggregator_client = MockAggregatorHTTPClient::new();
+            let message = serde_json::to_string(&certificate).unwrap();
+            aggregator_client
+                .expect_get_content()
+                .with(eq(AggregatorRequest::GetCertificate {
+                    hash: requested_hash.to_string(),
+                }))
+                .return_once(move |_| Ok(message));
+            let mut verifier = MockCertificateVerifier::new();
+            verifier.expect_verify_chain().returning(|_| Ok(()));
+
+            build_client(Arc::new(aggregator_client), Some(Arc::new(verifier)))
+        }
+
+        #[tokio::test]
+        async fn return_pinned_certificate_if_it_certifies_the_snapshot() {
+            let snapshot = certified_snapshot();
+            let certificate_client = build_client_returning(certificate_for(
+                "pinned-certificate-hash",
+                SignedEntityType::CardanoImmutableFilesFull(snapshot.beacon.clone()),
+                &snapshot.digest,
+            ));
+
+            let certificate = certificate_client
+                .verify_snapshot_certificate(&snapshot, "pinned-certificate-hash")
+                .await
+                .expect("Pinned certificate verification should succeed");
+
+            assert_eq!("pinned-certificate-hash", certificate.hash);
+        }
+
+        #[tokio::test]
+        async fn fail_if_returned_certificate_hash_differs_from_pinned_hash() {
+            let snapshot = certified_snapshot();
+            let certificate_client = build_client_returning_for_hash(
+                "pinned-certificate-hash",
+                certificate_for(
+                    "another-certificate-hash",
+                    SignedEntityType::CardanoImmutableFilesFull(snapshot.beacon.clone()),
+                    &snapshot.digest,
+                ),
+            );
+
+            let error = certificate_client
+                .verify_snapshot_certificate(&snapshot, "pinned-certificate-hash")
+                .await
+                .expect_err("Certificate with another hash than the pinned one should be rejected");
+
+            assert!(
+                matches!(
+                    error.downcast_ref::<CertificateClientError>(),
+                    Some(CertificateClientError::CertificateHashMismatch { .. })
+                ),
+                "unexpected error: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn fail_if_pinned_certificate_certifies_another_beacon() {
+            let snapshot = certified_snapshot();
+            let certificate_client = build_client_returning(certificate_for(
+                "pinned-certificate-hash",
+                SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon::new(
+                    "devnet".to_string(),
+                    8,
+                    121,
+                )),
+                &snapshot.digest,
+            ));
+
+            certificate_client
+                .verify_snapshot_certificate(&snapshot, "pinned-certificate-hash")
+                .await
+                .expect_err("Pinned certificate of another beacon should be rejected");
+        }
+
+        #[tokio::test]
+        async fn fail_if_pinned_certificate_certifies_another_digest() {
+            let snapshot = certified_snapshot();
+            let certificate_client = build_client_returning(certificate_for(
+                "pinned-certificate-hash",
+                SignedEntityType::CardanoImmutableFilesFull(snapshot.beacon.clone()),
+                "another-digest",
+            ));
+
+            certificate_client
+                .verify_snapshot_certificate(&snapshot, "pinned-certificate-hash")
+                .await
+                .expect_err("Pinned certificate of another digest should be rejected");
+        }
+    }
 }
@@ -12,9 +12,12 @@ use crate::certificate_client::{
 };
 use crate::feedback::{FeedbackReceiver, FeedbackSender};
 use crate::mithril_stake_distribution_client::MithrilStakeDistributionClient;
+use crate::signer_client::SignerClient;
 use crate::snapshot_client::SnapshotClient;
 #[cfg(feature = "fs")]
-use crate::snapshot_downloader::{HttpSnapshotDownloader, SnapshotDownloader};
+use crate::snapshot_downloader::{
+    HttpSnapshotDownloader, HttpSnapshotDownloaderConfig, SnapshotDownloader,
+};
 use crate::MithrilResult;
 
 /// Structure that aggregates the available clients for each of the Mithril types of certified data.
@@ -26,6 +29,7 @@ pub struct Client {
     cardano_transaction_client: Arc<CardanoTransactionClient>,
     certificate_client: Arc<CertificateClient>,
     mithril_stake_distribution_client: Arc<MithrilStakeDistributionClient>,
+    signer_client: Arc<SignerClient>,
     snapshot_client: Arc<SnapshotClient>,
 }
 
@@ -46,6 +50,11 @@ impl Client {
         self.mithril_stake_distribution_client.clone()
     }
 
+    /// Get the client that fetches the signers registered to the aggregator.
+    pub fn signer(&self) -> Arc<SignerClient> {
+        self.signer_client.clone()
+    }
+
     /// Get the client that fetches and downloads Mithril snapshots.
     pub fn snapshot(&self) -> Arc<SnapshotClient> {
         self.snapshot_client.clone()
@@ -60,6 +69,8 @@ pub struct ClientBuilder {
     certificate_verifier: Option<Arc<dyn CertificateVerifier>>,
     #[cfg(feature = "fs")]
     snapshot_downloader: Option<Arc<dyn SnapshotDownloader>>,
+    #[cfg(feature = "fs")]
+    snapshot_downloader_config: HttpSnapshotDownloaderConfig,
     logger: Option<Logger>,
     feedback_receivers: Vec<Arc<dyn FeedbackReceiver>>,
 }
@@ -75,6 +86,8 @@ impl ClientBuilder {
             certificate_verifier: None,
             #[cfg(feature = "fs")]
             snapshot_downloader: None,
+            #[cfg(feature = "fs")]
+            snapshot_downloader_config: HttpSnapshotDownloaderConfig::default(),
             logger: None,
             feedback_receivers: vec![],
         }
@@ -92,6 +105,8 @@ impl ClientBuilder {
             certificate_verifier: None,
             #[cfg(feature = "fs")]
             snapshot_downloader: None,
+            #[cfg(feature = "fs")]
+            snapshot_downloader_config: HttpSnapshotDownloaderConfig::default(),
             logger: None,
             feedback_receivers: vec![],
         }
@@ -133,8 +148,12 @@ impl ClientBuilder {
         #[cfg(feature = "fs")]
         let snapshot_downloader = match self.snapshot_downloader {
             None => Arc::new(
-                HttpSnapshotDownloader::new(feedback_sender.clone(), logger.clone())
-                    .with_context(|| "Building snapshot downloader failed")?,
+                HttpSnapshotDownloader::new_with_config(
+                    self.snapshot_downloader_config,
+                    feedback_sender.clone(),
+                    logger.clone(),
+                )
+                .with_context(|| "Building snapshot downloader failed")?,
             ),
             Some(snapshot_downloader) => snapshot_downloader,
         };
@@ -164,6 +183,7 @@ impl ClientBuilder {
         let mithril_stake_distribution_client = Arc::new(MithrilStakeDistributionClient::new(
             aggregator_client.clone(),
         ));
+        let signer_client = Arc::new(SignerClient::new(aggregator_client.clone()));
         let snapshot_client = Arc::new(SnapshotClient::new(
             aggregator_client,
             #[cfg(feature = "fs")]
@@ -179,6 +199,7 @@ impl ClientBuilder {
             cardano_transaction_client,
             certificate_client,
             mithril_stake_distribution_client,
+            signer_client,
             snapshot_client,
         })
     }
@@ -210,6 +231,18 @@ impl ClientBuilder {
         self.snapshot_downloader = Some(snapshot_downloader);
         self
     }
+
+    /// Set the network configuration (DNS resolution, connection timeouts) of the default
+    /// [HttpSnapshotDownloader].
+    ///
+    /// This configuration is ignored if a custom [SnapshotDownloader] is set.
+    pub fn with_snapshot_downloader_config(
+        mut self,
+        snapshot_downloader_config: HttpSnapshotDownloaderConfig,
+    ) -> ClientBuilder {
+        self.snapshot_downloader_config = snapshot_downloader_config;
+        self
+    }
     }
 
     /// Set the [Logger] to use.
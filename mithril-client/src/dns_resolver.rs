@@ -0,0 +1,272 @@
+//! DNS resolution used by the snapshot downloads.
+//!
+//! The [DnsResolver] trait abstracts how the hosts of the snapshots locations are resolved,
+//! by default the system resolver is used (see [SystemDnsResolver]) but it can be replaced,
+//! for example to pin some hosts to known addresses with a [StaticDnsResolver].
+//!
+//! The resolved addresses are given to the HTTP connector ordered by the
+//! [preferred address family][IpFamilyPreference] and with both IPv6 and IPv4 addresses: the
+//! connector then runs a _happy eyeballs_ race ([RFC 8305](https://www.rfc-editor.org/rfc/rfc8305)),
+//! meaning that if the connection with the preferred family is not established after a short
+//! delay, a connection with the other family is attempted concurrently and the first one to
+//! succeed is used. This avoids stalling on dual-stack hosts with a broken IPv6 connectivity.
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::MithrilResult;
+
+/// API that defines a DNS resolver
+#[async_trait]
+pub trait DnsResolver: Sync + Send {
+    /// Resolve the given host name to its IP addresses.
+    async fn resolve(&self, host: &str) -> MithrilResult<Vec<IpAddr>>;
+}
+
+/// A [DnsResolver] that uses the resolver of the operating system.
+pub struct SystemDnsResolver;
+
+#[async_trait]
+impl DnsResolver for SystemDnsResolver {
+    async fn resolve(&self, host: &str) -> MithrilResult<Vec<IpAddr>> {
+        let addresses = tokio::net::lookup_host((host, 0))
+            .await
+            .with_context(|| format!("Could not resolve host '{host}'"))?
+            .map(|socket_address| socket_address.ip())
+            .collect();
+
+        Ok(addresses)
+    }
+}
+
+/// A [DnsResolver] that resolves some hosts to fixed addresses and delegates the resolution
+/// of the other hosts to another resolver.
+pub struct StaticDnsResolver {
+    hosts: HashMap<String, Vec<IpAddr>>,
+    fallback_resolver: Arc<dyn DnsResolver>,
+}
+
+impl StaticDnsResolver {
+    /// Constructs a new `StaticDnsResolver` that resolves the hosts without fixed addresses
+    /// with the given resolver.
+    pub fn new(fallback_resolver: Arc<dyn DnsResolver>) -> Self {
+        Self {
+            hosts: HashMap::new(),
+            fallback_resolver,
+        }
+    }
+
+    /// Resolve the given host to the given addresses.
+    pub fn with_host(mut self, host: &str, addresses: &[IpAddr]) -> Self {
+        self.hosts.insert(host.to_lowercase(), addresses.to_vec());
+        self
+    }
+}
+
+#[async_trait]
+impl DnsResolver for StaticDnsResolver {
+    async fn resolve(&self, host: &str) -> MithrilResult<Vec<IpAddr>> {
+        match self.hosts.get(&host.to_lowercase()) {
+            Some(addresses) => Ok(addresses.clone()),
+            None => self.fallback_resolver.resolve(host).await,
+        }
+    }
+}
+
+/// IP address family tried first when connecting to a host that has both IPv6 and IPv4
+/// addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IpFamilyPreference {
+    /// Try IPv6 first, as recommended by RFC 8305.
+    #[default]
+    Ipv6,
+
+    /// Try IPv4 first.
+    Ipv4,
+}
+
+/// Order the addresses so that the addresses of the preferred family come first, keeping the
+/// order given by the resolver within each family.
+pub(crate) fn sort_addresses_by_family(
+    addresses: Vec<IpAddr>,
+    preference: IpFamilyPreference,
+) -> Vec<IpAddr> {
+    let (mut preferred, fallback): (Vec<_>, Vec<_>) =
+        addresses.into_iter().partition(|address| match preference {
+            IpFamilyPreference::Ipv6 => address.is_ipv6(),
+            IpFamilyPreference::Ipv4 => address.is_ipv4(),
+        });
+    preferred.extend(fallback);
+
+    preferred
+}
+
+/// Adapter that plugs a [DnsResolver] in the HTTP client used for the downloads.
+pub(crate) struct HttpClientDnsResolver {
+    resolver: Arc<dyn DnsResolver>,
+    ip_family_preference: IpFamilyPreference,
+    resolution_timeout: Duration,
+}
+
+impl HttpClientDnsResolver {
+    /// Constructs a new `HttpClientDnsResolver`.
+    pub fn new(
+        resolver: Arc<dyn DnsResolver>,
+        ip_family_preference: IpFamilyPreference,
+        resolution_timeout: Duration,
+    ) -> Self {
+        Self {
+            resolver,
+            ip_family_preference,
+            resolution_timeout,
+        }
+    }
+
+    async fn resolve_host(&self, host: &str) -> MithrilResult<Vec<IpAddr>> {
+        let addresses = tokio::time::timeout(self.resolution_timeout, self.resolver.resolve(host))
+            .await
+            .map_err(|_| {
+                anyhow!(
+                    "Resolution of host '{host}' timed out after {}ms",
+                    self.resolution_timeout.as_millis()
+                )
+            })??;
+
+        if addresses.is_empty() {
+            return Err(anyhow!("No address found for host '{host}'"));
+        }
+
+        Ok(sort_addresses_by_family(
+            addresses,
+            self.ip_family_preference,
+        ))
+    }
+}
+
+impl reqwest::dns::Resolve for HttpClientDnsResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let resolver = Self {
+            resolver: self.resolver.clone(),
+            ip_family_preference: self.ip_family_preference,
+            resolution_timeout: self.resolution_timeout,
+        };
+
+        Box::pin(async move {
+            let addresses = resolver
+                .resolve_host(name.as_str())
+                .await
+                .map_err(|error| -> Box<dyn std::error::Error + Send + Sync> { error.into() })?;
+            // The port is set by the HTTP connector
+            let socket_addresses: reqwest::dns::Addrs = Box::new(
+                addresses
+                    .into_iter()
+                    .map(|address| SocketAddr::new(address, 0)),
+            );
+
+            Ok(socket_addresses)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    const IPV4_1: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+    const IPV4_2: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2));
+    const IPV6_1: IpAddr = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    const IPV6_2: IpAddr = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2));
+
+    struct NeverRespondingDnsResolver;
+
+    #[async_trait]
+    impl DnsResolver for NeverRespondingDnsResolver {
+        async fn resolve(&self, _host: &str) -> MithrilResult<Vec<IpAddr>> {
+            futures::future::pending().await
+        }
+    }
+
+    #[test]
+    fn sort_addresses_with_preferred_family_first() {
+        let addresses = vec![IPV4_1, IPV6_1, IPV4_2, IPV6_2];
+
+        assert_eq!(
+            vec![IPV6_1, IPV6_2, IPV4_1, IPV4_2],
+            sort_addresses_by_family(addresses.clone(), IpFamilyPreference::Ipv6)
+        );
+        assert_eq!(
+            vec![IPV4_1, IPV4_2, IPV6_1, IPV6_2],
+            sort_addresses_by_family(addresses, IpFamilyPreference::Ipv4)
+        );
+    }
+
+    #[tokio::test]
+    async fn static_resolver_resolve_pinned_hosts_and_delegate_the_others() {
+        let fallback_resolver = StaticDnsResolver::new(Arc::new(SystemDnsResolver))
+            .with_host("other.example", &[IPV4_2]);
+        let resolver = StaticDnsResolver::new(Arc::new(fallback_resolver))
+            .with_host("snapshots.example", &[IPV6_1, IPV4_1]);
+
+        assert_eq!(
+            vec![IPV6_1, IPV4_1],
+            resolver.resolve("SNAPSHOTS.example").await.unwrap()
+        );
+        assert_eq!(
+            vec![IPV4_2],
+            resolver.resolve("other.example").await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn http_client_resolver_keep_both_families_ordered_by_preference() {
+        let resolver = HttpClientDnsResolver::new(
+            Arc::new(
+                StaticDnsResolver::new(Arc::new(SystemDnsResolver))
+                    .with_host("snapshots.example", &[IPV4_1, IPV6_1]),
+            ),
+            IpFamilyPreference::Ipv6,
+            Duration::from_secs(1),
+        );
+
+        let addresses = resolver.resolve_host("snapshots.example").await.unwrap();
+
+        assert_eq!(vec![IPV6_1, IPV4_1], addresses);
+    }
+
+    #[tokio::test]
+    async fn http_client_resolver_fail_if_no_address_found() {
+        let resolver = HttpClientDnsResolver::new(
+            Arc::new(
+                StaticDnsResolver::new(Arc::new(SystemDnsResolver))
+                    .with_host("snapshots.example", &[]),
+            ),
+            IpFamilyPreference::Ipv6,
+            Duration::from_secs(1),
+        );
+
+        resolver
+            .resolve_host("snapshots.example")
+            .await
+            .expect_err("Resolution without any address should fail");
+    }
+
+    #[tokio::test]
+    async fn http_client_resolver_fail_if_resolution_time_out() {
+        let resolver = HttpClientDnsResolver::new(
+            Arc::new(NeverRespondingDnsResolver),
+            IpFamilyPreference::Ipv6,
+            Duration::from_millis(10),
+        );
+
+        resolver
+            .resolve_host("snapshots.example")
+            .await
+            .expect_err("Resolution should time out");
+    }
+}
@@ -6,6 +6,7 @@
 //! Those tasks are:
 //! - Snapshot download
 //! - Certificate chain validation
+//! - Snapshot digest computation
 //!
 //! In order to have feedbacks for those tasks, a mechanism is available.
 //!
@@ -86,6 +87,26 @@ pub enum MithrilEvent {
         /// Unique identifier used to track this specific snapshot download
         download_id: String,
     },
+    /// A snapshot digest computation has started
+    SnapshotDigestComputationStarted {
+        /// Unique identifier used to track this specific snapshot digest computation
+        computation_id: String,
+    },
+    /// A snapshot digest computation is in progress, sent each time the percentage of hashed
+    /// files changes
+    SnapshotDigestComputationProgress {
+        /// Unique identifier used to track this specific snapshot digest computation
+        computation_id: String,
+        /// Number of immutable files that have been hashed
+        hashed_files: u64,
+        /// Number of immutable files to hash
+        total_files: u64,
+    },
+    /// A snapshot digest computation has completed
+    SnapshotDigestComputationCompleted {
+        /// Unique identifier used to track this specific snapshot digest computation
+        computation_id: String,
+    },
     /// A certificate chain validation has started
     CertificateChainValidationStarted {
         /// Unique identifier used to track this specific certificate chain validation
@@ -111,6 +132,11 @@ impl MithrilEvent {
         Uuid::new_v4().to_string()
     }
 
+    /// Generate a random unique identifier to identify a snapshot digest computation
+    pub fn new_snapshot_digest_computation_id() -> String {
+        Uuid::new_v4().to_string()
+    }
+
     /// Generate a random unique identifier to identify a certificate chain validation
     pub fn new_certificate_chain_validation_id() -> String {
         Uuid::new_v4().to_string()
@@ -122,6 +148,11 @@ impl MithrilEvent {
             MithrilEvent::SnapshotDownloadStarted { download_id, .. } => download_id,
             MithrilEvent::SnapshotDownloadProgress { download_id, .. } => download_id,
             MithrilEvent::SnapshotDownloadCompleted { download_id } => download_id,
+            MithrilEvent::SnapshotDigestComputationStarted { computation_id } => computation_id,
+            MithrilEvent::SnapshotDigestComputationProgress { computation_id, .. } => {
+                computation_id
+            }
+            MithrilEvent::SnapshotDigestComputationCompleted { computation_id } => computation_id,
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id,
             } => certificate_chain_validation_id,
@@ -214,6 +245,33 @@ impl FeedbackReceiver for SlogFeedbackReceiver {
             MithrilEvent::SnapshotDownloadCompleted { download_id } => {
                 info!(self.logger, "Snapshot download completed"; "download_id" => download_id);
             }
+            MithrilEvent::SnapshotDigestComputationStarted { computation_id } => {
+                info!(
+                    self.logger,
+                    "Snapshot digest computation started";
+                    "computation_id" => computation_id,
+                );
+            }
+            MithrilEvent::SnapshotDigestComputationProgress {
+                computation_id,
+                hashed_files,
+                total_files,
+            } => {
+                info!(
+                    self.logger,
+                    "Snapshot digest computation in progress ...";
+                    "hashed files" => hashed_files,
+                    "total files" => total_files,
+                    "computation_id" => computation_id,
+                );
+            }
+            MithrilEvent::SnapshotDigestComputationCompleted { computation_id } => {
+                info!(
+                    self.logger,
+                    "Snapshot digest computation completed";
+                    "computation_id" => computation_id,
+                );
+            }
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id,
             } => {
@@ -0,0 +1,634 @@
+//! Discovery of the genesis verification key.
+//!
+//! Instead of being given explicitly, the genesis verification key can be fetched from a
+//! well-known URL that serves a [GenesisVerificationKeyDocument]. The document holds the key and
+//! a chain of signatures that must start from a pinned root verification key: each link of the
+//! chain holds a verification key signed by the key of the previous link (the root key for the
+//! first link), and the last link must hold the genesis verification key.
+//!
+//! The root verification keys of the networks operated by the Mithril team are pinned in this
+//! module, see [pinned_root_verification_key]. They are the genesis verification keys of these
+//! networks at the time of the release: until a rotation the served document holds the root key
+//! itself with an empty signature chain, after a rotation the new genesis verification key must be
+//! endorsed by a chain that starts from the pinned key.
+//!
+//! Once verified, the document is cached locally so that next discoveries do not need to reach
+//! the URL. The cached document is verified again against the root key each time it is read, and
+//! it expires after a while so that a rotation of the genesis verification key is eventually
+//! picked up.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::genesis_verification_key_discovery::GenesisVerificationKeyDiscoverer;
+//! use mithril_client::ClientBuilder;
+//! use std::path::Path;
+//!
+//! let genesis_verification_key = GenesisVerificationKeyDiscoverer::for_network(
+//!     "https://example.com/genesis.json",
+//!     "mainnet",
+//! )?
+//! .with_cache_file(Path::new("/tmp/mithril/genesis.json"))
+//! .discover()
+//! .await?;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", &genesis_verification_key)
+//!     .build()?;
+//! #    Ok(())
+//! # }
+//! ```
+
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use slog::{debug, info, o, warn, Logger};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use mithril_common::crypto_helper::{ProtocolGenesisSignature, ProtocolGenesisVerificationKey};
+
+use crate::MithrilResult;
+
+/// Root verification keys pinned for the networks operated by the Mithril team, by network name
+const PINNED_ROOT_VERIFICATION_KEYS: [(&str, &str); 3] = [
+    (
+        "mainnet",
+        "5b3139312c36362c3134302c3138352c3133382c31312c3233372c3230372c3235302c3134342c32372c322c3138382c33302c31322c38312c3135352c3230342c31302c3137392c37352c32332c3133382c3139362c3231372c352c31342c32302c35372c37392c33392c3137365d",
+    ),
+    (
+        "preprod",
+        "5b3132372c37332c3132342c3136312c362c3133372c3133312c3231332c3230372c3131372c3139382c38352c3137362c3139392c3136322c3234312c36382c3132332c3131392c3134352c31332c3233322c3234332c34392c3232392c322c3234392c3230352c3230352c33392c3233352c34345d",
+    ),
+    (
+        "preview",
+        "5b3132372c37332c3132342c3136312c362c3133372c3133312c3231332c3230372c3131372c3139382c38352c3137362c3139392c3136322c3234312c36382c3132332c3131392c3134352c31332c3233322c3234332c34392c3232392c322c3234392c3230352c3230352c33392c3233352c34345d",
+    ),
+];
+
+/// Return the root verification key pinned for the given network, if any.
+pub fn pinned_root_verification_key(network: &str) -> Option<&'static str> {
+    PINNED_ROOT_VERIFICATION_KEYS
+        .iter()
+        .find(|(name, _)| *name == network)
+        .map(|(_, key)| *key)
+}
+
+/// A link of the signature chain of a [GenesisVerificationKeyDocument]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenesisVerificationKeySignatureLink {
+    /// Verification key signed by the key of the previous link, encoded like a genesis
+    /// verification key
+    pub verification_key: String,
+
+    /// Signature of the verification key, as published in this link, by the key of the
+    /// previous link (hex encoded bytes)
+    pub signature: String,
+}
+
+/// Document served at the discovery URL of a genesis verification key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenesisVerificationKeyDocument {
+    /// The genesis verification key
+    pub genesis_verification_key: String,
+
+    /// Chain of signatures from the root verification key to the genesis verification key
+    pub signature_chain: Vec<GenesisVerificationKeySignatureLink>,
+}
+
+impl GenesisVerificationKeyDocument {
+    /// Check that the signature chain starts from the given root verification key and ends with
+    /// the genesis verification key.
+    ///
+    /// An empty signature chain is only valid if the genesis verification key is the root key.
+    pub fn verify(&self, root_verification_key: &str) -> MithrilResult<()> {
+        if self.signature_chain.is_empty() {
+            if self.genesis_verification_key == root_verification_key {
+                return Ok(());
+            }
+
+            return Err(anyhow!(
+                "The genesis verification key document has an empty signature chain"
+            ));
+        }
+
+        let mut signer_key = ProtocolGenesisVerificationKey::from_json_hex(root_verification_key)
+            .with_context(|| "Could not decode the root verification key")?;
+        for (index, link) in self.signature_chain.iter().enumerate() {
+            let signature = ProtocolGenesisSignature::from_bytes_hex(&link.signature)
+                .with_context(|| format!("Could not decode the signature of link #{index}"))?;
+            signer_key
+                .verify(link.verification_key.as_bytes(), &signature)
+                .with_context(|| format!("Invalid signature for link #{index}"))?;
+            signer_key = ProtocolGenesisVerificationKey::from_json_hex(&link.verification_key)
+                .with_context(|| {
+                    format!("Could not decode the verification key of link #{index}")
+                })?;
+        }
+
+        // Safe to unwrap since the chain is not empty
+        let last_link = self.signature_chain.last().unwrap();
+        if last_link.verification_key != self.genesis_verification_key {
+            return Err(anyhow!(
+                "The signature chain does not end with the genesis verification key"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Verified [GenesisVerificationKeyDocument] stored in the cache file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedGenesisVerificationKeyDocument {
+    url: String,
+    document: GenesisVerificationKeyDocument,
+    expires_at: DateTime<Utc>,
+}
+
+/// Fetches a genesis verification key from a well-known URL and verifies it against a pinned
+/// root verification key.
+pub struct GenesisVerificationKeyDiscoverer {
+    url: String,
+    root_verification_key: String,
+    cache_file: Option<PathBuf>,
+    cache_ttl: Duration,
+    http_client: reqwest::Client,
+    logger: Logger,
+}
+
+impl GenesisVerificationKeyDiscoverer {
+    /// Default time during which a cached genesis verification key is used without checking
+    /// the discovery URL for a rotation.
+    pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Constructs a new `GenesisVerificationKeyDiscoverer` that verifies the discovered key
+    /// against the root verification key pinned for the given network.
+    pub fn for_network(url: &str, network: &str) -> MithrilResult<Self> {
+        let root_verification_key = pinned_root_verification_key(network)
+            .ok_or_else(|| anyhow!("No root verification key is pinned for network '{network}'"))?;
+
+        Self::new(url, root_verification_key)
+    }
+
+    /// Constructs a new `GenesisVerificationKeyDiscoverer`.
+    pub fn new(url: &str, root_verification_key: &str) -> MithrilResult<Self> {
+        let http_client = reqwest::ClientBuilder::new().build().with_context(|| {
+            "Building http client for genesis verification key discovery failed"
+        })?;
+
+        Ok(Self {
+            url: url.to_string(),
+            root_verification_key: root_verification_key.to_string(),
+            cache_file: None,
+            cache_ttl: Self::DEFAULT_CACHE_TTL,
+            http_client,
+            logger: Logger::root(slog::Discard, o!()),
+        })
+    }
+
+    /// Cache the verified document in the given file.
+    pub fn with_cache_file(mut self, cache_file: &Path) -> Self {
+        self.cache_file = Some(cache_file.to_path_buf());
+        self
+    }
+
+    /// Set the time during which a cached key is used without checking the discovery URL.
+    pub fn with_cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = cache_ttl;
+        self
+    }
+
+    /// Set the [Logger] to use.
+    pub fn with_logger(mut self, logger: Logger) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    /// Return the genesis verification key, from the cache if it holds a valid document that has
+    /// not expired, else from the discovery URL.
+    pub async fn discover(&self) -> MithrilResult<String> {
+        let cached_document = self.read_cache();
+        match cached_document {
+            Some(cached) if cached.expires_at > Utc::now() => {
+                Ok(cached.document.genesis_verification_key)
+            }
+            _ => {
+                self.refresh(cached_document.map(|cached| cached.document))
+                    .await
+            }
+        }
+    }
+
+    async fn refresh(
+        &self,
+        previous_document: Option<GenesisVerificationKeyDocument>,
+    ) -> MithrilResult<String> {
+        let document = self.fetch().await?;
+        document
+            .verify(&self.root_verification_key)
+            .with_context(|| {
+                format!(
+                    "Genesis verification key fetched from '{}' could not be verified",
+                    self.url
+                )
+            })?;
+        if previous_document.is_some_and(|previous| {
+            previous.genesis_verification_key != document.genesis_verification_key
+        }) {
+            info!(self.logger, "Genesis verification key has been rotated"; "url" => &self.url);
+        }
+        self.write_cache(&document);
+
+        Ok(document.genesis_verification_key)
+    }
+
+    async fn fetch(&self) -> MithrilResult<GenesisVerificationKeyDocument> {
+        debug!(self.logger, "Fetching genesis verification key"; "url" => &self.url);
+        let response = self
+            .http_client
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| {
+                format!(
+                    "Could not fetch the genesis verification key from '{}'",
+                    self.url
+                )
+            })?;
+
+        response.json().await.with_context(|| {
+            format!(
+                "Invalid genesis verification key document served at '{}'",
+                self.url
+            )
+        })
+    }
+
+    fn read_cache(&self) -> Option<CachedGenesisVerificationKeyDocument> {
+        let cache_file = self.cache_file.as_ref().filter(|file| file.exists())?;
+        let cached = std::fs::read(cache_file)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| {
+                serde_json::from_slice::<CachedGenesisVerificationKeyDocument>(&content)
+                    .map_err(anyhow::Error::from)
+            })
+            .and_then(|cached| {
+                if cached.url != self.url {
+                    return Err(anyhow!("Cached document was discovered at another url"));
+                }
+                cached.document.verify(&self.root_verification_key)?;
+                Ok(cached)
+            });
+
+        match cached {
+            Ok(cached) => {
+                debug!(self.logger, "Genesis verification key read from cache"; "cache_file" => cache_file.display(), "expires_at" => %cached.expires_at);
+                Some(cached)
+            }
+            Err(error) => {
+                warn!(self.logger, "Ignoring invalid genesis verification key cache"; "cache_file" => cache_file.display(), "error" => ?error);
+                None
+            }
+        }
+    }
+
+    fn write_cache(&self, document: &GenesisVerificationKeyDocument) {
+        if let Some(cache_file) = &self.cache_file {
+            let result = chrono::Duration::from_std(self.cache_ttl)
+                .map_err(anyhow::Error::from)
+                .map(|cache_ttl| CachedGenesisVerificationKeyDocument {
+                    url: self.url.clone(),
+                    document: document.clone(),
+                    expires_at: Utc::now() + cache_ttl,
+                })
+                .and_then(|cached| {
+                    if let Some(parent) = cache_file.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    Ok(std::fs::write(cache_file, serde_json::to_vec(&cached)?)?)
+                });
+
+            if let Err(error) = result {
+                warn!(self.logger, "Could not cache the genesis verification key"; "cache_file" => cache_file.display(), "error" => ?error);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::MockServer;
+    use mithril_common::crypto_helper::ProtocolGenesisSigner;
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    fn key_of(signer: &ProtocolGenesisSigner) -> String {
+        signer
+            .create_genesis_verifier()
+            .to_verification_key()
+            .to_json_hex()
+            .unwrap()
+    }
+
+    fn link(
+        signer: &ProtocolGenesisSigner,
+        verification_key: &str,
+    ) -> GenesisVerificationKeySignatureLink {
+        GenesisVerificationKeySignatureLink {
+            verification_key: verification_key.to_string(),
+            signature: signer.sign(verification_key.as_bytes()).to_bytes_hex(),
+        }
+    }
+
+    struct Keys {
+        root: ProtocolGenesisSigner,
+        intermediate: ProtocolGenesisSigner,
+        genesis: ProtocolGenesisSigner,
+    }
+
+    impl Keys {
+        fn new() -> Self {
+            Self {
+                root: ProtocolGenesisSigner::create_non_deterministic_genesis_signer(),
+                intermediate: ProtocolGenesisSigner::create_non_deterministic_genesis_signer(),
+                genesis: ProtocolGenesisSigner::create_deterministic_genesis_signer(),
+            }
+        }
+
+        fn document(&self) -> GenesisVerificationKeyDocument {
+            GenesisVerificationKeyDocument {
+                genesis_verification_key: key_of(&self.genesis),
+                signature_chain: vec![
+                    link(&self.root, &key_of(&self.intermediate)),
+                    link(&self.intermediate, &key_of(&self.genesis)),
+                ],
+            }
+        }
+    }
+
+    fn write_cache(
+        cache_file: &Path,
+        url: &str,
+        document: GenesisVerificationKeyDocument,
+        expires_at: DateTime<Utc>,
+    ) {
+        let cached = CachedGenesisVerificationKeyDocument {
+            url: url.to_string(),
+            document,
+            expires_at,
+        };
+        std::fs::write(cache_file, serde_json::to_vec(&cached).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn verify_valid_signature_chain() {
+        let keys = Keys::new();
+
+        keys.document().verify(&key_of(&keys.root)).unwrap();
+    }
+
+    #[test]
+    fn verify_fails_with_another_root_key() {
+        let keys = Keys::new();
+        let other_root = ProtocolGenesisSigner::create_non_deterministic_genesis_signer();
+
+        keys.document()
+            .verify(&key_of(&other_root))
+            .expect_err("Verification should fail with another root key");
+    }
+
+    #[test]
+    fn verify_fails_if_chain_does_not_end_with_genesis_key() {
+        let keys = Keys::new();
+        let document = GenesisVerificationKeyDocument {
+            signature_chain: vec![link(&keys.root, &key_of(&keys.intermediate))],
+            ..keys.document()
+        };
+
+        document
+            .verify(&key_of(&keys.root))
+            .expect_err("Verification should fail if the chain does not end with the genesis key");
+    }
+
+    #[test]
+    fn verify_fails_if_a_link_is_signed_by_another_key() {
+        let keys = Keys::new();
+        let document = GenesisVerificationKeyDocument {
+            signature_chain: vec![
+                link(&keys.root, &key_of(&keys.intermediate)),
+                link(&keys.root, &key_of(&keys.genesis)),
+            ],
+            ..keys.document()
+        };
+
+        document
+            .verify(&key_of(&keys.root))
+            .expect_err("Verification should fail if a link is not signed by the previous key");
+    }
+
+    #[test]
+    fn verify_fails_with_empty_chain() {
+        let keys = Keys::new();
+        let document = GenesisVerificationKeyDocument {
+            signature_chain: vec![],
+            ..keys.document()
+        };
+
+        document
+            .verify(&key_of(&keys.root))
+            .expect_err("Verification should fail with an empty signature chain");
+    }
+
+    #[test]
+    fn verify_empty_chain_if_genesis_key_is_the_root_key() {
+        let keys = Keys::new();
+        let document = GenesisVerificationKeyDocument {
+            genesis_verification_key: key_of(&keys.root),
+            signature_chain: vec![],
+        };
+
+        document.verify(&key_of(&keys.root)).unwrap();
+    }
+
+    #[test]
+    fn pinned_root_verification_keys_are_valid_verification_keys() {
+        for network in ["mainnet", "preprod", "preview"] {
+            let root_verification_key = pinned_root_verification_key(network)
+                .unwrap_or_else(|| panic!("A root key should be pinned for network '{network}'"));
+
+            ProtocolGenesisVerificationKey::from_json_hex(root_verification_key).unwrap();
+        }
+    }
+
+    #[test]
+    fn discoverer_for_a_network_without_pinned_root_key_fails() {
+        let result =
+            GenesisVerificationKeyDiscoverer::for_network("http://localhost/genesis.json", "unknown");
+
+        assert!(
+            result.is_err(),
+            "Building a discoverer for an unknown network should fail"
+        );
+    }
+
+    #[tokio::test]
+    async fn discover_fetches_verifies_and_caches_the_key() {
+        let keys = Keys::new();
+        let cache_file = TempDir::create(
+            "genesis_verification_key_discovery",
+            "discover_fetches_verifies_and_caches_the_key",
+        )
+        .join("cache")
+        .join("genesis.json");
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path("/genesis.json");
+            then.status(200).json_body_obj(&keys.document());
+        });
+
+        let genesis_verification_key = GenesisVerificationKeyDiscoverer::new(
+            &server.url("/genesis.json"),
+            &key_of(&keys.root),
+        )
+        .unwrap()
+        .with_cache_file(&cache_file)
+        .discover()
+        .await
+        .unwrap();
+
+        assert_eq!(key_of(&keys.genesis), genesis_verification_key);
+        mock.assert();
+        let cached: CachedGenesisVerificationKeyDocument =
+            serde_json::from_slice(&std::fs::read(&cache_file).unwrap()).unwrap();
+        assert_eq!(keys.document(), cached.document);
+        assert_eq!(server.url("/genesis.json"), cached.url);
+        assert!(cached.expires_at > Utc::now());
+    }
+
+    #[tokio::test]
+    async fn discover_uses_a_valid_cache_without_fetching() {
+        let keys = Keys::new();
+        let cache_file = TempDir::create(
+            "genesis_verification_key_discovery",
+            "discover_uses_a_valid_cache_without_fetching",
+        )
+        .join("genesis.json");
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path("/genesis.json");
+            then.status(500);
+        });
+        write_cache(
+            &cache_file,
+            &server.url("/genesis.json"),
+            keys.document(),
+            Utc::now() + chrono::Duration::hours(1),
+        );
+
+        let genesis_verification_key = GenesisVerificationKeyDiscoverer::new(
+            &server.url("/genesis.json"),
+            &key_of(&keys.root),
+        )
+        .unwrap()
+        .with_cache_file(&cache_file)
+        .discover()
+        .await
+        .unwrap();
+
+        assert_eq!(key_of(&keys.genesis), genesis_verification_key);
+        mock.assert_hits(0);
+    }
+
+    #[tokio::test]
+    async fn discover_fetches_again_if_cache_does_not_match_root_key() {
+        let keys = Keys::new();
+        let previous_keys = Keys::new();
+        let cache_file = TempDir::create(
+            "genesis_verification_key_discovery",
+            "discover_fetches_again_if_cache_does_not_match_root_key",
+        )
+        .join("genesis.json");
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path("/genesis.json");
+            then.status(200).json_body_obj(&keys.document());
+        });
+        write_cache(
+            &cache_file,
+            &server.url("/genesis.json"),
+            previous_keys.document(),
+            Utc::now() + chrono::Duration::hours(1),
+        );
+
+        GenesisVerificationKeyDiscoverer::new(&server.url("/genesis.json"), &key_of(&keys.root))
+            .unwrap()
+            .with_cache_file(&cache_file)
+            .discover()
+            .await
+            .unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn discover_fetches_the_rotated_key_once_the_cache_has_expired() {
+        let keys = Keys::new();
+        let rotated_genesis = ProtocolGenesisSigner::create_non_deterministic_genesis_signer();
+        let rotated_document = GenesisVerificationKeyDocument {
+            genesis_verification_key: key_of(&rotated_genesis),
+            signature_chain: vec![
+                link(&keys.root, &key_of(&keys.intermediate)),
+                link(&keys.intermediate, &key_of(&rotated_genesis)),
+            ],
+        };
+        let cache_file = TempDir::create(
+            "genesis_verification_key_discovery",
+            "discover_fetches_the_rotated_key_once_the_cache_has_expired",
+        )
+        .join("genesis.json");
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path("/genesis.json");
+            then.status(200).json_body_obj(&rotated_document);
+        });
+        write_cache(
+            &cache_file,
+            &server.url("/genesis.json"),
+            keys.document(),
+            Utc::now() - chrono::Duration::seconds(1),
+        );
+
+        let genesis_verification_key = GenesisVerificationKeyDiscoverer::new(
+            &server.url("/genesis.json"),
+            &key_of(&keys.root),
+        )
+        .unwrap()
+        .with_cache_file(&cache_file)
+        .discover()
+        .await
+        .unwrap();
+
+        assert_eq!(key_of(&rotated_genesis), genesis_verification_key);
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn discover_fails_if_fetched_document_is_not_signed_by_root_key() {
+        let keys = Keys::new();
+        let other_root = ProtocolGenesisSigner::create_non_deterministic_genesis_signer();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.path("/genesis.json");
+            then.status(200).json_body_obj(&keys.document());
+        });
+
+        GenesisVerificationKeyDiscoverer::new(&server.url("/genesis.json"), &key_of(&other_root))
+            .unwrap()
+            .discover()
+            .await
+            .expect_err("Discovery should fail if the document is not signed by the root key");
+    }
+}
@@ -11,9 +11,14 @@
 //! - [Cardano transactions][cardano_transaction_client] list & get snapshot, get proofs
 //! _(available using crate feature_ **unstable**_)_.
 //! - [Certificates][certificate_client] list, get, and chain validation.
+//! - [Signers][signer_client] registrations list and get.
 //!
 //! The [Client] aggregates the queries of all of those types.
 //!
+//! The [prelude] module re-exports the types and traits needed by most integrators, including
+//! the `mithril-common` entities used by the certified types, so that they can be imported at
+//! once with `use mithril_client::prelude::*;`.
+//!
 //! **NOTE:** Snapshot download and Certificate chain validation can take quite some time even with a fast
 //! computer and network.
 //! For those a feedback mechanism is available, more details on it in the [feedback] submodule.
@@ -88,12 +93,21 @@ cfg_unstable! {
 }
 pub mod certificate_client;
 mod client;
+cfg_fs! {
+    pub mod dns_resolver;
+}
 pub mod feedback;
+cfg_fs! {
+    pub mod genesis_verification_key_discovery;
+}
 mod message;
 pub mod mithril_stake_distribution_client;
+pub mod prelude;
+pub mod signer_client;
 pub mod snapshot_client;
 cfg_fs! {
     pub mod snapshot_downloader;
+    pub mod snapshot_entries_filter;
 }
 
 mod type_alias;
@@ -2,7 +2,10 @@ use anyhow::Context;
 use mithril_common::protocol::SignerBuilder;
 #[cfg(feature = "fs")]
 use mithril_common::{
-    digesters::{CardanoImmutableDigester, ImmutableDigester},
+    digesters::{
+        CardanoImmutableDigester, ImmutableDigester, ImmutableDigesterIoConfig,
+        ImmutableDigesterProgressReporter,
+    },
     entities::SignedEntityType,
 };
 use slog::{o, Logger};
@@ -10,8 +13,12 @@ use slog::{o, Logger};
 use std::path::Path;
 #[cfg(feature = "fs")]
 use std::sync::Arc;
+#[cfg(feature = "fs")]
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
 
 use crate::common::{ProtocolMessage, ProtocolMessagePartKey};
+#[cfg(feature = "fs")]
+use crate::feedback::{FeedbackReceiver, FeedbackSender, MithrilEvent};
 #[cfg(any(feature = "fs", feature = "unstable"))]
 use crate::MithrilCertificate;
 #[cfg(feature = "unstable")]
@@ -22,9 +29,28 @@ use crate::{MithrilResult, MithrilSigner, MithrilStakeDistribution};
 pub struct MessageBuilder {
     #[cfg(feature = "fs")]
     immutable_digester: Option<Arc<dyn ImmutableDigester>>,
+    #[cfg(feature = "fs")]
+    max_digest_threads: usize,
+    #[cfg(feature = "fs")]
+    feedback_receivers: Vec<Arc<dyn FeedbackReceiver>>,
     logger: Logger,
 }
 
+/// [ImmutableDigesterProgressReporter] that forwards the hashing progress to a channel, since
+/// the digester reports from a blocking thread.
+#[cfg(feature = "fs")]
+struct ChannelDigesterProgressReporter {
+    sender: UnboundedSender<(usize, usize)>,
+}
+
+#[cfg(feature = "fs")]
+impl ImmutableDigesterProgressReporter for ChannelDigesterProgressReporter {
+    fn report(&self, hashed_files: usize, total_files: usize) {
+        // The receiver is only dropped once the digest is computed
+        let _ = self.sender.send((hashed_files, total_files));
+    }
+}
+
 impl MessageBuilder {
     /// Constructs a new `MessageBuilder`.
     pub fn new() -> MessageBuilder {
@@ -32,6 +58,10 @@ impl MessageBuilder {
         Self {
             #[cfg(feature = "fs")]
             immutable_digester: None,
+            #[cfg(feature = "fs")]
+            max_digest_threads: ImmutableDigesterIoConfig::default().io_concurrency,
+            #[cfg(feature = "fs")]
+            feedback_receivers: vec![],
             logger,
         }
     }
@@ -43,13 +73,43 @@ impl MessageBuilder {
     }
 
     cfg_fs! {
-        fn get_immutable_digester(&self) -> Arc<dyn ImmutableDigester> {
+        fn get_immutable_digester(
+            &self,
+            progress_sender: UnboundedSender<(usize, usize)>,
+        ) -> Arc<dyn ImmutableDigester> {
             match self.immutable_digester.as_ref() {
-                None => Arc::new(CardanoImmutableDigester::new(None, self.logger.clone())),
+                None => Arc::new(
+                    CardanoImmutableDigester::new(None, self.logger.clone())
+                        .with_io_config(ImmutableDigesterIoConfig {
+                            io_concurrency: self.max_digest_threads,
+                            ..ImmutableDigesterIoConfig::default()
+                        })
+                        .with_progress_reporter(Arc::new(ChannelDigesterProgressReporter {
+                            sender: progress_sender,
+                        })),
+                ),
                 Some(digester) => digester.clone(),
             }
         }
 
+        /// Set the maximum number of threads used to hash the immutable files when computing
+        /// the message of a snapshot, default to 1.
+        ///
+        /// Ignored if a custom [ImmutableDigester] is set.
+        pub fn with_max_digest_threads(mut self, max_digest_threads: usize) -> Self {
+            self.max_digest_threads = max_digest_threads.max(1);
+            self
+        }
+
+        /// Add a [feedback receiver][FeedbackReceiver] to receive the progress of the
+        /// snapshot digest computation.
+        ///
+        /// The progress is only reported if no custom [ImmutableDigester] is set.
+        pub fn add_feedback_receiver(mut self, receiver: Arc<dyn FeedbackReceiver>) -> Self {
+            self.feedback_receivers.push(receiver);
+            self
+        }
+
         /// Set the [ImmutableDigester] to be used for the message computation for snapshot.
         ///
         /// If not set a default implementation will be used.
@@ -69,7 +129,8 @@ impl MessageBuilder {
             snapshot_certificate: &MithrilCertificate,
             unpacked_snapshot_directory: &Path,
         ) -> MithrilResult<ProtocolMessage> {
-            let digester = self.get_immutable_digester();
+            let (progress_sender, mut progress_receiver) = unbounded_channel();
+            let digester = self.get_immutable_digester(progress_sender);
             let beacon =
                 match &snapshot_certificate.signed_entity_type {
                 SignedEntityType::CardanoImmutableFilesFull(beacon) => {Ok(beacon)},
@@ -84,15 +145,53 @@ impl MessageBuilder {
 
             let mut message = snapshot_certificate.protocol_message.clone();
 
-            let digest = digester
-                .compute_digest(unpacked_snapshot_directory, beacon)
-                .await
+            let feedback_sender = FeedbackSender::new(&self.feedback_receivers);
+            let computation_id = MithrilEvent::new_snapshot_digest_computation_id();
+            feedback_sender
+                .send_event(MithrilEvent::SnapshotDigestComputationStarted {
+                    computation_id: computation_id.clone(),
+                })
+                .await;
+            let digest_computation = async move {
+                // The digester, and so the progress sender, is dropped once the digest is
+                // computed, ending the progress forwarding
+                let digest = digester
+                    .compute_digest(unpacked_snapshot_directory, beacon)
+                    .await;
+                drop(digester);
+                digest
+            };
+            let progress_forwarding = async {
+                // The progress is reported per immutable file, only forward it when its
+                // percentage changes to not flood the receivers
+                let mut last_forwarded_percent = None;
+                while let Some((hashed_files, total_files)) = progress_receiver.recv().await {
+                    let percent = hashed_files * 100 / total_files.max(1);
+                    if last_forwarded_percent == Some(percent) {
+                        continue;
+                    }
+                    last_forwarded_percent = Some(percent);
+
+                    feedback_sender
+                        .send_event(MithrilEvent::SnapshotDigestComputationProgress {
+                            computation_id: computation_id.clone(),
+                            hashed_files: hashed_files as u64,
+                            total_files: total_files as u64,
+                        })
+                        .await;
+                }
+            };
+            let (digest, _) = futures::join!(digest_computation, progress_forwarding);
+            let digest = digest
                 .with_context(|| {
                     format!(
                         "Snapshot digest computation failed: unpacked_dir: '{}'",
                         unpacked_snapshot_directory.display()
                     )
                 })?;
+            feedback_sender
+                .send_event(MithrilEvent::SnapshotDigestComputationCompleted { computation_id })
+                .await;
             message.set_message_part(ProtocolMessagePartKey::SnapshotDigest, digest);
 
             Ok(message)
@@ -146,3 +245,92 @@ impl Default for MessageBuilder {
         Self::new()
     }
 }
+
+#[cfg(all(test, feature = "fs"))]
+mod tests {
+    use mithril_common::digesters::DummyImmutablesDbBuilder;
+    use mithril_common::entities::CardanoDbBeacon;
+
+    use crate::feedback::StackFeedbackReceiver;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn compute_snapshot_message_report_digest_computation_progress() {
+        let immutable_db =
+            DummyImmutablesDbBuilder::new("compute_snapshot_message_report_progress")
+                .with_immutables(&[1, 2])
+                .append_immutable_trio()
+                .build();
+        let certificate =
+            MithrilCertificate {
+                signed_entity_type: SignedEntityType::CardanoImmutableFilesFull(
+                    CardanoDbBeacon::new("devnet".to_string(), 1, 2),
+                ),
+                ..MithrilCertificate::dummy()
+            };
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+
+        MessageBuilder::new()
+            .with_max_digest_threads(2)
+            .add_feedback_receiver(feedback_receiver.clone())
+            .compute_snapshot_message(&certificate, &immutable_db.dir)
+            .await
+            .expect("Computing snapshot message should not fail");
+
+        let events = feedback_receiver.stacked_events();
+        let computation_id = events[0].event_id().to_string();
+        let mut expected_events = vec![MithrilEvent::SnapshotDigestComputationStarted {
+            computation_id: computation_id.clone(),
+        }];
+        expected_events.extend((1..=6).map(|hashed_files| {
+            MithrilEvent::SnapshotDigestComputationProgress {
+                computation_id: computation_id.clone(),
+                hashed_files,
+                total_files: 6,
+            }
+        }));
+        expected_events.push(MithrilEvent::SnapshotDigestComputationCompleted { computation_id });
+        assert_eq!(expected_events, events);
+    }
+
+    #[tokio::test]
+    async fn compute_snapshot_message_report_digest_computation_progress_once_per_percent() {
+        let immutable_db = DummyImmutablesDbBuilder::new(
+            "compute_snapshot_message_report_progress_once_per_percent",
+        )
+        .with_immutables(&(1..=100).collect::<Vec<_>>())
+        .append_immutable_trio()
+        .build();
+        let certificate =
+            MithrilCertificate {
+                signed_entity_type: SignedEntityType::CardanoImmutableFilesFull(
+                    CardanoDbBeacon::new("devnet".to_string(), 1, 100),
+                ),
+                ..MithrilCertificate::dummy()
+            };
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+
+        MessageBuilder::new()
+            .add_feedback_receiver(feedback_receiver.clone())
+            .compute_snapshot_message(&certificate, &immutable_db.dir)
+            .await
+            .expect("Computing snapshot message should not fail");
+
+        let progress_events: Vec<(u64, u64)> = feedback_receiver
+            .stacked_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                MithrilEvent::SnapshotDigestComputationProgress {
+                    hashed_files,
+                    total_files,
+                    ..
+                } => Some((hashed_files, total_files)),
+                _ => None,
+            })
+            .collect();
+        // One event per percent, from 0% to 100%
+        assert_eq!(101, progress_events.len());
+        assert_eq!(Some(&(300, 300)), progress_events.last());
+    }
+}
@@ -0,0 +1,41 @@
+//! Re-exports of the types and traits needed by most integrators of the Mithril client.
+//!
+//! Glob importing this module is enough to list, download and verify the artifacts certified
+//! by a Mithril aggregator, without depending on `mithril-common` directly:
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::prelude::*;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//!
+//! let snapshots: Vec<SnapshotListItem> = client.snapshot().list().await?;
+//! let beacon: &CardanoDbBeacon = &snapshots.first().unwrap().beacon;
+//! let certificate: MithrilCertificate = client
+//!     .certificate()
+//!     .verify_chain(&snapshots.first().unwrap().certificate_hash)
+//!     .await?;
+//! #    Ok(())
+//! # }
+//! ```
+
+pub use crate::certificate_client::CertificateVerifier;
+pub use crate::common::{
+    CardanoDbBeacon, CompressionAlgorithm, Epoch, ImmutableFileNumber, PartyId, ProtocolMessage,
+    ProtocolMessagePartKey, ProtocolParameters, SignedEntityType, SignedEntityTypeDiscriminants,
+    Stake,
+};
+pub use crate::feedback::{FeedbackReceiver, MithrilEvent};
+pub use crate::{
+    Client, ClientBuilder, MessageBuilder, MithrilCertificate, MithrilCertificateListItem,
+    MithrilError, MithrilResult, MithrilSigner, MithrilStakeDistribution,
+    MithrilStakeDistributionListItem, SignerRegistration, SignerRegistrations, Snapshot,
+    SnapshotListItem,
+};
+
+cfg_unstable! {
+    pub use crate::{
+        CardanoTransactionSnapshot, CardanoTransactionSnapshotListItem, CardanoTransactionsProofs,
+        VerifiedCardanoTransactions,
+    };
+}
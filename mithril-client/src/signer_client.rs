@@ -0,0 +1,204 @@
+//! A client to retrieve the signers registered to an Aggregator.
+//!
+//! In order to do so it defines a [SignerClient] which exposes the following features:
+//!  - [get_current_epoch][SignerClient::get_current_epoch]: get the current epoch of the aggregator
+//!  - [list_registered][SignerClient::list_registered]: get the registrations of the signers sent at an epoch
+//!  - [get_registered][SignerClient::get_registered]: get the registration of a signer sent at an epoch
+//!
+//! # List the signers registered at the current epoch
+//!
+//! To list the signers registered at the current epoch using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let epoch = client.signer().get_current_epoch().await?;
+//! let signer_registrations = client.signer().list_registered(epoch).await?;
+//!
+//! for registration in signer_registrations.registrations {
+//!     println!("Signer party_id={}, stake={}, signing at epoch={}", registration.party_id, registration.stake, signer_registrations.signing_at);
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use anyhow::Context;
+use mithril_common::messages::EpochSettingsMessage;
+
+use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::common::{Epoch, PartyId};
+use crate::{MithrilResult, SignerRegistration, SignerRegistrations};
+
+/// HTTP client for the signers API from the Aggregator
+pub struct SignerClient {
+    aggregator_client: Arc<dyn AggregatorClient>,
+}
+
+impl SignerClient {
+    /// Constructs a new `SignerClient`.
+    pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
+        Self { aggregator_client }
+    }
+
+    /// Get the current epoch of the aggregator.
+    pub async fn get_current_epoch(&self) -> MithrilResult<Epoch> {
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::GetEpochSettings)
+            .await
+            .with_context(|| "Signer Client can not get the epoch settings")?;
+        let epoch_settings = serde_json::from_str::<EpochSettingsMessage>(&response)
+            .with_context(|| "Signer Client can not deserialize the epoch settings")?;
+
+        Ok(epoch_settings.epoch)
+    }
+
+    /// Get the registrations of the signers sent at the given epoch.
+    ///
+    /// If no signer registered at this epoch, the returned registrations are empty.
+    pub async fn list_registered(&self, epoch: Epoch) -> MithrilResult<SignerRegistrations> {
+        match self
+            .aggregator_client
+            .get_content(AggregatorRequest::ListRegisteredSigners { epoch })
+            .await
+        {
+            Ok(content) => {
+                let signer_registrations: SignerRegistrations = serde_json::from_str(&content)
+                    .with_context(|| {
+                        "Signer Client can not deserialize the signer registrations"
+                    })?;
+
+                Ok(signer_registrations)
+            }
+            Err(AggregatorClientError::RemoteServerLogical(_)) => Ok(SignerRegistrations {
+                registered_at: epoch,
+                signing_at: epoch.offset_to_signer_signing_offset(),
+                registrations: vec![],
+            }),
+            Err(e) => Err(e).with_context(|| {
+                format!("Signer Client can not get the signers registered at epoch {epoch}")
+            }),
+        }
+    }
+
+    /// Get the registration of the given signer sent at the given epoch. If it cannot be found,
+    /// a None is returned.
+    pub async fn get_registered(
+        &self,
+        epoch: Epoch,
+        party_id: &PartyId,
+    ) -> MithrilResult<Option<SignerRegistration>> {
+        let signer_registrations = self.list_registered(epoch).await?;
+
+        Ok(signer_registrations
+            .registrations
+            .into_iter()
+            .find(|registration| &registration.party_id == party_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::aggregator_client::MockAggregatorHTTPClient;
+
+    use super::*;
+
+    fn fake_signer_registrations(epoch: Epoch) -> SignerRegistrations {
+        SignerRegistrations::new(
+            epoch,
+            vec![
+                SignerRegistration {
+                    party_id: "pool1".to_string(),
+                    stake: 100,
+                    pool_ticker: Some("[POOL1]".to_string()),
+                    registration_time: None,
+                },
+                SignerRegistration {
+                    party_id: "pool2".to_string(),
+                    stake: 200,
+                    pool_ticker: None,
+                    registration_time: None,
+                },
+            ],
+        )
+    }
+
+    #[tokio::test]
+    async fn get_current_epoch_from_epoch_settings() {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .withf(|request| *request == AggregatorRequest::GetEpochSettings)
+            .return_once(|_| Ok(serde_json::to_string(&EpochSettingsMessage::dummy()).unwrap()));
+        let client = SignerClient::new(Arc::new(http_client));
+
+        let epoch = client.get_current_epoch().await.unwrap();
+
+        assert_eq!(EpochSettingsMessage::dummy().epoch, epoch);
+    }
+
+    #[tokio::test]
+    async fn list_signers_registered_at_epoch() {
+        let message = fake_signer_registrations(Epoch(12));
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .withf(|request| {
+                *request == AggregatorRequest::ListRegisteredSigners { epoch: Epoch(12) }
+            })
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = SignerClient::new(Arc::new(http_client));
+
+        let signer_registrations = client.list_registered(Epoch(12)).await.unwrap();
+
+        assert_eq!(fake_signer_registrations(Epoch(12)), signer_registrations);
+    }
+
+    #[tokio::test]
+    async fn list_signers_registered_at_epoch_without_registration_is_empty() {
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client.expect_get_content().return_once(move |_| {
+            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                "not found"
+            )))
+        });
+        let client = SignerClient::new(Arc::new(http_client));
+
+        let signer_registrations = client.list_registered(Epoch(12)).await.unwrap();
+
+        assert!(signer_registrations.registrations.is_empty());
+        assert_eq!(Epoch(12), signer_registrations.registered_at);
+    }
+
+    #[tokio::test]
+    async fn get_signer_registered_at_epoch() {
+        let message = fake_signer_registrations(Epoch(12));
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .times(2)
+            .returning(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = SignerClient::new(Arc::new(http_client));
+
+        let registration = client
+            .get_registered(Epoch(12), &"pool2".to_string())
+            .await
+            .unwrap();
+        let unknown_registration = client
+            .get_registered(Epoch(12), &"pool3".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some(fake_signer_registrations(Epoch(12)).registrations[1].clone()),
+            registration
+        );
+        assert_eq!(None, unknown_registration);
+    }
+}
@@ -4,6 +4,7 @@
 //!  - [get][SnapshotClient::get]: get a single snapshot data from its digest
 //!  - [list][SnapshotClient::list]: get the list of available snapshots
 //!  - [download_unpack][SnapshotClient::download_unpack]: download and unpack the tarball of a snapshot to a directory
+//!  - [download_unpack_selection][SnapshotClient::download_unpack_selection]: download the tarball of a snapshot and unpack only some of its files to a directory
 //!
 //! # Get a single snapshot
 //!
@@ -190,6 +191,25 @@ impl SnapshotClient {
             &self,
             snapshot: &Snapshot,
             target_dir: &std::path::Path,
+        ) -> MithrilResult<()> {
+            self.download_unpack_selection(
+                snapshot,
+                target_dir,
+                &crate::snapshot_entries_filter::SnapshotEntriesFilter::default(),
+            )
+            .await
+        }
+
+        /// Download the given snapshot and unpack only the entries selected by the given
+        /// filter to the given directory
+        ///
+        /// **NOTE**: The directory should already exist, and the user running the binary
+        /// must have read/write access to it.
+        pub async fn download_unpack_selection(
+            &self,
+            snapshot: &Snapshot,
+            target_dir: &std::path::Path,
+            entries_filter: &crate::snapshot_entries_filter::SnapshotEntriesFilter,
         ) -> MithrilResult<()> {
             use crate::feedback::MithrilEvent;
 
@@ -211,6 +231,8 @@ impl SnapshotClient {
                             snapshot.compression_algorithm.unwrap_or_default(),
                             &download_id,
                             snapshot.size,
+                            snapshot.chunk_checksums.clone(),
+                            entries_filter,
                         )
                         .await
                     {
@@ -272,7 +294,7 @@ mod tests_download {
         snapshot_downloader.expect_probe().returning(|_| Ok(()));
         snapshot_downloader
             .expect_download_unpack()
-            .returning(|_, _, _, _, _| Ok(()));
+            .returning(|_, _, _, _, _, _, _| Ok(()));
         let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
         let client = SnapshotClient::new(
             Arc::new(MockAggregatorHTTPClient::new()),
@@ -6,19 +6,29 @@
 //! Snapshots locations can be of various kinds, right now we only support HTTP
 //! download (using the [HttpSnapshotDownloader]) but other types may be added in
 //! the future.
+//!
+//! When the snapshot comes with [chunk checksums][crate::common::SnapshotChunkChecksums], each
+//! chunk of the archive is verified while it's downloaded: the download fails as soon as a chunk
+//! is corrupted and can't be fetched again, and only the corrupted chunks are fetched again.
 
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use futures::StreamExt;
-use reqwest::{Response, StatusCode};
-use slog::{debug, Logger};
+use reqwest::{header::RANGE, Response, StatusCode};
+use slog::{debug, warn, Logger};
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[cfg(test)]
 use mockall::automock;
 
-use crate::common::CompressionAlgorithm;
+use crate::common::{CompressionAlgorithm, SnapshotChunkChecksums};
+use crate::dns_resolver::{
+    DnsResolver, HttpClientDnsResolver, IpFamilyPreference, SystemDnsResolver,
+};
 use crate::feedback::{FeedbackSender, MithrilEvent};
+use crate::snapshot_entries_filter::SnapshotEntriesFilter;
 use crate::utils::SnapshotUnpacker;
 use crate::MithrilResult;
 
@@ -30,7 +40,12 @@ pub trait SnapshotDownloader: Sync + Send {
     /// The `download_id` is a unique identifier that allow
     /// [feedback receivers][crate::feedback::FeedbackReceiver] to track concurrent downloads.
     ///
+    /// If `chunk_checksums` are given, each chunk of the archive is verified before being unpacked.
+    ///
+    /// Only the entries of the archive selected by the `entries_filter` are unpacked.
+    ///
     /// Warning: this can be a quite long operation depending on the snapshot size.
+    #[allow(clippy::too_many_arguments)]
     async fn download_unpack(
         &self,
         location: &str,
@@ -38,12 +53,50 @@ pub trait SnapshotDownloader: Sync + Send {
         compression_algorithm: CompressionAlgorithm,
         download_id: &str,
         snapshot_size: u64,
+        chunk_checksums: Option<SnapshotChunkChecksums>,
+        entries_filter: &SnapshotEntriesFilter,
     ) -> MithrilResult<()>;
 
     /// Test if the given snapshot location exists.
     async fn probe(&self, location: &str) -> MithrilResult<()>;
 }
 
+/// Maximum number of attempts to fetch again a corrupted chunk of a snapshot archive
+const MAX_CHUNK_FETCH_ATTEMPTS: usize = 3;
+
+/// Maximum size of the chunks of a snapshot archive that are verified with a checksum, a whole
+/// chunk is kept in memory until it's verified
+const MAX_CHUNK_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Configuration of the network connections of the [HttpSnapshotDownloader].
+#[derive(Clone)]
+pub struct HttpSnapshotDownloaderConfig {
+    /// Resolver of the hosts of the snapshots locations
+    pub dns_resolver: Arc<dyn DnsResolver>,
+
+    /// Address family tried first when a host has both IPv6 and IPv4 addresses, the other
+    /// family is tried concurrently if the connection is not established after a short delay.
+    pub ip_family_preference: IpFamilyPreference,
+
+    /// Maximum duration of the resolution of a host
+    pub dns_resolution_timeout: Duration,
+
+    /// Maximum duration to establish a connection with a host, shared between all the
+    /// addresses of the host
+    pub connect_timeout: Duration,
+}
+
+impl Default for HttpSnapshotDownloaderConfig {
+    fn default() -> Self {
+        Self {
+            dns_resolver: Arc::new(SystemDnsResolver),
+            ip_family_preference: IpFamilyPreference::default(),
+            dns_resolution_timeout: Duration::from_secs(10),
+            connect_timeout: Duration::from_secs(20),
+        }
+    }
+}
+
 /// A snapshot downloader that only handles download through HTTP.
 pub struct HttpSnapshotDownloader {
     http_client: reqwest::Client,
@@ -52,9 +105,28 @@ pub struct HttpSnapshotDownloader {
 }
 
 impl HttpSnapshotDownloader {
-    /// Constructs a new `HttpSnapshotDownloader`.
+    /// Constructs a new `HttpSnapshotDownloader` with the default network configuration.
     pub fn new(feedback_sender: FeedbackSender, logger: Logger) -> MithrilResult<Self> {
+        Self::new_with_config(
+            HttpSnapshotDownloaderConfig::default(),
+            feedback_sender,
+            logger,
+        )
+    }
+
+    /// Constructs a new `HttpSnapshotDownloader` with the given network configuration.
+    pub fn new_with_config(
+        config: HttpSnapshotDownloaderConfig,
+        feedback_sender: FeedbackSender,
+        logger: Logger,
+    ) -> MithrilResult<Self> {
         let http_client = reqwest::ClientBuilder::new()
+            .dns_resolver(Arc::new(HttpClientDnsResolver::new(
+                config.dns_resolver,
+                config.ip_family_preference,
+                config.dns_resolution_timeout,
+            )))
+            .connect_timeout(config.connect_timeout)
             .build()
             .with_context(|| "Building http client for HttpSnapshotDownloader failed")?;
 
@@ -78,6 +150,93 @@ impl HttpSnapshotDownloader {
             status_code => Err(anyhow!("Unhandled error {status_code}")),
         }
     }
+
+    async fn get_range(&self, location: &str, start: u64, end: u64) -> MithrilResult<Vec<u8>> {
+        debug!(
+            self.logger,
+            "GET Snapshot location='{location}', range='{start}-{end}'."
+        );
+        let request_builder = self
+            .http_client
+            .get(location)
+            .header(RANGE, format!("bytes={start}-{end}"));
+        let response = request_builder.send().await.with_context(|| {
+            format!("Cannot perform a GET for the snapshot (location='{location}', range='{start}-{end}')")
+        })?;
+
+        match response.status() {
+            StatusCode::PARTIAL_CONTENT => Ok(response
+                .bytes()
+                .await
+                .with_context(|| "Download: Could not read the range response body")?
+                .to_vec()),
+            StatusCode::NOT_FOUND => Err(anyhow!("Location='{location} not found")),
+            status_code => Err(anyhow!(
+                "Unhandled status {status_code} for a range request (location='{location}')"
+            )),
+        }
+    }
+
+    /// Check that the chunk size of the given checksums can be used to verify the archive
+    fn check_chunk_size(chunk_checksums: &SnapshotChunkChecksums) -> MithrilResult<usize> {
+        if chunk_checksums.chunk_size == 0 || chunk_checksums.chunk_size > MAX_CHUNK_SIZE {
+            return Err(anyhow!(
+                "Download: invalid chunk size {} for the snapshot checksums, it must be between 1 and {MAX_CHUNK_SIZE} bytes",
+                chunk_checksums.chunk_size
+            ));
+        }
+
+        usize::try_from(chunk_checksums.chunk_size).with_context(|| {
+            format!(
+                "Download: chunk size {} does not fit in memory",
+                chunk_checksums.chunk_size
+            )
+        })
+    }
+
+    /// Verify a downloaded chunk, fetching it again if it's corrupted
+    async fn verify_chunk(
+        &self,
+        location: &str,
+        chunk_checksums: &SnapshotChunkChecksums,
+        chunk_index: usize,
+        chunk: Vec<u8>,
+        snapshot_size: u64,
+    ) -> MithrilResult<Vec<u8>> {
+        if chunk_index >= chunk_checksums.sha256.len() {
+            return Err(anyhow!(
+                "Download: the snapshot archive has more chunks than the {} published checksums",
+                chunk_checksums.sha256.len()
+            ));
+        }
+        if chunk_checksums.is_valid_chunk(chunk_index, &chunk) {
+            return Ok(chunk);
+        }
+
+        let (start, end) = chunk_checksums.chunk_range(chunk_index, snapshot_size);
+        for attempt in 1..=MAX_CHUNK_FETCH_ATTEMPTS {
+            warn!(
+                self.logger,
+                "Snapshot chunk #{chunk_index} is corrupted, fetching it again (attempt {attempt}/{MAX_CHUNK_FETCH_ATTEMPTS})."
+            );
+            match self.get_range(location, start, end).await {
+                Ok(chunk) if chunk_checksums.is_valid_chunk(chunk_index, &chunk) => {
+                    return Ok(chunk)
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    warn!(
+                        self.logger,
+                        "Fetching snapshot chunk #{chunk_index} failed: {error:?}"
+                    );
+                }
+            }
+        }
+
+        Err(anyhow!(
+            "Download: snapshot chunk #{chunk_index} (bytes {start}-{end}) is still corrupted after {MAX_CHUNK_FETCH_ATTEMPTS} attempts"
+        ))
+    }
 }
 
 #[cfg_attr(test, automock)]
@@ -90,6 +249,8 @@ impl SnapshotDownloader for HttpSnapshotDownloader {
         compression_algorithm: CompressionAlgorithm,
         download_id: &str,
         snapshot_size: u64,
+        chunk_checksums: Option<SnapshotChunkChecksums>,
+        entries_filter: &SnapshotEntriesFilter,
     ) -> MithrilResult<()> {
         if !target_dir.is_dir() {
             Err(
@@ -97,24 +258,56 @@ impl SnapshotDownloader for HttpSnapshotDownloader {
                     .context("Download-Unpack: prerequisite error"),
             )?;
         }
+        let chunk_size = chunk_checksums
+            .as_ref()
+            .map(Self::check_chunk_size)
+            .transpose()?;
         let mut downloaded_bytes: u64 = 0;
+        let mut pending_chunk: Vec<u8> = vec![];
+        let mut chunk_index = 0;
         let mut remote_stream = self.get(location).await?.bytes_stream();
         let (sender, receiver) = flume::bounded(5);
 
         let dest_dir = target_dir.to_path_buf();
+        let entries_filter = entries_filter.clone();
         let unpack_thread = tokio::task::spawn_blocking(move || -> MithrilResult<()> {
             let unpacker = SnapshotUnpacker;
-            unpacker.unpack_snapshot(receiver, compression_algorithm, &dest_dir)
+            unpacker.unpack_snapshot(receiver, compression_algorithm, &entries_filter, &dest_dir)
         });
 
         while let Some(item) = remote_stream.next().await {
             let chunk = item.with_context(|| "Download: Could not read from byte stream")?;
+            downloaded_bytes += chunk.len() as u64;
 
-            sender.send_async(chunk.to_vec()).await.with_context(|| {
-                format!("Download: could not write {} bytes to stream.", chunk.len())
-            })?;
+            match chunk_checksums.as_ref().zip(chunk_size) {
+                None => {
+                    sender.send_async(chunk.to_vec()).await.with_context(|| {
+                        format!("Download: could not write {} bytes to stream.", chunk.len())
+                    })?;
+                }
+                Some((chunk_checksums, chunk_size)) => {
+                    pending_chunk.extend_from_slice(&chunk);
+                    while pending_chunk.len() >= chunk_size {
+                        let remaining = pending_chunk.split_off(chunk_size);
+                        let chunk = std::mem::replace(&mut pending_chunk, remaining);
+                        let chunk = self
+                            .verify_chunk(
+                                location,
+                                chunk_checksums,
+                                chunk_index,
+                                chunk,
+                                snapshot_size,
+                            )
+                            .await?;
+                        chunk_index += 1;
+
+                        sender.send_async(chunk).await.with_context(|| {
+                            "Download: could not write verified chunk to stream."
+                        })?;
+                    }
+                }
+            }
 
-            downloaded_bytes += chunk.len() as u64;
             self.feedback_sender
                 .send_event(MithrilEvent::SnapshotDownloadProgress {
                     download_id: download_id.to_owned(),
@@ -124,6 +317,32 @@ impl SnapshotDownloader for HttpSnapshotDownloader {
                 .await
         }
 
+        if let Some(chunk_checksums) = &chunk_checksums {
+            if !pending_chunk.is_empty() {
+                let chunk = self
+                    .verify_chunk(
+                        location,
+                        chunk_checksums,
+                        chunk_index,
+                        pending_chunk,
+                        snapshot_size,
+                    )
+                    .await?;
+                chunk_index += 1;
+
+                sender
+                    .send_async(chunk)
+                    .await
+                    .with_context(|| "Download: could not write verified chunk to stream.")?;
+            }
+            if chunk_index != chunk_checksums.sha256.len() {
+                return Err(anyhow!(
+                    "Download: the snapshot archive is truncated, {chunk_index} chunks downloaded but {} checksums published",
+                    chunk_checksums.sha256.len()
+                ));
+            }
+        }
+
         drop(sender); // Signal EOF
         unpack_thread
             .await
@@ -155,3 +374,192 @@ impl SnapshotDownloader for HttpSnapshotDownloader {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use flate2::{write::GzEncoder, Compression};
+    use httpmock::prelude::{HttpMockRequest, MockServer, GET};
+    use mithril_common::test_utils::TempDir;
+    use std::path::PathBuf;
+
+    use crate::test_utils::test_logger;
+
+    use super::*;
+
+    const CHUNK_SIZE: u64 = 16;
+
+    fn build_archive(file_content: &[u8]) -> Vec<u8> {
+        let mut archive_builder = tar::Builder::new(GzEncoder::new(vec![], Compression::default()));
+        let mut header = tar::Header::new_gnu();
+        header.set_size(file_content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        archive_builder
+            .append_data(&mut header, "immutable/00001.chunk", file_content)
+            .unwrap();
+
+        archive_builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn corrupt_second_chunk(archive: &[u8]) -> Vec<u8> {
+        let mut corrupted_archive = archive.to_vec();
+        corrupted_archive[CHUNK_SIZE as usize + 4] ^= 0xFF;
+
+        corrupted_archive
+    }
+
+    fn is_not_a_range_request(request: &HttpMockRequest) -> bool {
+        !request.headers.as_ref().is_some_and(|headers| {
+            headers
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case("range"))
+        })
+    }
+
+    fn second_chunk_range_header() -> String {
+        format!("bytes={}-{}", CHUNK_SIZE, 2 * CHUNK_SIZE - 1)
+    }
+
+    async fn download_unpack(
+        server: &MockServer,
+        archive: &[u8],
+        chunk_checksums: &SnapshotChunkChecksums,
+        target_dir: &Path,
+    ) -> MithrilResult<()> {
+        let downloader =
+            HttpSnapshotDownloader::new(FeedbackSender::new(&[]), test_logger()).unwrap();
+
+        downloader
+            .download_unpack(
+                &server.url("/snapshot.tar.gz"),
+                target_dir,
+                CompressionAlgorithm::Gzip,
+                "download_id",
+                archive.len() as u64,
+                Some(chunk_checksums.clone()),
+                &SnapshotEntriesFilter::default(),
+            )
+            .await
+    }
+
+    fn unpacked_file_path(target_dir: &Path) -> PathBuf {
+        target_dir.join("immutable").join("00001.chunk")
+    }
+
+    #[tokio::test]
+    async fn download_unpack_verify_chunks_and_fetch_again_only_the_corrupted_chunk() {
+        let file_content = b"immutable file content ".repeat(20);
+        let archive = build_archive(&file_content);
+        let chunk_checksums = SnapshotChunkChecksums::compute(&archive[..], CHUNK_SIZE).unwrap();
+        let server = MockServer::start_async().await;
+        let full_download_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/snapshot.tar.gz")
+                .matches(is_not_a_range_request);
+            then.status(200).body(corrupt_second_chunk(&archive));
+        });
+        let range_download_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/snapshot.tar.gz")
+                .header("range", second_chunk_range_header());
+            then.status(206)
+                .body(&archive[CHUNK_SIZE as usize..2 * CHUNK_SIZE as usize]);
+        });
+        let target_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_verify_chunks_and_fetch_again_only_the_corrupted_chunk",
+        );
+
+        download_unpack(&server, &archive, &chunk_checksums, &target_dir)
+            .await
+            .expect("Download should succeed after fetching again the corrupted chunk");
+
+        full_download_mock.assert();
+        range_download_mock.assert_hits(1);
+        assert_eq!(
+            file_content,
+            std::fs::read(unpacked_file_path(&target_dir)).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn download_unpack_fails_if_a_corrupted_chunk_can_not_be_fetched_again() {
+        let archive = build_archive(&b"immutable file content ".repeat(20));
+        let chunk_checksums = SnapshotChunkChecksums::compute(&archive[..], CHUNK_SIZE).unwrap();
+        let corrupted_archive = corrupt_second_chunk(&archive);
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/snapshot.tar.gz")
+                .matches(is_not_a_range_request);
+            then.status(200).body(&corrupted_archive);
+        });
+        let range_download_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/snapshot.tar.gz")
+                .header("range", second_chunk_range_header());
+            then.status(206)
+                .body(&corrupted_archive[CHUNK_SIZE as usize..2 * CHUNK_SIZE as usize]);
+        });
+        let target_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_fails_if_a_corrupted_chunk_can_not_be_fetched_again",
+        );
+
+        download_unpack(&server, &archive, &chunk_checksums, &target_dir)
+            .await
+            .expect_err("Download should fail if a corrupted chunk is still corrupted");
+
+        range_download_mock.assert_hits(MAX_CHUNK_FETCH_ATTEMPTS);
+        assert!(!unpacked_file_path(&target_dir).exists());
+    }
+
+    #[tokio::test]
+    async fn download_unpack_fails_before_downloading_if_the_chunk_size_is_invalid() {
+        let archive = build_archive(&b"immutable file content ".repeat(20));
+        let server = MockServer::start_async().await;
+        let download_mock = server.mock(|when, then| {
+            when.method(GET).path("/snapshot.tar.gz");
+            then.status(200).body(&archive);
+        });
+        let target_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_fails_before_downloading_if_the_chunk_size_is_invalid",
+        );
+
+        for chunk_size in [0, MAX_CHUNK_SIZE + 1] {
+            let chunk_checksums = SnapshotChunkChecksums {
+                chunk_size,
+                ..SnapshotChunkChecksums::compute(&archive[..], CHUNK_SIZE).unwrap()
+            };
+
+            let error = download_unpack(&server, &archive, &chunk_checksums, &target_dir)
+                .await
+                .expect_err("Download with an invalid chunk size should fail");
+
+            assert!(error.to_string().contains("invalid chunk size"), "{error}");
+        }
+        download_mock.assert_hits(0);
+    }
+
+    #[tokio::test]
+    async fn download_unpack_fails_if_the_archive_is_truncated() {
+        let archive = build_archive(&b"immutable file content ".repeat(20));
+        let chunk_checksums = SnapshotChunkChecksums::compute(&archive[..], CHUNK_SIZE).unwrap();
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method(GET).path("/snapshot.tar.gz");
+            then.status(200).body(&archive[..2 * CHUNK_SIZE as usize]);
+        });
+        let target_dir = TempDir::create(
+            "snapshot_downloader",
+            "download_unpack_fails_if_the_archive_is_truncated",
+        );
+
+        let error = download_unpack(&server, &archive, &chunk_checksums, &target_dir)
+            .await
+            .expect_err("Download of a truncated archive should fail");
+
+        assert!(error.to_string().contains("truncated"), "{error}");
+    }
+}
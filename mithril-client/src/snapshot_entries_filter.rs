@@ -0,0 +1,252 @@
+//! Selection of the files extracted when unpacking a snapshot archive.
+//!
+//! A [SnapshotEntriesFilter] is built from glob patterns matched against the paths of the
+//! archive entries, relative to the root of the Cardano database (ie: `immutable/00001.chunk`,
+//! `ledger/1234`):
+//!  - if include patterns are given, only the entries that match one of them are extracted,
+//!  - the entries that match one of the exclude patterns are never extracted.
+//!
+//! A pattern that matches a directory also matches everything below it, so `immutable` or
+//! `immutable/` select the whole immutable files directory. In patterns `*` does not match the
+//! path separator, use `**` to match any number of directories.
+//!
+//! **Note:** the immutable files must be extracted for the snapshot to be verified against its
+//! certificate.
+
+use anyhow::Context;
+use glob::{MatchOptions, Pattern};
+use std::path::{Component, Path, PathBuf};
+
+use crate::MithrilResult;
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Filter the entries of a snapshot archive that are extracted, by default every entry is.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SnapshotEntriesFilter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl SnapshotEntriesFilter {
+    /// Constructs a new `SnapshotEntriesFilter` from the given include and exclude glob patterns.
+    pub fn new<T: AsRef<str>>(include: &[T], exclude: &[T]) -> MithrilResult<Self> {
+        Ok(Self {
+            include: Self::parse_patterns(include)?,
+            exclude: Self::parse_patterns(exclude)?,
+        })
+    }
+
+    fn parse_patterns<T: AsRef<str>>(patterns: &[T]) -> MithrilResult<Vec<Pattern>> {
+        patterns
+            .iter()
+            .map(|pattern| {
+                let pattern = pattern.as_ref();
+                Pattern::new(pattern.trim_start_matches("./").trim_end_matches('/'))
+                    .with_context(|| format!("Invalid snapshot entries glob pattern: '{pattern}'"))
+            })
+            .collect()
+    }
+
+    /// Returns `true` if every entry of the archive is extracted.
+    pub fn selects_everything(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Returns `true` if all the immutable files, needed to verify the snapshot against its
+    /// certificate, are extracted.
+    ///
+    /// The patterns are checked against probes of immutable files names with various numbers,
+    /// so a selection that only keeps some of the immutable files is rejected.
+    pub fn selects_immutable_files(&self) -> bool {
+        immutable_file_probes().all(|probe| self.is_selected(&probe))
+    }
+
+    /// Returns `true` if the archive entry at the given path must be extracted.
+    pub fn is_selected(&self, entry_path: &Path) -> bool {
+        let entry_path = normalize(entry_path);
+        if entry_path.as_os_str().is_empty() {
+            return false;
+        }
+
+        (self.include.is_empty() || Self::matches_any(&self.include, &entry_path))
+            && !Self::matches_any(&self.exclude, &entry_path)
+    }
+
+    /// Check if one of the patterns matches the path or one of its parent directories
+    fn matches_any(patterns: &[Pattern], entry_path: &Path) -> bool {
+        entry_path
+            .ancestors()
+            .filter(|path| !path.as_os_str().is_empty())
+            .any(|path| {
+                patterns
+                    .iter()
+                    .any(|pattern| pattern.matches_path_with(path, MATCH_OPTIONS))
+            })
+    }
+}
+
+/// Paths of immutable files, for each extension, with numbers made of a repeated digit or of a
+/// single non zero digit at their start or end, ie: `immutable/00000.chunk`,
+/// `immutable/77777.primary`, `immutable/00004.secondary`, `immutable/900000.chunk`.
+fn immutable_file_probes() -> impl Iterator<Item = PathBuf> {
+    let numbers = [5, 6].into_iter().flat_map(|width| {
+        ('0'..='9').flat_map(move |digit| {
+            let zeros = "0".repeat(width - 1);
+            [
+                digit.to_string().repeat(width),
+                format!("{zeros}{digit}"),
+                format!("{digit}{zeros}"),
+            ]
+        })
+    });
+
+    numbers.flat_map(|number| {
+        ["chunk", "primary", "secondary"]
+            .into_iter()
+            .map(move |extension| Path::new("immutable").join(format!("{number}.{extension}")))
+    })
+}
+
+/// Remove the leading `./` and `/` of the archive entries paths
+fn normalize(entry_path: &Path) -> PathBuf {
+    entry_path
+        .components()
+        .filter(|component| matches!(component, Component::Normal(_)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selected_entries(filter: &SnapshotEntriesFilter) -> Vec<&'static str> {
+        [
+            "./",
+            "./immutable/",
+            "./immutable/00001.chunk",
+            "./immutable/00001.primary",
+            "./ledger/",
+            "./ledger/1234",
+            "./volatile/blocks-0.dat",
+            "./protocolMagicId",
+        ]
+        .into_iter()
+        .filter(|path| filter.is_selected(Path::new(path)))
+        .collect()
+    }
+
+    #[test]
+    fn default_filter_selects_every_entry() {
+        let filter = SnapshotEntriesFilter::default();
+
+        assert!(filter.selects_everything());
+        assert_eq!(
+            vec![
+                "./immutable/",
+                "./immutable/00001.chunk",
+                "./immutable/00001.primary",
+                "./ledger/",
+                "./ledger/1234",
+                "./volatile/blocks-0.dat",
+                "./protocolMagicId",
+            ],
+            selected_entries(&filter)
+        );
+    }
+
+    #[test]
+    fn include_directory_selects_its_content() {
+        let filter = SnapshotEntriesFilter::new(&["immutable/"], &[]).unwrap();
+
+        assert!(!filter.selects_everything());
+        assert_eq!(
+            vec![
+                "./immutable/",
+                "./immutable/00001.chunk",
+                "./immutable/00001.primary"
+            ],
+            selected_entries(&filter)
+        );
+    }
+
+    #[test]
+    fn exclude_has_precedence_over_include() {
+        let filter =
+            SnapshotEntriesFilter::new(&["immutable", "ledger"], &["**/*.primary"]).unwrap();
+
+        assert_eq!(
+            vec![
+                "./immutable/",
+                "./immutable/00001.chunk",
+                "./ledger/",
+                "./ledger/1234"
+            ],
+            selected_entries(&filter)
+        );
+    }
+
+    #[test]
+    fn exclude_only_selects_every_other_entry() {
+        let filter = SnapshotEntriesFilter::new(&[], &["./ledger"]).unwrap();
+
+        assert_eq!(
+            vec![
+                "./immutable/",
+                "./immutable/00001.chunk",
+                "./immutable/00001.primary",
+                "./volatile/blocks-0.dat",
+                "./protocolMagicId",
+            ],
+            selected_entries(&filter)
+        );
+    }
+
+    #[test]
+    fn star_does_not_match_path_separator() {
+        let filter = SnapshotEntriesFilter::new(&["*.chunk"], &[]).unwrap();
+
+        assert!(selected_entries(&filter).is_empty());
+    }
+
+    #[test]
+    fn check_if_the_immutable_files_are_selected() {
+        for (include, exclude, expected) in [
+            (vec![], vec![], true),
+            (vec!["immutable/"], vec![], true),
+            (vec!["immutable", "ledger"], vec![], true),
+            (vec![], vec!["ledger"], true),
+            (vec!["ledger"], vec![], false),
+            (vec![], vec!["immutable"], false),
+            (vec!["immutable"], vec!["**/*.secondary"], false),
+            (vec!["immutable/*"], vec![], true),
+            (vec!["immutable/[0-4]*", "immutable/[5-9]*"], vec![], true),
+            (vec![], vec!["immutable/*.tmp"], true),
+            // Partial selections of the immutable files
+            (vec!["immutable/00001.*"], vec![], false),
+            (vec!["immutable/0*"], vec![], false),
+            (vec!["immutable/*.chunk", "immutable/*.primary"], vec![], false),
+            (vec![], vec!["immutable/0000[2-9]*"], false),
+            (vec![], vec!["immutable/9*"], false),
+            (vec![], vec!["**/1*.chunk"], false),
+        ] {
+            let filter = SnapshotEntriesFilter::new(&include, &exclude).unwrap();
+
+            assert_eq!(
+                expected,
+                filter.selects_immutable_files(),
+                "include: {include:?}, exclude: {exclude:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_pattern_is_rejected() {
+        SnapshotEntriesFilter::new(&["immutable/[0-9"], &[])
+            .expect_err("Unclosed character class should be rejected");
+    }
+}
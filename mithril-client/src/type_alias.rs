@@ -36,6 +36,14 @@ pub use mithril_common::messages::CertificateListItemMessageMetadata as MithrilC
 ///
 pub use mithril_common::messages::SignerWithStakeMessagePart as MithrilSigner;
 
+/// Registrations of the signers sent to the aggregator at an epoch
+///
+pub use mithril_common::messages::SignerRegistrationsMessage as SignerRegistrations;
+
+/// A signer registration, item of [SignerRegistrations]
+///
+pub use mithril_common::messages::SignerRegistrationsListItemMessage as SignerRegistration;
+
 cfg_unstable! {
     pub use mithril_common::messages::CardanoTransactionsProofsMessage as CardanoTransactionsProofs;
 
@@ -55,10 +63,14 @@ cfg_unstable! {
 }
 
 /// `mithril-common` re-exports
+///
+/// Integrators should use those re-exports (or the [prelude][crate::prelude]) instead of depending
+/// directly on `mithril-common` as its modules layout may change without notice.
 pub mod common {
     pub use mithril_common::entities::{
-        CardanoDbBeacon, CompressionAlgorithm, Epoch, ImmutableFileNumber, ProtocolMessage,
-        ProtocolMessagePartKey, ProtocolParameters,
+        ApiVersionMismatchError, CardanoDbBeacon, CompressionAlgorithm, Epoch, ImmutableFileNumber,
+        PartyId, ProtocolMessage, ProtocolMessagePartKey, ProtocolParameters, SignedEntityType,
+        SignedEntityTypeDiscriminants, SnapshotChunkChecksums, Stake,
     };
     cfg_unstable! {
         pub use mithril_common::entities::{ChainPoint, TransactionHash, SlotNumber, BlockHash, BlockNumber};
@@ -1,10 +1,12 @@
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use flate2::read::GzDecoder;
 use flume::Receiver;
+use std::io::Read;
 use std::path::Path;
 use tar::Archive;
 
 use crate::common::CompressionAlgorithm;
+use crate::snapshot_entries_filter::SnapshotEntriesFilter;
 use crate::utils::StreamReader;
 use crate::MithrilResult;
 
@@ -13,11 +15,13 @@ use crate::MithrilResult;
 pub struct SnapshotUnpacker;
 
 impl SnapshotUnpacker {
-    /// Unpack the snapshot from the given stream into the given directory.
+    /// Unpack the entries of the snapshot selected by the given filter from the given stream
+    /// into the given directory.
     pub fn unpack_snapshot(
         &self,
         stream: Receiver<Vec<u8>>,
         compression_algorithm: CompressionAlgorithm,
+        entries_filter: &SnapshotEntriesFilter,
         unpack_dir: &Path,
     ) -> MithrilResult<()> {
         let input = StreamReader::new(stream);
@@ -25,27 +29,169 @@ impl SnapshotUnpacker {
         match compression_algorithm {
             CompressionAlgorithm::Gzip => {
                 let gzip_decoder = GzDecoder::new(input);
-                let mut snapshot_archive = Archive::new(gzip_decoder);
-                snapshot_archive.unpack(unpack_dir).with_context(|| {
-                    format!(
-                        "Could not unpack from streamed data snapshot to directory '{}'",
-                        unpack_dir.display()
-                    )
-                })?;
+                let snapshot_archive = Archive::new(gzip_decoder);
+                Self::unpack_archive(snapshot_archive, entries_filter, unpack_dir)?;
             }
             CompressionAlgorithm::Zstandard => {
                 let zstandard_decoder = zstd::Decoder::new(input)
                     .with_context(|| "Unpack failed: Create Zstandard decoder error")?;
-                let mut snapshot_archive = Archive::new(zstandard_decoder);
-                snapshot_archive.unpack(unpack_dir).with_context(|| {
+                let snapshot_archive = Archive::new(zstandard_decoder);
+                Self::unpack_archive(snapshot_archive, entries_filter, unpack_dir)?;
+            }
+        };
+
+        Ok(())
+    }
+
+    fn unpack_archive<R: Read>(
+        mut snapshot_archive: Archive<R>,
+        entries_filter: &SnapshotEntriesFilter,
+        unpack_dir: &Path,
+    ) -> MithrilResult<()> {
+        let unpack_error_context = || {
+            format!(
+                "Could not unpack from streamed data snapshot to directory '{}'",
+                unpack_dir.display()
+            )
+        };
+
+        if entries_filter.selects_everything() {
+            return snapshot_archive
+                .unpack(unpack_dir)
+                .with_context(unpack_error_context);
+        }
+
+        // The entries that are not selected are skipped while iterating over the archive
+        for entry in snapshot_archive
+            .entries()
+            .with_context(unpack_error_context)?
+        {
+            let mut entry = entry.with_context(unpack_error_context)?;
+            let entry_path = entry
+                .path()
+                .with_context(unpack_error_context)?
+                .into_owned();
+            if entries_filter.is_selected(&entry_path) {
+                // The size of the selected files is only known here, the disk space can't be
+                // checked against it before the download
+                let entry_size = entry.header().size().with_context(unpack_error_context)?;
+                let available_space = fs2::available_space(unpack_dir).with_context(|| {
                     format!(
-                        "Could not unpack from streamed data snapshot to directory '{}'",
+                        "Could not get the available space in directory '{}'",
                         unpack_dir.display()
                     )
                 })?;
+                if entry_size > available_space {
+                    return Err(anyhow!(
+                        "Not enough space to unpack '{}' ({entry_size} bytes) in directory '{}': only {available_space} bytes left",
+                        entry_path.display(),
+                        unpack_dir.display()
+                    ));
+                }
+
+                entry
+                    .unpack_in(unpack_dir)
+                    .with_context(unpack_error_context)?;
             }
-        };
+        }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use flate2::{write::GzEncoder, Compression};
+    use mithril_common::test_utils::TempDir;
+
+    use super::*;
+
+    fn build_archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut archive_builder = tar::Builder::new(GzEncoder::new(vec![], Compression::default()));
+        for (path, content) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            archive_builder
+                .append_data(&mut header, path, *content)
+                .unwrap();
+        }
+
+        archive_builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn unpack(archive: Vec<u8>, entries_filter: &SnapshotEntriesFilter, unpack_dir: &Path) {
+        let (sender, receiver) = flume::bounded(1);
+        sender.send(archive).unwrap();
+        drop(sender);
+
+        SnapshotUnpacker
+            .unpack_snapshot(
+                receiver,
+                CompressionAlgorithm::Gzip,
+                entries_filter,
+                unpack_dir,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn unpack_only_selected_entries() {
+        let archive = build_archive(&[
+            ("./immutable/00001.chunk", b"chunk"),
+            ("./immutable/00001.primary", b"primary"),
+            ("./ledger/1234", b"ledger"),
+        ]);
+        let unpack_dir = TempDir::create("unpacker", "unpack_only_selected_entries");
+
+        unpack(
+            archive,
+            &SnapshotEntriesFilter::new(&["immutable"], &["**/*.primary"]).unwrap(),
+            &unpack_dir,
+        );
+
+        assert_eq!(
+            b"chunk".to_vec(),
+            std::fs::read(unpack_dir.join("immutable").join("00001.chunk")).unwrap()
+        );
+        assert!(!unpack_dir.join("immutable").join("00001.primary").exists());
+        assert!(!unpack_dir.join("ledger").exists());
+    }
+
+    #[test]
+    fn fail_if_a_selected_entry_does_not_fit_in_the_available_space() {
+        let mut archive_builder = tar::Builder::new(GzEncoder::new(vec![], Compression::default()));
+        let mut header = tar::Header::new_gnu();
+        // Only the declared size of the entry is checked, it is larger than any disk
+        header.set_size(1 << 60);
+        header.set_mode(0o644);
+        header.set_cksum();
+        archive_builder
+            .append_data(&mut header, "./ledger/1234", b"ledger".as_slice())
+            .unwrap();
+        let archive = archive_builder.into_inner().unwrap().finish().unwrap();
+        let unpack_dir = TempDir::create(
+            "unpacker",
+            "fail_if_a_selected_entry_does_not_fit_in_the_available_space",
+        );
+        let (sender, receiver) = flume::bounded(1);
+        sender.send(archive).unwrap();
+        drop(sender);
+
+        let error = SnapshotUnpacker
+            .unpack_snapshot(
+                receiver,
+                CompressionAlgorithm::Gzip,
+                &SnapshotEntriesFilter::new(&["ledger"], &[]).unwrap(),
+                &unpack_dir,
+            )
+            .expect_err("Unpacking an entry larger than the available space should fail");
+
+        assert!(
+            error.to_string().contains("Not enough space"),
+            "unexpected error: {error:?}"
+        );
+        assert!(!unpack_dir.join("ledger").join("1234").exists());
+    }
+}
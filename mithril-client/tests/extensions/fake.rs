@@ -279,10 +279,12 @@ mod file {
         }
     }
 
-    /// Compress the given db into an zstd archive in the given target directory.
+    /// Compress the given db into an zstd archive in the given target directory, the archive
+    /// is built the same way as the aggregator does so its content is reproducible.
     ///
     /// return the path to the compressed archive.
     pub fn build_fake_zstd_snapshot(immutable_db: &DummyImmutableDb, target_dir: &Path) -> PathBuf {
+        use mithril_common::archive::DeterministicTarBuilder;
         use std::fs::File;
 
         let snapshot_name = format!(
@@ -293,7 +295,7 @@ mod file {
         let target_file = target_dir.join(snapshot_name);
         let tar_file = File::create(&target_file).unwrap();
         let enc = zstd::Encoder::new(tar_file, 3).unwrap();
-        let mut tar = tar::Builder::new(enc);
+        let mut tar = DeterministicTarBuilder::new(enc);
 
         tar.append_dir_all(".", immutable_db.dir.parent().unwrap())
             .unwrap();
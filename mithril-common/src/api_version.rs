@@ -43,7 +43,12 @@ impl APIVersionProvider {
 
     /// Compute the current api version requirement
     pub fn compute_current_version_requirement(&self) -> StdResult<VersionReq> {
-        let version = &self.compute_current_version()?;
+        Self::compute_version_requirement(&self.compute_current_version()?)
+    }
+
+    /// Compute the requirement that a version must satisfy to be compatible with the given
+    /// api version
+    pub fn compute_version_requirement(version: &Version) -> StdResult<VersionReq> {
         let version_req = if version.major > 0 {
             format!("={}", version.major)
         } else {
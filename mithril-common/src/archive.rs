@@ -0,0 +1,229 @@
+//! Tools to create tar archives that are byte for byte reproducible.
+//!
+//! Two archives built from the same files contents are identical whatever the order in which
+//! the filesystem lists the files, their owners or their modification times.
+
+use anyhow::Context;
+use std::io::Write;
+use std::path::Path;
+use tar::HeaderMode;
+use walkdir::WalkDir;
+
+use crate::StdResult;
+
+/// Modification time set to the appended entries, the same value is used by the `tar` crate
+/// for the entries appended from the filesystem (_Jul 23, 2006_).
+const DETERMINISTIC_TIMESTAMP: u64 = 1153704088;
+
+/// Tar archive builder with deterministic entries:
+/// * entries are appended ordered by their path,
+/// * owners, modification times and permissions are normalized (only the user execute bit
+///   is kept on unix).
+pub struct DeterministicTarBuilder<W: Write> {
+    builder: tar::Builder<W>,
+}
+
+impl<W: Write> DeterministicTarBuilder<W> {
+    /// Create a new builder writing the archive to the given writer.
+    pub fn new(writer: W) -> Self {
+        let mut builder = tar::Builder::new(writer);
+        builder.mode(HeaderMode::Deterministic);
+
+        Self { builder }
+    }
+
+    /// Recursively append the content of `source_directory` to the archive under
+    /// `path_in_archive`.
+    ///
+    /// Symlinks are followed and the files they point to are appended as regular files.
+    pub fn append_dir_all<P: AsRef<Path>>(
+        &mut self,
+        path_in_archive: P,
+        source_directory: &Path,
+    ) -> StdResult<()> {
+        let path_in_archive = path_in_archive.as_ref();
+
+        for entry in WalkDir::new(source_directory)
+            .follow_links(true)
+            .sort_by_file_name()
+        {
+            let entry = entry.with_context(|| {
+                format!(
+                    "Could not list the content of directory: '{}'",
+                    source_directory.display()
+                )
+            })?;
+            let relative_path = entry.path().strip_prefix(source_directory)?;
+            let name = if relative_path.as_os_str().is_empty() {
+                path_in_archive.to_path_buf()
+            } else {
+                path_in_archive.join(relative_path)
+            };
+
+            // The root directory can't be appended without a name
+            if name.as_os_str().is_empty() {
+                continue;
+            }
+
+            self.builder
+                .append_path_with_name(entry.path(), &name)
+                .with_context(|| {
+                    format!(
+                        "Could not append '{}' to the archive",
+                        entry.path().display()
+                    )
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Append a regular file with the given content to the archive.
+    pub fn append_data<P: AsRef<Path>>(
+        &mut self,
+        path_in_archive: P,
+        data: &[u8],
+    ) -> StdResult<()> {
+        let path_in_archive = path_in_archive.as_ref();
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(DETERMINISTIC_TIMESTAMP);
+        header.set_uid(0);
+        header.set_gid(0);
+        header.set_entry_type(tar::EntryType::Regular);
+
+        self.builder
+            .append_data(&mut header, path_in_archive, data)
+            .with_context(|| {
+                format!(
+                    "Could not append data '{}' to the archive",
+                    path_in_archive.display()
+                )
+            })
+    }
+
+    /// Finish writing the archive and return the underlying writer.
+    pub fn into_inner(self) -> StdResult<W> {
+        self.builder
+            .into_inner()
+            .with_context(|| "Could not finish writing the archive")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    use crate::test_utils::TempDir;
+
+    use super::*;
+
+    fn create_files(root: &Path, files: &[(&str, &str)]) {
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+    }
+
+    fn set_modification_time(root: &Path, files: &[(&str, &str)], modified: SystemTime) {
+        for (path, _) in files {
+            fs::File::options()
+                .write(true)
+                .open(root.join(path))
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        }
+    }
+
+    fn build_archive(source_directory: &Path) -> Vec<u8> {
+        let mut builder = DeterministicTarBuilder::new(vec![]);
+        builder.append_dir_all(".", source_directory).unwrap();
+        builder.into_inner().unwrap()
+    }
+
+    fn list_entries(archive: &[u8]) -> Vec<PathBuf> {
+        tar::Archive::new(archive)
+            .entries()
+            .unwrap()
+            .map(|entry| entry.unwrap().path().unwrap().to_path_buf())
+            .collect()
+    }
+
+    #[test]
+    fn entries_are_ordered_by_path() {
+        let dir = TempDir::create("archive", "entries_are_ordered_by_path");
+        create_files(
+            &dir,
+            &[
+                ("immutable/00002.chunk", "2"),
+                ("ledger/100", "ledger"),
+                ("immutable/00001.chunk", "1"),
+                ("protocolMagicId", "42"),
+            ],
+        );
+
+        let entries = list_entries(&build_archive(&dir));
+
+        assert_eq!(
+            vec![
+                PathBuf::from("."),
+                PathBuf::from("immutable"),
+                PathBuf::from("immutable/00001.chunk"),
+                PathBuf::from("immutable/00002.chunk"),
+                PathBuf::from("ledger"),
+                PathBuf::from("ledger/100"),
+                PathBuf::from("protocolMagicId"),
+            ],
+            entries
+        );
+    }
+
+    #[test]
+    fn archives_of_same_content_are_identical() {
+        let files = [
+            ("immutable/00001.chunk", "1"),
+            ("immutable/00002.chunk", "2"),
+            ("ledger/100", "ledger"),
+        ];
+        let first_dir = TempDir::create("archive", "archives_of_same_content_first");
+        create_files(&first_dir, &files);
+        set_modification_time(&first_dir, &files, SystemTime::UNIX_EPOCH);
+        // Create the files in another order with other modification times
+        let second_dir = TempDir::create("archive", "archives_of_same_content_second");
+        let mut reversed_files = files;
+        reversed_files.reverse();
+        create_files(&second_dir, &reversed_files);
+        set_modification_time(
+            &second_dir,
+            &reversed_files,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(3600),
+        );
+
+        assert_eq!(build_archive(&first_dir), build_archive(&second_dir));
+    }
+
+    #[test]
+    fn entries_metadata_are_normalized() {
+        let dir = TempDir::create("archive", "entries_metadata_are_normalized");
+        create_files(&dir, &[("file", "content")]);
+        let mut builder = DeterministicTarBuilder::new(vec![]);
+        builder.append_dir_all(".", &dir).unwrap();
+        builder.append_data("data", b"data content").unwrap();
+        let archive = builder.into_inner().unwrap();
+
+        for entry in tar::Archive::new(archive.as_slice()).entries().unwrap() {
+            let header = entry.unwrap().header().clone();
+            assert_eq!(0, header.uid().unwrap());
+            assert_eq!(0, header.gid().unwrap());
+            assert_eq!(DETERMINISTIC_TIMESTAMP, header.mtime().unwrap());
+            if header.entry_type().is_file() {
+                assert_eq!(0o644, header.mode().unwrap());
+            }
+        }
+    }
+}
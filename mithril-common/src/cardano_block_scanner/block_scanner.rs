@@ -4,8 +4,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use slog::{warn, Logger};
 
-use crate::cardano_block_scanner::{BlockScanner, BlockStreamer, ImmutableBlockStreamer};
-use crate::digesters::ImmutableFile;
+use crate::cardano_block_scanner::{
+    BlockScanner, BlockStreamer, BlockStreamerBatchSize, ImmutableBlockStreamer,
+    ImmutableFileSource, LocalImmutableFileSource,
+};
 use crate::entities::{BlockNumber, ChainPoint, ImmutableFileNumber};
 use crate::StdResult;
 
@@ -20,7 +22,8 @@ pub trait ImmutableLowerBoundFinder: Send + Sync {
 
 /// Cardano block scanner
 ///
-/// This scanner reads the immutable files in the given directory and returns the blocks.
+/// This scanner reads the immutable files in the given directory and returns the blocks, the
+/// immutable files are read from the local filesystem unless another [ImmutableFileSource] is set.
 ///
 /// Both the lower and upper bounds of the [BlockScanner] are ignored, instead:
 /// * for the lower bound: the result of the [ImmutableLowerBoundFinder] is used.
@@ -33,9 +36,18 @@ pub struct CardanoBlockScanner {
     allow_unparsable_block: bool,
     lower_bound_finder: Arc<dyn ImmutableLowerBoundFinder>,
     rescan_offset: Option<usize>,
+    batch_size: BlockStreamerBatchSize,
+    file_source: Arc<dyn ImmutableFileSource>,
 }
 
 impl CardanoBlockScanner {
+    /// Default bounds of the batches of blocks yielded by the streamers returned by the scanner,
+    /// it keeps the memory footprint of a scan low whatever the size of the immutable files.
+    pub const DEFAULT_BATCH_SIZE: BlockStreamerBatchSize = BlockStreamerBatchSize {
+        max_blocks: Some(1000),
+        max_bytes: Some(16 * 1024 * 1024),
+    };
+
     /// Factory
     pub fn new(
         logger: Logger,
@@ -54,9 +66,23 @@ impl CardanoBlockScanner {
             allow_unparsable_block,
             lower_bound_finder,
             rescan_offset,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            file_source: Arc::new(LocalImmutableFileSource),
         }
     }
 
+    /// Set the bounds of the batches of blocks yielded by the streamers returned by the scanner.
+    pub fn with_batch_size(mut self, batch_size: BlockStreamerBatchSize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the source from which the immutable files are listed and read.
+    pub fn with_file_source(mut self, file_source: Arc<dyn ImmutableFileSource>) -> Self {
+        self.file_source = file_source;
+        self
+    }
+
     async fn get_lower_bound(&self) -> StdResult<Option<ImmutableFileNumber>> {
         let highest = self.lower_bound_finder.find_lower_bound().await?;
         let rescan_offset = self.rescan_offset.unwrap_or(0);
@@ -74,26 +100,30 @@ impl BlockScanner for CardanoBlockScanner {
         _until: BlockNumber,
     ) -> StdResult<Box<dyn BlockStreamer>> {
         let lower_bound = self.get_lower_bound().await?;
-        let is_in_bounds = |number: ImmutableFileNumber| match lower_bound {
-            Some(from) => from <= number,
-            None => true,
-        };
-        let immutable_chunks = ImmutableFile::list_completed_in_dir(dirpath)?
+        let immutable_chunks = self
+            .file_source
+            .list_completed_immutable_files(dirpath, lower_bound)
+            .await?
             .into_iter()
-            .filter(|f| is_in_bounds(f.number) && f.filename.contains("chunk"))
+            .filter(|f| f.filename.contains("chunk"))
             .collect::<Vec<_>>();
 
-        Ok(Box::new(ImmutableBlockStreamer::new(
-            immutable_chunks,
-            self.allow_unparsable_block,
-            self.logger.clone(),
-        )))
+        Ok(Box::new(
+            ImmutableBlockStreamer::new(
+                immutable_chunks,
+                self.allow_unparsable_block,
+                self.logger.clone(),
+            )
+            .with_batch_size(self.batch_size)
+            .with_file_source(self.file_source.clone()),
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::cardano_block_scanner::BlockStreamerTestExtensions;
+    use crate::cardano_block_scanner::{BlockStreamerTestExtensions, ChainScannedBlocks};
+    use crate::digesters::ImmutableFile;
     use crate::test_utils::{TempDir, TestLogger};
 
     use super::*;
@@ -146,6 +176,42 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "test_http_server")]
+    #[tokio::test]
+    async fn test_scan_immutable_files_served_over_http() {
+        use crate::cardano_block_scanner::HttpImmutableFileSource;
+        use crate::test_utils::test_http_server::test_http_server;
+
+        let server = test_http_server(warp::fs::dir("../mithril-test-lab/test_data"));
+        let download_dir = TempDir::create(
+            "cardano_block_scanner",
+            "test_scan_immutable_files_served_over_http",
+        );
+        let file_source = HttpImmutableFileSource::new(&server.url(), &download_dir).unwrap();
+        let lower_bound_finder = lower_bound_finder(|mock| {
+            mock.expect_find_lower_bound().returning(|| Ok(Some(0)));
+        });
+        let cardano_transaction_parser =
+            CardanoBlockScanner::new(TestLogger::stdout(), false, lower_bound_finder, None)
+                .with_file_source(Arc::new(file_source));
+
+        let mut streamer = cardano_transaction_parser
+            .scan(Path::new("unused"), None, 10000)
+            .await
+            .unwrap();
+        let immutable_blocks = streamer.poll_all().await.unwrap();
+
+        let immutable_file_numbers = immutable_blocks
+            .iter()
+            .map(|b| b.immutable_file_number)
+            .collect::<std::collections::BTreeSet<_>>();
+        assert_eq!(
+            vec![1, 2],
+            immutable_file_numbers.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(0, std::fs::read_dir(&download_dir).unwrap().count());
+    }
+
     #[tokio::test]
     async fn test_scan_with_lower_bound_ignore_upper_bound() {
         let db_path = Path::new("../mithril-test-lab/test_data/immutable/");
@@ -211,6 +277,29 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn scan_yield_batches_bounded_by_the_configured_batch_size() {
+        let db_path = Path::new("../mithril-test-lab/test_data/immutable/");
+        let lower_bound_finder = lower_bound_finder(|mock| {
+            mock.expect_find_lower_bound().returning(|| Ok(None));
+        });
+        let scanner =
+            CardanoBlockScanner::new(TestLogger::stdout(), false, lower_bound_finder, None)
+                .with_batch_size(BlockStreamerBatchSize {
+                    max_blocks: Some(1),
+                    max_bytes: None,
+                });
+
+        let mut streamer = scanner.scan(db_path, None, 10000000).await.unwrap();
+
+        while let Some(scanned_blocks) = streamer.poll_next().await.unwrap() {
+            match scanned_blocks {
+                ChainScannedBlocks::RollForwards(blocks) => assert!(blocks.len() <= 1),
+                ChainScannedBlocks::RollBackward(_) => panic!("no rollback expected"),
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_instantiate_parser_with_allow_unparsable_block_should_log_warning() {
         let temp_dir = TempDir::create(
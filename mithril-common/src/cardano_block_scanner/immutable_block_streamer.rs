@@ -1,5 +1,7 @@
 use std::collections::VecDeque;
+use std::iter::Peekable;
 use std::path::Path;
+use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
@@ -8,13 +10,48 @@ use pallas_traverse::MultiEraBlock;
 use slog::{debug, error, Logger};
 
 use crate::cardano_block_scanner::ChainScannedBlocks;
-use crate::cardano_block_scanner::{BlockStreamer, ScannedBlock};
+use crate::cardano_block_scanner::{
+    BlockStreamer, ImmutableFileSource, LocalImmutableFileSource, ScannedBlock,
+};
 use crate::digesters::ImmutableFile;
 use crate::StdResult;
 
+/// Bounds of the batches of blocks yielded by the [ImmutableBlockStreamer] on each poll.
+///
+/// A batch never contains blocks of more than one immutable file, if no bound is set all the
+/// blocks of an immutable file are yielded at once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockStreamerBatchSize {
+    /// Maximum number of blocks in a batch
+    pub max_blocks: Option<usize>,
+
+    /// Maximum cumulated size, in bytes, of the encoded blocks of a batch (a batch always
+    /// contains at least one block)
+    pub max_bytes: Option<usize>,
+}
+
+impl BlockStreamerBatchSize {
+    fn is_reached(&self, number_of_blocks: usize, number_of_bytes: usize) -> bool {
+        self.max_blocks.is_some_and(|max| number_of_blocks >= max)
+            || self.max_bytes.is_some_and(|max| number_of_bytes >= max)
+    }
+}
+
+/// Immutable file which blocks are being streamed
+struct ImmutableFileReader {
+    immutable_file: ImmutableFile,
+    reader: Peekable<Reader>,
+}
+
 /// [Block streamer][BlockStreamer] that streams blocks immutable files per immutable files
+///
+/// Blocks are only read from the immutable files when polled, in batches bounded by a
+/// [BlockStreamerBatchSize].
 pub struct ImmutableBlockStreamer {
     remaining_immutable_files: VecDeque<ImmutableFile>,
+    current_immutable_file: Option<ImmutableFileReader>,
+    file_source: Arc<dyn ImmutableFileSource>,
+    batch_size: BlockStreamerBatchSize,
     allow_unparsable_block: bool,
     logger: Logger,
 }
@@ -22,26 +59,29 @@ pub struct ImmutableBlockStreamer {
 #[async_trait]
 impl BlockStreamer for ImmutableBlockStreamer {
     async fn poll_next(&mut self) -> StdResult<Option<ChainScannedBlocks>> {
-        match &self.remaining_immutable_files.pop_front() {
-            Some(immutable_file) => {
-                debug!(
-                    self.logger,
-                    "Reading blocks from immutable file: '{}'",
-                    immutable_file.path.display()
-                );
-
-                let blocks = self
-                    .read_blocks_from_immutable_file(immutable_file)
-                    .with_context(|| {
-                        format!(
-                            "BlockStreamer failed to read blocks from immutable file: '{}'.",
-                            immutable_file.path.display()
-                        )
-                    })?;
-                Ok(Some(ChainScannedBlocks::RollForwards(blocks)))
-            }
-            None => Ok(None),
+        let mut current = match self.current_immutable_file.take() {
+            Some(current) => current,
+            None => match self.remaining_immutable_files.pop_front() {
+                Some(immutable_file) => self.open_immutable_file(immutable_file).await?,
+                None => return Ok(None),
+            },
+        };
+
+        let batch = self.read_next_batch(&mut current).with_context(|| {
+            format!(
+                "BlockStreamer failed to read blocks from immutable file: '{}'.",
+                current.immutable_file.path.display()
+            )
+        });
+        if batch.is_err() || current.reader.peek().is_none() {
+            self.file_source
+                .release_immutable_trio(&current.immutable_file)
+                .await?;
+        } else {
+            self.current_immutable_file = Some(current);
         }
+
+        Ok(Some(ChainScannedBlocks::RollForwards(batch?)))
     }
 }
 
@@ -54,25 +94,89 @@ impl ImmutableBlockStreamer {
     ) -> Self {
         Self {
             remaining_immutable_files: VecDeque::from(immutables_chunk_to_stream),
+            current_immutable_file: None,
+            file_source: Arc::new(LocalImmutableFileSource),
+            batch_size: BlockStreamerBatchSize::default(),
             allow_unparsable_block,
             logger,
         }
     }
 
-    fn read_blocks_from_immutable_file(
+    /// Set the source from which the immutable files are read, by default they are read from
+    /// the local filesystem.
+    pub fn with_file_source(mut self, file_source: Arc<dyn ImmutableFileSource>) -> Self {
+        self.file_source = file_source;
+        self
+    }
+
+    /// Set the bounds of the batches of blocks yielded on each poll, by default all the blocks
+    /// of an immutable file are yielded at once.
+    pub fn with_batch_size(mut self, batch_size: BlockStreamerBatchSize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    async fn open_immutable_file(
         &self,
-        immutable_file: &ImmutableFile,
-    ) -> StdResult<Vec<ScannedBlock>> {
-        let cardano_blocks_reader = Self::cardano_blocks_reader(immutable_file)?;
+        immutable_file: ImmutableFile,
+    ) -> StdResult<ImmutableFileReader> {
+        debug!(
+            self.logger,
+            "Reading blocks from immutable file: '{}'",
+            immutable_file.path.display()
+        );
+
+        let dir_path = self
+            .file_source
+            .fetch_immutable_trio(&immutable_file)
+            .await
+            .with_context(|| {
+                format!(
+                    "BlockStreamer failed to fetch immutable file: '{}'.",
+                    immutable_file.path.display()
+                )
+            })?;
+        let reader = match Self::cardano_blocks_reader(&dir_path, &immutable_file) {
+            Ok(reader) => reader,
+            Err(error) => {
+                self.file_source
+                    .release_immutable_trio(&immutable_file)
+                    .await?;
+                return Err(error.context(format!(
+                    "BlockStreamer failed to open immutable file: '{}'.",
+                    immutable_file.path.display()
+                )));
+            }
+        };
+
+        Ok(ImmutableFileReader {
+            immutable_file,
+            reader: reader.peekable(),
+        })
+    }
 
+    fn read_next_batch(&self, current: &mut ImmutableFileReader) -> StdResult<Vec<ScannedBlock>> {
+        let immutable_file = &current.immutable_file;
         let mut blocks = Vec::new();
-        for parsed_block in cardano_blocks_reader {
+        let mut number_of_read_blocks = 0;
+        let mut number_of_read_bytes = 0;
+
+        while !self
+            .batch_size
+            .is_reached(number_of_read_blocks, number_of_read_bytes)
+        {
+            let Some(parsed_block) = current.reader.next() else {
+                break;
+            };
             let block = parsed_block.with_context(|| {
                 format!(
                     "Error while reading block in immutable file: '{:?}'",
                     immutable_file.path
                 )
             })?;
+            number_of_read_blocks += 1;
+            number_of_read_bytes += block.len();
+
             match Self::convert_to_block(&block, immutable_file) {
                 Ok(convert_to_block) => {
                     blocks.push(convert_to_block);
@@ -104,11 +208,7 @@ impl ImmutableBlockStreamer {
         ))
     }
 
-    fn cardano_blocks_reader(immutable_file: &ImmutableFile) -> StdResult<Reader> {
-        let dir_path = immutable_file.path.parent().ok_or(anyhow!(format!(
-            "Could not retrieve immutable file directory with immutable file path: '{:?}'",
-            immutable_file.path
-        )))?;
+    fn cardano_blocks_reader(dir_path: &Path, immutable_file: &ImmutableFile) -> StdResult<Reader> {
         let file_name = &Path::new(&immutable_file.filename)
             .file_stem()
             .ok_or(anyhow!(format!(
@@ -179,6 +279,105 @@ mod tests {
         assert!(immutable_blocks.is_none());
     }
 
+    #[cfg(feature = "test_http_server")]
+    #[tokio::test]
+    async fn stream_blocks_from_immutable_files_served_over_http() {
+        use crate::cardano_block_scanner::HttpImmutableFileSource;
+        use crate::test_utils::test_http_server::test_http_server;
+
+        let server = test_http_server(warp::fs::dir("../mithril-test-lab/test_data"));
+        let download_dir = TempDir::create(
+            "cardano_transaction_parser",
+            "stream_blocks_from_immutable_files_served_over_http",
+        );
+        let file_source = HttpImmutableFileSource::new(&server.url(), &download_dir).unwrap();
+
+        let mut streamer = ImmutableBlockStreamer::new(
+            ["00001.chunk", "00002.chunk"]
+                .iter()
+                .map(|filename| ImmutableFile::new(Path::new("immutable").join(filename)).unwrap())
+                .collect(),
+            false,
+            TestLogger::stdout(),
+        )
+        .with_file_source(Arc::new(file_source));
+        let blocks = streamer.poll_all().await.unwrap();
+
+        assert_eq!(
+            5,
+            blocks.iter().map(|b| b.transactions_len()).sum::<usize>()
+        );
+        assert_eq!(0, std::fs::read_dir(&download_dir).unwrap().count());
+    }
+
+    #[tokio::test]
+    async fn yield_batches_bounded_by_the_maximum_number_of_blocks() {
+        let db_path = Path::new("../mithril-test-lab/test_data/immutable/");
+        let immutable_files = || {
+            ["00001.chunk", "00002.chunk"]
+                .iter()
+                .map(|filename| ImmutableFile::new(db_path.join(filename)).unwrap())
+                .collect::<Vec<_>>()
+        };
+        let all_blocks =
+            ImmutableBlockStreamer::new(immutable_files(), false, TestLogger::stdout())
+                .poll_all()
+                .await
+                .unwrap();
+
+        let mut streamer =
+            ImmutableBlockStreamer::new(immutable_files(), false, TestLogger::stdout())
+                .with_batch_size(BlockStreamerBatchSize {
+                    max_blocks: Some(2),
+                    max_bytes: None,
+                });
+        let mut batches = vec![];
+        while let Some(ChainScannedBlocks::RollForwards(blocks)) =
+            streamer.poll_next().await.unwrap()
+        {
+            batches.push(blocks);
+        }
+
+        assert!(
+            batches.len() > 2,
+            "blocks should have been split in several batches"
+        );
+        for batch in &batches {
+            assert!(!batch.is_empty() && batch.len() <= 2, "{batch:?}");
+            assert!(batch
+                .iter()
+                .all(|b| b.immutable_file_number == batch[0].immutable_file_number));
+        }
+        assert_eq!(all_blocks, batches.concat());
+    }
+
+    #[tokio::test]
+    async fn yield_at_least_one_block_per_batch_when_bounded_by_bytes() {
+        let db_path = Path::new("../mithril-test-lab/test_data/immutable/");
+        let immutable_file = ImmutableFile::new(db_path.join("00002.chunk")).unwrap();
+        let all_blocks =
+            ImmutableBlockStreamer::new(vec![immutable_file.clone()], false, TestLogger::stdout())
+                .poll_all()
+                .await
+                .unwrap();
+
+        let mut streamer =
+            ImmutableBlockStreamer::new(vec![immutable_file], false, TestLogger::stdout())
+                .with_batch_size(BlockStreamerBatchSize {
+                    max_blocks: None,
+                    max_bytes: Some(1),
+                });
+        let mut number_of_batches = 0;
+        while let Some(ChainScannedBlocks::RollForwards(blocks)) =
+            streamer.poll_next().await.unwrap()
+        {
+            assert_eq!(1, blocks.len());
+            number_of_batches += 1;
+        }
+
+        assert_eq!(all_blocks.len(), number_of_batches);
+    }
+
     #[tokio::test]
     async fn if_disallowed_reading_unparsable_block_should_fail() {
         let db_path = Path::new("../mithril-test-lab/test_data/parsing_error/immutable/");
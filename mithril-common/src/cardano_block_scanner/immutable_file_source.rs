@@ -0,0 +1,358 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use tokio::io::AsyncWriteExt;
+
+use crate::digesters::ImmutableFile;
+use crate::entities::ImmutableFileNumber;
+use crate::StdResult;
+
+/// Extensions of the files that must be available to read the blocks of an immutable file
+const IMMUTABLE_TRIO_EXTENSIONS: [&str; 3] = ["chunk", "primary", "secondary"];
+
+/// Source of the immutable files read by the [ImmutableBlockStreamer][crate::cardano_block_scanner::ImmutableBlockStreamer].
+#[async_trait]
+pub trait ImmutableFileSource: Sync + Send {
+    /// List the completed immutable files of the Cardano DB, starting from the given immutable
+    /// file number if any.
+    ///
+    /// For a local source, the immutable files are listed in the given directory.
+    async fn list_completed_immutable_files(
+        &self,
+        dirpath: &Path,
+        from: Option<ImmutableFileNumber>,
+    ) -> StdResult<Vec<ImmutableFile>>;
+
+    /// Make the trio of files (chunk, primary and secondary) of the given immutable file
+    /// readable on the local filesystem and return the directory that contains them.
+    async fn fetch_immutable_trio(&self, immutable_file: &ImmutableFile) -> StdResult<PathBuf>;
+
+    /// Called once the blocks of the given immutable file have been read, allowing the source
+    /// to free the resources used to make its trio readable.
+    async fn release_immutable_trio(&self, _immutable_file: &ImmutableFile) -> StdResult<()> {
+        Ok(())
+    }
+}
+
+/// [ImmutableFileSource] reading the immutable files directly from the local filesystem
+pub struct LocalImmutableFileSource;
+
+#[async_trait]
+impl ImmutableFileSource for LocalImmutableFileSource {
+    async fn list_completed_immutable_files(
+        &self,
+        dirpath: &Path,
+        from: Option<ImmutableFileNumber>,
+    ) -> StdResult<Vec<ImmutableFile>> {
+        let immutable_files = ImmutableFile::list_completed_in_dir(dirpath)?
+            .into_iter()
+            .filter(|f| match from {
+                Some(from) => from <= f.number,
+                None => true,
+            })
+            .collect();
+
+        Ok(immutable_files)
+    }
+
+    async fn fetch_immutable_trio(&self, immutable_file: &ImmutableFile) -> StdResult<PathBuf> {
+        let dir_path = immutable_file.path.parent().ok_or(anyhow!(format!(
+            "Could not retrieve immutable file directory with immutable file path: '{:?}'",
+            immutable_file.path
+        )))?;
+
+        Ok(dir_path.to_path_buf())
+    }
+}
+
+/// [ImmutableFileSource] downloading the immutable files from a Cardano DB served over HTTP(S),
+/// ie: a web server or an object storage bucket.
+///
+/// The immutable files are fetched at `{base_url}/immutable/{filename}` and are stored in the
+/// download directory only for the time needed to read their blocks.
+///
+/// Since a static web server or a bucket can not be expected to list its content, the immutable
+/// files are discovered by checking the existence of their chunk file, in sequence from the
+/// requested immutable file number.
+pub struct HttpImmutableFileSource {
+    base_url: String,
+    download_dir: PathBuf,
+    http_client: Client,
+}
+
+impl HttpImmutableFileSource {
+    /// Factory
+    pub fn new(base_url: &str, download_dir: &Path) -> StdResult<Self> {
+        std::fs::create_dir_all(download_dir).with_context(|| {
+            format!(
+                "Could not create immutable files download directory: '{}'",
+                download_dir.display()
+            )
+        })?;
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            download_dir: download_dir.to_path_buf(),
+            http_client: Client::new(),
+        })
+    }
+
+    fn trio_filenames(immutable_file: &ImmutableFile) -> StdResult<Vec<String>> {
+        let file_stem = Path::new(&immutable_file.filename)
+            .file_stem()
+            .ok_or(anyhow!(format!(
+                "Could not extract immutable file name from file: '{}'",
+                immutable_file.filename
+            )))?
+            .to_string_lossy()
+            .to_string();
+
+        Ok(IMMUTABLE_TRIO_EXTENSIONS
+            .iter()
+            .map(|extension| format!("{file_stem}.{extension}"))
+            .collect())
+    }
+
+    fn url(&self, filename: &str) -> String {
+        format!("{}/immutable/{filename}", self.base_url)
+    }
+
+    async fn exists(&self, filename: &str) -> StdResult<bool> {
+        let url = self.url(filename);
+        let response = self
+            .http_client
+            .head(&url)
+            .send()
+            .await
+            .with_context(|| format!("Could not check existence of immutable file at '{url}'"))?;
+
+        match response.status() {
+            StatusCode::OK => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            status => Err(anyhow!(
+                "Could not check existence of immutable file at '{url}': unexpected status code {status}"
+            )),
+        }
+    }
+
+    async fn download(&self, filename: &str) -> StdResult<()> {
+        let url = self.url(filename);
+        let mut response = self
+            .http_client
+            .get(&url)
+            .send()
+            .await
+            .with_context(|| format!("Could not download immutable file at '{url}'"))?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let filepath = self.download_dir.join(filename);
+                let mut file = tokio::fs::File::create(&filepath).await.with_context(|| {
+                    format!("Could not create immutable file: '{}'", filepath.display())
+                })?;
+                while let Some(chunk) = response
+                    .chunk()
+                    .await
+                    .with_context(|| format!("Could not read immutable file at '{url}'"))?
+                {
+                    file.write_all(&chunk).await.with_context(|| {
+                        format!("Could not write immutable file: '{}'", filepath.display())
+                    })?;
+                }
+                file.flush().await.with_context(|| {
+                    format!("Could not write immutable file: '{}'", filepath.display())
+                })?;
+
+                Ok(())
+            }
+            status => Err(anyhow!(
+                "Could not download immutable file at '{url}': unexpected status code {status}"
+            )),
+        }
+    }
+
+    async fn download_trio(&self, immutable_file: &ImmutableFile) -> StdResult<()> {
+        for filename in Self::trio_filenames(immutable_file)? {
+            self.download(&filename).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ImmutableFileSource for HttpImmutableFileSource {
+    async fn list_completed_immutable_files(
+        &self,
+        _dirpath: &Path,
+        from: Option<ImmutableFileNumber>,
+    ) -> StdResult<Vec<ImmutableFile>> {
+        let mut immutable_files = vec![];
+        let mut number = from.unwrap_or(0);
+        while self.exists(&format!("{number:05}.chunk")).await? {
+            for extension in IMMUTABLE_TRIO_EXTENSIONS {
+                immutable_files.push(ImmutableFile::new(
+                    self.download_dir.join(format!("{number:05}.{extension}")),
+                )?);
+            }
+            number += 1;
+        }
+        // The last immutable file trio is not completed yet
+        immutable_files.retain(|f| f.number + 1 < number);
+        immutable_files.sort();
+
+        Ok(immutable_files)
+    }
+
+    async fn fetch_immutable_trio(&self, immutable_file: &ImmutableFile) -> StdResult<PathBuf> {
+        if let Err(error) = self.download_trio(immutable_file).await {
+            // Do not leave partially downloaded files behind
+            self.release_immutable_trio(immutable_file).await?;
+            return Err(error);
+        }
+
+        Ok(self.download_dir.clone())
+    }
+
+    async fn release_immutable_trio(&self, immutable_file: &ImmutableFile) -> StdResult<()> {
+        for filename in Self::trio_filenames(immutable_file)? {
+            let path = self.download_dir.join(filename);
+            if path.exists() {
+                tokio::fs::remove_file(&path).await.with_context(|| {
+                    format!("Could not remove immutable file: '{}'", path.display())
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "test_http_server"))]
+mod tests {
+    use crate::test_utils::test_http_server::test_http_server;
+    use crate::test_utils::TempDir;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn http_source_download_and_release_immutable_trio() {
+        let server = test_http_server(warp::fs::dir("../mithril-test-lab/test_data"));
+        let download_dir = TempDir::create(
+            "immutable_file_source",
+            "http_source_download_and_release_immutable_trio",
+        );
+        let source = HttpImmutableFileSource::new(&server.url(), &download_dir).unwrap();
+        let immutable_file = ImmutableFile::new(PathBuf::from("immutable/00001.chunk")).unwrap();
+
+        let trio_dir = source.fetch_immutable_trio(&immutable_file).await.unwrap();
+
+        assert_eq!(download_dir, trio_dir);
+        for filename in ["00001.chunk", "00001.primary", "00001.secondary"] {
+            assert_eq!(
+                std::fs::read(Path::new("../mithril-test-lab/test_data/immutable").join(filename))
+                    .unwrap(),
+                std::fs::read(trio_dir.join(filename)).unwrap()
+            );
+        }
+
+        source
+            .release_immutable_trio(&immutable_file)
+            .await
+            .unwrap();
+
+        assert_eq!(0, std::fs::read_dir(&download_dir).unwrap().count());
+    }
+
+    #[tokio::test]
+    async fn http_source_fail_if_an_immutable_file_is_missing() {
+        let server = test_http_server(warp::fs::dir("../mithril-test-lab/test_data"));
+        let download_dir = TempDir::create(
+            "immutable_file_source",
+            "http_source_fail_if_an_immutable_file_is_missing",
+        );
+        let source = HttpImmutableFileSource::new(&server.url(), &download_dir).unwrap();
+        let immutable_file = ImmutableFile::new(PathBuf::from("immutable/99999.chunk")).unwrap();
+
+        source
+            .fetch_immutable_trio(&immutable_file)
+            .await
+            .expect_err("fetching a missing immutable file should fail");
+    }
+
+    #[tokio::test]
+    async fn http_source_remove_downloaded_files_if_the_trio_is_incomplete() {
+        let served_dir = TempDir::create(
+            "immutable_file_source",
+            "http_source_remove_downloaded_files_if_the_trio_is_incomplete_served",
+        );
+        std::fs::create_dir(served_dir.join("immutable")).unwrap();
+        std::fs::copy(
+            "../mithril-test-lab/test_data/immutable/00001.chunk",
+            served_dir.join("immutable").join("00001.chunk"),
+        )
+        .unwrap();
+        let server = test_http_server(warp::fs::dir(served_dir));
+        let download_dir = TempDir::create(
+            "immutable_file_source",
+            "http_source_remove_downloaded_files_if_the_trio_is_incomplete",
+        );
+        let source = HttpImmutableFileSource::new(&server.url(), &download_dir).unwrap();
+        let immutable_file = ImmutableFile::new(PathBuf::from("immutable/00001.chunk")).unwrap();
+
+        source
+            .fetch_immutable_trio(&immutable_file)
+            .await
+            .expect_err("fetching an incomplete immutable trio should fail");
+
+        assert_eq!(0, std::fs::read_dir(&download_dir).unwrap().count());
+    }
+
+    #[tokio::test]
+    async fn http_source_list_completed_immutable_files_from_the_given_number() {
+        let server = test_http_server(warp::fs::dir("../mithril-test-lab/test_data"));
+        let download_dir = TempDir::create(
+            "immutable_file_source",
+            "http_source_list_completed_immutable_files_from_the_given_number",
+        );
+        let source = HttpImmutableFileSource::new(&server.url(), &download_dir).unwrap();
+
+        let immutable_files = source
+            .list_completed_immutable_files(Path::new("unused"), Some(1))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                "00001.chunk",
+                "00001.primary",
+                "00001.secondary",
+                "00002.chunk",
+                "00002.primary",
+                "00002.secondary",
+            ],
+            immutable_files
+                .iter()
+                .map(|f| f.filename.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn local_source_list_completed_immutable_files_from_the_given_number() {
+        let immutable_files = LocalImmutableFileSource
+            .list_completed_immutable_files(Path::new("../mithril-test-lab/test_data"), Some(2))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec!["00002.chunk", "00002.primary", "00002.secondary"],
+            immutable_files
+                .iter()
+                .map(|f| f.filename.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+}
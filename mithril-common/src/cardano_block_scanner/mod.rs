@@ -2,11 +2,13 @@
 mod block_scanner;
 mod dumb_block_scanner;
 mod immutable_block_streamer;
+mod immutable_file_source;
 mod interface;
 mod scanned_block;
 
 pub use block_scanner::*;
 pub use dumb_block_scanner::*;
 pub use immutable_block_streamer::*;
+pub use immutable_file_source::*;
 pub use interface::*;
 pub use scanned_block::*;
@@ -748,4 +748,18 @@ mod tests {
             .unwrap();
         assert_eq!(404, kes_period);
     }
+
+    #[tokio::test]
+    async fn test_get_utxo_set_is_unsupported() {
+        let observer = CardanoCliChainObserver::new(Box::<TestCliRunner>::default());
+        let error = observer
+            .get_utxo_set(&ChainPoint::dummy())
+            .await
+            .expect_err("get_utxo_set should fail");
+
+        assert!(
+            matches!(error, ChainObserverError::Unsupported(_)),
+            "Expected ChainObserverError::Unsupported, got: {error:?}"
+        );
+    }
 }
@@ -22,6 +22,11 @@ pub struct FakeObserver {
     ///
     /// [get_current_datums]: ChainObserver::get_current_datums
     pub datums: RwLock<Vec<TxDatum>>,
+
+    /// A list of [CardanoUtxo], used by [get_utxo_set]
+    ///
+    /// [get_utxo_set]: ChainObserver::get_utxo_set
+    pub utxo_set: RwLock<Vec<CardanoUtxo>>,
 }
 
 impl FakeObserver {
@@ -31,6 +36,7 @@ impl FakeObserver {
             signers: RwLock::new(vec![]),
             current_time_point: RwLock::new(current_time_point.clone()),
             datums: RwLock::new(vec![]),
+            utxo_set: RwLock::new(vec![]),
         }
     }
 
@@ -97,6 +103,13 @@ impl FakeObserver {
         let mut datums = self.datums.write().await;
         *datums = new_datums;
     }
+
+    /// Set the UTxO set that will be returned by
+    /// [get_utxo_set][ChainObserver::get_utxo_set].
+    pub async fn set_utxo_set(&self, new_utxo_set: Vec<CardanoUtxo>) {
+        let mut utxo_set = self.utxo_set.write().await;
+        *utxo_set = new_utxo_set;
+    }
 }
 
 impl Default for FakeObserver {
@@ -155,6 +168,28 @@ impl ChainObserver for FakeObserver {
     ) -> Result<Option<KESPeriod>, ChainObserverError> {
         Ok(Some(0))
     }
+
+    async fn get_epoch_boundary_chain_point(
+        &self,
+        epoch: Epoch,
+        _from: &ChainPoint,
+    ) -> Result<Option<ChainPoint>, ChainObserverError> {
+        Ok(self
+            .current_time_point
+            .read()
+            .await
+            .as_ref()
+            .filter(|time_point| time_point.epoch >= epoch)
+            .map(|time_point| time_point.chain_point.clone()))
+    }
+
+    async fn get_utxo_set(
+        &self,
+        _chain_point: &ChainPoint,
+    ) -> Result<Vec<CardanoUtxo>, ChainObserverError> {
+        let utxo_set = self.utxo_set.read().await;
+        Ok(utxo_set.to_vec())
+    }
 }
 
 #[cfg(test)]
@@ -219,6 +254,40 @@ mod tests {
         assert_eq!(fake_datums, datums);
     }
 
+    #[tokio::test]
+    async fn test_get_epoch_boundary_chain_point() {
+        let time_point = TimePoint::dummy();
+        let fake_observer = FakeObserver::new(Some(time_point.clone()));
+
+        let chain_point = fake_observer
+            .get_epoch_boundary_chain_point(time_point.epoch, &ChainPoint::dummy())
+            .await
+            .unwrap();
+        assert_eq!(Some(time_point.chain_point.clone()), chain_point);
+
+        let chain_point = fake_observer
+            .get_epoch_boundary_chain_point(time_point.epoch + 1, &ChainPoint::dummy())
+            .await
+            .unwrap();
+        assert_eq!(None, chain_point);
+    }
+
+    #[tokio::test]
+    async fn test_get_utxo_set() {
+        let fake_utxo_set = vec![
+            CardanoUtxo::new("tx-hash-1", 0, "output-1"),
+            CardanoUtxo::new("tx-hash-2", 3, "output-2"),
+        ];
+        let fake_observer = FakeObserver::new(None);
+        fake_observer.set_utxo_set(fake_utxo_set.clone()).await;
+        let utxo_set = fake_observer
+            .get_utxo_set(&ChainPoint::dummy())
+            .await
+            .expect("get_utxo_set should not fail");
+
+        assert_eq!(fake_utxo_set, utxo_set);
+    }
+
     #[tokio::test]
     async fn test_increase_block_number() {
         let fake_observer = FakeObserver::new(None);
@@ -21,6 +21,10 @@ pub enum ChainObserverError {
     /// Error raised when the content could not be parsed.
     #[error("could not parse content")]
     InvalidContent(#[source] StdError),
+
+    /// Error raised when the query is not supported by the [ChainObserver] implementation.
+    #[error("unsupported query: {0}")]
+    Unsupported(String),
 }
 
 /// Retrieve data from the cardano network
@@ -51,4 +55,26 @@ pub trait ChainObserver: Sync + Send {
     ) -> Result<Option<KESPeriod>, ChainObserverError> {
         Ok(None)
     }
+
+    /// Retrieve the chain point of the last block of the epoch preceding the given epoch.
+    ///
+    /// The chain is followed from the `from` chain point, that must be older than the epoch
+    /// boundary. Returns `None` if the epoch boundary has not been reached yet.
+    async fn get_epoch_boundary_chain_point(
+        &self,
+        _epoch: Epoch,
+        _from: &ChainPoint,
+    ) -> Result<Option<ChainPoint>, ChainObserverError> {
+        Err(ChainObserverError::Unsupported(
+            "get_epoch_boundary_chain_point".to_string(),
+        ))
+    }
+
+    /// Retrieve the UTxO set of the ledger state at the given chain point
+    async fn get_utxo_set(
+        &self,
+        _chain_point: &ChainPoint,
+    ) -> Result<Vec<CardanoUtxo>, ChainObserverError> {
+        Err(ChainObserverError::Unsupported("get_utxo_set".to_string()))
+    }
 }
@@ -1,22 +1,28 @@
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use pallas_addresses::Address;
-use pallas_codec::utils::{Bytes, CborWrap, TagWrap};
+use pallas_codec::{
+    minicbor,
+    utils::{Bytes, CborWrap, TagWrap},
+};
 use pallas_network::{
     facades::NodeClient,
     miniprotocols::{
+        chainsync::{N2CClient, NextResponse},
         localstate::{
             queries_v16::{
-                self, Addr, Addrs, ChainBlockNumber, Genesis, PostAlonsoTransactionOutput,
-                StakeSnapshot, Stakes, TransactionOutput, UTxOByAddress,
+                self, Addr, Addrs, BlockQuery, ChainBlockNumber, Genesis, LedgerQuery,
+                PostAlonsoTransactionOutput, Request, StakeSnapshot, Stakes, TransactionOutput,
+                UTxOByAddress,
             },
-            Client,
+            Client, State,
         },
         Point,
     },
 };
 
 use pallas_primitives::ToCanonicalJson;
+use pallas_traverse::MultiEraBlock;
 use std::{
     collections::BTreeSet,
     path::{Path, PathBuf},
@@ -25,7 +31,7 @@ use std::{
 use crate::{
     chain_observer::{interface::*, ChainAddress, TxDatum},
     crypto_helper::{encode_bech32, KESPeriod, OpCert},
-    entities::{ChainPoint, Epoch, StakeDistribution},
+    entities::{CardanoUtxo, ChainPoint, Epoch, StakeDistribution},
     CardanoNetwork, StdResult,
 };
 
@@ -175,6 +181,220 @@ impl PallasChainObserver {
         Ok(utxo)
     }
 
+    /// Converts the given [ChainPoint] to a pallas [Point].
+    fn to_pallas_point(&self, chain_point: &ChainPoint) -> StdResult<Point> {
+        let block_hash = hex::decode(&chain_point.block_hash).with_context(|| {
+            format!(
+                "PallasChainObserver failed to decode block hash: '{}'",
+                chain_point.block_hash
+            )
+        })?;
+
+        Ok(Point::Specific(chain_point.slot_number, block_hash))
+    }
+
+    /// Acquires the ledger state at the given point using the provided `statequery` client,
+    /// re-acquiring it if a ledger state is already acquired.
+    async fn acquire_at(&self, statequery: &mut Client, point: Point) -> StdResult<()> {
+        let result = if matches!(statequery.state(), State::Acquired) {
+            match statequery.send_reacquire(Some(point)).await {
+                Ok(()) => statequery.recv_while_acquiring().await,
+                Err(err) => Err(err),
+            }
+        } else {
+            statequery.acquire(Some(point)).await
+        };
+
+        result
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to acquire statequery")
+    }
+
+    /// Fetches the epoch of the ledger state at the given point using the provided `statequery` client.
+    async fn get_epoch_at(&self, statequery: &mut Client, point: Point) -> StdResult<Epoch> {
+        self.acquire_at(statequery, point).await?;
+
+        let era = queries_v16::get_current_era(statequery)
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to get current era")?;
+
+        let epoch = queries_v16::get_block_epoch_number(statequery, era)
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to get block epoch number")?;
+
+        Ok(Epoch(epoch as u64))
+    }
+
+    /// Follows the chain from the given chain point up to the tip using the provided
+    /// `chainsync` client, and returns the chain points of the followed blocks.
+    ///
+    /// The given chain point is the first of the returned chain points.
+    async fn get_chain_points_from(
+        &self,
+        chainsync: &mut N2CClient,
+        from: &ChainPoint,
+    ) -> StdResult<Vec<ChainPoint>> {
+        let (intersection, mut tip) = chainsync
+            .find_intersect(vec![self.to_pallas_point(from)?])
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to find chainsync intersection")?;
+        if intersection.is_none() {
+            return Err(anyhow!(
+                "PallasChainObserver could not find chain point '{from:?}' on chain"
+            ));
+        }
+
+        let mut chain_points = vec![from.clone()];
+        while self.to_pallas_point(chain_points.last().unwrap())? != tip.0 {
+            match chainsync
+                .request_next()
+                .await
+                .map_err(|err| anyhow!(err))
+                .with_context(|| "PallasChainObserver failed to request next block")?
+            {
+                NextResponse::RollForward(block, next_tip) => {
+                    let block = MultiEraBlock::decode(&block.0)
+                        .map_err(|err| anyhow!(err))
+                        .with_context(|| "PallasChainObserver failed to decode block")?;
+                    chain_points.push(ChainPoint::new(
+                        block.slot(),
+                        block.number(),
+                        block.hash().to_string(),
+                    ));
+                    tip = next_tip;
+                }
+                NextResponse::RollBackward(point, next_tip) => {
+                    chain_points.retain(|chain_point| chain_point.slot_number <= point.slot_or_default());
+                    if chain_points.is_empty() {
+                        return Err(anyhow!(
+                            "PallasChainObserver chain rolled back before chain point '{from:?}'"
+                        ));
+                    }
+                    tip = next_tip;
+                }
+                NextResponse::Await => {
+                    return Err(anyhow!(
+                        "PallasChainObserver reached the tip of the chain unexpectedly"
+                    ));
+                }
+            }
+        }
+
+        Ok(chain_points)
+    }
+
+    /// Returns the last of the given chain points that is in an epoch preceding the given epoch.
+    ///
+    /// The chain points must be in chain order, the first one must be in an epoch preceding the
+    /// given epoch. Returns `None` if no chain point is in the given epoch or a later one.
+    async fn find_epoch_boundary(
+        &self,
+        statequery: &mut Client,
+        chain_points: &[ChainPoint],
+        epoch: Epoch,
+    ) -> StdResult<Option<ChainPoint>> {
+        let mut before = 0;
+        let mut after = chain_points.len() - 1;
+        if self
+            .get_epoch_at(statequery, self.to_pallas_point(&chain_points[before])?)
+            .await?
+            >= epoch
+        {
+            return Err(anyhow!(
+                "PallasChainObserver can not find the boundary of epoch '{epoch}' since it is older than the chain point '{:?}'",
+                chain_points[before]
+            ));
+        }
+        if self
+            .get_epoch_at(statequery, self.to_pallas_point(&chain_points[after])?)
+            .await?
+            < epoch
+        {
+            return Ok(None);
+        }
+
+        while after - before > 1 {
+            let middle = before + (after - before) / 2;
+            let middle_epoch = self
+                .get_epoch_at(statequery, self.to_pallas_point(&chain_points[middle])?)
+                .await?;
+            if middle_epoch < epoch {
+                before = middle;
+            } else {
+                after = middle;
+            }
+        }
+
+        Ok(Some(chain_points[before].clone()))
+    }
+
+    /// Returns the chain point of the last block of the epoch preceding the given epoch.
+    async fn get_epoch_boundary(
+        &self,
+        client: &mut NodeClient,
+        epoch: Epoch,
+        from: &ChainPoint,
+    ) -> StdResult<Option<ChainPoint>> {
+        let chain_points = self.get_chain_points_from(client.chainsync(), from).await?;
+
+        self.find_epoch_boundary(client.statequery(), &chain_points, epoch)
+            .await
+    }
+
+    /// Fetches the whole UTxO set of the ledger state at the given point using the provided
+    /// `statequery` client.
+    async fn get_utxo_whole(&self, statequery: &mut Client, point: Point) -> StdResult<UTxOByAddress> {
+        self.acquire_at(statequery, point).await?;
+
+        // The era is queried on the acquired ledger state, so the query matches its era
+        let era = queries_v16::get_current_era(statequery)
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to get current era")?;
+
+        let query = Request::LedgerQuery(LedgerQuery::BlockQuery(era, BlockQuery::GetUTxOWhole));
+        let utxo = statequery
+            .query(query)
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to get whole utxo")?;
+
+        Ok(utxo)
+    }
+
+    /// Maps the given `UTxOByAddress` instance to a list of [CardanoUtxo].
+    fn map_utxo_set(&self, utxo: UTxOByAddress) -> StdResult<Vec<CardanoUtxo>> {
+        utxo.utxo
+            .iter()
+            .map(|(input, output)| {
+                let output = minicbor::to_vec(output)
+                    .map_err(|err| anyhow!(err))
+                    .with_context(|| "PallasChainObserver failed to encode transaction output")?;
+
+                Ok(CardanoUtxo::new(
+                    input.transaction_id.to_string(),
+                    u64::from(input.index),
+                    hex::encode(output),
+                ))
+            })
+            .collect()
+    }
+
+    /// Returns the UTxO set of the ledger state at the given chain point.
+    async fn get_utxo_set_at(
+        &self,
+        client: &mut NodeClient,
+        chain_point: &ChainPoint,
+    ) -> StdResult<Vec<CardanoUtxo>> {
+        let point = self.to_pallas_point(chain_point)?;
+        let utxo = self.get_utxo_whole(client.statequery(), point).await?;
+
+        self.map_utxo_set(utxo)
+    }
+
     /// Fetches the current stake distribution using the provided `statequery` client.
     async fn do_stake_snapshots_state_query(
         &self,
@@ -473,6 +693,37 @@ impl ChainObserver for PallasChainObserver {
 
         Ok(current_kes_period)
     }
+
+    async fn get_epoch_boundary_chain_point(
+        &self,
+        epoch: Epoch,
+        from: &ChainPoint,
+    ) -> Result<Option<ChainPoint>, ChainObserverError> {
+        let mut client = self.get_client().await?;
+
+        let chain_point = self.get_epoch_boundary(&mut client, epoch, from).await?;
+
+        self.post_process_statequery(&mut client).await?;
+
+        client.abort().await;
+
+        Ok(chain_point)
+    }
+
+    async fn get_utxo_set(
+        &self,
+        chain_point: &ChainPoint,
+    ) -> Result<Vec<CardanoUtxo>, ChainObserverError> {
+        let mut client = self.get_client().await?;
+
+        let utxo_set = self.get_utxo_set_at(&mut client, chain_point).await?;
+
+        self.post_process_statequery(&mut client).await?;
+
+        client.abort().await;
+
+        Ok(utxo_set)
+    }
 }
 
 #[cfg(test)]
@@ -482,15 +733,20 @@ mod tests {
     use kes_summed_ed25519::{kes::Sum6Kes, traits::KesSk};
     use pallas_codec::utils::{AnyCbor, AnyUInt, KeyValuePairs, TagWrap};
     use pallas_crypto::hash::Hash;
-    use pallas_network::miniprotocols::{
-        localstate::{
-            queries_v16::{
-                BlockQuery, ChainBlockNumber, Fraction, Genesis, HardForkQuery, LedgerQuery,
-                Request, Snapshots, StakeSnapshot, SystemStart, Value,
+    use pallas_hardano::storage::immutable::chunk::read_blocks;
+    use pallas_network::{
+        facades::NodeServer,
+        miniprotocols::{
+            chainsync::{BlockContent, ClientRequest, Tip},
+            localstate::{
+                queries_v16::{
+                    ChainBlockNumber, Fraction, Genesis, HardForkQuery, Snapshots, StakeSnapshot,
+                    SystemStart, Value,
+                },
+                ClientAcquireRequest, ClientQueryRequest,
             },
-            ClientQueryRequest,
+            Point,
         },
-        Point,
     };
     use tokio::net::UnixListener;
 
@@ -610,13 +866,34 @@ mod tests {
         vec![genesis]
     }
 
+    /// Decodes the era of a `GetUTxOWhole` query, that can not be decoded by pallas.
+    ///
+    /// The query is encoded as `[0, [0, [era, [7]]]]`.
+    fn decode_utxo_whole_query_era(query: &AnyCbor) -> Option<u16> {
+        let mut decoder = minicbor::Decoder::new(query.raw_bytes());
+        let mut expect_tag = |tag: u16| -> Option<()> {
+            decoder.array().ok()?;
+            (decoder.u16().ok()? == tag).then_some(())
+        };
+        expect_tag(0)?;
+        expect_tag(0)?;
+        decoder.array().ok()?;
+        let era = decoder.u16().ok()?;
+        decoder.array().ok()?;
+
+        (decoder.u16().ok()? == 7).then_some(era)
+    }
+
     /// pallas responses mock server.
     async fn mock_server(server: &mut pallas_network::facades::NodeServer) -> AnyCbor {
-        let query: queries_v16::Request =
-            match server.statequery().recv_while_acquired().await.unwrap() {
-                ClientQueryRequest::Query(q) => q.into_decode().unwrap(),
-                x => panic!("unexpected message from client: {x:?}"),
-            };
+        let query = match server.statequery().recv_while_acquired().await.unwrap() {
+            ClientQueryRequest::Query(q) => q,
+            x => panic!("unexpected message from client: {x:?}"),
+        };
+        if decode_utxo_whole_query_era(&query).is_some() {
+            return AnyCbor::from_encode(get_fake_utxo_by_address());
+        }
+        let query: queries_v16::Request = query.into_decode().unwrap();
 
         match query {
             Request::GetChainPoint => {
@@ -655,25 +932,129 @@ mod tests {
     /// Use the `intersections` parameter to define exactly how many
     /// local state queries should be intersepted by the `mock_server`
     /// and avoid any panic errors.
-    async fn setup_server(socket_path: PathBuf, intersections: u32) -> tokio::task::JoinHandle<()> {
+    ///
+    /// The server task returns the point at which the client acquired the ledger state.
+    async fn setup_server(
+        socket_path: PathBuf,
+        intersections: u32,
+    ) -> tokio::task::JoinHandle<Option<Point>> {
         tokio::spawn({
             async move {
-                if socket_path.exists() {
-                    fs::remove_file(&socket_path).expect("Previous socket removal failed");
-                }
-
-                let unix_listener = UnixListener::bind(socket_path.as_path()).unwrap();
-                let mut server = pallas_network::facades::NodeServer::accept(&unix_listener, 10)
-                    .await
-                    .unwrap();
+                let mut server = accept_client(&socket_path).await;
 
-                server.statequery().recv_while_idle().await.unwrap();
+                let ClientAcquireRequest(acquired_point) =
+                    server.statequery().recv_while_idle().await.unwrap().unwrap();
                 server.statequery().send_acquired().await.unwrap();
 
                 for _ in 0..intersections {
                     let result = mock_server(&mut server).await;
                     server.statequery().send_result(result).await.unwrap();
                 }
+
+                acquired_point
+            }
+        })
+    }
+
+    async fn accept_client(socket_path: &Path) -> NodeServer {
+        if socket_path.exists() {
+            fs::remove_file(socket_path).expect("Previous socket removal failed");
+        }
+
+        let unix_listener = UnixListener::bind(socket_path).unwrap();
+        NodeServer::accept(&unix_listener, 10).await.unwrap()
+    }
+
+    /// Reads the first blocks of the test immutable files, with their chain points.
+    fn read_test_blocks(number_of_blocks: usize) -> Vec<(ChainPoint, Vec<u8>)> {
+        read_blocks(Path::new("../mithril-test-lab/test_data/immutable/"), "00000")
+            .unwrap()
+            .take(number_of_blocks)
+            .map(|block| {
+                let block = block.unwrap();
+                let decoded_block = MultiEraBlock::decode(&block).unwrap();
+                let chain_point = ChainPoint::new(
+                    decoded_block.slot(),
+                    decoded_block.number(),
+                    decoded_block.hash().to_string(),
+                );
+
+                (chain_point, block)
+            })
+            .collect()
+    }
+
+    /// Sets up a mock server that follows the given chain with chainsync from its first block,
+    /// and answers the epoch of the ledger state acquired at each of its blocks.
+    async fn setup_chain_server(
+        socket_path: PathBuf,
+        chain: Vec<(ChainPoint, Vec<u8>, Epoch)>,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn({
+            async move {
+                let mut server = accept_client(&socket_path).await;
+                let (tip_chain_point, _, _) = chain.last().cloned().unwrap();
+                let tip = Tip::from(tip_chain_point);
+
+                let from = match server.chainsync().recv_while_idle().await.unwrap() {
+                    Some(ClientRequest::Intersect(points)) => points.first().cloned().unwrap(),
+                    x => panic!("unexpected chainsync message from client: {x:?}"),
+                };
+                server
+                    .chainsync()
+                    .send_intersect_found(from.clone(), tip.clone())
+                    .await
+                    .unwrap();
+                server.chainsync().recv_while_idle().await.unwrap();
+                server
+                    .chainsync()
+                    .send_roll_backward(from, tip.clone())
+                    .await
+                    .unwrap();
+                for (_, block, _) in chain.iter().skip(1) {
+                    server.chainsync().recv_while_idle().await.unwrap();
+                    server
+                        .chainsync()
+                        .send_roll_forward(BlockContent(block.clone()), tip.clone())
+                        .await
+                        .unwrap();
+                }
+
+                let epoch_at = |point: &Option<Point>| {
+                    let chain_point = ChainPoint::from(point.clone().unwrap());
+                    chain
+                        .iter()
+                        .find(|(block_chain_point, _, _)| {
+                            block_chain_point.block_hash == chain_point.block_hash
+                        })
+                        .map(|(_, _, epoch)| *epoch)
+                        .unwrap()
+                };
+                let ClientAcquireRequest(mut acquired_point) =
+                    server.statequery().recv_while_idle().await.unwrap().unwrap();
+                server.statequery().send_acquired().await.unwrap();
+                loop {
+                    match server.statequery().recv_while_acquired().await.unwrap() {
+                        ClientQueryRequest::ReAcquire(point) => {
+                            acquired_point = point;
+                            server.statequery().send_acquired().await.unwrap();
+                        }
+                        ClientQueryRequest::Query(query) => {
+                            let result = match query.into_decode().unwrap() {
+                                Request::LedgerQuery(LedgerQuery::HardForkQuery(
+                                    HardForkQuery::GetCurrentEra,
+                                )) => AnyCbor::from_encode(4),
+                                Request::LedgerQuery(LedgerQuery::BlockQuery(
+                                    _,
+                                    BlockQuery::GetEpochNo,
+                                )) => AnyCbor::from_encode([*epoch_at(&acquired_point)]),
+                                query => panic!("unexpected query from client: {query:?}"),
+                            };
+                            server.statequery().send_result(result).await.unwrap();
+                        }
+                        ClientQueryRequest::Release => break,
+                    }
+                }
             }
         })
     }
@@ -710,6 +1091,90 @@ mod tests {
         assert_eq!(vec![TxDatum(r#"{"constructor":0,"fields":[{"bytes":"7b226d61726b657273223a5b7b226e616d65223a227468616c6573222c2265706f6368223a307d5d2c227369676e6174757265223a2238356632326562626164"},{"bytes":"33333537633865613264663036323039376639613138306464333564396633626131643236383263373263386431323238386661643863623864306365656562"},{"bytes":"366134643665383465653865353631376164323037313836366363313930373466326137366538373864663166393733346438343061227d"}]}"#.to_string())], datums);
     }
 
+    #[tokio::test]
+    async fn get_utxo_set_at_the_given_chain_point() {
+        let socket_path = create_temp_dir("get_utxo_set").join("node.socket");
+        let chain_point = ChainPoint::new(52851885, 1234, "010203");
+        let server = setup_server(socket_path.clone(), 2).await;
+        let client = tokio::spawn({
+            let chain_point = chain_point.clone();
+            async move {
+                let observer =
+                    PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+                observer.get_utxo_set(&chain_point).await.unwrap()
+            }
+        });
+
+        let (server_res, client_res) = tokio::join!(server, client);
+        let utxo_set = client_res.expect("Client failed");
+        assert_eq!(
+            Some(Point::Specific(52851885, vec![1, 2, 3])),
+            server_res.unwrap()
+        );
+        let (input, output) = get_fake_utxo_by_address().utxo.first().cloned().unwrap();
+        assert_eq!(
+            vec![CardanoUtxo::new(
+                "1e4e5cf2889d52f1745b941090f04a65dea6ce56c5e5e66e69f65c8e36347c17",
+                2,
+                hex::encode(minicbor::to_vec(output).unwrap()),
+            )],
+            utxo_set
+        );
+        assert_eq!(AnyUInt::MajorByte(2), input.index);
+    }
+
+    #[tokio::test]
+    async fn get_epoch_boundary_chain_point() {
+        let socket_path = create_temp_dir("get_epoch_boundary_chain_point").join("node.socket");
+        let chain: Vec<_> = read_test_blocks(6)
+            .into_iter()
+            .enumerate()
+            .map(|(index, (chain_point, block))| {
+                let epoch = if index < 4 { Epoch(8) } else { Epoch(9) };
+                (chain_point, block, epoch)
+            })
+            .collect();
+        let (from, _, _) = chain[0].clone();
+        let (expected_boundary, _, _) = chain[3].clone();
+        let server = setup_chain_server(socket_path.clone(), chain).await;
+        let client = tokio::spawn(async move {
+            let observer =
+                PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+            observer
+                .get_epoch_boundary_chain_point(Epoch(9), &from)
+                .await
+                .unwrap()
+        });
+
+        let (_, client_res) = tokio::join!(server, client);
+        let boundary = client_res.expect("Client failed");
+        assert_eq!(Some(expected_boundary), boundary);
+    }
+
+    #[tokio::test]
+    async fn get_epoch_boundary_chain_point_not_reached_yet() {
+        let socket_path =
+            create_temp_dir("get_epoch_boundary_chain_point_not_reached_yet").join("node.socket");
+        let chain: Vec<_> = read_test_blocks(3)
+            .into_iter()
+            .map(|(chain_point, block)| (chain_point, block, Epoch(8)))
+            .collect();
+        let (from, _, _) = chain[0].clone();
+        let server = setup_chain_server(socket_path.clone(), chain).await;
+        let client = tokio::spawn(async move {
+            let observer =
+                PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+            observer
+                .get_epoch_boundary_chain_point(Epoch(9), &from)
+                .await
+                .unwrap()
+        });
+
+        let (_, client_res) = tokio::join!(server, client);
+        let boundary = client_res.expect("Client failed");
+        assert_eq!(None, boundary);
+    }
+
     #[tokio::test]
     async fn get_current_stake_distribution() {
         let socket_path = create_temp_dir("get_current_stake_distribution").join("node.socket");
@@ -6,6 +6,8 @@ use crate::{
     entities::{CardanoDbBeacon, HexEncodedDigest, ImmutableFileName},
 };
 use async_trait::async_trait;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use slog::{debug, info, warn, Logger};
 use std::{collections::BTreeMap, io, path::Path, sync::Arc};
@@ -15,11 +17,61 @@ use std::{collections::BTreeMap, io, path::Path, sync::Arc};
 type CacheComputationResult =
     Result<([u8; 32], Vec<(ImmutableFileName, HexEncodedDigest)>), io::Error>;
 
+/// IO parameters of the [CardanoImmutableDigester].
+///
+/// The defaults read the immutable files one at a time with small reads, which is the best fit
+/// for local disks. When the Cardano DB is stored on a network filesystem (ie: NFS) larger reads
+/// and a higher concurrency hide the latency of each round trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImmutableDigesterIoConfig {
+    /// Size, in bytes, of the blocks read from the immutable files, default to 8 KiB.
+    #[serde(default = "ImmutableDigesterIoConfig::default_read_ahead_size")]
+    pub read_ahead_size: usize,
+
+    /// Number of immutable files hashed concurrently, default to 1.
+    #[serde(default = "ImmutableDigesterIoConfig::default_io_concurrency")]
+    pub io_concurrency: usize,
+}
+
+impl ImmutableDigesterIoConfig {
+    fn default_read_ahead_size() -> usize {
+        8 * 1024
+    }
+
+    fn default_io_concurrency() -> usize {
+        1
+    }
+}
+
+impl Default for ImmutableDigesterIoConfig {
+    fn default() -> Self {
+        Self {
+            read_ahead_size: Self::default_read_ahead_size(),
+            io_concurrency: Self::default_io_concurrency(),
+        }
+    }
+}
+
+/// Receive the progress of the immutable files hashing done by a [CardanoImmutableDigester].
+pub trait ImmutableDigesterProgressReporter: Sync + Send {
+    /// Called each time an immutable file has been hashed (or its digest found in the cache).
+    fn report(&self, hashed_files: usize, total_files: usize);
+}
+
 /// A digester working directly on a Cardano DB immutables files
 pub struct CardanoImmutableDigester {
     /// A [ImmutableFileDigestCacheProvider] instance
     cache_provider: Option<Arc<dyn ImmutableFileDigestCacheProvider>>,
 
+    /// IO parameters used when reading the immutable files
+    io_config: ImmutableDigesterIoConfig,
+
+    /// Pool hashing the immutable files concurrently, only set if the IO concurrency is above 1
+    thread_pool: Option<Arc<rayon::ThreadPool>>,
+
+    /// Receiver of the hashing progress
+    progress_reporter: Option<Arc<dyn ImmutableDigesterProgressReporter>>,
+
     /// The logger where the logs should be written
     logger: Logger,
 }
@@ -32,9 +84,45 @@ impl CardanoImmutableDigester {
     ) -> Self {
         Self {
             cache_provider,
+            io_config: ImmutableDigesterIoConfig::default(),
+            thread_pool: None,
+            progress_reporter: None,
             logger,
         }
     }
+
+    /// Set the IO parameters used when reading the immutable files
+    ///
+    /// If the pool hashing the files concurrently can't be built the files are hashed one at a
+    /// time.
+    pub fn with_io_config(mut self, io_config: ImmutableDigesterIoConfig) -> Self {
+        self.io_config = io_config;
+        self.thread_pool = None;
+        if io_config.io_concurrency > 1 {
+            match rayon::ThreadPoolBuilder::new()
+                .num_threads(io_config.io_concurrency)
+                .build()
+            {
+                Ok(thread_pool) => self.thread_pool = Some(Arc::new(thread_pool)),
+                Err(error) => warn!(
+                    self.logger,
+                    "Could not build the immutable files hashing thread pool, files will be hashed one at a time";
+                    "io_concurrency" => io_config.io_concurrency,
+                    "error" => ?error
+                ),
+            }
+        }
+        self
+    }
+
+    /// Set the receiver of the hashing progress
+    pub fn with_progress_reporter(
+        mut self,
+        progress_reporter: Arc<dyn ImmutableDigesterProgressReporter>,
+    ) -> Self {
+        self.progress_reporter = Some(progress_reporter);
+        self
+    }
 }
 
 #[async_trait]
@@ -45,7 +133,8 @@ impl ImmutableDigester for CardanoImmutableDigester {
         beacon: &CardanoDbBeacon,
     ) -> Result<String, ImmutableDigesterError> {
         let up_to_file_number = beacon.immutable_file_number;
-        let immutables = ImmutableFile::list_completed_in_dir(dirpath)?
+        let immutables =
+            ImmutableFile::list_completed_in_dir_with_network_filesystem_check(dirpath, &self.logger)?
             .into_iter()
             .filter(|f| f.number <= up_to_file_number)
             .collect::<Vec<_>>();
@@ -83,9 +172,19 @@ impl ImmutableDigester for CardanoImmutableDigester {
                 // digest is done in a separate thread because it is blocking the whole task
                 let logger = self.logger.clone();
                 let thread_beacon = beacon.clone();
+                let io_config = self.io_config;
+                let thread_pool = self.thread_pool.clone();
+                let progress_reporter = self.progress_reporter.clone();
                 let (hash, new_cache_entries) =
                     tokio::task::spawn_blocking(move || -> CacheComputationResult {
-                        compute_hash(logger, &thread_beacon, cached_values)
+                        compute_hash(
+                            logger,
+                            &thread_beacon,
+                            cached_values,
+                            io_config,
+                            thread_pool,
+                            progress_reporter,
+                        )
                     })
                     .await
                     .map_err(|e| ImmutableDigesterError::DigestComputationError(e.into()))??;
@@ -112,6 +211,9 @@ fn compute_hash(
     logger: Logger,
     beacon: &CardanoDbBeacon,
     entries: BTreeMap<ImmutableFile, Option<HexEncodedDigest>>,
+    io_config: ImmutableDigesterIoConfig,
+    thread_pool: Option<Arc<rayon::ThreadPool>>,
+    progress_reporter: Option<Arc<dyn ImmutableDigesterProgressReporter>>,
 ) -> CacheComputationResult {
     let mut hasher = Sha256::new();
     let mut new_cached_entries = Vec::new();
@@ -119,23 +221,63 @@ fn compute_hash(
         index: 0,
         total: entries.len(),
     };
+    let io_concurrency = thread_pool
+        .as_ref()
+        .map(|pool| pool.current_num_threads())
+        .unwrap_or(1);
+    let hash_entry = |entry: &ImmutableFile| -> Result<HexEncodedDigest, io::Error> {
+        Ok(hex::encode(
+            entry.compute_raw_hash_with_read_ahead::<Sha256>(io_config.read_ahead_size.max(1))?,
+        ))
+    };
 
     hasher.update(beacon.compute_hash().as_bytes());
 
-    for (ix, (entry, cache)) in entries.iter().enumerate() {
-        match cache {
-            None => {
-                let data = hex::encode(entry.compute_raw_hash::<Sha256>()?);
-                hasher.update(&data);
-                new_cached_entries.push((entry.filename.clone(), data));
+    // Entries are processed by windows: the uncached entries of a window are hashed
+    // concurrently, then all the window digests are fed, in order, to the hasher.
+    let entries = entries.into_iter().collect::<Vec<_>>();
+    let window_size = io_concurrency * 4;
+    for (window_ix, window) in entries.chunks(window_size).enumerate() {
+        let uncached_entries = window
+            .iter()
+            .filter(|(_, cache)| cache.is_none())
+            .map(|(entry, _)| entry)
+            .collect::<Vec<_>>();
+        let mut computed_digests = match &thread_pool {
+            Some(pool) => pool.install(|| {
+                uncached_entries
+                    .par_iter()
+                    .map(|entry| hash_entry(entry))
+                    .collect::<Result<Vec<_>, _>>()
+            })?,
+            None => uncached_entries
+                .iter()
+                .map(|entry| hash_entry(entry))
+                .collect::<Result<Vec<_>, _>>()?,
+        }
+        .into_iter();
+
+        for (entry_ix, (entry, cache)) in window.iter().enumerate() {
+            match cache {
+                None => {
+                    let data = computed_digests
+                        .next()
+                        .expect("a digest is computed for each uncached entry");
+                    hasher.update(&data);
+                    new_cached_entries.push((entry.filename.clone(), data));
+                }
+                Some(digest) => {
+                    hasher.update(digest);
+                }
+            };
+
+            let entry_index = window_ix * window_size + entry_ix;
+            if progress.report(entry_index) {
+                info!(logger, "hashing: {}", &progress);
             }
-            Some(digest) => {
-                hasher.update(digest);
+            if let Some(progress_reporter) = &progress_reporter {
+                progress_reporter.report(entry_index + 1, progress.total);
             }
-        };
-
-        if progress.report(ix) {
-            info!(logger, "hashing: {}", &progress);
         }
     }
 
@@ -175,13 +317,17 @@ mod tests {
                 MemoryImmutableFileDigestCacheProvider, MockImmutableFileDigestCacheProvider,
             },
             CardanoImmutableDigester, DummyImmutablesDbBuilder, ImmutableDigester,
-            ImmutableDigesterError,
+            ImmutableDigesterError, ImmutableDigesterIoConfig, ImmutableDigesterProgressReporter,
         },
         entities::{CardanoDbBeacon, ImmutableFileNumber},
         test_utils::TestLogger,
     };
     use sha2::Sha256;
-    use std::{collections::BTreeMap, io, sync::Arc};
+    use std::{
+        collections::BTreeMap,
+        io,
+        sync::{Arc, Mutex},
+    };
     use tokio::time::Instant;
 
     fn db_builder(dir_name: &str) -> DummyImmutablesDbBuilder {
@@ -329,6 +475,46 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn can_compute_hash_of_a_hundred_immutable_file_trio_with_concurrent_io() {
+        let immutable_db =
+            db_builder("can_compute_hash_of_a_hundred_immutable_file_trio_with_concurrent_io")
+                .with_immutables(&(1..=100).collect::<Vec<ImmutableFileNumber>>())
+                .append_immutable_trio()
+                .build();
+        let digester = CardanoImmutableDigester::new(None, TestLogger::stdout()).with_io_config(
+            ImmutableDigesterIoConfig {
+                read_ahead_size: 1024 * 1024,
+                io_concurrency: 4,
+            },
+        );
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 100);
+
+        let result = digester
+            .compute_digest(&immutable_db.dir, &beacon)
+            .await
+            .expect("compute_digest must not fail");
+
+        assert_eq!(
+            "a27fd67e495c2c77e4b6b0af9925b2b0bc39656c56adfad4aaab9f20fae49122".to_string(),
+            result
+        )
+    }
+
+    #[test]
+    fn io_config_missing_fields_are_set_to_their_default() {
+        let io_config: ImmutableDigesterIoConfig =
+            serde_json::from_str(r#"{ "read_ahead_size": 1048576 }"#).unwrap();
+
+        assert_eq!(
+            ImmutableDigesterIoConfig {
+                read_ahead_size: 1048576,
+                ..ImmutableDigesterIoConfig::default()
+            },
+            io_config
+        );
+    }
+
     #[tokio::test]
     async fn digests_are_stored_into_cache_provider() {
         let immutable_db = db_builder("digests_are_stored_into_cache_provider")
@@ -485,4 +671,38 @@ mod tests {
             .await
             .expect("compute_digest must not fail even with cache read failure");
     }
+
+    #[derive(Default)]
+    struct StackProgressReporter {
+        reports: Mutex<Vec<(usize, usize)>>,
+    }
+
+    impl ImmutableDigesterProgressReporter for StackProgressReporter {
+        fn report(&self, hashed_files: usize, total_files: usize) {
+            self.reports
+                .lock()
+                .unwrap()
+                .push((hashed_files, total_files));
+        }
+    }
+
+    #[tokio::test]
+    async fn report_progress_for_each_hashed_immutable_file() {
+        let immutable_db = db_builder("report_progress_for_each_hashed_immutable_file")
+            .with_immutables(&[1, 2])
+            .append_immutable_trio()
+            .build();
+        let progress_reporter = Arc::new(StackProgressReporter::default());
+        let digester = CardanoImmutableDigester::new(None, TestLogger::stdout())
+            .with_progress_reporter(progress_reporter.clone());
+        let beacon = CardanoDbBeacon::new("devnet".to_string(), 1, 2);
+
+        digester
+            .compute_digest(&immutable_db.dir, &beacon)
+            .await
+            .expect("compute_digest must not fail");
+
+        let expected_reports = (1..=6).map(|hashed| (hashed, 6)).collect::<Vec<_>>();
+        assert_eq!(expected_reports, *progress_reporter.reports.lock().unwrap());
+    }
 }
@@ -1,13 +1,17 @@
 use crate::entities::{ImmutableFileName, ImmutableFileNumber};
 
 use crate::digesters::ImmutableFileListingError::MissingImmutableFolder;
+use crate::digesters::NetworkFilesystemMount;
 use digest::{Digest, Output};
+use slog::{info, warn, Logger};
 use std::{
     cmp::Ordering,
+    collections::BTreeSet,
     fs::File,
-    io,
+    io::{self, BufReader},
     num::ParseIntError,
     path::{Path, PathBuf},
+    sync::Mutex,
 };
 use thiserror::Error;
 use walkdir::WalkDir;
@@ -31,6 +35,40 @@ fn find_immutables_dir(path_to_walk: &Path) -> Option<PathBuf> {
         .map(|e| e.into_path())
 }
 
+/// Immutable directories already checked for network filesystem pitfalls
+static NETWORK_FILESYSTEM_CHECKED_DIRS: Mutex<BTreeSet<PathBuf>> = Mutex::new(BTreeSet::new());
+
+/// Return true the first time it's called for the given immutable directory
+fn is_first_network_filesystem_check(immutable_dir: &Path) -> bool {
+    NETWORK_FILESYSTEM_CHECKED_DIRS
+        .lock()
+        .map(|mut checked_dirs| checked_dirs.insert(immutable_dir.to_path_buf()))
+        .unwrap_or_default()
+}
+
+/// Warn if the immutable directory is on a NFS mount that caches files attributes since its
+/// listing may then be stale.
+fn check_network_filesystem(immutable_dir: &Path, logger: &Logger) {
+    if let Some(mount) = NetworkFilesystemMount::detect(immutable_dir) {
+        if mount.has_attribute_caching() {
+            warn!(
+                logger,
+                "Cardano database is stored on a NFS mount with attribute caching enabled, recently written immutable files may be missed or seen with a stale size. Mount it with the `noac` or `actimeo=0` option to avoid this.";
+                "immutable_dir" => %immutable_dir.display(),
+                "mount_point" => %mount.mount_point.display(),
+                "filesystem_type" => &mount.filesystem_type,
+            );
+        } else {
+            info!(
+                logger,
+                "Cardano database is stored on a NFS mount";
+                "immutable_dir" => %immutable_dir.display(),
+                "mount_point" => %mount.mount_point.display(),
+            );
+        }
+    }
+}
+
 /// Represent an immutable file in a Cardano node database directory
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ImmutableFile {
@@ -127,6 +165,23 @@ impl ImmutableFile {
         Ok(hasher.finalize())
     }
 
+    /// Compute the hash of this immutable file, reading it by blocks of `read_ahead_size` bytes.
+    ///
+    /// Large blocks reduce the number of round trips when the file is stored on a network
+    /// filesystem.
+    pub fn compute_raw_hash_with_read_ahead<D>(
+        &self,
+        read_ahead_size: usize,
+    ) -> Result<Output<D>, io::Error>
+    where
+        D: Digest + io::Write,
+    {
+        let mut hasher = D::new();
+        let mut reader = BufReader::with_capacity(read_ahead_size, File::open(&self.path)?);
+        io::copy(&mut reader, &mut hasher)?;
+        Ok(hasher.finalize())
+    }
+
     /// List all [`ImmutableFile`] in a given directory.
     ///
     /// Important Note: It will skip the last chunk / primary / secondary trio since they're not yet
@@ -136,6 +191,30 @@ impl ImmutableFile {
     ) -> Result<Vec<ImmutableFile>, ImmutableFileListingError> {
         let immutable_dir =
             find_immutables_dir(dir).ok_or(MissingImmutableFolder(dir.to_path_buf()))?;
+
+        Self::list_completed_in_immutable_dir(immutable_dir)
+    }
+
+    /// List all [`ImmutableFile`] in a given directory, like [ImmutableFile::list_completed_in_dir].
+    ///
+    /// The first time a directory is listed, a warning is logged if it's stored on a NFS mount
+    /// that caches the files attributes, since the listing can then miss recently written files.
+    pub fn list_completed_in_dir_with_network_filesystem_check(
+        dir: &Path,
+        logger: &Logger,
+    ) -> Result<Vec<ImmutableFile>, ImmutableFileListingError> {
+        let immutable_dir =
+            find_immutables_dir(dir).ok_or(MissingImmutableFolder(dir.to_path_buf()))?;
+        if is_first_network_filesystem_check(&immutable_dir) {
+            check_network_filesystem(&immutable_dir, logger);
+        }
+
+        Self::list_completed_in_immutable_dir(immutable_dir)
+    }
+
+    fn list_completed_in_immutable_dir(
+        immutable_dir: PathBuf,
+    ) -> Result<Vec<ImmutableFile>, ImmutableFileListingError> {
         let mut files: Vec<ImmutableFile> = vec![];
 
         for path in WalkDir::new(immutable_dir)
@@ -181,8 +260,8 @@ impl Ord for ImmutableFile {
 
 #[cfg(test)]
 mod tests {
-    use super::ImmutableFile;
-    use crate::test_utils::TempDir;
+    use super::{is_first_network_filesystem_check, ImmutableFile};
+    use crate::test_utils::{TempDir, TestLogger};
     use std::fs::File;
     use std::io::prelude::*;
     use std::path::{Path, PathBuf};
@@ -352,4 +431,51 @@ mod tests {
         let expected: Vec<&str> = entries.into_iter().rev().skip(1).rev().collect();
         assert_eq!(expected, immutables_names);
     }
+
+    #[test]
+    fn compute_raw_hash_with_read_ahead_yield_the_same_hash() {
+        let target_dir = get_test_dir("compute_raw_hash_with_read_ahead/immutable");
+        let path = target_dir.join("00001.chunk");
+        let mut file = File::create(&path).unwrap();
+        write!(file, "{}", "cardano chunk content ".repeat(1024)).unwrap();
+        let immutable = ImmutableFile::new(path).unwrap();
+
+        let expected = immutable.compute_raw_hash::<sha2::Sha256>().unwrap();
+
+        for read_ahead_size in [1, 100, 4 * 1024 * 1024] {
+            assert_eq!(
+                expected,
+                immutable
+                    .compute_raw_hash_with_read_ahead::<sha2::Sha256>(read_ahead_size)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn list_immutable_file_with_network_filesystem_check_yield_the_same_files() {
+        let target_dir = get_test_dir(
+            "list_immutable_file_with_network_filesystem_check_yield_the_same_files/immutable",
+        );
+        let entries = vec!["21.chunk", "21.primary", "21.secondary", "123.chunk"];
+        create_fake_files(&target_dir, &entries);
+
+        let expected = ImmutableFile::list_completed_in_dir(target_dir.parent().unwrap()).unwrap();
+        let immutables = ImmutableFile::list_completed_in_dir_with_network_filesystem_check(
+            target_dir.parent().unwrap(),
+            &TestLogger::stdout(),
+        )
+        .unwrap();
+
+        assert_eq!(expected, immutables);
+    }
+
+    #[test]
+    fn network_filesystem_is_checked_once_per_immutable_dir() {
+        let target_dir = get_test_dir("network_filesystem_is_checked_once_per_immutable_dir");
+
+        assert!(is_first_network_filesystem_check(&target_dir.join("immutable-1")));
+        assert!(is_first_network_filesystem_check(&target_dir.join("immutable-2")));
+        assert!(!is_first_network_filesystem_check(&target_dir.join("immutable-1")));
+    }
 }
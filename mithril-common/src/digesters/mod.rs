@@ -6,14 +6,18 @@ mod dumb_immutable_observer;
 mod immutable_digester;
 mod immutable_file;
 mod immutable_file_observer;
+mod network_filesystem;
 
-pub use cardano_immutable_digester::CardanoImmutableDigester;
+pub use cardano_immutable_digester::{
+    CardanoImmutableDigester, ImmutableDigesterIoConfig, ImmutableDigesterProgressReporter,
+};
 pub use immutable_digester::{ImmutableDigester, ImmutableDigesterError};
 pub use immutable_file::{ImmutableFile, ImmutableFileCreationError, ImmutableFileListingError};
 pub use immutable_file_observer::{
     DumbImmutableFileObserver, ImmutableFileObserver, ImmutableFileObserverError,
     ImmutableFileSystemObserver,
 };
+pub use network_filesystem::NetworkFilesystemMount;
 
 pub use dumb_immutable_observer::DumbImmutableDigester;
 
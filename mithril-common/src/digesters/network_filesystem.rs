@@ -0,0 +1,115 @@
+use std::path::{Path, PathBuf};
+
+/// Information about a network filesystem (NFS) mount on which a Cardano DB is stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkFilesystemMount {
+    /// Type of the filesystem (ie: `nfs` or `nfs4`)
+    pub filesystem_type: String,
+
+    /// Mount point of the filesystem
+    pub mount_point: PathBuf,
+
+    /// Mount options of the filesystem
+    pub options: Vec<String>,
+}
+
+impl NetworkFilesystemMount {
+    /// Detect if the given path is stored on a NFS mount.
+    ///
+    /// Only supported on Linux (using `/proc/mounts`), always return `None` on other platforms.
+    pub fn detect(path: &Path) -> Option<Self> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+
+        let path = path.canonicalize().ok()?;
+        let mounts = std::fs::read_to_string("/proc/mounts").ok()?;
+
+        Self::find_in_mounts(&mounts, &path)
+    }
+
+    /// Find the NFS mount containing the given path in a `/proc/mounts` formatted content.
+    ///
+    /// The most specific mount point containing the path is used, so a local filesystem
+    /// mounted inside a NFS mount is not reported.
+    pub fn find_in_mounts(mounts: &str, path: &Path) -> Option<Self> {
+        mounts
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let _device = fields.next()?;
+                let mount_point = PathBuf::from(fields.next()?.replace("\\040", " "));
+                let filesystem_type = fields.next()?.to_string();
+                let options = fields.next()?.split(',').map(|o| o.to_string()).collect();
+
+                Some(Self {
+                    filesystem_type,
+                    mount_point,
+                    options,
+                })
+            })
+            .filter(|mount| path.starts_with(&mount.mount_point))
+            .max_by_key(|mount| mount.mount_point.components().count())
+            .filter(|mount| mount.filesystem_type.starts_with("nfs"))
+    }
+
+    /// Check if the attributes of the files are cached by the NFS client.
+    ///
+    /// With attribute caching a lister running on the NFS client can see stale sizes or miss
+    /// immutable files recently written by the Cardano node, disabling it requires to mount
+    /// the filesystem with either the `noac` or the `actimeo=0` option.
+    pub fn has_attribute_caching(&self) -> bool {
+        !self
+            .options
+            .iter()
+            .any(|option| option == "noac" || option == "actimeo=0")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOUNTS: &str = "\
+sysfs /sys sysfs rw,nosuid,nodev,noexec,relatime 0 0
+/dev/sda1 / ext4 rw,relatime 0 0
+nas:/export/cardano /mnt/cardano nfs4 rw,relatime,vers=4.2,rsize=1048576,wsize=1048576,hard,proto=tcp 0 0
+nas:/export/no-cache /mnt/no-cache nfs rw,noac,vers=3,hard,proto=tcp 0 0
+/dev/sdb1 /mnt/cardano/local ext4 rw,relatime 0 0
+";
+
+    #[test]
+    fn path_on_local_filesystem_is_not_detected() {
+        assert_eq!(
+            None,
+            NetworkFilesystemMount::find_in_mounts(MOUNTS, Path::new("/home/cardano/db"))
+        );
+    }
+
+    #[test]
+    fn path_on_nfs_mount_is_detected() {
+        let mount =
+            NetworkFilesystemMount::find_in_mounts(MOUNTS, Path::new("/mnt/cardano/db/immutable"))
+                .expect("path should be detected as stored on a NFS mount");
+
+        assert_eq!("nfs4", mount.filesystem_type);
+        assert_eq!(PathBuf::from("/mnt/cardano"), mount.mount_point);
+        assert!(mount.has_attribute_caching());
+    }
+
+    #[test]
+    fn most_specific_mount_point_is_used() {
+        assert_eq!(
+            None,
+            NetworkFilesystemMount::find_in_mounts(MOUNTS, Path::new("/mnt/cardano/local/db"))
+        );
+    }
+
+    #[test]
+    fn nfs_mount_with_noac_option_has_no_attribute_caching() {
+        let mount = NetworkFilesystemMount::find_in_mounts(MOUNTS, Path::new("/mnt/no-cache/db"))
+            .expect("path should be detected as stored on a NFS mount");
+
+        assert!(!mount.has_attribute_caching());
+    }
+}
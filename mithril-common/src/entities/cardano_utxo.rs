@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{crypto_helper::MKTreeNode, entities::TransactionHash};
+
+/// Unspent transaction output of the Cardano ledger.
+///
+/// The derived ordering, by transaction hash then output index, is the canonical order of the
+/// UTxO set.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct CardanoUtxo {
+    /// Hash of the transaction that created the output
+    pub transaction_hash: TransactionHash,
+
+    /// Index of the output in the transaction
+    pub output_index: u64,
+
+    /// Hex encoded CBOR of the transaction output, as stored in the ledger
+    pub output: String,
+}
+
+impl CardanoUtxo {
+    /// CardanoUtxo factory
+    pub fn new<T: Into<TransactionHash>, U: Into<String>>(
+        transaction_hash: T,
+        output_index: u64,
+        output: U,
+    ) -> Self {
+        Self {
+            transaction_hash: transaction_hash.into(),
+            output_index,
+            output: output.into(),
+        }
+    }
+
+    /// Canonical serialization of the UTxO: `{transaction_hash}#{output_index}:{output}`
+    pub fn canonical_serialization(&self) -> String {
+        format!(
+            "{}#{}:{}",
+            self.transaction_hash, self.output_index, self.output
+        )
+    }
+}
+
+impl From<CardanoUtxo> for MKTreeNode {
+    fn from(other: CardanoUtxo) -> Self {
+        (&other).into()
+    }
+}
+
+impl From<&CardanoUtxo> for MKTreeNode {
+    fn from(other: &CardanoUtxo) -> Self {
+        MKTreeNode::new(other.canonical_serialization().into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_serialization() {
+        let utxo = CardanoUtxo::new("tx-hash-123", 2, "a200581d");
+
+        assert_eq!("tx-hash-123#2:a200581d", utxo.canonical_serialization());
+    }
+
+    #[test]
+    fn test_canonical_order_is_by_transaction_hash_then_output_index() {
+        let mut utxos = vec![
+            CardanoUtxo::new("tx-hash-2", 0, "00"),
+            CardanoUtxo::new("tx-hash-1", 10, "00"),
+            CardanoUtxo::new("tx-hash-1", 2, "ff"),
+        ];
+        utxos.sort();
+
+        assert_eq!(
+            vec![
+                CardanoUtxo::new("tx-hash-1", 2, "ff"),
+                CardanoUtxo::new("tx-hash-1", 10, "00"),
+                CardanoUtxo::new("tx-hash-2", 0, "00"),
+            ],
+            utxos
+        );
+    }
+}
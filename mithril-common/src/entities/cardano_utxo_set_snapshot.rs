@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::signable_builder::Artifact;
+
+use super::Epoch;
+
+/// Snapshot of the Cardano UTxO set at the boundary of an epoch
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoUtxoSetSnapshot {
+    /// Hash of the Cardano UTxO set snapshot
+    pub hash: String,
+
+    /// Merkle root of the Cardano UTxO set
+    pub merkle_root: String,
+
+    /// Epoch at the boundary of which the UTxO set was taken
+    pub epoch: Epoch,
+
+    /// Number of UTxOs in the set
+    pub utxos_count: u64,
+
+    /// Locations where the canonical serialization of the UTxO set can be downloaded
+    pub locations: Vec<String>,
+}
+
+impl CardanoUtxoSetSnapshot {
+    /// Creates a new [CardanoUtxoSetSnapshot]
+    pub fn new(
+        merkle_root: String,
+        epoch: Epoch,
+        utxos_count: u64,
+        locations: Vec<String>,
+    ) -> Self {
+        let mut cardano_utxo_set_snapshot = Self {
+            hash: "".to_string(),
+            merkle_root,
+            epoch,
+            utxos_count,
+            locations,
+        };
+        cardano_utxo_set_snapshot.hash = cardano_utxo_set_snapshot.compute_hash();
+        cardano_utxo_set_snapshot
+    }
+
+    /// Compute the hash of a Cardano UTxO set snapshot from its merkle root and epoch
+    pub fn compute_hash_from(merkle_root: &str, epoch: Epoch) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(merkle_root.as_bytes());
+        hasher.update(epoch.to_be_bytes());
+
+        hex::encode(hasher.finalize())
+    }
+
+    /// Cardano UTxO set snapshot hash computation
+    fn compute_hash(&self) -> String {
+        Self::compute_hash_from(&self.merkle_root, self.epoch)
+    }
+}
+
+#[typetag::serde]
+impl Artifact for CardanoUtxoSetSnapshot {
+    fn get_id(&self) -> String {
+        self.hash.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cardano_utxo_set_snapshot_compute_hash() {
+        let hash_expected =
+            CardanoUtxoSetSnapshot::new("mk-root-123".to_string(), Epoch(5), 10, vec![]).hash;
+
+        assert_eq!(
+            hash_expected,
+            CardanoUtxoSetSnapshot::new(
+                "mk-root-123".to_string(),
+                Epoch(5),
+                20,
+                vec!["location".to_string()]
+            )
+            .compute_hash()
+        );
+
+        assert_ne!(
+            hash_expected,
+            CardanoUtxoSetSnapshot::new("mk-root-456".to_string(), Epoch(5), 10, vec![])
+                .compute_hash()
+        );
+
+        assert_ne!(
+            hash_expected,
+            CardanoUtxoSetSnapshot::new("mk-root-123".to_string(), Epoch(6), 10, vec![])
+                .compute_hash()
+        );
+    }
+}
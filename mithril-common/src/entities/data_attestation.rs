@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::{Display, Formatter};
+
+use crate::signable_builder::{Artifact, Beacon};
+
+use super::Epoch;
+
+/// A payload hash submitted by an operator to be certified at a given epoch.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DataAttestationBeacon {
+    /// Epoch at which the attestation was submitted
+    pub epoch: Epoch,
+
+    /// Namespace of the attested data, ie: `db-sync-snapshot`
+    pub namespace: String,
+
+    /// Hex encoded hash of the attested payload
+    pub payload_hash: String,
+}
+
+impl DataAttestationBeacon {
+    /// DataAttestationBeacon factory
+    pub fn new<T: Into<String>, U: Into<String>>(
+        epoch: Epoch,
+        namespace: T,
+        payload_hash: U,
+    ) -> Self {
+        Self {
+            epoch,
+            namespace: namespace.into(),
+            payload_hash: payload_hash.into(),
+        }
+    }
+}
+
+impl Beacon for DataAttestationBeacon {}
+
+impl Display for DataAttestationBeacon {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DataAttestationBeacon (epoch: {}, namespace: {}, payload_hash: {})",
+            self.epoch, self.namespace, self.payload_hash
+        )
+    }
+}
+
+/// A payload hash certified on behalf of an operator
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataAttestation {
+    /// Hash of the data attestation
+    pub hash: String,
+
+    /// Epoch at which the attestation was submitted
+    pub epoch: Epoch,
+
+    /// Namespace of the attested data
+    pub namespace: String,
+
+    /// Hex encoded hash of the attested payload
+    pub payload_hash: String,
+}
+
+impl DataAttestation {
+    /// Creates a new [DataAttestation] from its beacon
+    pub fn new(beacon: DataAttestationBeacon) -> Self {
+        let mut data_attestation = Self {
+            hash: "".to_string(),
+            epoch: beacon.epoch,
+            namespace: beacon.namespace,
+            payload_hash: beacon.payload_hash,
+        };
+        data_attestation.hash = data_attestation.compute_hash();
+        data_attestation
+    }
+
+    /// Data attestation hash computation
+    fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.epoch.to_be_bytes());
+        hasher.update(self.namespace.as_bytes());
+        hasher.update(self.payload_hash.as_bytes());
+
+        hex::encode(hasher.finalize())
+    }
+}
+
+#[typetag::serde]
+impl Artifact for DataAttestation {
+    fn get_id(&self) -> String {
+        self.hash.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_attestation_compute_hash() {
+        let hash_expected =
+            DataAttestation::new(DataAttestationBeacon::new(Epoch(5), "namespace", "abc123")).hash;
+
+        assert_ne!(
+            hash_expected,
+            DataAttestation::new(DataAttestationBeacon::new(Epoch(6), "namespace", "abc123"))
+                .compute_hash()
+        );
+
+        assert_ne!(
+            hash_expected,
+            DataAttestation::new(DataAttestationBeacon::new(Epoch(5), "other", "abc123"))
+                .compute_hash()
+        );
+
+        assert_ne!(
+            hash_expected,
+            DataAttestation::new(DataAttestationBeacon::new(Epoch(5), "namespace", "def456"))
+                .compute_hash()
+        );
+    }
+}
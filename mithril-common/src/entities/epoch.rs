@@ -7,6 +7,7 @@ use std::{
 };
 use thiserror::Error;
 
+use crate::entities::EpochSettingsCalculator;
 use crate::signable_builder::Beacon as SignableBeacon;
 
 /// Epoch represents a Cardano epoch
@@ -16,6 +17,9 @@ use crate::signable_builder::Beacon as SignableBeacon;
 pub struct Epoch(pub u64);
 
 impl Epoch {
+    // The offsets below are the defaults of the [EpochSettingsCalculator], the `offset_to_*`
+    // methods delegate to it so the rules are applied in a single place.
+
     /// The epoch offset used for signers stake distribution and verification keys retrieval.
     pub const SIGNER_RETRIEVAL_OFFSET: i64 = -1;
 
@@ -46,27 +50,27 @@ impl Epoch {
 
     /// Apply the [retrieval offset][Self::SIGNER_RETRIEVAL_OFFSET] to this epoch
     pub fn offset_to_signer_retrieval_epoch(&self) -> Result<Self, EpochError> {
-        self.offset_by(Self::SIGNER_RETRIEVAL_OFFSET)
+        EpochSettingsCalculator::default().signer_retrieval_epoch(*self)
     }
 
     /// Apply the [next signer retrieval offset][Self::NEXT_SIGNER_RETRIEVAL_OFFSET] to this epoch
     pub fn offset_to_next_signer_retrieval_epoch(&self) -> Self {
-        *self + Self::NEXT_SIGNER_RETRIEVAL_OFFSET
+        EpochSettingsCalculator::default().next_signer_retrieval_epoch(*self)
     }
 
     /// Apply the [recording offset][Self::SIGNER_RECORDING_OFFSET] to this epoch
     pub fn offset_to_recording_epoch(&self) -> Self {
-        *self + Self::SIGNER_RECORDING_OFFSET
+        EpochSettingsCalculator::default().signer_registration_epoch(*self)
     }
 
     /// Apply the [protocol parameters recording offset][Self::PROTOCOL_PARAMETERS_RECORDING_OFFSET] to this epoch
     pub fn offset_to_protocol_parameters_recording_epoch(&self) -> Self {
-        *self + Self::PROTOCOL_PARAMETERS_RECORDING_OFFSET
+        EpochSettingsCalculator::default().protocol_parameters_recording_epoch(*self)
     }
 
     /// Apply the [signer signing offset][Self::SIGNER_SIGNING_OFFSET] to this epoch
     pub fn offset_to_signer_signing_offset(&self) -> Self {
-        *self + Self::SIGNER_SIGNING_OFFSET
+        EpochSettingsCalculator::default().signer_signing_epoch(*self)
     }
 
     /// Computes the next Epoch
@@ -0,0 +1,206 @@
+use crate::entities::{Epoch, EpochError};
+
+/// Epochs at which the signers and protocol parameters data are recorded, retrieved and used,
+/// computed for a given epoch by an [EpochSettingsCalculator].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EpochSchedule {
+    /// Epoch for which the schedule is computed
+    pub current_epoch: Epoch,
+
+    /// Epoch of the signers stake distribution and verification keys used to sign during the
+    /// current epoch
+    pub signer_retrieval_epoch: Epoch,
+
+    /// Epoch of the signers stake distribution and verification keys that will be used to sign
+    /// during the next epoch
+    pub next_signer_retrieval_epoch: Epoch,
+
+    /// Epoch at which the signers registering during the current epoch are recorded
+    pub signer_registration_epoch: Epoch,
+
+    /// Epoch at which the signers registering during the current epoch can send single signatures
+    pub signer_signing_epoch: Epoch,
+
+    /// Epoch at which the protocol parameters set during the current epoch are recorded
+    pub protocol_parameters_recording_epoch: Epoch,
+}
+
+/// Offsets, in number of epochs, applied by an [EpochSettingsCalculator].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EpochOffsets {
+    /// Offset used for signers stake distribution and verification keys retrieval
+    pub signer_retrieval: i64,
+
+    /// Offset used to retrieve the signers stake distribution and verification keys that's
+    /// currently being signed so it can be used in the next epoch
+    pub next_signer_retrieval: u64,
+
+    /// Offset used for signers stake distribution and verification keys recording
+    pub signer_recording: u64,
+
+    /// Offset used for the protocol parameters recording
+    pub protocol_parameters_recording: u64,
+
+    /// Offset used to retrieve, given the epoch at which a signer registered, the epoch at which
+    /// the signer can send single signatures
+    pub signer_signing: u64,
+}
+
+impl Default for EpochOffsets {
+    fn default() -> Self {
+        Self {
+            signer_retrieval: Epoch::SIGNER_RETRIEVAL_OFFSET,
+            next_signer_retrieval: Epoch::NEXT_SIGNER_RETRIEVAL_OFFSET,
+            signer_recording: Epoch::SIGNER_RECORDING_OFFSET,
+            protocol_parameters_recording: Epoch::PROTOCOL_PARAMETERS_RECORDING_OFFSET,
+            signer_signing: Epoch::SIGNER_SIGNING_OFFSET,
+        }
+    }
+}
+
+/// Single place where the epoch offset rules shared by the aggregator and the signer are applied.
+///
+/// The `offset_to_*` methods of [Epoch] are shortcuts to the default calculator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EpochSettingsCalculator {
+    offsets: EpochOffsets,
+}
+
+impl EpochSettingsCalculator {
+    /// EpochSettingsCalculator factory
+    pub fn new(offsets: EpochOffsets) -> Self {
+        Self { offsets }
+    }
+
+    /// Offsets applied by this calculator
+    pub fn offsets(&self) -> EpochOffsets {
+        self.offsets
+    }
+
+    /// Epoch of the signers data used to sign during the given epoch.
+    ///
+    /// Will fail if the computed epoch is negative.
+    pub fn signer_retrieval_epoch(&self, epoch: Epoch) -> Result<Epoch, EpochError> {
+        epoch.offset_by(self.offsets.signer_retrieval)
+    }
+
+    /// Epoch of the signers data that will be used to sign during the epoch after the given one.
+    pub fn next_signer_retrieval_epoch(&self, epoch: Epoch) -> Epoch {
+        epoch + self.offsets.next_signer_retrieval
+    }
+
+    /// Epoch at which the signers registering during the given epoch are recorded.
+    pub fn signer_registration_epoch(&self, epoch: Epoch) -> Epoch {
+        epoch + self.offsets.signer_recording
+    }
+
+    /// Epoch at which the protocol parameters set during the given epoch are recorded.
+    pub fn protocol_parameters_recording_epoch(&self, epoch: Epoch) -> Epoch {
+        epoch + self.offsets.protocol_parameters_recording
+    }
+
+    /// Epoch at which a signer registered at the given epoch can send single signatures.
+    pub fn signer_signing_epoch(&self, registration_epoch: Epoch) -> Epoch {
+        registration_epoch + self.offsets.signer_signing
+    }
+
+    /// Compute the whole [EpochSchedule] of the given epoch.
+    ///
+    /// Will fail if one of the computed epochs is negative.
+    pub fn compute_schedule(&self, current_epoch: Epoch) -> Result<EpochSchedule, EpochError> {
+        let signer_registration_epoch = self.signer_registration_epoch(current_epoch);
+
+        Ok(EpochSchedule {
+            current_epoch,
+            signer_retrieval_epoch: self.signer_retrieval_epoch(current_epoch)?,
+            next_signer_retrieval_epoch: self.next_signer_retrieval_epoch(current_epoch),
+            signer_registration_epoch,
+            signer_signing_epoch: self.signer_signing_epoch(signer_registration_epoch),
+            protocol_parameters_recording_epoch: self
+                .protocol_parameters_recording_epoch(current_epoch),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_schedule_with_default_offsets() {
+        let schedule = EpochSettingsCalculator::default()
+            .compute_schedule(Epoch(10))
+            .unwrap();
+
+        assert_eq!(
+            EpochSchedule {
+                current_epoch: Epoch(10),
+                signer_retrieval_epoch: Epoch(9),
+                next_signer_retrieval_epoch: Epoch(10),
+                signer_registration_epoch: Epoch(11),
+                signer_signing_epoch: Epoch(13),
+                protocol_parameters_recording_epoch: Epoch(12),
+            },
+            schedule
+        );
+    }
+
+    #[test]
+    fn default_calculator_match_epoch_shortcuts() {
+        let calculator = EpochSettingsCalculator::default();
+        let epoch = Epoch(42);
+
+        assert_eq!(
+            epoch.offset_to_signer_retrieval_epoch().unwrap(),
+            calculator.signer_retrieval_epoch(epoch).unwrap()
+        );
+        assert_eq!(
+            epoch.offset_to_next_signer_retrieval_epoch(),
+            calculator.next_signer_retrieval_epoch(epoch)
+        );
+        assert_eq!(
+            epoch.offset_to_recording_epoch(),
+            calculator.signer_registration_epoch(epoch)
+        );
+        assert_eq!(
+            epoch.offset_to_protocol_parameters_recording_epoch(),
+            calculator.protocol_parameters_recording_epoch(epoch)
+        );
+        assert_eq!(
+            epoch.offset_to_signer_signing_offset(),
+            calculator.signer_signing_epoch(epoch)
+        );
+    }
+
+    #[test]
+    fn compute_schedule_with_custom_offsets() {
+        let calculator = EpochSettingsCalculator::new(EpochOffsets {
+            signer_retrieval: -2,
+            next_signer_retrieval: 1,
+            signer_recording: 3,
+            protocol_parameters_recording: 4,
+            signer_signing: 5,
+        });
+
+        let schedule = calculator.compute_schedule(Epoch(10)).unwrap();
+
+        assert_eq!(
+            EpochSchedule {
+                current_epoch: Epoch(10),
+                signer_retrieval_epoch: Epoch(8),
+                next_signer_retrieval_epoch: Epoch(11),
+                signer_registration_epoch: Epoch(13),
+                signer_signing_epoch: Epoch(18),
+                protocol_parameters_recording_epoch: Epoch(14),
+            },
+            schedule
+        );
+    }
+
+    #[test]
+    fn compute_schedule_fail_if_an_epoch_is_negative() {
+        EpochSettingsCalculator::default()
+            .compute_schedule(Epoch(0))
+            .expect_err("Signer retrieval epoch of epoch 0 should be negative");
+    }
+}
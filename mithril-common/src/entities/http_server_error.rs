@@ -52,3 +52,65 @@ impl ClientError {
         }
     }
 }
+
+/// Representation of the error raised by an http server when the API version sent by a client
+/// does not satisfy the version requirement of the server
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[error(
+    "API version mismatch: client version '{client_version}' does not match server version '{server_version}' (requirement: '{server_version_requirement}')"
+)]
+pub struct ApiVersionMismatchError {
+    /// API version sent by the client
+    pub client_version: String,
+
+    /// API version of the server
+    pub server_version: String,
+
+    /// Requirement that the client API version must satisfy to be accepted by the server
+    pub server_version_requirement: String,
+}
+
+impl ApiVersionMismatchError {
+    /// ApiVersionMismatchError factory
+    pub fn new<C: Into<String>, S: Into<String>, R: Into<String>>(
+        client_version: C,
+        server_version: S,
+        server_version_requirement: R,
+    ) -> ApiVersionMismatchError {
+        ApiVersionMismatchError {
+            client_version: client_version.into(),
+            server_version: server_version.into(),
+            server_version_requirement: server_version_requirement.into(),
+        }
+    }
+
+    /// Check if the client API version is older than the server one, `None` if one of the
+    /// versions can not be parsed.
+    pub fn is_client_outdated(&self) -> Option<bool> {
+        let client_version = semver::Version::parse(&self.client_version).ok()?;
+        let server_version = semver::Version::parse(&self.server_version).ok()?;
+
+        Some(client_version < server_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_version_mismatch_error_tells_which_side_is_outdated() {
+        assert_eq!(
+            Some(true),
+            ApiVersionMismatchError::new("0.1.2", "0.2.0", "=0.2").is_client_outdated()
+        );
+        assert_eq!(
+            Some(false),
+            ApiVersionMismatchError::new("0.3.0", "0.2.0", "=0.2").is_client_outdated()
+        );
+        assert_eq!(
+            None,
+            ApiVersionMismatchError::new("not_a_version", "0.2.0", "=0.2").is_client_outdated()
+        );
+    }
+}
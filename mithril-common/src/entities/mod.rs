@@ -7,11 +7,15 @@ mod cardano_network;
 mod cardano_transaction;
 mod cardano_transactions_set_proof;
 mod cardano_transactions_snapshot;
+mod cardano_utxo;
+mod cardano_utxo_set_snapshot;
 mod certificate;
 mod certificate_metadata;
 mod certificate_pending;
+mod data_attestation;
 mod epoch;
 mod epoch_settings;
+mod epoch_settings_calculator;
 mod http_server_error;
 mod mithril_stake_distribution;
 mod protocol_message;
@@ -32,12 +36,16 @@ pub use cardano_network::CardanoNetwork;
 pub use cardano_transaction::{CardanoTransaction, TransactionHash};
 pub use cardano_transactions_set_proof::CardanoTransactionsSetProof;
 pub use cardano_transactions_snapshot::CardanoTransactionsSnapshot;
+pub use cardano_utxo::CardanoUtxo;
+pub use cardano_utxo_set_snapshot::CardanoUtxoSetSnapshot;
 pub use certificate::{Certificate, CertificateSignature};
 pub use certificate_metadata::{CertificateMetadata, StakeDistributionParty};
 pub use certificate_pending::CertificatePending;
+pub use data_attestation::{DataAttestation, DataAttestationBeacon};
 pub use epoch::{Epoch, EpochError};
 pub use epoch_settings::EpochSettings;
-pub use http_server_error::{ClientError, InternalServerError};
+pub use epoch_settings_calculator::{EpochOffsets, EpochSchedule, EpochSettingsCalculator};
+pub use http_server_error::{ApiVersionMismatchError, ClientError, InternalServerError};
 pub use mithril_stake_distribution::MithrilStakeDistribution;
 pub use protocol_message::{ProtocolMessage, ProtocolMessagePartKey, ProtocolMessagePartValue};
 pub use protocol_parameters::ProtocolParameters;
@@ -46,6 +54,6 @@ pub use signed_entity_config::*;
 pub use signed_entity_type::*;
 pub use signer::{Signer, SignerWithStake};
 pub use single_signatures::*;
-pub use snapshot::{CompressionAlgorithm, Snapshot};
+pub use snapshot::{CompressionAlgorithm, Snapshot, SnapshotChunkChecksums};
 pub use time_point::*;
 pub use type_alias::*;
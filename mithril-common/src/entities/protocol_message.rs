@@ -22,6 +22,18 @@ pub enum ProtocolMessagePartKey {
     /// The ProtocolMessage part key associated to the latest block number signed
     #[serde(rename = "latest_block_number")]
     LatestBlockNumber,
+
+    /// The ProtocolMessage part key associated to the Cardano UTxO Set Merkle Root
+    #[serde(rename = "cardano_utxo_set_merkle_root")]
+    CardanoUtxoSetMerkleRoot,
+
+    /// The ProtocolMessage part key associated to the namespace of a data attestation
+    #[serde(rename = "data_attestation_namespace")]
+    DataAttestationNamespace,
+
+    /// The ProtocolMessage part key associated to the payload hash of a data attestation
+    #[serde(rename = "data_attestation_payload_hash")]
+    DataAttestationPayloadHash,
 }
 
 impl Display for ProtocolMessagePartKey {
@@ -31,6 +43,9 @@ impl Display for ProtocolMessagePartKey {
             Self::NextAggregateVerificationKey => write!(f, "next_aggregate_verification_key"),
             Self::CardanoTransactionsMerkleRoot => write!(f, "cardano_transactions_merkle_root"),
             Self::LatestBlockNumber => write!(f, "latest_block_number"),
+            Self::CardanoUtxoSetMerkleRoot => write!(f, "cardano_utxo_set_merkle_root"),
+            Self::DataAttestationNamespace => write!(f, "data_attestation_namespace"),
+            Self::DataAttestationPayloadHash => write!(f, "data_attestation_payload_hash"),
         }
     }
 }
@@ -143,6 +158,34 @@ mod tests {
         assert_ne!(hash_expected, protocol_message_modified.compute_hash());
     }
 
+    #[test]
+    fn test_protocol_message_compute_hash_include_cardano_utxo_set_merkle_root() {
+        let protocol_message = build_protocol_message_reference();
+        let hash_expected = protocol_message.compute_hash();
+
+        let mut protocol_message_modified = protocol_message.clone();
+        protocol_message_modified.set_message_part(
+            ProtocolMessagePartKey::CardanoUtxoSetMerkleRoot,
+            "utxo-set-merkle-root-456".to_string(),
+        );
+
+        assert_ne!(hash_expected, protocol_message_modified.compute_hash());
+    }
+
+    #[test]
+    fn test_protocol_message_compute_hash_include_data_attestation_payload_hash() {
+        let protocol_message = build_protocol_message_reference();
+        let hash_expected = protocol_message.compute_hash();
+
+        let mut protocol_message_modified = protocol_message.clone();
+        protocol_message_modified.set_message_part(
+            ProtocolMessagePartKey::DataAttestationPayloadHash,
+            "payload-hash-456".to_string(),
+        );
+
+        assert_ne!(hash_expected, protocol_message_modified.compute_hash());
+    }
+
     #[test]
     fn test_protocol_message_compute_hash_the_same_hash_with_same_protocol_message() {
         assert_eq!(
@@ -169,6 +212,10 @@ mod tests {
             ProtocolMessagePartKey::LatestBlockNumber,
             "latest-immutable-file-number-123".to_string(),
         );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoUtxoSetMerkleRoot,
+            "utxo-set-merkle-root-123".to_string(),
+        );
 
         protocol_message
     }
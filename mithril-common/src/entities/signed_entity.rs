@@ -6,7 +6,10 @@ use crate::test_utils::fake_data;
 
 #[cfg(any(test, feature = "test_tools"))]
 use super::{CardanoDbBeacon, Epoch};
-use super::{CardanoTransactionsSnapshot, MithrilStakeDistribution, SignedEntityType, Snapshot};
+use super::{
+    CardanoTransactionsSnapshot, CardanoUtxoSetSnapshot, MithrilStakeDistribution,
+    SignedEntityType, Snapshot,
+};
 
 /// Aggregate for signed entity
 #[derive(Debug, Clone)]
@@ -81,3 +84,26 @@ impl SignedEntity<CardanoTransactionsSnapshot> {
         }
     }
 }
+
+impl SignedEntity<CardanoUtxoSetSnapshot> {
+    cfg_test_tools! {
+        /// Create a dummy [SignedEntity] for [CardanoUtxoSetSnapshot] entity
+        pub fn dummy() -> Self {
+            let epoch = Epoch(5);
+            SignedEntity {
+                signed_entity_id: "cardano-utxo-set-id-123".to_string(),
+                signed_entity_type: SignedEntityType::CardanoUtxoSet(epoch),
+                certificate_id: "certificate-hash-123".to_string(),
+                artifact: CardanoUtxoSetSnapshot::new(
+                    "mkroot123".to_string(),
+                    epoch,
+                    10,
+                    vec!["https://host/cardano-utxo-set.json".to_string()],
+                ),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
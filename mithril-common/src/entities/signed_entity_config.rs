@@ -53,12 +53,15 @@ impl SignedEntityConfig {
     }
 
     /// Convert this time point to a signed entity type based on the given discriminant.
+    ///
+    /// Return `None` for the discriminants that are not derived from a time point, such as
+    /// [SignedEntityTypeDiscriminants::DataAttestation].
     pub fn time_point_to_signed_entity<D: Into<SignedEntityTypeDiscriminants>>(
         &self,
         discriminant: D,
         time_point: &TimePoint,
-    ) -> SignedEntityType {
-        match discriminant.into() {
+    ) -> Option<SignedEntityType> {
+        let signed_entity_type = match discriminant.into() {
             SignedEntityTypeDiscriminants::MithrilStakeDistribution => {
                 SignedEntityType::MithrilStakeDistribution(time_point.epoch)
             }
@@ -79,10 +82,17 @@ impl SignedEntityConfig {
                         .compute_block_number_to_be_signed(time_point.chain_point.block_number),
                 )
             }
-        }
+            SignedEntityTypeDiscriminants::CardanoUtxoSet => {
+                SignedEntityType::CardanoUtxoSet(time_point.epoch)
+            }
+            SignedEntityTypeDiscriminants::DataAttestation => return None,
+        };
+
+        Some(signed_entity_type)
     }
 
-    /// Create the deduplicated list of allowed signed entity types discriminants.
+    /// Create the deduplicated list of allowed signed entity types derived from the given
+    /// time point.
     ///
     /// The list is the aggregation of [Self::DEFAULT_ALLOWED_DISCRIMINANTS] and
     /// `allowed_discriminants`.
@@ -92,7 +102,7 @@ impl SignedEntityConfig {
     ) -> Vec<SignedEntityType> {
         self.list_allowed_signed_entity_types_discriminants()
             .into_iter()
-            .map(|discriminant| self.time_point_to_signed_entity(discriminant, time_point))
+            .filter_map(|discriminant| self.time_point_to_signed_entity(discriminant, time_point))
             .collect()
     }
 }
@@ -176,7 +186,7 @@ mod tests {
         };
 
         assert_eq!(
-            SignedEntityType::MithrilStakeDistribution(Epoch(1)),
+            Some(SignedEntityType::MithrilStakeDistribution(Epoch(1))),
             config.time_point_to_signed_entity(
                 SignedEntityTypeDiscriminants::MithrilStakeDistribution,
                 &time_point
@@ -184,7 +194,7 @@ mod tests {
         );
 
         assert_eq!(
-            SignedEntityType::CardanoStakeDistribution(Epoch(1)),
+            Some(SignedEntityType::CardanoStakeDistribution(Epoch(1))),
             config.time_point_to_signed_entity(
                 SignedEntityTypeDiscriminants::CardanoStakeDistribution,
                 &time_point
@@ -192,7 +202,9 @@ mod tests {
         );
 
         assert_eq!(
-            SignedEntityType::CardanoImmutableFilesFull(CardanoDbBeacon::new("devnet", 1, 5)),
+            Some(SignedEntityType::CardanoImmutableFilesFull(
+                CardanoDbBeacon::new("devnet", 1, 5)
+            )),
             config.time_point_to_signed_entity(
                 SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
                 &time_point
@@ -203,12 +215,28 @@ mod tests {
         // the security parameter is 0.
         // This is further tested in the "computing_block_number_to_be_signed" tests below.
         assert_eq!(
-            SignedEntityType::CardanoTransactions(Epoch(1), 15),
+            Some(SignedEntityType::CardanoTransactions(Epoch(1), 15)),
             config.time_point_to_signed_entity(
                 SignedEntityTypeDiscriminants::CardanoTransactions,
                 &time_point
             )
         );
+
+        assert_eq!(
+            Some(SignedEntityType::CardanoUtxoSet(Epoch(1))),
+            config.time_point_to_signed_entity(
+                SignedEntityTypeDiscriminants::CardanoUtxoSet,
+                &time_point
+            )
+        );
+
+        assert_eq!(
+            None,
+            config.time_point_to_signed_entity(
+                SignedEntityTypeDiscriminants::DataAttestation,
+                &time_point
+            )
+        );
     }
 
     #[test]
@@ -409,6 +437,7 @@ mod tests {
             allowed_discriminants: BTreeSet::from([
                 SignedEntityTypeDiscriminants::CardanoStakeDistribution,
                 SignedEntityTypeDiscriminants::CardanoTransactions,
+                SignedEntityTypeDiscriminants::DataAttestation,
             ]),
             network,
             cardano_transactions_signing_config: CardanoTransactionsSigningConfig {
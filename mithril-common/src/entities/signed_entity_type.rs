@@ -8,9 +8,10 @@ use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 use strum::{AsRefStr, Display, EnumDiscriminants, EnumIter, EnumString, IntoEnumIterator};
 
+use crate::era::SupportedEra;
 use crate::StdResult;
 
-use super::{BlockNumber, CardanoDbBeacon, Epoch};
+use super::{BlockNumber, CardanoDbBeacon, DataAttestationBeacon, Epoch};
 
 /// Database representation of the SignedEntityType::MithrilStakeDistribution value
 const ENTITY_TYPE_MITHRIL_STAKE_DISTRIBUTION: usize = 0;
@@ -24,6 +25,12 @@ const ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL: usize = 2;
 /// Database representation of the SignedEntityType::CardanoTransactions value
 const ENTITY_TYPE_CARDANO_TRANSACTIONS: usize = 3;
 
+/// Database representation of the SignedEntityType::CardanoUtxoSet value
+const ENTITY_TYPE_CARDANO_UTXO_SET: usize = 4;
+
+/// Database representation of the SignedEntityType::DataAttestation value
+const ENTITY_TYPE_DATA_ATTESTATION: usize = 5;
+
 /// The signed entity type that represents a type of data signed by the Mithril
 /// protocol Note: Each variant of this enum must be associated to an entry in
 /// the `signed_entity_type` table of the signer/aggregator nodes. The variant
@@ -31,7 +38,7 @@ const ENTITY_TYPE_CARDANO_TRANSACTIONS: usize = 3;
 /// modification of this type should only ever consist of appending new
 /// variants.
 // Important note: The order of the variants is important as it is used for the derived Ord trait.
-#[derive(Display, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, EnumDiscriminants)]
+#[derive(Display, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, EnumDiscriminants)]
 #[strum(serialize_all = "PascalCase")]
 #[strum_discriminants(derive(
     Display,
@@ -55,6 +62,12 @@ pub enum SignedEntityType {
 
     /// Cardano Transactions
     CardanoTransactions(Epoch, BlockNumber),
+
+    /// Cardano UTxO Set at the boundary of an epoch
+    CardanoUtxoSet(Epoch),
+
+    /// Payload hash submitted by an operator
+    DataAttestation(DataAttestationBeacon),
 }
 
 impl SignedEntityType {
@@ -72,9 +85,11 @@ impl SignedEntityType {
     pub fn get_epoch(&self) -> Epoch {
         match self {
             Self::CardanoImmutableFilesFull(b) => b.epoch,
+            Self::DataAttestation(b) => b.epoch,
             Self::CardanoStakeDistribution(e)
             | Self::MithrilStakeDistribution(e)
-            | Self::CardanoTransactions(e, _) => *e,
+            | Self::CardanoTransactions(e, _)
+            | Self::CardanoUtxoSet(e) => *e,
         }
     }
 
@@ -85,6 +100,8 @@ impl SignedEntityType {
             Self::CardanoStakeDistribution(_) => ENTITY_TYPE_CARDANO_STAKE_DISTRIBUTION,
             Self::CardanoImmutableFilesFull(_) => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions(_, _) => ENTITY_TYPE_CARDANO_TRANSACTIONS,
+            Self::CardanoUtxoSet(_) => ENTITY_TYPE_CARDANO_UTXO_SET,
+            Self::DataAttestation(_) => ENTITY_TYPE_DATA_ATTESTATION,
         }
     }
 
@@ -92,9 +109,10 @@ impl SignedEntityType {
     pub fn get_json_beacon(&self) -> StdResult<String> {
         let value = match self {
             Self::CardanoImmutableFilesFull(value) => serde_json::to_string(value)?,
-            Self::CardanoStakeDistribution(value) | Self::MithrilStakeDistribution(value) => {
-                serde_json::to_string(value)?
-            }
+            Self::DataAttestation(value) => serde_json::to_string(value)?,
+            Self::CardanoStakeDistribution(value)
+            | Self::MithrilStakeDistribution(value)
+            | Self::CardanoUtxoSet(value) => serde_json::to_string(value)?,
             Self::CardanoTransactions(epoch, block_number) => {
                 let json = serde_json::json!({
                     "epoch": epoch,
@@ -113,15 +131,16 @@ impl SignedEntityType {
             Self::MithrilStakeDistribution(_) | Self::CardanoImmutableFilesFull(_) => None,
             Self::CardanoStakeDistribution(_) => Some(Duration::from_secs(600)),
             Self::CardanoTransactions(_, _) => Some(Duration::from_secs(1800)),
+            Self::CardanoUtxoSet(_) => Some(Duration::from_secs(1800)),
+            Self::DataAttestation(_) => Some(Duration::from_secs(600)),
         }
     }
 
     pub(crate) fn feed_hash(&self, hasher: &mut Sha256) {
         match self {
             SignedEntityType::MithrilStakeDistribution(epoch)
-            | SignedEntityType::CardanoStakeDistribution(epoch) => {
-                hasher.update(&epoch.to_be_bytes())
-            }
+            | SignedEntityType::CardanoStakeDistribution(epoch)
+            | SignedEntityType::CardanoUtxoSet(epoch) => hasher.update(&epoch.to_be_bytes()),
             SignedEntityType::CardanoImmutableFilesFull(db_beacon) => {
                 hasher.update(db_beacon.network.as_bytes());
                 hasher.update(&db_beacon.epoch.to_be_bytes());
@@ -131,6 +150,11 @@ impl SignedEntityType {
                 hasher.update(&epoch.to_be_bytes());
                 hasher.update(&block_number.to_be_bytes())
             }
+            SignedEntityType::DataAttestation(beacon) => {
+                hasher.update(&beacon.epoch.to_be_bytes());
+                hasher.update(beacon.namespace.as_bytes());
+                hasher.update(beacon.payload_hash.as_bytes());
+            }
         }
     }
 }
@@ -148,6 +172,16 @@ impl SignedEntityTypeDiscriminants {
             Self::CardanoStakeDistribution => ENTITY_TYPE_CARDANO_STAKE_DISTRIBUTION,
             Self::CardanoImmutableFilesFull => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions => ENTITY_TYPE_CARDANO_TRANSACTIONS,
+            Self::CardanoUtxoSet => ENTITY_TYPE_CARDANO_UTXO_SET,
+            Self::DataAttestation => ENTITY_TYPE_DATA_ATTESTATION,
+        }
+    }
+
+    /// Check if the signed entity types of this discriminant can be signed in the given era
+    pub fn is_supported_in_era(&self, era: SupportedEra) -> bool {
+        match self {
+            Self::DataAttestation => era >= SupportedEra::Pythagoras,
+            _ => true,
         }
     }
 
@@ -158,6 +192,8 @@ impl SignedEntityTypeDiscriminants {
             ENTITY_TYPE_CARDANO_STAKE_DISTRIBUTION => Ok(Self::CardanoStakeDistribution),
             ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL => Ok(Self::CardanoImmutableFilesFull),
             ENTITY_TYPE_CARDANO_TRANSACTIONS => Ok(Self::CardanoTransactions),
+            ENTITY_TYPE_CARDANO_UTXO_SET => Ok(Self::CardanoUtxoSet),
+            ENTITY_TYPE_DATA_ATTESTATION => Ok(Self::DataAttestation),
             index => Err(anyhow!("Invalid entity_type_id {index}.")),
         }
     }
@@ -272,6 +308,34 @@ mod tests {
             reference_hash,
             hash(SignedEntityType::CardanoTransactions(Epoch(35), 98765))
         );
+
+        let reference_hash = hash(SignedEntityType::CardanoUtxoSet(Epoch(5)));
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoUtxoSet(Epoch(15)))
+        );
+
+        let reference_hash = hash(SignedEntityType::DataAttestation(
+            DataAttestationBeacon::new(Epoch(5), "namespace", "abc123"),
+        ));
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::DataAttestation(
+                DataAttestationBeacon::new(Epoch(15), "namespace", "abc123")
+            ))
+        );
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::DataAttestation(
+                DataAttestationBeacon::new(Epoch(5), "other_namespace", "abc123")
+            ))
+        );
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::DataAttestation(
+                DataAttestationBeacon::new(Epoch(5), "namespace", "def456")
+            ))
+        );
     }
 
     #[test]
@@ -302,13 +366,42 @@ mod tests {
             .get_json_beacon()
             .unwrap();
         assert_same_json!("15", &msd_json);
+
+        let cardano_utxo_set_json = SignedEntityType::CardanoUtxoSet(Epoch(45))
+            .get_json_beacon()
+            .unwrap();
+        assert_same_json!("45", &cardano_utxo_set_json);
+
+        let data_attestation_json = SignedEntityType::DataAttestation(DataAttestationBeacon::new(
+            Epoch(55),
+            "namespace",
+            "abc123",
+        ))
+        .get_json_beacon()
+        .unwrap();
+        assert_same_json!(
+            r#"{"epoch":55,"namespace":"namespace","payload_hash":"abc123"}"#,
+            &data_attestation_json
+        );
+    }
+
+    #[test]
+    fn data_attestation_is_only_supported_from_pythagoras_era() {
+        assert!(!SignedEntityTypeDiscriminants::DataAttestation
+            .is_supported_in_era(SupportedEra::Thales));
+        assert!(SignedEntityTypeDiscriminants::DataAttestation
+            .is_supported_in_era(SupportedEra::Pythagoras));
+        assert!(SignedEntityTypeDiscriminants::CardanoImmutableFilesFull
+            .is_supported_in_era(SupportedEra::Thales));
     }
 
     // Expected ord:
-    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoTransactions
+    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoTransactions < CardanoUtxoSet < DataAttestation
     #[test]
     fn ordering_discriminant() {
         let mut list = vec![
+            SignedEntityTypeDiscriminants::DataAttestation,
+            SignedEntityTypeDiscriminants::CardanoUtxoSet,
             SignedEntityTypeDiscriminants::CardanoStakeDistribution,
             SignedEntityTypeDiscriminants::CardanoTransactions,
             SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
@@ -323,6 +416,8 @@ mod tests {
                 SignedEntityTypeDiscriminants::CardanoStakeDistribution,
                 SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
                 SignedEntityTypeDiscriminants::CardanoTransactions,
+                SignedEntityTypeDiscriminants::CardanoUtxoSet,
+                SignedEntityTypeDiscriminants::DataAttestation,
             ]
         );
     }
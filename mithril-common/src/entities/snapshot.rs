@@ -1,6 +1,8 @@
 use crate::{entities::CardanoDbBeacon, signable_builder::Artifact};
 use semver::Version;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{self, Read};
 use strum::{Display, EnumIter, IntoEnumIterator};
 
 /// Snapshot represents a snapshot file and its metadata
@@ -23,6 +25,71 @@ pub struct Snapshot {
 
     /// Version of the Cardano node used to create snapshot archive.
     pub cardano_node_version: String,
+
+    /// Checksums of the consecutive chunks of the snapshot archive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk_checksums: Option<SnapshotChunkChecksums>,
+}
+
+/// Checksums of the consecutive fixed size chunks of a snapshot archive, allowing a client to
+/// verify each chunk as it's downloaded instead of waiting for the whole archive to be unpacked.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SnapshotChunkChecksums {
+    /// Size of the chunks in Bytes, the last chunk may be smaller
+    pub chunk_size: u64,
+
+    /// Hex encoded SHA256 checksum of each chunk, in the archive order
+    pub sha256: Vec<String>,
+}
+
+impl SnapshotChunkChecksums {
+    /// Compute the checksums of the chunks of the data read from the given reader
+    pub fn compute<R: Read>(mut reader: R, chunk_size: u64) -> io::Result<Self> {
+        if chunk_size == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "chunk size must be strictly positive",
+            ));
+        }
+
+        let mut sha256 = vec![];
+        loop {
+            let mut hasher = Sha256::new();
+            let read_bytes = io::copy(&mut (&mut reader).take(chunk_size), &mut hasher)?;
+            if read_bytes == 0 {
+                break;
+            }
+            sha256.push(hex::encode(hasher.finalize()));
+            if read_bytes < chunk_size {
+                break;
+            }
+        }
+
+        Ok(Self { chunk_size, sha256 })
+    }
+
+    /// Compute the checksum of a single chunk
+    pub fn compute_chunk_checksum(chunk: &[u8]) -> String {
+        hex::encode(Sha256::digest(chunk))
+    }
+
+    /// Check that the given chunk matches the checksum at the given index
+    pub fn is_valid_chunk(&self, index: usize, chunk: &[u8]) -> bool {
+        self.sha256
+            .get(index)
+            .is_some_and(|checksum| *checksum == Self::compute_chunk_checksum(chunk))
+    }
+
+    /// Byte range (start and inclusive end) of the chunk at the given index in an archive
+    /// of the given total size
+    pub fn chunk_range(&self, index: usize, archive_size: u64) -> (u64, u64) {
+        let start = index as u64 * self.chunk_size;
+        let end = (start + self.chunk_size)
+            .min(archive_size)
+            .saturating_sub(1);
+
+        (start, end)
+    }
 }
 
 /// Compression algorithm for the snapshot archive artifacts.
@@ -81,8 +148,15 @@ impl Snapshot {
             locations,
             compression_algorithm,
             cardano_node_version,
+            chunk_checksums: None,
         }
     }
+
+    /// Set the checksums of the chunks of the snapshot archive
+    pub fn with_chunk_checksums(mut self, chunk_checksums: SnapshotChunkChecksums) -> Self {
+        self.chunk_checksums = Some(chunk_checksums);
+        self
+    }
 }
 
 #[typetag::serde]
@@ -91,3 +165,55 @@ impl Artifact for Snapshot {
         self.digest.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_chunk_checksums_of_an_archive() {
+        let archive = b"0123456789";
+
+        let checksums = SnapshotChunkChecksums::compute(&archive[..], 4).unwrap();
+
+        assert_eq!(4, checksums.chunk_size);
+        assert_eq!(
+            vec![
+                SnapshotChunkChecksums::compute_chunk_checksum(b"0123"),
+                SnapshotChunkChecksums::compute_chunk_checksum(b"4567"),
+                SnapshotChunkChecksums::compute_chunk_checksum(b"89"),
+            ],
+            checksums.sha256
+        );
+    }
+
+    #[test]
+    fn compute_chunk_checksums_of_an_archive_which_size_is_a_multiple_of_the_chunk_size() {
+        let checksums = SnapshotChunkChecksums::compute(&b"01234567"[..], 4).unwrap();
+
+        assert_eq!(2, checksums.sha256.len());
+    }
+
+    #[test]
+    fn compute_chunk_checksums_fails_with_a_zero_chunk_size() {
+        SnapshotChunkChecksums::compute(&b"0123"[..], 0)
+            .expect_err("a zero chunk size should fail");
+    }
+
+    #[test]
+    fn verify_chunk_against_its_checksum() {
+        let checksums = SnapshotChunkChecksums::compute(&b"0123456789"[..], 4).unwrap();
+
+        assert!(checksums.is_valid_chunk(1, b"4567"));
+        assert!(!checksums.is_valid_chunk(1, b"4560"));
+        assert!(!checksums.is_valid_chunk(3, b""));
+    }
+
+    #[test]
+    fn chunk_range_of_the_last_chunk_is_truncated_to_the_archive_size() {
+        let checksums = SnapshotChunkChecksums::compute(&b"0123456789"[..], 4).unwrap();
+
+        assert_eq!((0, 3), checksums.chunk_range(0, 10));
+        assert_eq!((8, 9), checksums.chunk_range(2, 10));
+    }
+}
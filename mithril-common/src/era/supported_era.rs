@@ -2,14 +2,34 @@ use serde::{Deserialize, Serialize};
 use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
 
 /// The era that the software is running or will run
+///
+/// Eras are ordered by activation: a later era is greater than an earlier one.
 #[derive(
-    Display, EnumString, EnumIter, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,
+    Display,
+    EnumString,
+    EnumIter,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
 )]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
 pub enum SupportedEra {
     /// Thales era
     Thales,
+
+    /// Pythagoras era
+    ///
+    /// Gates the signed entity types that all the signers of a network must support before they
+    /// can be signed, such as the data attestations. It only becomes active once an era marker
+    /// activating it is published, so networks keep running in the Thales era until then.
+    Pythagoras,
 }
 
 impl SupportedEra {
@@ -44,4 +64,9 @@ mod tests {
 
         assert_eq!(SupportedEra::dummy(), supported_era);
     }
+
+    #[test]
+    fn eras_are_ordered_by_activation() {
+        assert!(SupportedEra::Thales < SupportedEra::Pythagoras);
+    }
 }
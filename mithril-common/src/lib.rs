@@ -69,6 +69,7 @@ cfg_test_tools! {
 
 cfg_fs! {
     mod ticker_service;
+    pub mod archive;
     pub mod digesters;
     pub mod cardano_block_scanner;
     pub mod chain_reader;
@@ -0,0 +1,87 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::Epoch;
+
+/// Message structure of a Cardano UTxO set snapshot
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CardanoUtxoSetSnapshotMessage {
+    /// Merkle root of the Cardano UTxO set snapshot
+    pub merkle_root: String,
+
+    /// Epoch at the boundary of which the Cardano UTxO set snapshot was taken
+    pub epoch: Epoch,
+
+    /// Number of UTxOs in the Cardano UTxO set snapshot
+    pub utxos_count: u64,
+
+    /// Locations where the Cardano UTxO set can be downloaded
+    pub locations: Vec<String>,
+
+    /// Hash of the Cardano UTxO set snapshot
+    pub hash: String,
+
+    /// Hash of the associated certificate
+    pub certificate_hash: String,
+
+    /// DateTime of creation
+    pub created_at: DateTime<Utc>,
+}
+
+impl CardanoUtxoSetSnapshotMessage {
+    cfg_test_tools! {
+        /// Return a dummy test entity (test-only).
+        pub fn dummy() -> Self {
+            Self {
+                merkle_root: "mkroot-123".to_string(),
+                epoch: Epoch(10),
+                utxos_count: 100,
+                locations: vec!["https://host/cardano-utxo-set.json".to_string()],
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message() -> CardanoUtxoSetSnapshotMessage {
+        CardanoUtxoSetSnapshotMessage {
+            merkle_root: "mkroot-123".to_string(),
+            epoch: Epoch(8),
+            utxos_count: 6,
+            locations: vec!["https://host/cardano-utxo-set.json".to_string()],
+            hash: "hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+
+    // Test the retro compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"{
+            "merkle_root": "mkroot-123",
+            "epoch": 8,
+            "utxos_count": 6,
+            "locations": ["https://host/cardano-utxo-set.json"],
+            "hash": "hash-123",
+            "certificate_hash": "certificate-hash-123",
+            "created_at": "2023-01-19T13:43:05.618857482Z"
+        }"#;
+        let message: CardanoUtxoSetSnapshotMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a CardanoUtxoSetSnapshotMessage instance.",
+        );
+
+        assert_eq!(golden_message(), message);
+    }
+}
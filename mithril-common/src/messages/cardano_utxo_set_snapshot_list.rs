@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::Epoch;
+
+/// Message structure of a Cardano UTxO set snapshots list
+pub type CardanoUtxoSetSnapshotListMessage = Vec<CardanoUtxoSetSnapshotListItemMessage>;
+
+/// Message structure of a Cardano UTxO set snapshot list item
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoUtxoSetSnapshotListItemMessage {
+    /// Merkle root of the Cardano UTxO set snapshot
+    pub merkle_root: String,
+
+    /// Epoch at the boundary of which the Cardano UTxO set snapshot was taken
+    pub epoch: Epoch,
+
+    /// Number of UTxOs in the Cardano UTxO set snapshot
+    pub utxos_count: u64,
+
+    /// Hash of the Cardano UTxO set snapshot
+    pub hash: String,
+
+    /// Hash of the associated certificate
+    pub certificate_hash: String,
+
+    /// DateTime of creation
+    pub created_at: DateTime<Utc>,
+}
+
+impl CardanoUtxoSetSnapshotListItemMessage {
+    cfg_test_tools! {
+        /// Return a dummy test entity (test-only).
+        pub fn dummy() -> Self {
+            Self {
+                merkle_root: "mkroot-123".to_string(),
+                epoch: Epoch(10),
+                utxos_count: 100,
+                hash: "hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message() -> CardanoUtxoSetSnapshotListMessage {
+        vec![CardanoUtxoSetSnapshotListItemMessage {
+            merkle_root: "mkroot-123".to_string(),
+            epoch: Epoch(7),
+            utxos_count: 5,
+            hash: "hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }]
+    }
+
+    // Test the retro compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"[{
+        "merkle_root": "mkroot-123",
+        "epoch": 7,
+        "utxos_count": 5,
+        "hash": "hash-123",
+        "certificate_hash": "certificate-hash-123",
+        "created_at": "2023-01-19T13:43:05.618857482Z"
+        }]"#;
+
+        let message: CardanoUtxoSetSnapshotListMessage = serde_json::from_str(json).expect(
+                    "This JSON is expected to be successfully parsed into a CardanoUtxoSetSnapshotListMessage instance.",
+                );
+        assert_eq!(golden_message(), message);
+    }
+}
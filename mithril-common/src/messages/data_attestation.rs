@@ -0,0 +1,81 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::Epoch;
+
+/// Message structure of a certified data attestation
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DataAttestationMessage {
+    /// Hash of the data attestation
+    pub hash: String,
+
+    /// Epoch at which the attestation was certified
+    pub epoch: Epoch,
+
+    /// Namespace of the attested data
+    pub namespace: String,
+
+    /// Hex encoded hash of the attested payload
+    pub payload_hash: String,
+
+    /// Hash of the associated certificate
+    pub certificate_hash: String,
+
+    /// DateTime of creation
+    pub created_at: DateTime<Utc>,
+}
+
+impl DataAttestationMessage {
+    cfg_test_tools! {
+        /// Return a dummy test entity (test-only).
+        pub fn dummy() -> Self {
+            Self {
+                hash: "hash-123".to_string(),
+                epoch: Epoch(10),
+                namespace: "db-sync-snapshot".to_string(),
+                payload_hash: "payload-hash-123".to_string(),
+                certificate_hash: "cert-hash-123".to_string(),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message() -> DataAttestationMessage {
+        DataAttestationMessage {
+            hash: "hash-123".to_string(),
+            epoch: Epoch(8),
+            namespace: "db-sync-snapshot".to_string(),
+            payload_hash: "payload-hash-123".to_string(),
+            certificate_hash: "certificate-hash-123".to_string(),
+            created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+
+    // Test the retro compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"{
+            "hash": "hash-123",
+            "epoch": 8,
+            "namespace": "db-sync-snapshot",
+            "payload_hash": "payload-hash-123",
+            "certificate_hash": "certificate-hash-123",
+            "created_at": "2023-01-19T13:43:05.618857482Z"
+        }"#;
+        let message: DataAttestationMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a DataAttestationMessage instance.",
+        );
+
+        assert_eq!(golden_message(), message);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::entities::Epoch;
+use serde::{Deserialize, Serialize};
+
+/// EpochScheduleMessage represents the epochs at which the signers and protocol parameters data
+/// are recorded, retrieved and used, computed for the current epoch
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct EpochScheduleMessage {
+    /// Current Epoch
+    pub epoch: Epoch,
+
+    /// Epoch of the signers data used to sign during the current epoch
+    pub signer_retrieval_epoch: Epoch,
+
+    /// Epoch of the signers data that will be used to sign during the next epoch
+    pub next_signer_retrieval_epoch: Epoch,
+
+    /// Epoch at which the signers registering during the current epoch are recorded
+    pub signer_registration_epoch: Epoch,
+
+    /// Epoch at which the signers registering during the current epoch can send single signatures
+    pub signer_signing_epoch: Epoch,
+
+    /// Epoch at which the protocol parameters set during the current epoch are recorded
+    pub protocol_parameters_recording_epoch: Epoch,
+}
+
+impl EpochScheduleMessage {
+    /// Dummy instance for test purposes.
+    pub fn dummy() -> Self {
+        Self {
+            epoch: Epoch(10),
+            signer_retrieval_epoch: Epoch(9),
+            next_signer_retrieval_epoch: Epoch(10),
+            signer_registration_epoch: Epoch(11),
+            signer_signing_epoch: Epoch(13),
+            protocol_parameters_recording_epoch: Epoch(12),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message() -> EpochScheduleMessage {
+        EpochScheduleMessage {
+            epoch: Epoch(10),
+            signer_retrieval_epoch: Epoch(9),
+            next_signer_retrieval_epoch: Epoch(10),
+            signer_registration_epoch: Epoch(11),
+            signer_signing_epoch: Epoch(13),
+            protocol_parameters_recording_epoch: Epoch(12),
+        }
+    }
+
+    // Test the retro compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"{
+"epoch": 10,
+"signer_retrieval_epoch": 9,
+"next_signer_retrieval_epoch": 10,
+"signer_registration_epoch": 11,
+"signer_signing_epoch": 13,
+"protocol_parameters_recording_epoch": 12
+}"#;
+        let message: EpochScheduleMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be succesfully parsed into a EpochScheduleMessage instance.",
+        );
+
+        assert_eq!(golden_message(), message);
+    }
+}
@@ -3,16 +3,23 @@
 mod cardano_transaction_snapshot;
 mod cardano_transaction_snapshot_list;
 mod cardano_transactions_proof;
+mod cardano_utxo_set_snapshot;
+mod cardano_utxo_set_snapshot_list;
 mod certificate;
 mod certificate_list;
 mod certificate_pending;
+mod data_attestation;
+mod epoch_schedule;
 mod epoch_settings;
 mod interface;
 mod message_parts;
 mod mithril_stake_distribution;
 mod mithril_stake_distribution_list;
+mod register_data_attestation;
 mod register_signature;
+mod register_signatures_batch;
 mod register_signer;
+mod signer_registrations;
 mod snapshot;
 mod snapshot_download;
 mod snapshot_list;
@@ -25,11 +32,17 @@ pub use cardano_transactions_proof::{
     CardanoTransactionsProofsMessage, VerifiedCardanoTransactions,
     VerifyCardanoTransactionsProofsError,
 };
+pub use cardano_utxo_set_snapshot::CardanoUtxoSetSnapshotMessage;
+pub use cardano_utxo_set_snapshot_list::{
+    CardanoUtxoSetSnapshotListItemMessage, CardanoUtxoSetSnapshotListMessage,
+};
 pub use certificate::CertificateMessage;
 pub use certificate_list::{
     CertificateListItemMessage, CertificateListItemMessageMetadata, CertificateListMessage,
 };
 pub use certificate_pending::CertificatePendingMessage;
+pub use data_attestation::DataAttestationMessage;
+pub use epoch_schedule::EpochScheduleMessage;
 pub use epoch_settings::EpochSettingsMessage;
 pub use interface::*;
 pub use message_parts::*;
@@ -37,8 +50,15 @@ pub use mithril_stake_distribution::MithrilStakeDistributionMessage;
 pub use mithril_stake_distribution_list::{
     MithrilStakeDistributionListItemMessage, MithrilStakeDistributionListMessage,
 };
+pub use register_data_attestation::{
+    RegisterDataAttestationMessage, RegisterDataAttestationResponseMessage,
+};
 pub use register_signature::RegisterSignatureMessage;
+pub use register_signatures_batch::{
+    RegisterSignaturesBatchItemMessage, RegisterSignaturesBatchResponseMessage,
+};
 pub use register_signer::RegisterSignerMessage;
+pub use signer_registrations::{SignerRegistrationsListItemMessage, SignerRegistrationsMessage};
 pub use snapshot::SnapshotMessage;
 pub use snapshot_download::SnapshotDownloadMessage;
 pub use snapshot_list::{SnapshotListItemMessage, SnapshotListMessage};
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{DataAttestation, Epoch};
+
+/// Message structure of a payload hash submitted for certification by an operator
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisterDataAttestationMessage {
+    /// Namespace of the attested data, ie: `db-sync-snapshot`
+    pub namespace: String,
+
+    /// Hex encoded hash of the attested payload
+    pub payload_hash: String,
+}
+
+impl RegisterDataAttestationMessage {
+    cfg_test_tools! {
+        /// Return a dummy test entity (test-only).
+        pub fn dummy() -> Self {
+            Self {
+                namespace: "db-sync-snapshot".to_string(),
+                payload_hash: "b4e1d4b4c3a2f7e9d2c9b8a7f6e5d4c3b2a1f0e9d8c7b6a5f4e3d2c1b0a9f8e7"
+                    .to_string(),
+            }
+        }
+    }
+}
+
+/// Message structure of an accepted data attestation, waiting to be certified
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisterDataAttestationResponseMessage {
+    /// Hash of the data attestation, identifier of its artifact once certified
+    pub hash: String,
+
+    /// Epoch at which the attestation will be certified
+    pub epoch: Epoch,
+
+    /// Namespace of the attested data
+    pub namespace: String,
+
+    /// Hex encoded hash of the attested payload
+    pub payload_hash: String,
+}
+
+impl From<DataAttestation> for RegisterDataAttestationResponseMessage {
+    fn from(value: DataAttestation) -> Self {
+        Self {
+            hash: value.hash,
+            epoch: value.epoch,
+            namespace: value.namespace,
+            payload_hash: value.payload_hash,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message() -> RegisterDataAttestationMessage {
+        RegisterDataAttestationMessage {
+            namespace: "db-sync-snapshot".to_string(),
+            payload_hash: "abc123".to_string(),
+        }
+    }
+
+    // Test the retro compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"{
+            "namespace": "db-sync-snapshot",
+            "payload_hash": "abc123"
+        }"#;
+        let message: RegisterDataAttestationMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a RegisterDataAttestationMessage instance.",
+        );
+
+        assert_eq!(golden_message(), message);
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// Message structure of the multi-status response of a batch of single signatures registration.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RegisterSignaturesBatchResponseMessage {
+    /// Registration result of each single signature of the batch, in the order of the request
+    pub items: Vec<RegisterSignaturesBatchItemMessage>,
+}
+
+/// Message structure of the registration result of a single signature of a batch.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RegisterSignaturesBatchItemMessage {
+    /// Position of the single signature in the batch (zero based)
+    pub index: usize,
+
+    /// HTTP status code that the registration of this single signature alone would have returned
+    pub status: u16,
+
+    /// Error message, if the registration failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl RegisterSignaturesBatchResponseMessage {
+    cfg_test_tools! {
+        /// Return a dummy test entity (test-only).
+        pub fn dummy() -> Self {
+            Self {
+                items: vec![
+                    RegisterSignaturesBatchItemMessage {
+                        index: 0,
+                        status: 201,
+                        message: None,
+                    },
+                    RegisterSignaturesBatchItemMessage {
+                        index: 1,
+                        status: 400,
+                        message: Some("Could not decode signature payload".to_string()),
+                    },
+                ],
+            }
+        }
+    }
+
+    /// Check if all the single signatures of the batch have been registered
+    pub fn is_fully_registered(&self) -> bool {
+        self.items.iter().all(|item| item.status == 201)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn golden_message_v1() -> RegisterSignaturesBatchResponseMessage {
+        RegisterSignaturesBatchResponseMessage {
+            items: vec![
+                RegisterSignaturesBatchItemMessage {
+                    index: 0,
+                    status: 201,
+                    message: None,
+                },
+                RegisterSignaturesBatchItemMessage {
+                    index: 1,
+                    status: 410,
+                    message: Some("open message already certified".to_string()),
+                },
+            ],
+        }
+    }
+
+    // Test the retro compatibility with possible future upgrades.
+    #[test]
+    fn test_v1() {
+        let json = r#"{
+            "items": [
+                { "index": 0, "status": 201 },
+                { "index": 1, "status": 410, "message": "open message already certified" }
+            ]
+        }"#;
+        let message: RegisterSignaturesBatchResponseMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be successfully parsed into a RegisterSignaturesBatchResponseMessage instance.",
+        );
+
+        assert_eq!(golden_message_v1(), message);
+    }
+
+    #[test]
+    fn is_fully_registered_only_if_all_items_are_created() {
+        assert!(RegisterSignaturesBatchResponseMessage::default().is_fully_registered());
+        assert!(!RegisterSignaturesBatchResponseMessage::dummy().is_fully_registered());
+    }
+}
@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Epoch, PartyId, Stake};
+
+/// Message structure of signer registrations for an epoch.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SignerRegistrationsMessage {
+    /// The epoch at which the registration was sent.
+    pub registered_at: Epoch,
+
+    /// The epoch at which the registration was able to send signatures.
+    pub signing_at: Epoch,
+
+    /// The signer registrations
+    pub registrations: Vec<SignerRegistrationsListItemMessage>,
+}
+
+/// Message structure of a signer registration
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SignerRegistrationsListItemMessage {
+    /// The registered signer party id
+    pub party_id: PartyId,
+
+    /// The registered signer stake
+    pub stake: Stake,
+
+    /// The registered signer pool ticker
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_ticker: Option<String>,
+
+    /// Date and time at which the signer registered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registration_time: Option<DateTime<Utc>>,
+}
+
+impl SignerRegistrationsMessage {
+    /// Build a [SignerRegistrationsMessage] for the given registration epoch.
+    pub fn new(
+        registered_at: Epoch,
+        registrations: Vec<SignerRegistrationsListItemMessage>,
+    ) -> Self {
+        Self {
+            registered_at,
+            signing_at: registered_at.offset_to_signer_signing_offset(),
+            registrations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACTUAL_JSON: &str = r#"{
+        "registered_at": 420,
+        "signing_at": 422,
+        "registrations": [
+            {
+                "party_id": "pool1234567890",
+                "stake": 1234,
+                "pool_ticker": "[Pool_Name]",
+                "registration_time": "2024-02-12T13:11:47Z"
+            }
+        ]
+    }"#;
+
+    const PREVIOUS_JSON: &str = r#"{
+        "registered_at": 420,
+        "signing_at": 422,
+        "registrations": [
+            {
+                "party_id": "pool1234567890",
+                "stake": 1234
+            }
+        ]
+    }"#;
+
+    fn golden_message(
+        pool_ticker: Option<String>,
+        registration_time: Option<DateTime<Utc>>,
+    ) -> SignerRegistrationsMessage {
+        SignerRegistrationsMessage::new(
+            Epoch(420),
+            vec![SignerRegistrationsListItemMessage {
+                party_id: "pool1234567890".to_string(),
+                stake: 1234,
+                pool_ticker,
+                registration_time,
+            }],
+        )
+    }
+
+    #[test]
+    fn test_actual_json_deserialized_into_actual_message() {
+        let message: SignerRegistrationsMessage = serde_json::from_str(ACTUAL_JSON).expect(
+            "This JSON is expected to be successfully parsed into a SignerRegistrationsMessage instance.",
+        );
+
+        assert_eq!(
+            golden_message(
+                Some("[Pool_Name]".to_string()),
+                Some(
+                    DateTime::parse_from_rfc3339("2024-02-12T13:11:47Z")
+                        .unwrap()
+                        .with_timezone(&Utc)
+                )
+            ),
+            message
+        );
+    }
+
+    #[test]
+    fn test_previous_json_deserialized_into_actual_message() {
+        let message: SignerRegistrationsMessage = serde_json::from_str(PREVIOUS_JSON).expect(
+            "This JSON is expected to be successfully parsed into a SignerRegistrationsMessage instance.",
+        );
+
+        assert_eq!(golden_message(None, None), message);
+    }
+}
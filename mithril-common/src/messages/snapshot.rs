@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::entities::{CardanoDbBeacon, CompressionAlgorithm, Epoch};
+use crate::entities::{CardanoDbBeacon, CompressionAlgorithm, Epoch, SnapshotChunkChecksums};
 
 /// Message structure of a snapshot
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -31,6 +31,11 @@ pub struct SnapshotMessage {
     /// Cardano node version
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cardano_node_version: Option<String>,
+
+    /// Checksums of the consecutive chunks of the snapshot archive, allowing to verify each
+    /// chunk during the download
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk_checksums: Option<SnapshotChunkChecksums>,
 }
 
 impl SnapshotMessage {
@@ -52,6 +57,7 @@ impl SnapshotMessage {
             locations: vec!["https://host/certificate.tar.gz".to_string()],
             compression_algorithm: Some(CompressionAlgorithm::Gzip),
             cardano_node_version: Some("0.0.1".to_string()),
+            chunk_checksums: None,
         }
     }
 }
@@ -77,6 +83,7 @@ mod tests {
             locations: vec!["https://host/certificate.tar.gz".to_string()],
             compression_algorithm: None,
             cardano_node_version: None,
+            chunk_checksums: None,
         }
     }
 
@@ -97,6 +104,20 @@ mod tests {
             locations: vec!["https://host/certificate.tar.gz".to_string()],
             compression_algorithm: Some(CompressionAlgorithm::Gzip),
             cardano_node_version: Some("0.0.1".to_string()),
+            chunk_checksums: None,
+        }
+    }
+
+    fn golden_message_v3() -> SnapshotMessage {
+        SnapshotMessage {
+            chunk_checksums: Some(SnapshotChunkChecksums {
+                chunk_size: 67108864,
+                sha256: vec![
+                    "a3c7f9b3e1d2c4a5b6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d".to_string(),
+                    "0f1e2d3c4b5a69788796a5b4c3d2e1f00f1e2d3c4b5a69788796a5b4c3d2e1f0".to_string(),
+                ],
+            }),
+            ..golden_message_v2()
         }
     }
 
@@ -148,4 +169,36 @@ mod tests {
 
         assert_eq!(golden_message_v2(), message);
     }
+
+    #[test]
+    fn test_v3() {
+        let json = r#"{
+"digest": "0b9f5ad7f33cc523775c82249294eb8a1541d54f08eb3107cafc5638403ec7c6",
+"beacon": {
+  "network": "preview",
+  "epoch": 86,
+  "immutable_file_number": 1728
+},
+"certificate_hash": "d5daf6c03ace4a9c074e951844075b9b373bafc4e039160e3e2af01823e9abfb",
+"size": 807803196,
+"created_at": "2023-01-19T13:43:05.618857482Z",
+"locations": [
+  "https://host/certificate.tar.gz"
+],
+"compression_algorithm": "gzip",
+"cardano_node_version": "0.0.1",
+"chunk_checksums": {
+  "chunk_size": 67108864,
+  "sha256": [
+    "a3c7f9b3e1d2c4a5b6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d",
+    "0f1e2d3c4b5a69788796a5b4c3d2e1f00f1e2d3c4b5a69788796a5b4c3d2e1f0"
+  ]
+}
+}"#;
+        let message: SnapshotMessage = serde_json::from_str(json).expect(
+            "This JSON is expected to be succesfully parsed into a SnapshotMessage instance.",
+        );
+
+        assert_eq!(golden_message_v3(), message);
+    }
 }
@@ -0,0 +1,577 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use slog::{debug, info, Logger};
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    cardano_block_scanner::{BlockStreamer, ChainScannedBlocks, ImmutableBlockStreamer},
+    chain_observer::ChainObserver,
+    crypto_helper::{MKTree, MKTreeNode},
+    digesters::ImmutableFile,
+    entities::{CardanoUtxo, ChainPoint, Epoch, ProtocolMessage, ProtocolMessagePartKey},
+    signable_builder::SignableBuilder,
+    StdResult,
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+/// File holding a UTxO set, with one JSON serialized [CardanoUtxo] per line in canonical order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UtxoSetFile {
+    path: PathBuf,
+}
+
+impl UtxoSetFile {
+    /// Number of UTxOs appended at once to the Merkle tree when computing its root
+    const MERKLE_TREE_BATCH_SIZE: usize = 10_000;
+
+    /// UtxoSetFile factory, the file at the given path must already be written
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+        }
+    }
+
+    /// Write the given UTxO set, in canonical order, to a file at the given path.
+    ///
+    /// The file is written next to its final path and then renamed, so an existing file is
+    /// always complete.
+    pub fn write(path: &Path, mut utxo_set: Vec<CardanoUtxo>) -> StdResult<Self> {
+        utxo_set.sort();
+        let temporary_path = path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&temporary_path).with_context(|| {
+            format!("Can not create UTxO set file: '{}'", temporary_path.display())
+        })?);
+        for utxo in utxo_set {
+            serde_json::to_writer(&mut writer, &utxo)?;
+            writer.write_all(b"\n")?;
+        }
+        writer
+            .into_inner()
+            .map_err(|err| anyhow!(err.into_error()))?
+            .sync_all()?;
+        std::fs::rename(&temporary_path, path)
+            .with_context(|| format!("Can not write UTxO set file: '{}'", path.display()))?;
+
+        Ok(Self::new(path))
+    }
+
+    /// Path of the file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Compute the Merkle root of the UTxO set and its number of UTxOs, the leaves of the Merkle
+    /// tree are the canonical serialization of the UTxOs.
+    ///
+    /// The file is read by batches of UTxOs, it is never loaded at once in memory.
+    pub fn compute_merkle_root(&self) -> StdResult<(MKTreeNode, u64)> {
+        let file = File::open(&self.path)
+            .with_context(|| format!("Can not open UTxO set file: '{}'", self.path.display()))?;
+        let mut mk_tree = MKTree::new::<MKTreeNode>(&[])?;
+        let mut batch = Vec::with_capacity(Self::MERKLE_TREE_BATCH_SIZE);
+        let mut utxos_count = 0;
+        for line in BufReader::new(file).lines() {
+            let utxo: CardanoUtxo = serde_json::from_str(&line?).with_context(|| {
+                format!("Can not parse UTxO set file: '{}'", self.path.display())
+            })?;
+            batch.push(MKTreeNode::from(utxo));
+            utxos_count += 1;
+            if batch.len() == Self::MERKLE_TREE_BATCH_SIZE {
+                mk_tree.append(&batch)?;
+                batch.clear();
+            }
+        }
+        mk_tree.append(&batch)?;
+
+        let mk_root = mk_tree
+            .compute_root()
+            .with_context(|| "Can not compute the Merkle root of the UTxO set")?;
+
+        Ok((mk_root, utxos_count))
+    }
+}
+
+/// Cardano UTxO set retriever
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait UtxoSetRetriever: Send + Sync {
+    /// Returns the file of the UTxO set of the ledger state at the boundary of the given epoch
+    async fn retrieve_utxo_set(&self, epoch: Epoch) -> StdResult<UtxoSetFile>;
+}
+
+/// A [UtxoSetRetriever] that takes snapshots of the UTxO set of the ledger state at the boundary
+/// of the epochs, that is to say right after the last block of the preceding epoch.
+///
+/// The epoch boundary is searched from the tip of the immutable files using a [ChainObserver],
+/// so a snapshot can only be taken while the boundary is not yet immutable. Snapshots are written
+/// to files named after their epoch and reused as is, so the UTxO set of an epoch is the same
+/// on every node and across restarts.
+pub struct EpochBoundaryUtxoSetRetriever {
+    chain_observer: Arc<dyn ChainObserver>,
+    immutable_db_directory: PathBuf,
+    snapshots_directory: PathBuf,
+    snapshot_lock: Mutex<()>,
+    logger: Logger,
+}
+
+impl EpochBoundaryUtxoSetRetriever {
+    /// Number of epochs for which the snapshots are kept
+    const KEPT_SNAPSHOTS: u64 = 3;
+
+    /// Constructor
+    pub fn new(
+        chain_observer: Arc<dyn ChainObserver>,
+        immutable_db_directory: &Path,
+        snapshots_directory: &Path,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            chain_observer,
+            immutable_db_directory: immutable_db_directory.to_path_buf(),
+            snapshots_directory: snapshots_directory.to_path_buf(),
+            snapshot_lock: Mutex::new(()),
+            logger,
+        }
+    }
+
+    fn snapshot_path(&self, epoch: Epoch) -> PathBuf {
+        self.snapshots_directory
+            .join(format!("utxo-set-{epoch}.jsonl"))
+    }
+
+    /// Returns the chain point of the last block of the last completed immutable file
+    async fn get_immutable_tip(&self) -> StdResult<ChainPoint> {
+        let last_immutable_chunk = ImmutableFile::list_completed_in_dir(&self.immutable_db_directory)?
+            .into_iter()
+            .rfind(|f| f.filename.contains("chunk"))
+            .ok_or(anyhow!(
+                "No completed immutable file in directory: '{}'",
+                self.immutable_db_directory.display()
+            ))?;
+        let mut streamer =
+            ImmutableBlockStreamer::new(vec![last_immutable_chunk], false, self.logger.clone());
+
+        let mut last_block = None;
+        while let Some(ChainScannedBlocks::RollForwards(blocks)) = streamer.poll_next().await? {
+            last_block = blocks.into_iter().last().or(last_block);
+        }
+
+        last_block
+            .map(|block| ChainPoint::new(block.slot_number, block.block_number, block.block_hash))
+            .ok_or(anyhow!("No block in the last completed immutable file"))
+    }
+
+    async fn take_snapshot(&self, epoch: Epoch) -> StdResult<UtxoSetFile> {
+        let immutable_tip = self.get_immutable_tip().await?;
+        let boundary = self
+            .chain_observer
+            .get_epoch_boundary_chain_point(epoch, &immutable_tip)
+            .await?
+            .ok_or(anyhow!("The boundary of epoch '{epoch}' is not reached yet"))?;
+        info!(
+            self.logger,
+            "Take snapshot of the UTxO set at the boundary of epoch '{epoch}'";
+            "chain_point" => ?boundary
+        );
+
+        let utxo_set = self.chain_observer.get_utxo_set(&boundary).await?;
+        std::fs::create_dir_all(&self.snapshots_directory)?;
+        let path = self.snapshot_path(epoch);
+        let utxo_set_file =
+            tokio::task::spawn_blocking(move || UtxoSetFile::write(&path, utxo_set)).await??;
+        self.prune_snapshots(epoch)?;
+
+        Ok(utxo_set_file)
+    }
+
+    fn prune_snapshots(&self, epoch: Epoch) -> StdResult<()> {
+        let oldest_kept_epoch = epoch - (Self::KEPT_SNAPSHOTS - 1);
+        for entry in std::fs::read_dir(&self.snapshots_directory)? {
+            let path = entry?.path();
+            let snapshot_epoch = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix("utxo-set-"))
+                .and_then(|name| name.strip_suffix(".jsonl"))
+                .and_then(|snapshot_epoch| snapshot_epoch.parse::<u64>().ok());
+            if snapshot_epoch.is_some_and(|snapshot_epoch| snapshot_epoch < *oldest_kept_epoch) {
+                debug!(self.logger, "Prune UTxO set snapshot: '{}'", path.display());
+                std::fs::remove_file(&path)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UtxoSetRetriever for EpochBoundaryUtxoSetRetriever {
+    async fn retrieve_utxo_set(&self, epoch: Epoch) -> StdResult<UtxoSetFile> {
+        let _snapshot_lock = self.snapshot_lock.lock().await;
+        let path = self.snapshot_path(epoch);
+        if path.exists() {
+            return Ok(UtxoSetFile::new(&path));
+        }
+
+        self.take_snapshot(epoch).await.with_context(|| {
+            format!("EpochBoundaryUtxoSetRetriever can not retrieve the UTxO set of epoch: '{epoch}'")
+        })
+    }
+}
+
+/// A [CardanoUtxoSetSignableBuilder] builder
+pub struct CardanoUtxoSetSignableBuilder {
+    utxo_set_retriever: Arc<dyn UtxoSetRetriever>,
+    epoch_merkle_root: RwLock<Option<(Epoch, MKTreeNode)>>,
+    logger: Logger,
+}
+
+impl CardanoUtxoSetSignableBuilder {
+    /// Constructor
+    pub fn new(utxo_set_retriever: Arc<dyn UtxoSetRetriever>, logger: Logger) -> Self {
+        Self {
+            utxo_set_retriever,
+            epoch_merkle_root: RwLock::new(None),
+            logger,
+        }
+    }
+
+    async fn get_merkle_root(&self, epoch: Epoch) -> StdResult<MKTreeNode> {
+        let mut epoch_merkle_root = self.epoch_merkle_root.write().await;
+        match epoch_merkle_root.as_ref() {
+            Some((merkle_root_epoch, merkle_root)) if *merkle_root_epoch == epoch => {
+                Ok(merkle_root.clone())
+            }
+            _ => {
+                let utxo_set_file = self.utxo_set_retriever.retrieve_utxo_set(epoch).await?;
+                let (merkle_root, _) =
+                    tokio::task::spawn_blocking(move || utxo_set_file.compute_merkle_root())
+                        .await??;
+                *epoch_merkle_root = Some((epoch, merkle_root.clone()));
+
+                Ok(merkle_root)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl SignableBuilder<Epoch> for CardanoUtxoSetSignableBuilder {
+    async fn compute_protocol_message(&self, beacon: Epoch) -> StdResult<ProtocolMessage> {
+        debug!(
+            self.logger,
+            "Compute protocol message for CardanoUtxoSet at epoch: {beacon}"
+        );
+
+        let mk_root = self.get_merkle_root(beacon).await?;
+
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoUtxoSetMerkleRoot,
+            mk_root.to_hex(),
+        );
+
+        Ok(protocol_message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chain_observer::{FakeObserver, MockChainObserver},
+        entities::TimePoint,
+        test_utils::{TempDir, TestLogger},
+    };
+
+    use super::*;
+
+    fn fake_utxo_set() -> Vec<CardanoUtxo> {
+        vec![
+            CardanoUtxo::new("tx-hash-1", 0, "output-1"),
+            CardanoUtxo::new("tx-hash-1", 1, "output-2"),
+            CardanoUtxo::new("tx-hash-2", 0, "output-3"),
+        ]
+    }
+
+    fn immutable_db_directory() -> PathBuf {
+        PathBuf::from("../mithril-test-lab/test_data/immutable/")
+    }
+
+    fn write_utxo_set_file(dir: &Path, utxo_set: Vec<CardanoUtxo>) -> UtxoSetFile {
+        UtxoSetFile::write(&dir.join("utxo-set.jsonl"), utxo_set).unwrap()
+    }
+
+    #[test]
+    fn utxo_set_file_is_written_in_canonical_order() {
+        let dir = TempDir::create("cardano_utxo_set", "utxo_set_file_is_written_in_canonical_order");
+        let mut reversed_utxo_set = fake_utxo_set();
+        reversed_utxo_set.reverse();
+
+        let utxo_set_file = write_utxo_set_file(&dir, reversed_utxo_set);
+
+        let written_utxo_set: Vec<CardanoUtxo> = std::fs::read_to_string(utxo_set_file.path())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(fake_utxo_set(), written_utxo_set);
+    }
+
+    #[test]
+    fn utxo_set_file_compute_merkle_root_of_its_utxos() {
+        let dir = TempDir::create(
+            "cardano_utxo_set",
+            "utxo_set_file_compute_merkle_root_of_its_utxos",
+        );
+        let utxo_set_file = write_utxo_set_file(&dir, fake_utxo_set());
+
+        let (merkle_root, utxos_count) = utxo_set_file.compute_merkle_root().unwrap();
+
+        let expected_merkle_root = MKTree::new(&fake_utxo_set())
+            .unwrap()
+            .compute_root()
+            .unwrap();
+        assert_eq!(expected_merkle_root, merkle_root);
+        assert_eq!(3, utxos_count);
+    }
+
+    #[tokio::test]
+    async fn test_compute_signable() {
+        let dir = TempDir::create("cardano_utxo_set", "test_compute_signable");
+        let utxo_set_file = write_utxo_set_file(&dir, fake_utxo_set());
+        let expected_mk_root = MKTree::new(&fake_utxo_set())
+            .unwrap()
+            .compute_root()
+            .unwrap();
+        let mut utxo_set_retriever = MockUtxoSetRetriever::new();
+        utxo_set_retriever
+            .expect_retrieve_utxo_set()
+            .withf(|epoch| *epoch == Epoch(5))
+            .return_once(move |_| Ok(utxo_set_file));
+        let signable_builder =
+            CardanoUtxoSetSignableBuilder::new(Arc::new(utxo_set_retriever), TestLogger::stdout());
+
+        let signable = signable_builder
+            .compute_protocol_message(Epoch(5))
+            .await
+            .unwrap();
+
+        let mut signable_expected = ProtocolMessage::new();
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::CardanoUtxoSetMerkleRoot,
+            expected_mk_root.to_hex(),
+        );
+        assert_eq!(signable_expected, signable);
+    }
+
+    #[tokio::test]
+    async fn test_compute_signable_read_the_utxo_set_once_per_epoch() {
+        let dir = TempDir::create(
+            "cardano_utxo_set",
+            "test_compute_signable_read_the_utxo_set_once_per_epoch",
+        );
+        let utxo_set_file = write_utxo_set_file(&dir, fake_utxo_set());
+        let mut utxo_set_retriever = MockUtxoSetRetriever::new();
+        utxo_set_retriever
+            .expect_retrieve_utxo_set()
+            .times(1)
+            .returning(move |_| Ok(utxo_set_file.clone()));
+        let signable_builder =
+            CardanoUtxoSetSignableBuilder::new(Arc::new(utxo_set_retriever), TestLogger::stdout());
+
+        let first_signable = signable_builder
+            .compute_protocol_message(Epoch(5))
+            .await
+            .unwrap();
+        let second_signable = signable_builder
+            .compute_protocol_message(Epoch(5))
+            .await
+            .unwrap();
+
+        assert_eq!(first_signable, second_signable);
+    }
+
+    #[tokio::test]
+    async fn test_compute_signable_fail_with_empty_utxo_set() {
+        let dir = TempDir::create(
+            "cardano_utxo_set",
+            "test_compute_signable_fail_with_empty_utxo_set",
+        );
+        let utxo_set_file = write_utxo_set_file(&dir, vec![]);
+        let mut utxo_set_retriever = MockUtxoSetRetriever::new();
+        utxo_set_retriever
+            .expect_retrieve_utxo_set()
+            .return_once(|_| Ok(utxo_set_file));
+        let signable_builder =
+            CardanoUtxoSetSignableBuilder::new(Arc::new(utxo_set_retriever), TestLogger::stdout());
+
+        signable_builder
+            .compute_protocol_message(Epoch(5))
+            .await
+            .expect_err("Merkle root of an empty UTxO set should not be computed");
+    }
+
+    #[tokio::test]
+    async fn epoch_boundary_retriever_take_a_snapshot_in_canonical_order() {
+        let snapshots_dir = TempDir::create(
+            "cardano_utxo_set",
+            "epoch_boundary_retriever_take_a_snapshot_in_canonical_order",
+        );
+        let chain_observer = FakeObserver::new(Some(TimePoint::dummy()));
+        let mut reversed_utxo_set = fake_utxo_set();
+        reversed_utxo_set.reverse();
+        chain_observer.set_utxo_set(reversed_utxo_set).await;
+        let retriever = EpochBoundaryUtxoSetRetriever::new(
+            Arc::new(chain_observer),
+            &immutable_db_directory(),
+            &snapshots_dir,
+            TestLogger::stdout(),
+        );
+
+        let utxo_set_file = retriever
+            .retrieve_utxo_set(TimePoint::dummy().epoch)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            snapshots_dir.join(format!("utxo-set-{}.jsonl", TimePoint::dummy().epoch)),
+            utxo_set_file.path()
+        );
+        let expected_utxo_set_file = write_utxo_set_file(&snapshots_dir, fake_utxo_set());
+        assert_eq!(
+            std::fs::read(expected_utxo_set_file.path()).unwrap(),
+            std::fs::read(utxo_set_file.path()).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn epoch_boundary_retriever_reuse_the_snapshot_of_an_epoch() {
+        let snapshots_dir = TempDir::create(
+            "cardano_utxo_set",
+            "epoch_boundary_retriever_reuse_the_snapshot_of_an_epoch",
+        );
+        let epoch = TimePoint::dummy().epoch;
+        let chain_observer = Arc::new(FakeObserver::new(Some(TimePoint::dummy())));
+        chain_observer.set_utxo_set(fake_utxo_set()).await;
+        let retriever = EpochBoundaryUtxoSetRetriever::new(
+            chain_observer.clone(),
+            &immutable_db_directory(),
+            &snapshots_dir,
+            TestLogger::stdout(),
+        );
+        let (merkle_root, _) = retriever
+            .retrieve_utxo_set(epoch)
+            .await
+            .unwrap()
+            .compute_merkle_root()
+            .unwrap();
+
+        chain_observer
+            .set_utxo_set(vec![CardanoUtxo::new("tx-hash-3", 0, "output-4")])
+            .await;
+        chain_observer.next_epoch().await;
+        let (merkle_root_after_epoch_change, _) = retriever
+            .retrieve_utxo_set(epoch)
+            .await
+            .unwrap()
+            .compute_merkle_root()
+            .unwrap();
+
+        assert_eq!(merkle_root, merkle_root_after_epoch_change);
+    }
+
+    #[tokio::test]
+    async fn epoch_boundary_retriever_reuse_existing_snapshot_after_restart() {
+        let snapshots_dir = TempDir::create(
+            "cardano_utxo_set",
+            "epoch_boundary_retriever_reuse_existing_snapshot_after_restart",
+        );
+        let epoch = Epoch(5);
+        let existing_snapshot = UtxoSetFile::write(
+            &snapshots_dir.join(format!("utxo-set-{epoch}.jsonl")),
+            fake_utxo_set(),
+        )
+        .unwrap();
+        let retriever = EpochBoundaryUtxoSetRetriever::new(
+            Arc::new(MockChainObserver::new()),
+            &immutable_db_directory(),
+            &snapshots_dir,
+            TestLogger::stdout(),
+        );
+
+        let utxo_set_file = retriever.retrieve_utxo_set(epoch).await.unwrap();
+
+        assert_eq!(existing_snapshot, utxo_set_file);
+    }
+
+    #[tokio::test]
+    async fn epoch_boundary_retriever_fail_if_the_epoch_boundary_is_not_reached() {
+        let snapshots_dir = TempDir::create(
+            "cardano_utxo_set",
+            "epoch_boundary_retriever_fail_if_the_epoch_boundary_is_not_reached",
+        );
+        let chain_observer = FakeObserver::new(Some(TimePoint::dummy()));
+        chain_observer.set_utxo_set(fake_utxo_set()).await;
+        let retriever = EpochBoundaryUtxoSetRetriever::new(
+            Arc::new(chain_observer),
+            &immutable_db_directory(),
+            &snapshots_dir,
+            TestLogger::stdout(),
+        );
+
+        retriever
+            .retrieve_utxo_set(TimePoint::dummy().epoch + 1)
+            .await
+            .expect_err("Retrieving the UTxO set of an epoch not started yet should fail");
+    }
+
+    #[tokio::test]
+    async fn epoch_boundary_retriever_prune_old_snapshots() {
+        let snapshots_dir = TempDir::create(
+            "cardano_utxo_set",
+            "epoch_boundary_retriever_prune_old_snapshots",
+        );
+        for epoch in 1..=4 {
+            UtxoSetFile::write(
+                &snapshots_dir.join(format!("utxo-set-{epoch}.jsonl")),
+                fake_utxo_set(),
+            )
+            .unwrap();
+        }
+        let time_point = TimePoint {
+            epoch: Epoch(5),
+            ..TimePoint::dummy()
+        };
+        let chain_observer = FakeObserver::new(Some(time_point));
+        chain_observer.set_utxo_set(fake_utxo_set()).await;
+        let retriever = EpochBoundaryUtxoSetRetriever::new(
+            Arc::new(chain_observer),
+            &immutable_db_directory(),
+            &snapshots_dir,
+            TestLogger::stdout(),
+        );
+
+        retriever.retrieve_utxo_set(Epoch(5)).await.unwrap();
+
+        let mut snapshots: Vec<String> = std::fs::read_dir(&snapshots_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        snapshots.sort();
+        assert_eq!(
+            vec![
+                "utxo-set-3.jsonl".to_string(),
+                "utxo-set-4.jsonl".to_string(),
+                "utxo-set-5.jsonl".to_string(),
+            ],
+            snapshots
+        );
+    }
+}
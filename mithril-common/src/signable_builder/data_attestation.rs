@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+
+use crate::{
+    entities::{DataAttestationBeacon, ProtocolMessage, ProtocolMessagePartKey},
+    signable_builder::SignableBuilder,
+    StdResult,
+};
+
+/// A [DataAttestationSignableBuilder] builder
+#[derive(Default)]
+pub struct DataAttestationSignableBuilder {}
+
+#[async_trait]
+impl SignableBuilder<DataAttestationBeacon> for DataAttestationSignableBuilder {
+    // The attested payload hash is given by the beacon, there is no data to read to compute it
+    async fn compute_protocol_message(
+        &self,
+        beacon: DataAttestationBeacon,
+    ) -> StdResult<ProtocolMessage> {
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::DataAttestationNamespace,
+            beacon.namespace,
+        );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::DataAttestationPayloadHash,
+            beacon.payload_hash,
+        );
+
+        Ok(protocol_message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::Epoch;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_compute_signable() {
+        let data_attestation_signable_builder = DataAttestationSignableBuilder::default();
+        let signable = data_attestation_signable_builder
+            .compute_protocol_message(DataAttestationBeacon::new(Epoch(1), "namespace", "abc123"))
+            .await
+            .unwrap();
+
+        let mut signable_expected = ProtocolMessage::new();
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::DataAttestationNamespace,
+            "namespace".to_string(),
+        );
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::DataAttestationPayloadHash,
+            "abc123".to_string(),
+        );
+        assert_eq!(signable_expected, signable);
+    }
+}
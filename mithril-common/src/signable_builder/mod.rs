@@ -1,9 +1,11 @@
 //! The module used for building signables
 
+mod data_attestation;
 mod interface;
 mod mithril_stake_distribution;
 mod signable_builder_service;
 
+pub use data_attestation::*;
 pub use interface::*;
 pub use mithril_stake_distribution::*;
 pub use signable_builder_service::*;
@@ -11,7 +13,9 @@ pub use signable_builder_service::*;
 cfg_fs! {
     mod cardano_immutable_full_signable_builder;
     mod cardano_transactions;
+    mod cardano_utxo_set;
 
     pub use cardano_immutable_full_signable_builder::*;
     pub use cardano_transactions::*;
+    pub use cardano_utxo_set::*;
 }
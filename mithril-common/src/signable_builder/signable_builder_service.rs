@@ -3,7 +3,10 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 use crate::{
-    entities::{BlockNumber, CardanoDbBeacon, Epoch, ProtocolMessage, SignedEntityType},
+    entities::{
+        BlockNumber, CardanoDbBeacon, DataAttestationBeacon, Epoch, ProtocolMessage,
+        SignedEntityType,
+    },
     signable_builder::SignableBuilder,
     StdResult,
 };
@@ -27,6 +30,8 @@ pub struct MithrilSignableBuilderService {
     mithril_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
     immutable_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
     cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
+    cardano_utxo_set_signable_builder: Arc<dyn SignableBuilder<Epoch>>,
+    data_attestation_signable_builder: Arc<dyn SignableBuilder<DataAttestationBeacon>>,
 }
 
 impl MithrilSignableBuilderService {
@@ -35,11 +40,15 @@ impl MithrilSignableBuilderService {
         mithril_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
         immutable_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
         cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
+        cardano_utxo_set_signable_builder: Arc<dyn SignableBuilder<Epoch>>,
+        data_attestation_signable_builder: Arc<dyn SignableBuilder<DataAttestationBeacon>>,
     ) -> Self {
         Self {
             mithril_stake_distribution_builder,
             immutable_signable_builder,
             cardano_transactions_signable_builder,
+            cardano_utxo_set_signable_builder,
+            data_attestation_signable_builder,
         }
     }
 }
@@ -73,6 +82,20 @@ impl SignableBuilderService for MithrilSignableBuilderService {
                 .with_context(|| format!(
                     "Signable builder service can not compute protocol message with block_number: '{block_number}'"
                 ))?,
+            SignedEntityType::CardanoUtxoSet(e) => self
+                .cardano_utxo_set_signable_builder
+                .compute_protocol_message(e)
+                .await
+                .with_context(|| format!(
+                    "Signable builder service can not compute protocol message with epoch: '{e}'"
+                ))?,
+            SignedEntityType::DataAttestation(beacon) => self
+                .data_attestation_signable_builder
+                .compute_protocol_message(beacon.clone())
+                .await
+                .with_context(|| format!(
+                    "Signable builder service can not compute protocol message with beacon: '{beacon}'"
+                ))?,
         };
 
         Ok(protocol_message)
@@ -119,11 +142,16 @@ mod tests {
             MockSignableBuilderImpl::<CardanoDbBeacon>::new();
         let mock_cardano_transactions_signable_builder =
             MockSignableBuilderImpl::<BlockNumber>::new();
+        let mock_cardano_utxo_set_signable_builder = MockSignableBuilderImpl::<Epoch>::new();
+        let mock_data_attestation_signable_builder =
+            MockSignableBuilderImpl::<DataAttestationBeacon>::new();
 
         let signable_builder_service = MithrilSignableBuilderService::new(
             Arc::new(mock_mithril_stake_distribution_signable_builder),
             Arc::new(mock_cardano_immutable_files_full_signable_builder),
             Arc::new(mock_cardano_transactions_signable_builder),
+            Arc::new(mock_cardano_utxo_set_signable_builder),
+            Arc::new(mock_data_attestation_signable_builder),
         );
 
         let signed_entity_type = SignedEntityType::MithrilStakeDistribution(Epoch(1));
@@ -148,11 +176,16 @@ mod tests {
             .return_once(move |_| Ok(protocol_message_clone));
         let mock_cardano_transactions_signable_builder =
             MockSignableBuilderImpl::<BlockNumber>::new();
+        let mock_cardano_utxo_set_signable_builder = MockSignableBuilderImpl::<Epoch>::new();
+        let mock_data_attestation_signable_builder =
+            MockSignableBuilderImpl::<DataAttestationBeacon>::new();
 
         let signable_builder_service = MithrilSignableBuilderService::new(
             Arc::new(mock_mithril_stake_distribution_signable_builder),
             Arc::new(mock_cardano_immutable_files_full_signable_builder),
             Arc::new(mock_cardano_transactions_signable_builder),
+            Arc::new(mock_cardano_utxo_set_signable_builder),
+            Arc::new(mock_data_attestation_signable_builder),
         );
 
         let signed_entity_type =
@@ -178,11 +211,16 @@ mod tests {
             .expect_compute_protocol_message()
             .once()
             .return_once(move |_| Ok(protocol_message_clone));
+        let mock_cardano_utxo_set_signable_builder = MockSignableBuilderImpl::<Epoch>::new();
+        let mock_data_attestation_signable_builder =
+            MockSignableBuilderImpl::<DataAttestationBeacon>::new();
 
         let signable_builder_service = MithrilSignableBuilderService::new(
             Arc::new(mock_mithril_stake_distribution_signable_builder),
             Arc::new(mock_cardano_immutable_files_full_signable_builder),
             Arc::new(mock_cardano_transactions_signable_builder),
+            Arc::new(mock_cardano_utxo_set_signable_builder),
+            Arc::new(mock_data_attestation_signable_builder),
         );
 
         let signed_entity_type = SignedEntityType::CardanoTransactions(Epoch(5), 1000);
@@ -191,4 +229,74 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn build_utxo_set_signable_when_given_cardano_utxo_set_entity_type() {
+        let protocol_message = ProtocolMessage::new();
+        let protocol_message_clone = protocol_message.clone();
+        let mock_mithril_stake_distribution_signable_builder =
+            MockSignableBuilderImpl::<Epoch>::new();
+        let mock_cardano_immutable_files_full_signable_builder =
+            MockSignableBuilderImpl::<CardanoDbBeacon>::new();
+        let mock_cardano_transactions_signable_builder =
+            MockSignableBuilderImpl::<BlockNumber>::new();
+        let mut mock_cardano_utxo_set_signable_builder = MockSignableBuilderImpl::<Epoch>::new();
+        mock_cardano_utxo_set_signable_builder
+            .expect_compute_protocol_message()
+            .once()
+            .return_once(move |_| Ok(protocol_message_clone));
+        let mock_data_attestation_signable_builder =
+            MockSignableBuilderImpl::<DataAttestationBeacon>::new();
+
+        let signable_builder_service = MithrilSignableBuilderService::new(
+            Arc::new(mock_mithril_stake_distribution_signable_builder),
+            Arc::new(mock_cardano_immutable_files_full_signable_builder),
+            Arc::new(mock_cardano_transactions_signable_builder),
+            Arc::new(mock_cardano_utxo_set_signable_builder),
+            Arc::new(mock_data_attestation_signable_builder),
+        );
+
+        let signed_entity_type = SignedEntityType::CardanoUtxoSet(Epoch(5));
+        signable_builder_service
+            .compute_protocol_message(signed_entity_type)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn build_data_attestation_signable_when_given_data_attestation_entity_type() {
+        let protocol_message = ProtocolMessage::new();
+        let protocol_message_clone = protocol_message.clone();
+        let mock_mithril_stake_distribution_signable_builder =
+            MockSignableBuilderImpl::<Epoch>::new();
+        let mock_cardano_immutable_files_full_signable_builder =
+            MockSignableBuilderImpl::<CardanoDbBeacon>::new();
+        let mock_cardano_transactions_signable_builder =
+            MockSignableBuilderImpl::<BlockNumber>::new();
+        let mock_cardano_utxo_set_signable_builder = MockSignableBuilderImpl::<Epoch>::new();
+        let mut mock_data_attestation_signable_builder =
+            MockSignableBuilderImpl::<DataAttestationBeacon>::new();
+        mock_data_attestation_signable_builder
+            .expect_compute_protocol_message()
+            .once()
+            .return_once(move |_| Ok(protocol_message_clone));
+
+        let signable_builder_service = MithrilSignableBuilderService::new(
+            Arc::new(mock_mithril_stake_distribution_signable_builder),
+            Arc::new(mock_cardano_immutable_files_full_signable_builder),
+            Arc::new(mock_cardano_transactions_signable_builder),
+            Arc::new(mock_cardano_utxo_set_signable_builder),
+            Arc::new(mock_data_attestation_signable_builder),
+        );
+
+        let signed_entity_type = SignedEntityType::DataAttestation(DataAttestationBeacon::new(
+            Epoch(5),
+            "namespace",
+            "abc123",
+        ));
+        signable_builder_service
+            .compute_protocol_message(signed_entity_type)
+            .await
+            .unwrap();
+    }
 }
@@ -0,0 +1,430 @@
+use slog_scope::{debug, info, warn};
+use std::io;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+use mithril_common::StdResult;
+
+use crate::Configuration;
+
+/// Name of the socket, in the data stores directory, exposed by the TCP bridge
+const TCP_BRIDGE_SOCKET_FILE: &str = "cardano-node-bridge.socket";
+
+/// Timeout of the health check connections
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// [CardanoNodeConnectivity] related errors.
+#[derive(Error, Debug)]
+pub enum CardanoNodeConnectivityError {
+    /// Error raised when the socket path remapping can't be parsed.
+    #[error("Invalid Cardano node socket path remapping '{0}', expected format is '<node_side_directory>:<signer_side_directory>'")]
+    InvalidRemapping(String),
+
+    /// Error raised when the Cardano node socket does not exist.
+    #[error("Cardano node socket '{path}' does not exist (configured path: '{configured_path}'). If the Cardano node runs in a Docker container, check that the directory of its socket is mounted as a volume in the signer and that `cardano_node_socket_path_remapping` matches this mount.")]
+    SocketNotFound {
+        /// Path of the socket after remapping
+        path: PathBuf,
+        /// Path of the socket as configured
+        configured_path: PathBuf,
+    },
+
+    /// Error raised when the Cardano node socket path is not a socket.
+    #[error("Cardano node socket path '{0}' is not a socket: it should target the socket file created by the Cardano node, not its directory or another file.")]
+    NotASocket(PathBuf),
+
+    /// Error raised when the connection to the Cardano node socket failed.
+    #[error("Could not connect to the Cardano node socket '{path}': check that the Cardano node is running and that the socket is writable by the signer user.")]
+    SocketUnreachable {
+        /// Path of the socket
+        path: PathBuf,
+        /// Connection error
+        source: io::Error,
+    },
+
+    /// Error raised when the TCP bridge to the Cardano node socket can't be reached.
+    #[error("Could not connect to the Cardano node socket TCP bridge '{address}': check that the bridge (ie: socat) is running and that its port is exposed to the signer.")]
+    TcpBridgeUnreachable {
+        /// Address of the TCP bridge
+        address: String,
+        /// Connection error
+        source: io::Error,
+    },
+
+    /// Error raised when the connectivity mode is not supported on the current platform.
+    #[error("Cardano node connectivity through '{0}' is not supported on this platform")]
+    UnsupportedPlatform(String),
+}
+
+/// How the signer reaches the Cardano node local socket.
+///
+/// Containerized setups either share the node socket through a Docker volume, mounted at a
+/// different path in the signer container, or expose it over TCP with a bridge such as
+/// `socat TCP-LISTEN:3333,fork UNIX-CONNECT:/ipc/node.socket`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardanoNodeConnectivity {
+    /// The node socket is directly reachable, at the given path
+    Socket {
+        /// Path of the socket, after remapping
+        path: PathBuf,
+        /// Path of the socket as configured
+        configured_path: PathBuf,
+    },
+
+    /// The node socket is exposed over TCP and bridged to a local socket
+    TcpBridge {
+        /// Address of the TCP bridge
+        address: String,
+        /// Path of the local socket forwarding to the TCP bridge
+        bridge_socket_path: PathBuf,
+    },
+}
+
+impl CardanoNodeConnectivity {
+    /// Create the connectivity described by the configuration
+    pub fn from_configuration(
+        config: &Configuration,
+    ) -> Result<Self, CardanoNodeConnectivityError> {
+        match &config.cardano_node_socket_tcp_bridge {
+            Some(address) => Ok(Self::TcpBridge {
+                address: address.to_owned(),
+                bridge_socket_path: config.data_stores_directory.join(TCP_BRIDGE_SOCKET_FILE),
+            }),
+            None => {
+                let configured_path = config.cardano_node_socket_path.clone();
+                let path = match &config.cardano_node_socket_path_remapping {
+                    Some(remapping) => remap_socket_path(&configured_path, remapping)?,
+                    None => configured_path.clone(),
+                };
+
+                Ok(Self::Socket {
+                    path,
+                    configured_path,
+                })
+            }
+        }
+    }
+
+    /// Check that the Cardano node socket, or its TCP bridge, accepts connections
+    pub fn check_health(&self) -> Result<(), CardanoNodeConnectivityError> {
+        match self {
+            Self::Socket {
+                path,
+                configured_path,
+            } => check_socket_health(path, configured_path),
+            Self::TcpBridge { address, .. } => check_tcp_bridge_health(address),
+        }
+    }
+
+    /// Check the connectivity health, start the TCP bridge forwarding if needed, and return
+    /// the path of the socket to use to communicate with the Cardano node.
+    ///
+    /// Must be called from within a tokio runtime when using a TCP bridge.
+    pub fn prepare(&self) -> StdResult<PathBuf> {
+        self.check_health()?;
+
+        match self {
+            Self::Socket { path, .. } => {
+                info!("Cardano node socket is reachable"; "path" => %path.display());
+                Ok(path.to_owned())
+            }
+            Self::TcpBridge {
+                address,
+                bridge_socket_path,
+            } => {
+                start_tcp_bridge_forwarding(address, bridge_socket_path)?;
+                info!(
+                    "Cardano node socket TCP bridge is reachable, forwarding it to a local socket";
+                    "address" => address, "socket_path" => %bridge_socket_path.display()
+                );
+                Ok(bridge_socket_path.to_owned())
+            }
+        }
+    }
+}
+
+/// Replace the node side directory prefix of the socket path with the signer side one.
+fn remap_socket_path(
+    socket_path: &Path,
+    remapping: &str,
+) -> Result<PathBuf, CardanoNodeConnectivityError> {
+    let (node_side, signer_side) = remapping
+        .split_once(':')
+        .filter(|(node_side, signer_side)| !node_side.is_empty() && !signer_side.is_empty())
+        .ok_or_else(|| CardanoNodeConnectivityError::InvalidRemapping(remapping.to_string()))?;
+
+    match socket_path.strip_prefix(node_side) {
+        Ok(relative_path) => Ok(Path::new(signer_side).join(relative_path)),
+        Err(_) => {
+            warn!(
+                "Cardano node socket path is not in the node side directory of the remapping, it is used as is";
+                "socket_path" => %socket_path.display(), "remapping" => remapping
+            );
+            Ok(socket_path.to_owned())
+        }
+    }
+}
+
+fn check_socket_health(
+    path: &Path,
+    configured_path: &Path,
+) -> Result<(), CardanoNodeConnectivityError> {
+    let metadata = path
+        .metadata()
+        .map_err(|_| CardanoNodeConnectivityError::SocketNotFound {
+            path: path.to_owned(),
+            configured_path: configured_path.to_owned(),
+        })?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+
+        if !metadata.file_type().is_socket() {
+            return Err(CardanoNodeConnectivityError::NotASocket(path.to_owned()));
+        }
+        std::os::unix::net::UnixStream::connect(path).map_err(|source| {
+            CardanoNodeConnectivityError::SocketUnreachable {
+                path: path.to_owned(),
+                source,
+            }
+        })?;
+    }
+    #[cfg(not(unix))]
+    if metadata.is_dir() {
+        return Err(CardanoNodeConnectivityError::NotASocket(path.to_owned()));
+    }
+
+    Ok(())
+}
+
+fn check_tcp_bridge_health(address: &str) -> Result<(), CardanoNodeConnectivityError> {
+    let unreachable = |source| CardanoNodeConnectivityError::TcpBridgeUnreachable {
+        address: address.to_string(),
+        source,
+    };
+    let socket_address = address
+        .to_socket_addrs()
+        .map_err(unreachable)?
+        .next()
+        .ok_or_else(|| {
+            unreachable(io::Error::new(
+                io::ErrorKind::NotFound,
+                "address did not resolve to any socket address",
+            ))
+        })?;
+    TcpStream::connect_timeout(&socket_address, HEALTH_CHECK_TIMEOUT).map_err(unreachable)?;
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn start_tcp_bridge_forwarding(address: &str, bridge_socket_path: &Path) -> StdResult<()> {
+    use anyhow::Context;
+    use tokio::net::{TcpStream, UnixListener};
+
+    if bridge_socket_path.exists() {
+        std::fs::remove_file(bridge_socket_path).with_context(|| {
+            format!(
+                "Could not remove stale Cardano node bridge socket '{}'",
+                bridge_socket_path.display()
+            )
+        })?;
+    }
+    let listener = UnixListener::bind(bridge_socket_path).with_context(|| {
+        format!(
+            "Could not create Cardano node bridge socket '{}'",
+            bridge_socket_path.display()
+        )
+    })?;
+
+    let address = address.to_string();
+    tokio::spawn(async move {
+        loop {
+            let mut local_stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(error) => {
+                    warn!("Cardano node bridge socket failed to accept a connection"; "error" => ?error);
+                    continue;
+                }
+            };
+            let address = address.clone();
+            tokio::spawn(async move {
+                match TcpStream::connect(&address).await {
+                    Ok(mut remote_stream) => {
+                        if let Err(error) =
+                            tokio::io::copy_bidirectional(&mut local_stream, &mut remote_stream)
+                                .await
+                        {
+                            debug!("Cardano node bridge connection closed"; "error" => ?error);
+                        }
+                    }
+                    Err(error) => {
+                        warn!(
+                            "{}",
+                            CardanoNodeConnectivityError::TcpBridgeUnreachable {
+                                address,
+                                source: error
+                            }
+                        );
+                    }
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn start_tcp_bridge_forwarding(_address: &str, _bridge_socket_path: &Path) -> StdResult<()> {
+    Err(CardanoNodeConnectivityError::UnsupportedPlatform("a TCP bridge".to_string()).into())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+
+    fn socket_connectivity(path: &Path) -> CardanoNodeConnectivity {
+        CardanoNodeConnectivity::Socket {
+            path: path.to_owned(),
+            configured_path: path.to_owned(),
+        }
+    }
+
+    #[test]
+    fn remap_socket_path_replace_the_node_side_directory() {
+        assert_eq!(
+            PathBuf::from("/ipc/node.socket"),
+            remap_socket_path(
+                Path::new("/opt/cardano/ipc/node.socket"),
+                "/opt/cardano/ipc:/ipc"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn remap_socket_path_keep_a_path_outside_of_the_node_side_directory() {
+        assert_eq!(
+            PathBuf::from("/tmp/node.socket"),
+            remap_socket_path(Path::new("/tmp/node.socket"), "/opt/cardano/ipc:/ipc").unwrap()
+        );
+    }
+
+    #[test]
+    fn remap_socket_path_fails_with_an_invalid_remapping() {
+        for remapping in ["/opt/cardano/ipc", "/opt/cardano/ipc:", ":/ipc"] {
+            remap_socket_path(Path::new("/opt/cardano/ipc/node.socket"), remapping)
+                .expect_err("an invalid remapping should fail");
+        }
+    }
+
+    #[test]
+    fn connectivity_from_configuration_apply_the_remapping() {
+        let config = Configuration {
+            cardano_node_socket_path: PathBuf::from("/opt/cardano/ipc/node.socket"),
+            cardano_node_socket_path_remapping: Some("/opt/cardano/ipc:/ipc".to_string()),
+            ..Configuration::new_sample("party_id")
+        };
+
+        assert_eq!(
+            CardanoNodeConnectivity::Socket {
+                path: PathBuf::from("/ipc/node.socket"),
+                configured_path: PathBuf::from("/opt/cardano/ipc/node.socket"),
+            },
+            CardanoNodeConnectivity::from_configuration(&config).unwrap()
+        );
+    }
+
+    #[test]
+    fn health_check_fails_if_the_socket_does_not_exist() {
+        let dir = TempDir::create("cardano_node_connectivity", "socket_does_not_exist");
+
+        let error = socket_connectivity(&dir.join("node.socket"))
+            .check_health()
+            .unwrap_err();
+
+        assert!(
+            matches!(error, CardanoNodeConnectivityError::SocketNotFound { .. }),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[test]
+    fn health_check_fails_if_the_path_is_not_a_socket() {
+        let dir = TempDir::create("cardano_node_connectivity", "path_is_not_a_socket");
+
+        let error = socket_connectivity(&dir).check_health().unwrap_err();
+
+        assert!(
+            matches!(error, CardanoNodeConnectivityError::NotASocket(_)),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn health_check_succeeds_on_a_listening_socket() {
+        let dir = TempDir::create_with_short_path("cardano_node_connectivity", "listening_socket");
+        let path = dir.join("node.socket");
+        let _listener = tokio::net::UnixListener::bind(&path).unwrap();
+
+        socket_connectivity(&path).check_health().unwrap();
+    }
+
+    #[tokio::test]
+    async fn health_check_fails_if_the_tcp_bridge_is_unreachable() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        drop(listener);
+
+        let error = CardanoNodeConnectivity::TcpBridge {
+            address,
+            bridge_socket_path: PathBuf::new(),
+        }
+        .check_health()
+        .unwrap_err();
+
+        assert!(
+            matches!(
+                error,
+                CardanoNodeConnectivityError::TcpBridgeUnreachable { .. }
+            ),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn tcp_bridge_forward_the_local_socket_to_the_bridge() {
+        let dir =
+            TempDir::create_with_short_path("cardano_node_connectivity", "tcp_bridge_forwarding");
+        let echo_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = echo_listener.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = echo_listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let (mut reader, mut writer) = stream.split();
+                    tokio::io::copy(&mut reader, &mut writer).await.unwrap();
+                });
+            }
+        });
+        let connectivity = CardanoNodeConnectivity::TcpBridge {
+            address,
+            bridge_socket_path: dir.join(TCP_BRIDGE_SOCKET_FILE),
+        };
+
+        let socket_path = connectivity.prepare().unwrap();
+        let mut stream = tokio::net::UnixStream::connect(&socket_path).await.unwrap();
+        stream.write_all(b"ping").await.unwrap();
+        let mut buffer = [0; 4];
+        stream.read_exact(&mut buffer).await.unwrap();
+
+        assert_eq!(b"ping", &buffer);
+    }
+}
@@ -27,6 +27,17 @@ pub struct Configuration {
     #[example = "`/tmp/cardano.sock`"]
     pub cardano_node_socket_path: PathBuf,
 
+    /// Remapping of the directory of the Cardano node socket, formatted as
+    /// `<node_side_directory>:<signer_side_directory>`, useful when the socket is shared with
+    /// a Docker volume mounted at a different path in the signer container
+    #[example = "`/opt/cardano/ipc:/ipc`"]
+    pub cardano_node_socket_path_remapping: Option<String>,
+
+    /// Address of a TCP bridge (ie: socat) exposing the Cardano node socket, if set the
+    /// signer reaches the Cardano node through it instead of [Self::cardano_node_socket_path]
+    #[example = "`cardano-node:3333`"]
+    pub cardano_node_socket_tcp_bridge: Option<String>,
+
     /// Cardano network
     #[example = "`testnet` or `mainnet` or `devnet`"]
     pub network: String,
@@ -99,6 +110,15 @@ pub struct Configuration {
     /// Metrics HTTP Server listening port.
     pub metrics_server_port: u16,
 
+    /// Enable health server (dependencies statuses on /health).
+    pub enable_health_server: bool,
+
+    /// Health HTTP Server IP.
+    pub health_server_ip: String,
+
+    /// Health HTTP Server listening port.
+    pub health_server_port: u16,
+
     /// If set no error is returned in case of unparsable block and an error log is written instead.
     ///
     /// Will be ignored on (pre)production networks.
@@ -126,6 +146,8 @@ impl Configuration {
             relay_endpoint: None,
             cardano_cli_path: PathBuf::new(),
             cardano_node_socket_path: PathBuf::new(),
+            cardano_node_socket_path_remapping: None,
+            cardano_node_socket_tcp_bridge: None,
             db_directory: PathBuf::new(),
             network: "devnet".to_string(),
             network_magic: Some(42),
@@ -146,6 +168,9 @@ impl Configuration {
             enable_metrics_server: true,
             metrics_server_ip: "0.0.0.0".to_string(),
             metrics_server_port: 9090,
+            enable_health_server: false,
+            health_server_ip: "0.0.0.0".to_string(),
+            health_server_port: 9091,
             allow_unparsable_block: false,
             enable_transaction_pruning: false,
             transactions_import_block_chunk_size: 1000,
@@ -210,6 +235,12 @@ pub struct DefaultConfiguration {
     /// Metrics HTTP server listening port.
     pub metrics_server_port: u16,
 
+    /// Health HTTP server IP.
+    pub health_server_ip: String,
+
+    /// Health HTTP server listening port.
+    pub health_server_port: u16,
+
     /// Network security parameter
     pub network_security_parameter: BlockNumber,
 
@@ -235,6 +266,8 @@ impl Default for DefaultConfiguration {
             era_reader_adapter_type: "bootstrap".to_string(),
             metrics_server_ip: "0.0.0.0".to_string(),
             metrics_server_port: 9090,
+            health_server_ip: "0.0.0.0".to_string(),
+            health_server_port: 9091,
             network_security_parameter: 2160, // 2160 is the mainnet value
             preload_security_parameter: 3000,
             enable_transaction_pruning: true,
@@ -267,6 +300,8 @@ impl Source for DefaultConfiguration {
         insert_default_configuration!(result, myself.era_reader_adapter_type);
         insert_default_configuration!(result, myself.metrics_server_ip);
         insert_default_configuration!(result, myself.metrics_server_port);
+        insert_default_configuration!(result, myself.health_server_ip);
+        insert_default_configuration!(result, myself.health_server_port);
         insert_default_configuration!(result, myself.network_security_parameter);
         insert_default_configuration!(result, myself.preload_security_parameter);
         insert_default_configuration!(result, myself.enable_transaction_pruning);
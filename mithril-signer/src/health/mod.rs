@@ -0,0 +1,11 @@
+//! health module.
+//! This module contains the signer health service and health server, used by the monitoring of
+//! the signers to check their dependencies without parsing their logs.
+
+mod recorder;
+mod server;
+mod service;
+
+pub use recorder::{HealthRecorder, RecordedError, RecordedStateTransition};
+pub use server::HealthServer;
+pub use service::{DependencyHealth, HealthReport, HealthService, HealthStatus};
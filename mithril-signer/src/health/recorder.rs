@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::RwLock;
+
+use crate::SignerState;
+
+/// A state transition of the state machine
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RecordedStateTransition {
+    /// State before the transition
+    pub from: String,
+
+    /// State after the transition
+    pub to: String,
+
+    /// Date of the transition
+    pub at: DateTime<Utc>,
+}
+
+/// An error that occurred during a cycle of the state machine
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RecordedError {
+    /// Error message
+    pub message: String,
+
+    /// Date of the error
+    pub at: DateTime<Utc>,
+}
+
+/// Record the last state transition and the last error of the state machine so they can be
+/// reported by the [HealthService][crate::health::HealthService].
+#[derive(Debug, Default)]
+pub struct HealthRecorder {
+    last_state_transition: RwLock<Option<RecordedStateTransition>>,
+    last_error: RwLock<Option<RecordedError>>,
+}
+
+impl HealthRecorder {
+    /// Record a state transition of the state machine.
+    pub fn record_state_transition(&self, from: &SignerState, to: &SignerState) {
+        let mut last_state_transition = self.last_state_transition.write().unwrap();
+        *last_state_transition = Some(RecordedStateTransition {
+            from: from.to_string(),
+            to: to.to_string(),
+            at: Utc::now(),
+        });
+    }
+
+    /// Record an error that occurred during a cycle of the state machine.
+    pub fn record_error<E: ToString>(&self, error: &E) {
+        let mut last_error = self.last_error.write().unwrap();
+        *last_error = Some(RecordedError {
+            message: error.to_string(),
+            at: Utc::now(),
+        });
+    }
+
+    /// Get the last recorded state transition if any.
+    pub fn get_last_state_transition(&self) -> Option<RecordedStateTransition> {
+        self.last_state_transition.read().unwrap().clone()
+    }
+
+    /// Get the last recorded error if any.
+    pub fn get_last_error(&self) -> Option<RecordedError> {
+        self.last_error.read().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::entities::Epoch;
+
+    use super::*;
+
+    #[test]
+    fn keep_only_the_last_state_transition_and_error() {
+        let recorder = HealthRecorder::default();
+        assert_eq!(None, recorder.get_last_state_transition());
+        assert_eq!(None, recorder.get_last_error());
+
+        recorder.record_state_transition(
+            &SignerState::Init,
+            &SignerState::Unregistered { epoch: Epoch(1) },
+        );
+        recorder.record_state_transition(
+            &SignerState::Unregistered { epoch: Epoch(1) },
+            &SignerState::Registered { epoch: Epoch(1) },
+        );
+        recorder.record_error(&"first error");
+        recorder.record_error(&"second error");
+
+        let last_state_transition = recorder.get_last_state_transition().unwrap();
+        assert_eq!(
+            SignerState::Unregistered { epoch: Epoch(1) }.to_string(),
+            last_state_transition.from
+        );
+        assert_eq!(
+            SignerState::Registered { epoch: Epoch(1) }.to_string(),
+            last_state_transition.to
+        );
+        assert_eq!("second error", recorder.get_last_error().unwrap().message);
+    }
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
+use mithril_common::StdResult;
+use slog_scope::{info, warn};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot::Receiver;
+
+use crate::health::{HealthService, HealthStatus};
+
+/// The HealthServer is responsible for exposing the health of the signer.
+pub struct HealthServer {
+    server_port: u16,
+    server_ip: String,
+    health_service: Arc<HealthService>,
+}
+
+impl HealthServer {
+    /// Create a new HealthServer instance.
+    pub fn new(server_ip: &str, server_port: u16, health_service: Arc<HealthService>) -> Self {
+        Self {
+            server_port,
+            server_ip: server_ip.to_string(),
+            health_service,
+        }
+    }
+
+    /// Health server endpoint.
+    pub fn endpoint(&self) -> String {
+        format!("http://{}:{}", self.server_ip, self.server_port)
+    }
+
+    /// Serve the health report on a HTTP server.
+    ///
+    /// The `/health` route answers `200 OK` if all the dependencies of the signer are healthy and
+    /// `503 Service Unavailable` otherwise, with the health report as body in both cases.
+    pub async fn start(&self, shutdown_rx: Receiver<()>) -> StdResult<()> {
+        let listener =
+            tokio::net::TcpListener::bind(format!("{}:{}", self.server_ip, self.server_port))
+                .await?;
+
+        self.serve(listener, shutdown_rx).await
+    }
+
+    async fn serve(&self, listener: TcpListener, shutdown_rx: Receiver<()>) -> StdResult<()> {
+        let local_addr = listener.local_addr()?;
+        info!("HealthServer: starting HTTP server for health on {local_addr}");
+        let app = Router::new()
+            .route(
+                "/health",
+                get(|State(state): State<Arc<HealthService>>| async move {
+                    let report = state.compute_report().await;
+                    let status_code = match report.status {
+                        HealthStatus::Healthy => StatusCode::OK,
+                        HealthStatus::Unhealthy => StatusCode::SERVICE_UNAVAILABLE,
+                    };
+
+                    (status_code, Json(report))
+                }),
+            )
+            .with_state(self.health_service.clone());
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async {
+                shutdown_rx.await.ok();
+                warn!("HealthServer: shutting down HTTP server after receiving signal");
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use mithril_common::{chain_observer::FakeObserver, entities::TimePoint};
+    use mithril_persistence::store::adapter::MemoryAdapter;
+    use reqwest::StatusCode;
+    use std::time::Duration;
+    use tokio::{sync::oneshot, task::yield_now, time::sleep};
+
+    use crate::{health::HealthRecorder, DumbAggregatorClient, ProtocolInitializerStore};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_health_server() {
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let health_service = Arc::new(HealthService::new(
+            Arc::new(FakeObserver::new(Some(TimePoint::dummy()))),
+            Arc::new(DumbAggregatorClient::default()),
+            Arc::new(ProtocolInitializerStore::new(
+                Box::new(MemoryAdapter::new(None).unwrap()),
+                None,
+            )),
+            Arc::new(HealthRecorder::default()),
+        ));
+        // Bind a port chosen by the system to not collide with other tests or services
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let health_server_endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let health_server = HealthServer::new("127.0.0.1", 0, health_service);
+
+        let health_test = tokio::spawn(async move {
+            // Yield to make sure the web server starts first.
+            yield_now().await;
+
+            let response = reqwest::get(format!("{health_server_endpoint}/health"))
+                .await
+                .unwrap();
+
+            assert_eq!(StatusCode::OK, response.status());
+            let report: serde_json::Value = response.json().await.unwrap();
+            assert_eq!("healthy", report["status"]);
+            assert_eq!("healthy", report["aggregator"]["status"]);
+        });
+
+        tokio::select!(
+            res =  health_server.serve(listener, shutdown_rx)  => Err(anyhow!("Health server exited with value '{res:?}'")),
+            _res = sleep(Duration::from_secs(1)) => Err(anyhow!("Timeout: The test should have already completed.")),
+            res = health_test => res.map_err(|e| e.into()),
+        )
+        .unwrap();
+
+        shutdown_tx.send(()).unwrap();
+    }
+}
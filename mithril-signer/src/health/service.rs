@@ -0,0 +1,303 @@
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+use mithril_common::chain_observer::ChainObserver;
+
+use crate::{
+    health::{HealthRecorder, RecordedError, RecordedStateTransition},
+    AggregatorClient, ProtocolInitializerStorer,
+};
+
+/// Health status of the signer or of one of its dependencies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// Working as expected
+    Healthy,
+    /// Not working as expected
+    Unhealthy,
+}
+
+/// Health of a dependency of the signer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencyHealth {
+    /// Health status of the dependency
+    pub status: HealthStatus,
+
+    /// Error returned by the dependency when it is unhealthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DependencyHealth {
+    fn from_result<T, E: std::fmt::Debug>(result: Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self {
+                status: HealthStatus::Healthy,
+                error: None,
+            },
+            Err(error) => Self {
+                status: HealthStatus::Unhealthy,
+                error: Some(format!("{error:?}")),
+            },
+        }
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.status == HealthStatus::Healthy
+    }
+}
+
+/// Health report of the signer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    /// Health status of the signer, healthy only if all its dependencies are healthy
+    pub status: HealthStatus,
+
+    /// Reachability of the Cardano node through the chain observer
+    pub chain_observer: DependencyHealth,
+
+    /// Reachability of the aggregator
+    pub aggregator: DependencyHealth,
+
+    /// Health of the signer stores
+    pub store: DependencyHealth,
+
+    /// Last state transition of the state machine
+    pub last_state_transition: Option<RecordedStateTransition>,
+
+    /// Last error that occurred in the state machine
+    pub last_error: Option<RecordedError>,
+}
+
+/// Time during which the statuses of the dependencies are reused before being checked again.
+const DEPENDENCIES_STATUS_CACHE_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+struct DependenciesStatus {
+    chain_observer: DependencyHealth,
+    aggregator: DependencyHealth,
+    store: DependencyHealth,
+}
+
+/// The HealthService checks the dependencies of the signer and reports them with the last
+/// state transition and error of the state machine.
+///
+/// The statuses of the dependencies are cached so polling the health endpoint does not load the
+/// Cardano node and the aggregator.
+pub struct HealthService {
+    chain_observer: Arc<dyn ChainObserver>,
+    aggregator_client: Arc<dyn AggregatorClient>,
+    protocol_initializer_store: Arc<dyn ProtocolInitializerStorer>,
+    health_recorder: Arc<HealthRecorder>,
+    cache_ttl: Duration,
+    cached_dependencies_status: Mutex<Option<(Instant, DependenciesStatus)>>,
+}
+
+impl HealthService {
+    /// Create a new HealthService instance.
+    pub fn new(
+        chain_observer: Arc<dyn ChainObserver>,
+        aggregator_client: Arc<dyn AggregatorClient>,
+        protocol_initializer_store: Arc<dyn ProtocolInitializerStorer>,
+        health_recorder: Arc<HealthRecorder>,
+    ) -> Self {
+        Self {
+            chain_observer,
+            aggregator_client,
+            protocol_initializer_store,
+            health_recorder,
+            cache_ttl: DEPENDENCIES_STATUS_CACHE_TTL,
+            cached_dependencies_status: Mutex::new(None),
+        }
+    }
+
+    /// Set the time during which the statuses of the dependencies are reused.
+    pub fn with_cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = cache_ttl;
+        self
+    }
+
+    /// Check the dependencies of the signer and compute its health report.
+    ///
+    /// The dependencies are only checked again once their cached statuses are older than the
+    /// cache time to live.
+    pub async fn compute_report(&self) -> HealthReport {
+        let DependenciesStatus {
+            chain_observer,
+            aggregator,
+            store,
+        } = self.get_dependencies_status().await;
+        let status = if chain_observer.is_healthy() && aggregator.is_healthy() && store.is_healthy()
+        {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Unhealthy
+        };
+
+        HealthReport {
+            status,
+            chain_observer,
+            aggregator,
+            store,
+            last_state_transition: self.health_recorder.get_last_state_transition(),
+            last_error: self.health_recorder.get_last_error(),
+        }
+    }
+
+    async fn get_dependencies_status(&self) -> DependenciesStatus {
+        // The lock is held during the check so concurrent requests wait for a single check
+        let mut cached_dependencies_status = self.cached_dependencies_status.lock().await;
+        if let Some((checked_at, dependencies_status)) = cached_dependencies_status.as_ref() {
+            if checked_at.elapsed() < self.cache_ttl {
+                return dependencies_status.clone();
+            }
+        }
+
+        let (chain_observer, aggregator, store) = tokio::join!(
+            self.chain_observer.get_current_epoch(),
+            self.aggregator_client.retrieve_epoch_settings(),
+            self.protocol_initializer_store
+                .get_last_protocol_initializer(1),
+        );
+        let dependencies_status = DependenciesStatus {
+            chain_observer: DependencyHealth::from_result(chain_observer),
+            aggregator: DependencyHealth::from_result(aggregator),
+            store: DependencyHealth::from_result(store),
+        };
+        *cached_dependencies_status = Some((Instant::now(), dependencies_status.clone()));
+
+        dependencies_status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::{chain_observer::FakeObserver, entities::TimePoint};
+    use mithril_persistence::store::adapter::MemoryAdapter;
+
+    use crate::{
+        AggregatorClientError, DumbAggregatorClient, MockAggregatorClient,
+        ProtocolInitializerStore, SignerState,
+    };
+
+    use super::*;
+
+    fn protocol_initializer_store() -> Arc<dyn ProtocolInitializerStorer> {
+        Arc::new(ProtocolInitializerStore::new(
+            Box::new(MemoryAdapter::new(None).unwrap()),
+            None,
+        ))
+    }
+
+    #[tokio::test]
+    async fn report_healthy_when_all_dependencies_are_reachable() {
+        let health_recorder = Arc::new(HealthRecorder::default());
+        health_recorder.record_state_transition(
+            &SignerState::Init,
+            &SignerState::Unregistered {
+                epoch: TimePoint::dummy().epoch,
+            },
+        );
+        let service = HealthService::new(
+            Arc::new(FakeObserver::new(Some(TimePoint::dummy()))),
+            Arc::new(DumbAggregatorClient::default()),
+            protocol_initializer_store(),
+            health_recorder,
+        );
+
+        let report = service.compute_report().await;
+
+        assert_eq!(HealthStatus::Healthy, report.status);
+        assert_eq!(HealthStatus::Healthy, report.chain_observer.status);
+        assert_eq!(HealthStatus::Healthy, report.aggregator.status);
+        assert_eq!(HealthStatus::Healthy, report.store.status);
+        assert_eq!(
+            SignerState::Init.to_string(),
+            report.last_state_transition.unwrap().from
+        );
+        assert_eq!(None, report.last_error);
+    }
+
+    #[tokio::test]
+    async fn report_unhealthy_when_the_aggregator_is_unreachable() {
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client
+            .expect_retrieve_epoch_settings()
+            .returning(|| {
+                Err(AggregatorClientError::RemoteServerUnreachable(
+                    anyhow::anyhow!("connection refused"),
+                ))
+            });
+        let health_recorder = Arc::new(HealthRecorder::default());
+        health_recorder.record_error(&"could not retrieve epoch settings");
+        let service = HealthService::new(
+            Arc::new(FakeObserver::new(Some(TimePoint::dummy()))),
+            Arc::new(aggregator_client),
+            protocol_initializer_store(),
+            health_recorder,
+        );
+
+        let report = service.compute_report().await;
+
+        assert_eq!(HealthStatus::Unhealthy, report.status);
+        assert_eq!(HealthStatus::Healthy, report.chain_observer.status);
+        assert_eq!(HealthStatus::Unhealthy, report.aggregator.status);
+        assert!(report
+            .aggregator
+            .error
+            .unwrap()
+            .contains("connection refused"));
+        assert_eq!(
+            "could not retrieve epoch settings",
+            report.last_error.unwrap().message
+        );
+    }
+
+    #[tokio::test]
+    async fn reuse_the_dependencies_status_until_the_cache_expires() {
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client
+            .expect_retrieve_epoch_settings()
+            .returning(|| Ok(None))
+            .times(1);
+        let health_recorder = Arc::new(HealthRecorder::default());
+        let service = HealthService::new(
+            Arc::new(FakeObserver::new(Some(TimePoint::dummy()))),
+            Arc::new(aggregator_client),
+            protocol_initializer_store(),
+            health_recorder.clone(),
+        );
+
+        service.compute_report().await;
+        health_recorder.record_error(&"recorded after the first report");
+        let report = service.compute_report().await;
+
+        assert_eq!(HealthStatus::Healthy, report.aggregator.status);
+        assert_eq!(
+            "recorded after the first report",
+            report.last_error.unwrap().message
+        );
+    }
+
+    #[tokio::test]
+    async fn check_the_dependencies_again_once_the_cache_has_expired() {
+        let mut aggregator_client = MockAggregatorClient::new();
+        aggregator_client
+            .expect_retrieve_epoch_settings()
+            .returning(|| Ok(None))
+            .times(2);
+        let service = HealthService::new(
+            Arc::new(FakeObserver::new(Some(TimePoint::dummy()))),
+            Arc::new(aggregator_client),
+            protocol_initializer_store(),
+            Arc::new(HealthRecorder::default()),
+        )
+        .with_cache_ttl(Duration::ZERO);
+
+        service.compute_report().await;
+        service.compute_report().await;
+    }
+}
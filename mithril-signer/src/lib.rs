@@ -7,13 +7,16 @@
 //! for more information on how it works.
 
 mod aggregator_client;
+mod cardano_node_connectivity;
 mod cardano_transactions_importer;
 mod configuration;
 pub mod database;
+pub mod health;
 mod message_adapters;
 pub mod metrics;
 mod protocol_initializer_store;
 mod runtime;
+mod single_signature_store;
 mod single_signer;
 mod transactions_importer_by_chunk;
 mod transactions_importer_with_pruner;
@@ -22,14 +25,17 @@ mod transactions_importer_with_vacuum;
 #[cfg(test)]
 pub use aggregator_client::dumb::DumbAggregatorClient;
 pub use aggregator_client::*;
+pub use cardano_node_connectivity::*;
 pub use cardano_transactions_importer::*;
 pub use configuration::{Configuration, DefaultConfiguration};
+pub use health::{HealthRecorder, HealthServer, HealthService};
 pub use message_adapters::{
     FromEpochSettingsAdapter, FromPendingCertificateMessageAdapter, ToRegisterSignerMessageAdapter,
 };
 pub use metrics::*;
 pub use protocol_initializer_store::{ProtocolInitializerStore, ProtocolInitializerStorer};
 pub use runtime::*;
+pub use single_signature_store::{SingleSignatureKey, SingleSignatureStore, SingleSignatureStorer};
 pub use single_signer::*;
 pub use transactions_importer_by_chunk::*;
 pub use transactions_importer_with_pruner::*;
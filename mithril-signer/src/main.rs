@@ -16,8 +16,9 @@ use tokio::{
 use mithril_common::StdResult;
 use mithril_doc::{Documenter, DocumenterDefault, GenerateDocCommands, StructDoc};
 use mithril_signer::{
-    Configuration, DefaultConfiguration, MetricsServer, ProductionServiceBuilder, ServiceBuilder,
-    SignerRunner, SignerState, StateMachine,
+    Configuration, DefaultConfiguration, HealthRecorder, HealthServer, HealthService,
+    MetricsServer, ProductionServiceBuilder, ServiceBuilder, SignerRunner, SignerState,
+    StateMachine,
 };
 
 /// CLI args
@@ -75,6 +76,18 @@ pub struct Args {
     #[clap(long, env = "METRICS_SERVER_PORT", default_value_t = 9090)]
     metrics_server_port: u16,
 
+    /// Enable health HTTP server (dependencies statuses on /health).
+    #[clap(long, env = "ENABLE_HEALTH_SERVER", default_value_t = false)]
+    enable_health_server: bool,
+
+    /// Health HTTP server IP.
+    #[clap(long, env = "HEALTH_SERVER_IP", default_value = "0.0.0.0")]
+    health_server_ip: String,
+
+    /// Health HTTP server listening port.
+    #[clap(long, env = "HEALTH_SERVER_PORT", default_value_t = 9091)]
+    health_server_port: u16,
+
     /// If set no error is returned in case of unparsable block and an error log is written instead.
     ///
     /// Will be ignored on (pre)production networks.
@@ -141,6 +154,8 @@ async fn main() -> StdResult<()> {
         .with_context(|| "configuration error: could not set `reset_digests_cache`")?
         .set_default("enable_metrics_server", args.enable_metrics_server)
         .with_context(|| "configuration error: could not set `enable_metrics_server`")?
+        .set_default("enable_health_server", args.enable_health_server)
+        .with_context(|| "configuration error: could not set `enable_health_server`")?
         .set_default("allow_unparsable_block", args.allow_unparsable_block)
         .with_context(|| "configuration error: could not set `allow_unparsable_block`")?
         .add_source(DefaultConfiguration::default())
@@ -165,6 +180,13 @@ async fn main() -> StdResult<()> {
 
     let metrics_service = services.metrics_service.clone();
     let cardano_transaction_preloader = services.cardano_transactions_preloader.clone();
+    let health_recorder = Arc::new(HealthRecorder::default());
+    let health_service = Arc::new(HealthService::new(
+        services.chain_observer.clone(),
+        services.certificate_handler.clone(),
+        services.protocol_initializer_store.clone(),
+        health_recorder.clone(),
+    ));
 
     debug!("Started"; "run_mode" => &args.run_mode, "config" => format!("{config:?}"));
 
@@ -173,7 +195,8 @@ async fn main() -> StdResult<()> {
         Box::new(SignerRunner::new(config.clone(), services)),
         Duration::from_millis(config.run_interval),
         metrics_service.clone(),
-    );
+    )
+    .with_health_recorder(health_recorder);
 
     let mut join_set = JoinSet::new();
     join_set.spawn(async move {
@@ -201,6 +224,21 @@ async fn main() -> StdResult<()> {
         });
     }
 
+    let (health_server_shutdown_tx, health_server_shutdown_rx) = oneshot::channel();
+    if config.enable_health_server {
+        join_set.spawn(async move {
+            HealthServer::new(
+                &config.health_server_ip,
+                config.health_server_port,
+                health_service,
+            )
+            .start(health_server_shutdown_rx)
+            .await
+            .map_err(|e| anyhow!(e))
+            .map(|_| None)
+        });
+    }
+
     join_set.spawn(async {
         tokio::signal::ctrl_c()
             .await
@@ -238,6 +276,9 @@ async fn main() -> StdResult<()> {
     metrics_server_shutdown_tx
         .send(())
         .map_err(|e| anyhow!("Metrics server shutdown signal could not be sent: {e:?}"))?;
+    health_server_shutdown_tx
+        .send(())
+        .map_err(|e| anyhow!("Health server shutdown signal could not be sent: {e:?}"))?;
 
     if !preload_task.is_finished() {
         preload_task.abort();
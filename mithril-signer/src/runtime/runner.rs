@@ -9,12 +9,13 @@ use mockall::automock;
 use mithril_common::crypto_helper::{KESPeriod, OpCert, ProtocolOpCert, SerDeShelleyFileFormat};
 use mithril_common::entities::{
     CertificatePending, Epoch, EpochSettings, PartyId, ProtocolMessage, ProtocolMessagePartKey,
-    ProtocolParameters, SignedEntityType, Signer, SignerWithStake, SingleSignatures, TimePoint,
+    ProtocolParameters, SignedEntityType, SignedEntityTypeDiscriminants, Signer, SignerWithStake,
+    SingleSignatures, TimePoint,
 };
 use mithril_common::StdResult;
 use mithril_persistence::store::StakeStorer;
 
-use crate::{Configuration, MithrilProtocolInitializerBuilder};
+use crate::{Configuration, MithrilProtocolInitializerBuilder, SingleSignatureKey};
 
 use super::signer_services::SignerServices;
 
@@ -58,10 +59,11 @@ pub trait Runner: Send + Sync {
         next_signers: &[SignerWithStake],
     ) -> StdResult<ProtocolMessage>;
 
-    /// Create the single signature.
+    /// Create the single signature, or reuse the one already produced for the same message.
     async fn compute_single_signature(
         &self,
         epoch: Epoch,
+        signed_entity_type: &SignedEntityType,
         message: &ProtocolMessage,
         signers: &[SignerWithStake],
     ) -> StdResult<Option<SingleSignatures>>;
@@ -252,6 +254,14 @@ impl Runner for SignerRunner {
             return Ok(false);
         }
 
+        let current_era = self.services.era_checker.current_era();
+        if !SignedEntityTypeDiscriminants::from(&pending_certificate.signed_entity_type)
+            .is_supported_in_era(current_era)
+        {
+            debug!(" > signed entity type is not supported in the current era ({current_era}), can NOT sign");
+            return Ok(false);
+        }
+
         if let Some(signer) =
             pending_certificate.get_signer(self.services.single_signer.get_party_id())
         {
@@ -370,11 +380,24 @@ impl Runner for SignerRunner {
     async fn compute_single_signature(
         &self,
         epoch: Epoch,
+        signed_entity_type: &SignedEntityType,
         message: &ProtocolMessage,
         signers: &[SignerWithStake],
     ) -> StdResult<Option<SingleSignatures>> {
         debug!("RUNNER: compute_single_signature");
 
+        let single_signature_key =
+            SingleSignatureKey::new(epoch, signed_entity_type.clone(), message.compute_hash());
+        if let Some(signature) = self
+            .services
+            .single_signature_store
+            .get_single_signature(&single_signature_key)
+            .await?
+        {
+            info!(" > reusing the single signature already computed for this message");
+            return Ok(Some(signature));
+        }
+
         let signer_retrieval_epoch = epoch.offset_to_signer_retrieval_epoch()?;
         let protocol_initializer = self
             .services
@@ -400,6 +423,13 @@ impl Runner for SignerRunner {
             }
         );
 
+        if let Some(signature) = &signature {
+            self.services
+                .single_signature_store
+                .save_single_signature(&single_signature_key, signature.clone())
+                .await?;
+        }
+
         Ok(signature)
     }
 
@@ -463,12 +493,16 @@ mod tests {
         chain_observer::{ChainObserver, FakeObserver},
         crypto_helper::{MKMap, MKMapNode, MKTreeNode, ProtocolInitializer},
         digesters::{DumbImmutableDigester, DumbImmutableFileObserver},
-        entities::{BlockNumber, BlockRange, CardanoDbBeacon, Epoch, StakeDistribution},
-        era::{adapters::EraReaderBootstrapAdapter, EraChecker, EraReader},
+        entities::{
+            BlockNumber, BlockRange, CardanoDbBeacon, DataAttestationBeacon, Epoch,
+            StakeDistribution,
+        },
+        era::{adapters::EraReaderBootstrapAdapter, EraChecker, EraReader, SupportedEra},
         signable_builder::{
             BlockRangeRootRetriever, CardanoImmutableFilesFullSignableBuilder,
-            CardanoTransactionsSignableBuilder, MithrilSignableBuilderService,
-            MithrilStakeDistributionSignableBuilder,
+            CardanoTransactionsSignableBuilder, CardanoUtxoSetSignableBuilder,
+            DataAttestationSignableBuilder, EpochBoundaryUtxoSetRetriever,
+            MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
         },
         signed_entity_type_lock::SignedEntityTypeLock,
         test_utils::{fake_data, MithrilFixtureBuilder},
@@ -482,7 +516,7 @@ mod tests {
     use crate::{
         metrics::MetricsService, AggregatorClient, CardanoTransactionsImporter,
         DumbAggregatorClient, MithrilSingleSigner, MockAggregatorClient, MockTransactionStore,
-        ProtocolInitializerStore, SingleSigner,
+        ProtocolInitializerStore, SingleSignatureStore, SingleSigner,
     };
 
     use super::*;
@@ -560,10 +594,21 @@ mod tests {
             block_range_root_retriever,
             slog_scope::logger(),
         ));
+        let cardano_utxo_set_builder = Arc::new(CardanoUtxoSetSignableBuilder::new(
+            Arc::new(EpochBoundaryUtxoSetRetriever::new(
+                chain_observer.clone(),
+                Path::new("immutable"),
+                Path::new("cardano_utxo_set_snapshots"),
+                slog_scope::logger(),
+            )),
+            slog_scope::logger(),
+        ));
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             mithril_stake_distribution_signable_builder,
             cardano_immutable_signable_builder,
             cardano_transactions_builder,
+            cardano_utxo_set_builder,
+            Arc::new(DataAttestationSignableBuilder::default()),
         ));
         let metrics_service = Arc::new(MetricsService::new().unwrap());
         let signed_entity_type_lock = Arc::new(SignedEntityTypeLock::default());
@@ -587,6 +632,10 @@ mod tests {
                 Box::new(adapter),
                 None,
             )),
+            single_signature_store: Arc::new(SingleSignatureStore::new(
+                Box::new(MemoryAdapter::new(None).unwrap()),
+                None,
+            )),
             era_checker,
             era_reader,
             api_version_provider,
@@ -762,6 +811,50 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn can_i_sign_data_attestation_only_from_pythagoras_era() {
+        let mut pending_certificate = CertificatePending {
+            signed_entity_type: SignedEntityType::DataAttestation(DataAttestationBeacon::new(
+                fake_data::certificate_pending().epoch,
+                "db-sync-snapshot",
+                "abc123",
+            )),
+            ..fake_data::certificate_pending()
+        };
+        let epoch = pending_certificate.epoch;
+        let signer = &mut pending_certificate.signers[0];
+        let mut services = init_services().await;
+        let protocol_initializer_store = services.protocol_initializer_store.clone();
+        let era_checker = services.era_checker.clone();
+        services.single_signer = Arc::new(MithrilSingleSigner::new(signer.party_id.to_owned()));
+        let runner = init_runner(Some(services), None).await;
+
+        let protocol_initializer = MithrilProtocolInitializerBuilder::build(
+            &100,
+            &fake_data::protocol_parameters(),
+            None,
+            None,
+        )
+        .unwrap();
+        signer.verification_key = protocol_initializer.verification_key().into();
+        protocol_initializer_store
+            .save_protocol_initializer(
+                epoch.offset_to_signer_retrieval_epoch().unwrap(),
+                protocol_initializer,
+            )
+            .await
+            .unwrap();
+
+        era_checker.change_era(SupportedEra::Thales, epoch);
+        assert!(
+            !runner.can_i_sign(&pending_certificate).await.unwrap(),
+            "The signer should not be able to sign a data attestation before the Pythagoras era."
+        );
+
+        era_checker.change_era(SupportedEra::Pythagoras, epoch);
+        assert!(runner.can_i_sign(&pending_certificate).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_associate_signers_with_stake() {
         let services = init_services().await;
@@ -889,12 +982,43 @@ mod tests {
 
         let runner = init_runner(Some(services), None).await;
         let single_signature = runner
-            .compute_single_signature(current_time_point.epoch, &message, &signers)
+            .compute_single_signature(
+                current_time_point.epoch,
+                &SignedEntityType::MithrilStakeDistribution(current_time_point.epoch),
+                &message,
+                &signers,
+            )
             .await
             .expect("compute_message should not fail");
         assert_eq!(expected, single_signature);
     }
 
+    #[tokio::test]
+    async fn test_compute_single_signature_reuse_the_stored_signature() {
+        let services = init_services().await;
+        let epoch = Epoch(3);
+        let signed_entity_type = SignedEntityType::MithrilStakeDistribution(epoch);
+        let message = ProtocolMessage::new();
+        let stored_signature = fake_data::single_signatures(vec![2, 6]);
+        services
+            .single_signature_store
+            .save_single_signature(
+                &SingleSignatureKey::new(epoch, signed_entity_type.clone(), message.compute_hash()),
+                stored_signature.clone(),
+            )
+            .await
+            .unwrap();
+
+        // No protocol initializer is stored so computing a new single signature would fail
+        let runner = init_runner(Some(services), None).await;
+        let single_signature = runner
+            .compute_single_signature(epoch, &signed_entity_type, &message, &[])
+            .await
+            .expect("compute_single_signature should not fail");
+
+        assert_eq!(Some(stored_signature), single_signature);
+    }
+
     #[tokio::test]
     async fn test_send_single_signature() {
         let mut services = init_services().await;
@@ -16,8 +16,9 @@ use mithril_common::{
     era::{EraChecker, EraReader},
     signable_builder::{
         CardanoImmutableFilesFullSignableBuilder, CardanoTransactionsSignableBuilder,
-        MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
-        SignableBuilderService,
+        CardanoUtxoSetSignableBuilder, DataAttestationSignableBuilder,
+        EpochBoundaryUtxoSetRetriever, MithrilSignableBuilderService,
+        MithrilStakeDistributionSignableBuilder, SignableBuilderService,
     },
     signed_entity_type_lock::SignedEntityTypeLock,
     MithrilTickerService, StdResult, TickerService,
@@ -30,10 +31,11 @@ use mithril_persistence::{
 
 use crate::{
     aggregator_client::AggregatorClient, metrics::MetricsService, single_signer::SingleSigner,
-    AggregatorHTTPClient, CardanoTransactionsImporter, Configuration, MithrilSingleSigner,
-    ProtocolInitializerStore, ProtocolInitializerStorer, TransactionsImporterByChunk,
-    TransactionsImporterWithPruner, TransactionsImporterWithVacuum, HTTP_REQUEST_TIMEOUT_DURATION,
-    SQLITE_FILE, SQLITE_FILE_CARDANO_TRANSACTION,
+    AggregatorHTTPClient, CardanoNodeConnectivity, CardanoTransactionsImporter, Configuration,
+    MithrilSingleSigner, ProtocolInitializerStore, ProtocolInitializerStorer, SingleSignatureStore,
+    SingleSignatureStorer, TransactionsImporterByChunk, TransactionsImporterWithPruner,
+    TransactionsImporterWithVacuum, HTTP_REQUEST_TIMEOUT_DURATION, SQLITE_FILE,
+    SQLITE_FILE_CARDANO_TRANSACTION,
 };
 
 type StakeStoreService = Arc<StakeStore>;
@@ -43,6 +45,7 @@ type DigesterService = Arc<dyn ImmutableDigester>;
 type SingleSignerService = Arc<dyn SingleSigner>;
 type TimePointProviderService = Arc<dyn TickerService>;
 type ProtocolInitializerStoreService = Arc<dyn ProtocolInitializerStorer>;
+type SingleSignatureStoreService = Arc<dyn SingleSignatureStorer>;
 
 /// The ServiceBuilder is intended to manage Services instance creation.
 /// The goal of this is to put all this code out of the way of business code.
@@ -67,7 +70,12 @@ impl<'a> ProductionServiceBuilder<'a> {
             |config: &Configuration| {
                 let chain_observer_type = ChainObserverType::Pallas;
                 let cardano_cli_path = &config.cardano_cli_path;
-                let cardano_node_socket_path = &config.cardano_node_socket_path;
+                let cardano_node_socket_path =
+                    &CardanoNodeConnectivity::from_configuration(config)?
+                        .prepare()
+                        .with_context(|| {
+                            "Production Service Builder can not reach the Cardano node"
+                        })?;
                 let cardano_network = &config.get_network().with_context(|| {
                     "Production Service Builder can not get Cardano network while building the chain observer"
                 })?;
@@ -218,6 +226,13 @@ impl<'a> ServiceBuilder for ProductionServiceBuilder<'a> {
             )?),
             self.config.store_retention_limit,
         ));
+        let single_signature_store = Arc::new(SingleSignatureStore::new(
+            Box::new(SQLiteAdapter::new(
+                "single_signature",
+                sqlite_connection.clone(),
+            )?),
+            self.config.store_retention_limit,
+        ));
         let single_signer = Arc::new(MithrilSingleSigner::new(self.compute_protocol_party_id()?));
         let digester = Arc::new(CardanoImmutableDigester::new(
             self.build_digester_cache_provider().await?,
@@ -318,10 +333,24 @@ impl<'a> ServiceBuilder for ProductionServiceBuilder<'a> {
             block_range_root_retriever,
             slog_scope::logger(),
         ));
+        let cardano_utxo_set_builder = Arc::new(CardanoUtxoSetSignableBuilder::new(
+            Arc::new(EpochBoundaryUtxoSetRetriever::new(
+                chain_observer.clone(),
+                &self.config.db_directory,
+                &self
+                    .config
+                    .data_stores_directory
+                    .join("cardano_utxo_set_snapshots"),
+                slog_scope::logger(),
+            )),
+            slog_scope::logger(),
+        ));
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             mithril_stake_distribution_signable_builder,
             cardano_immutable_snapshot_builder,
             cardano_transactions_builder,
+            cardano_utxo_set_builder,
+            Arc::new(DataAttestationSignableBuilder::default()),
         ));
         let metrics_service = Arc::new(MetricsService::new().unwrap());
         let cardano_transactions_preloader = Arc::new(CardanoTransactionsPreloader::new(
@@ -340,6 +369,7 @@ impl<'a> ServiceBuilder for ProductionServiceBuilder<'a> {
             single_signer,
             stake_store,
             protocol_initializer_store,
+            single_signature_store,
             era_checker,
             era_reader,
             api_version_provider,
@@ -376,6 +406,9 @@ pub struct SignerServices {
     /// ProtocolInitializer store
     pub protocol_initializer_store: ProtocolInitializerStoreService,
 
+    /// Single signature store
+    pub single_signature_store: SingleSignatureStoreService,
+
     /// Era checker service
     pub era_checker: Arc<EraChecker>,
 
@@ -1,5 +1,5 @@
 use slog_scope::{crit, debug, error, info};
-use std::{fmt::Display, ops::Deref, sync::Arc, time::Duration};
+use std::{fmt::Display, sync::Arc, time::Duration};
 use tokio::{sync::Mutex, time::sleep};
 
 use mithril_common::{
@@ -9,7 +9,7 @@ use mithril_common::{
     },
 };
 
-use crate::MetricsService;
+use crate::{HealthRecorder, MetricsService};
 
 use super::{Runner, RuntimeError};
 
@@ -91,6 +91,7 @@ pub struct StateMachine {
     runner: Box<dyn Runner>,
     state_sleep: Duration,
     metrics_service: Arc<MetricsService>,
+    health_recorder: Arc<HealthRecorder>,
 }
 
 impl StateMachine {
@@ -106,9 +107,17 @@ impl StateMachine {
             runner,
             state_sleep,
             metrics_service,
+            health_recorder: Arc::new(HealthRecorder::default()),
         }
     }
 
+    /// Record the state transitions and errors in the given [HealthRecorder], so they can be
+    /// reported by the health server.
+    pub fn with_health_recorder(mut self, health_recorder: Arc<HealthRecorder>) -> Self {
+        self.health_recorder = health_recorder;
+        self
+    }
+
     /// Return the current state of the state machine.
     pub async fn get_state(&self) -> SignerState {
         self.state.lock().await.to_owned()
@@ -120,6 +129,7 @@ impl StateMachine {
 
         loop {
             if let Err(e) = self.cycle().await {
+                self.health_recorder.record_error(&e);
                 if e.is_critical() {
                     crit!("{e}");
 
@@ -146,7 +156,8 @@ impl StateMachine {
         self.metrics_service
             .runtime_cycle_total_since_startup_counter_increment();
 
-        match state.deref() {
+        let previous_state = state.clone();
+        match &previous_state {
             SignerState::Init => {
                 *state = self.transition_from_init_to_unregistered().await?;
             }
@@ -255,6 +266,11 @@ impl StateMachine {
             }
         };
 
+        if *state != previous_state {
+            self.health_recorder
+                .record_state_transition(&previous_state, &state);
+        }
+
         self.metrics_service
             .runtime_cycle_success_since_startup_counter_increment();
 
@@ -410,7 +426,12 @@ impl StateMachine {
             })?;
         let single_signatures = self
             .runner
-            .compute_single_signature(current_epoch, &message, &signers)
+            .compute_single_signature(
+                current_epoch,
+                &pending_certificate.signed_entity_type,
+                &message,
+                &signers,
+            )
             .await
             .map_err(|e| RuntimeError::KeepState {
                 message: format!("Could not compute single signature during 'registered → signed' phase (current epoch {current_epoch:?})"),
@@ -477,6 +498,7 @@ mod tests {
             runner: Box::new(runner),
             state_sleep: Duration::from_millis(100),
             metrics_service,
+            health_recorder: Arc::new(HealthRecorder::default()),
         }
     }
 
@@ -508,6 +530,10 @@ mod tests {
             },
             state_machine.get_state().await
         );
+        assert_eq!(
+            None,
+            state_machine.health_recorder.get_last_state_transition()
+        );
     }
 
     #[tokio::test]
@@ -581,6 +607,17 @@ mod tests {
                 state_machine.get_state().await
             );
         }
+        let last_state_transition = state_machine
+            .health_recorder
+            .get_last_state_transition()
+            .expect("The state transition should have been recorded");
+        assert_eq!(
+            SignerState::Unregistered {
+                epoch: TimePoint::dummy().epoch
+            }
+            .to_string(),
+            last_state_transition.from
+        );
     }
 
     #[tokio::test]
@@ -682,7 +719,7 @@ mod tests {
         runner
             .expect_compute_single_signature()
             .once()
-            .returning(|_, _, _| Ok(Some(fake_data::single_signatures(vec![1, 5, 23]))));
+            .returning(|_, _, _, _| Ok(Some(fake_data::single_signatures(vec![1, 5, 23]))));
         runner
             .expect_compute_message()
             .once()
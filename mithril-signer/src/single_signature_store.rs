@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use mithril_common::{
+    entities::{Epoch, SignedEntityType, SingleSignatures},
+    StdResult,
+};
+use mithril_persistence::store::{adapter::StoreAdapter, StorePruner};
+
+type Adapter = Box<dyn StoreAdapter<Key = SingleSignatureKey, Record = SingleSignatures>>;
+
+/// Key identifying a single signature produced by the signer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SingleSignatureKey {
+    /// Epoch at which the single signature was produced
+    pub epoch: Epoch,
+
+    /// Signed entity type of the signed message
+    pub signed_entity_type: SignedEntityType,
+
+    /// Hash of the signed message
+    pub message_hash: String,
+}
+
+impl SingleSignatureKey {
+    /// SingleSignatureKey factory
+    pub fn new(epoch: Epoch, signed_entity_type: SignedEntityType, message_hash: String) -> Self {
+        Self {
+            epoch,
+            signed_entity_type,
+            message_hash,
+        }
+    }
+}
+
+#[async_trait]
+/// Store the single signatures produced by the signer, this allows to reuse them instead of
+/// computing them again if the signer restarts during a signature round.
+pub trait SingleSignatureStorer: Sync + Send {
+    /// Save a single signature for the given key.
+    async fn save_single_signature(
+        &self,
+        key: &SingleSignatureKey,
+        single_signature: SingleSignatures,
+    ) -> StdResult<()>;
+
+    /// Fetch the single signature saved for the given key if any.
+    async fn get_single_signature(
+        &self,
+        key: &SingleSignatureKey,
+    ) -> StdResult<Option<SingleSignatures>>;
+}
+
+/// Implementation of the SingleSignatureStorer
+pub struct SingleSignatureStore {
+    adapter: RwLock<Adapter>,
+    retention_limit: Option<usize>,
+}
+
+impl SingleSignatureStore {
+    /// Create a new SingleSignatureStore.
+    pub fn new(adapter: Adapter, retention_limit: Option<usize>) -> Self {
+        Self {
+            adapter: RwLock::new(adapter),
+            retention_limit,
+        }
+    }
+}
+
+#[async_trait]
+impl StorePruner for SingleSignatureStore {
+    type Key = SingleSignatureKey;
+    type Record = SingleSignatures;
+
+    fn get_adapter(
+        &self,
+    ) -> &RwLock<Box<dyn StoreAdapter<Key = Self::Key, Record = Self::Record>>> {
+        &self.adapter
+    }
+
+    fn get_max_records(&self) -> Option<usize> {
+        self.retention_limit
+    }
+}
+
+#[async_trait]
+impl SingleSignatureStorer for SingleSignatureStore {
+    async fn save_single_signature(
+        &self,
+        key: &SingleSignatureKey,
+        single_signature: SingleSignatures,
+    ) -> StdResult<()> {
+        self.adapter
+            .write()
+            .await
+            .store_record(key, &single_signature)
+            .await?;
+        self.prune().await?;
+
+        Ok(())
+    }
+
+    async fn get_single_signature(
+        &self,
+        key: &SingleSignatureKey,
+    ) -> StdResult<Option<SingleSignatures>> {
+        let record = self.adapter.read().await.get_record(key).await?;
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::fake_data;
+    use mithril_persistence::store::adapter::MemoryAdapter;
+
+    use super::*;
+
+    fn init_store(retention_limit: Option<usize>) -> SingleSignatureStore {
+        let adapter: MemoryAdapter<SingleSignatureKey, SingleSignatures> =
+            MemoryAdapter::new(None).unwrap();
+        SingleSignatureStore::new(Box::new(adapter), retention_limit)
+    }
+
+    fn key(epoch: u64, message_hash: &str) -> SingleSignatureKey {
+        SingleSignatureKey::new(
+            Epoch(epoch),
+            SignedEntityType::MithrilStakeDistribution(Epoch(epoch)),
+            message_hash.to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn get_saved_single_signature() {
+        let store = init_store(None);
+        let single_signature = fake_data::single_signatures(vec![1, 3]);
+        store
+            .save_single_signature(&key(3, "message-hash"), single_signature.clone())
+            .await
+            .unwrap();
+
+        let res = store
+            .get_single_signature(&key(3, "message-hash"))
+            .await
+            .unwrap();
+
+        assert_eq!(Some(single_signature), res);
+    }
+
+    #[tokio::test]
+    async fn get_single_signature_for_another_message() {
+        let store = init_store(None);
+        store
+            .save_single_signature(
+                &key(3, "message-hash"),
+                fake_data::single_signatures(vec![1, 3]),
+            )
+            .await
+            .unwrap();
+
+        let res = store
+            .get_single_signature(&key(3, "another-message-hash"))
+            .await
+            .unwrap();
+
+        assert_eq!(None, res);
+    }
+
+    #[tokio::test]
+    async fn check_retention_limit() {
+        let store = init_store(Some(2));
+        for epoch in 1..=4 {
+            store
+                .save_single_signature(
+                    &key(epoch, "message-hash"),
+                    fake_data::single_signatures(vec![1]),
+                )
+                .await
+                .unwrap();
+        }
+
+        assert!(store
+            .get_single_signature(&key(1, "message-hash"))
+            .await
+            .unwrap()
+            .is_none());
+        assert!(store
+            .get_single_signature(&key(4, "message-hash"))
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
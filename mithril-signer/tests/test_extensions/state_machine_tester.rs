@@ -19,7 +19,9 @@ use mithril_common::{
     era::{adapters::EraReaderDummyAdapter, EraChecker, EraMarker, EraReader, SupportedEra},
     signable_builder::{
         CardanoImmutableFilesFullSignableBuilder, CardanoTransactionsSignableBuilder,
-        MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
+        CardanoUtxoSetSignableBuilder, DataAttestationSignableBuilder,
+        EpochBoundaryUtxoSetRetriever, MithrilSignableBuilderService,
+        MithrilStakeDistributionSignableBuilder,
     },
     signed_entity_type_lock::SignedEntityTypeLock,
     MithrilTickerService, StdError, TickerService,
@@ -34,7 +36,7 @@ use mithril_signer::{
     metrics::*, AggregatorClient, CardanoTransactionsImporter, Configuration, MetricsService,
     MithrilSingleSigner, ProductionServiceBuilder, ProtocolInitializerStore,
     ProtocolInitializerStorer, RuntimeError, SignerRunner, SignerServices, SignerState,
-    StateMachine,
+    SingleSignatureStore, StateMachine,
 };
 
 use super::FakeAggregator;
@@ -134,6 +136,10 @@ impl StateMachineTester {
             Box::new(MemoryAdapter::new(None).unwrap()),
             config.store_retention_limit,
         ));
+        let single_signature_store = Arc::new(SingleSignatureStore::new(
+            Box::new(MemoryAdapter::new(None).unwrap()),
+            config.store_retention_limit,
+        ));
         let single_signer = Arc::new(MithrilSingleSigner::new(
             config.party_id.to_owned().unwrap_or_default(),
         ));
@@ -183,10 +189,23 @@ impl StateMachineTester {
             block_range_root_retriever,
             slog_scope::logger(),
         ));
+        let cardano_utxo_set_builder = Arc::new(CardanoUtxoSetSignableBuilder::new(
+            Arc::new(EpochBoundaryUtxoSetRetriever::new(
+                chain_observer.clone(),
+                &config.db_directory,
+                &config
+                    .data_stores_directory
+                    .join("cardano_utxo_set_snapshots"),
+                slog_scope::logger(),
+            )),
+            slog_scope::logger(),
+        ));
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             mithril_stake_distribution_signable_builder,
             cardano_immutable_snapshot_builder,
             cardano_transactions_builder,
+            cardano_utxo_set_builder,
+            Arc::new(DataAttestationSignableBuilder::default()),
         ));
         let metrics_service = Arc::new(MetricsService::new().unwrap());
         let expected_metrics_service = Arc::new(MetricsService::new().unwrap());
@@ -206,6 +225,7 @@ impl StateMachineTester {
             chain_observer: chain_observer.clone(),
             digester: digester.clone(),
             protocol_initializer_store: protocol_initializer_store.clone(),
+            single_signature_store,
             single_signer: single_signer.clone(),
             stake_store: stake_store.clone(),
             era_checker: era_checker.clone(),
@@ -85,7 +85,9 @@ done
 # Prepare transactions for delegating to stake pools
 for N in ${POOL_NODES_N}; do
   cat >> delegate.sh <<EOF
-    AMOUNT_STAKED=\$(( $N*1000000 +  DELEGATION_ROUND*1 ))
+    # The stake weight of the pool can be overridden with the POOL_STAKE_WEIGHT_N env var
+    POOL_STAKE_WEIGHT=\${POOL_STAKE_WEIGHT_${N}:-${N}}
+    AMOUNT_STAKED=\$(( POOL_STAKE_WEIGHT*1000000 +  DELEGATION_ROUND*1 ))
 
     # Get the UTxO
     TX_IN=\$(CARDANO_NODE_SOCKET_PATH=node-pool${N}/ipc/node.sock ./cardano-cli query utxo \\
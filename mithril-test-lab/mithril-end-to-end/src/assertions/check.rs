@@ -7,8 +7,8 @@ use mithril_common::{
     entities::{Epoch, TransactionHash},
     messages::{
         CardanoTransactionSnapshotListMessage, CardanoTransactionSnapshotMessage,
-        CertificateMessage, MithrilStakeDistributionListMessage, MithrilStakeDistributionMessage,
-        SnapshotMessage,
+        CertificateListMessage, CertificateMessage, MithrilStakeDistributionListMessage,
+        MithrilStakeDistributionMessage, SnapshotMessage,
     },
     StdResult,
 };
@@ -281,6 +281,83 @@ pub async fn assert_is_creating_certificate_with_enough_signers(
     }
 }
 
+pub async fn assert_aggregator_has_certificate(aggregator_endpoint: &str) -> StdResult<String> {
+    let url = format!("{aggregator_endpoint}/certificates");
+    info!("Fetching the latest certificate of the aggregator");
+
+    match reqwest::get(url.clone()).await {
+        Ok(response) => match response.status() {
+            StatusCode::OK => match response.json::<CertificateListMessage>().await.as_deref() {
+                Ok([certificate, ..]) => {
+                    info!("Aggregator has a certificate"; "hash" => &certificate.hash);
+                    Ok(certificate.hash.clone())
+                }
+                Ok(&[]) => Err(anyhow!("Aggregator has no certificate")),
+                Err(err) => Err(anyhow!("Invalid certificate list body : {err}",)),
+            },
+            s => Err(anyhow!("Unexpected status code from Aggregator: {s}")),
+        },
+        Err(err) => Err(anyhow!(err).context(format!("Request to `{url}` failed"))),
+    }
+}
+
+/// Walk the certificate chain from the given certificate, following the previous hashes, until
+/// the genesis certificate or the maximum depth is reached.
+///
+/// This only asserts that the chain is correctly linked, the cryptographic verification of the
+/// chain is done by the client when it downloads an artifact.
+pub async fn assert_certificate_chain_is_linked(
+    aggregator_endpoint: &str,
+    certificate_hash: &str,
+    max_depth: u64,
+) -> StdResult<()> {
+    info!(
+        "Asserting the certificate chain is linked from certificate `{}` with a maximum depth of {}",
+        certificate_hash, max_depth
+    );
+    let mut current_hash = certificate_hash.to_string();
+
+    for depth in 0..max_depth {
+        let url = format!("{aggregator_endpoint}/certificate/{current_hash}");
+        let certificate = match reqwest::get(url.clone()).await {
+            Ok(response) => match response.status() {
+                StatusCode::OK => response
+                    .json::<CertificateMessage>()
+                    .await
+                    .with_context(|| format!("Invalid certificate body for `{current_hash}`"))?,
+                StatusCode::NOT_FOUND => {
+                    return Err(anyhow!(
+                        "Certificate chain is broken: certificate `{current_hash}` not found at depth {depth}"
+                    ))
+                }
+                s => return Err(anyhow!("Unexpected status code from Aggregator: {s}")),
+            },
+            Err(err) => return Err(anyhow!(err).context(format!("Request to `{url}` failed"))),
+        };
+
+        if certificate.hash != current_hash {
+            return Err(anyhow!(
+                "Certificate chain is broken: certificate `{current_hash}` has hash `{}`",
+                certificate.hash
+            ));
+        }
+        if !certificate.genesis_signature.is_empty() {
+            info!("Certificate chain is linked up to the genesis certificate"; "depth" => depth, "genesis_hash" => &certificate.hash);
+            return Ok(());
+        }
+        if certificate.previous_hash.is_empty() {
+            return Err(anyhow!(
+                "Certificate chain is broken: certificate `{current_hash}` is not a genesis certificate but has no previous hash"
+            ));
+        }
+
+        current_hash = certificate.previous_hash;
+    }
+
+    info!("Certificate chain is linked up to the maximum depth"; "max_depth" => max_depth);
+    Ok(())
+}
+
 pub async fn assert_client_can_verify_snapshot(client: &mut Client, digest: &str) -> StdResult<()> {
     client
         .run(ClientCommand::CardanoDb(CardanoDbCommand::Download {
@@ -0,0 +1,254 @@
+use anyhow::{anyhow, Context};
+use mithril_common::StdResult;
+use slog_scope::info;
+use sqlite::{Connection, OpenFlags, State};
+use std::path::Path;
+
+/// Row counts of the aggregator database tables checked between the end to end phases
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AggregatorDatabaseState {
+    pub certificates: i64,
+    pub open_messages: i64,
+    pub certified_open_messages: i64,
+    pub single_signatures: i64,
+}
+
+/// Open the aggregator SQLite database and assert row-level invariants that the HTTP API
+/// can't reveal (corrupted pages, broken certificate links, orphan single signatures, ...).
+///
+/// If a previous state is given, the number of certificates must not have decreased since then.
+pub fn assert_aggregator_database_is_consistent(
+    database_path: &Path,
+    previous_state: Option<AggregatorDatabaseState>,
+) -> StdResult<AggregatorDatabaseState> {
+    info!("Asserting the aggregator database is consistent"; "database_path" => %database_path.display());
+
+    // Read only: the aggregator is still running and writing to the database
+    let connection = Connection::open_with_flags(database_path, OpenFlags::new().with_read_only())
+        .with_context(|| {
+            format!(
+                "Could not open aggregator database '{}'",
+                database_path.display()
+            )
+        })?;
+
+    assert_database_integrity(&connection)?;
+    assert_certificates_are_linked(&connection)?;
+    assert_certified_open_messages_have_single_signatures(&connection)?;
+    assert_single_signatures_have_open_message(&connection)?;
+
+    let state = AggregatorDatabaseState {
+        certificates: count(&connection, "select count(*) from certificate")?,
+        open_messages: count(&connection, "select count(*) from open_message")?,
+        certified_open_messages: count(
+            &connection,
+            "select count(*) from open_message where is_certified = true",
+        )?,
+        single_signatures: count(&connection, "select count(*) from single_signature")?,
+    };
+
+    if let Some(previous_state) = previous_state {
+        if state.certificates < previous_state.certificates {
+            return Err(anyhow!(
+                "Certificates have been removed from the aggregator database: {} previously, {} now",
+                previous_state.certificates,
+                state.certificates
+            ));
+        }
+    }
+
+    info!("Aggregator database is consistent"; "state" => ?state);
+    Ok(state)
+}
+
+fn assert_database_integrity(connection: &Connection) -> StdResult<()> {
+    let results = fetch_strings(connection, "pragma integrity_check")?;
+    if results != ["ok"] {
+        return Err(anyhow!(
+            "Aggregator database integrity check failed: {}",
+            results.join("; ")
+        ));
+    }
+
+    Ok(())
+}
+
+fn assert_certificates_are_linked(connection: &Connection) -> StdResult<()> {
+    let genesis_certificates = count(
+        connection,
+        "select count(*) from certificate where parent_certificate_id is null",
+    )?;
+    let certificates = count(connection, "select count(*) from certificate")?;
+    if certificates > 0 && genesis_certificates == 0 {
+        return Err(anyhow!(
+            "Aggregator database has {certificates} certificates but no genesis certificate"
+        ));
+    }
+
+    let unlinked_certificates = fetch_strings(
+        connection,
+        "select certificate.certificate_id from certificate \
+        left join certificate as parent on parent.certificate_id = certificate.parent_certificate_id \
+        where certificate.parent_certificate_id is not null \
+        and (parent.certificate_id is null \
+            or parent.certificate_id = certificate.certificate_id \
+            or parent.epoch > certificate.epoch)",
+    )?;
+    if !unlinked_certificates.is_empty() {
+        return Err(anyhow!(
+            "Aggregator database has certificates with a missing, self referencing or more recent parent: {}",
+            unlinked_certificates.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+fn assert_certified_open_messages_have_single_signatures(connection: &Connection) -> StdResult<()> {
+    let open_messages = fetch_strings(
+        connection,
+        "select open_message.open_message_id from open_message \
+        left join single_signature on single_signature.open_message_id = open_message.open_message_id \
+        where open_message.is_certified = true \
+        group by open_message.open_message_id \
+        having count(single_signature.signer_id) = 0",
+    )?;
+    if !open_messages.is_empty() {
+        return Err(anyhow!(
+            "Aggregator database has certified open messages without any single signature: {}",
+            open_messages.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+fn assert_single_signatures_have_open_message(connection: &Connection) -> StdResult<()> {
+    let orphan_signatures = count(
+        connection,
+        "select count(*) from single_signature \
+        left join open_message on open_message.open_message_id = single_signature.open_message_id \
+        where open_message.open_message_id is null",
+    )?;
+    if orphan_signatures > 0 {
+        return Err(anyhow!(
+            "Aggregator database has {orphan_signatures} pending single signatures without open message"
+        ));
+    }
+
+    Ok(())
+}
+
+fn count(connection: &Connection, sql: &str) -> StdResult<i64> {
+    let mut statement = connection
+        .prepare(sql)
+        .with_context(|| format!("Could not prepare query `{sql}`"))?;
+    match statement.next()? {
+        State::Row => Ok(statement.read::<i64, _>(0)?),
+        State::Done => Err(anyhow!("Query `{sql}` returned no row")),
+    }
+}
+
+fn fetch_strings(connection: &Connection, sql: &str) -> StdResult<Vec<String>> {
+    let mut statement = connection
+        .prepare(sql)
+        .with_context(|| format!("Could not prepare query `{sql}`"))?;
+    let mut values = vec![];
+    while let State::Row = statement.next()? {
+        values.push(statement.read::<String, _>(0)?);
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn create_database(test_name: &str, inserts: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join("mithril_end_to_end_test")
+            .join("database");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{test_name}.sqlite3"));
+        if path.exists() {
+            std::fs::remove_file(&path).unwrap();
+        }
+
+        let connection = Connection::open(&path).unwrap();
+        connection
+            .execute(
+                r#"
+create table certificate (certificate_id text not null primary key, parent_certificate_id text, epoch integer not null);
+create table open_message (open_message_id text not null primary key, is_certified bool not null default false);
+create table single_signature (open_message_id text not null, signer_id text not null);
+"#,
+            )
+            .unwrap();
+        connection.execute(inserts).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn consistent_database_yield_its_state() {
+        let path = create_database(
+            "consistent_database_yield_its_state",
+            r#"
+insert into certificate values ('genesis', null, 1), ('cert-2', 'genesis', 2), ('cert-3', 'cert-2', 2);
+insert into open_message values ('om-1', true), ('om-2', false);
+insert into single_signature values ('om-1', 'pool1'), ('om-1', 'pool2');
+"#,
+        );
+
+        let state = assert_aggregator_database_is_consistent(&path, None).unwrap();
+
+        assert_eq!(
+            AggregatorDatabaseState {
+                certificates: 3,
+                open_messages: 2,
+                certified_open_messages: 1,
+                single_signatures: 2,
+            },
+            state
+        );
+    }
+
+    #[test]
+    fn fail_if_a_certificate_parent_is_missing() {
+        let path = create_database(
+            "fail_if_a_certificate_parent_is_missing",
+            "insert into certificate values ('genesis', null, 1), ('cert-3', 'cert-2', 2);",
+        );
+
+        let error = assert_aggregator_database_is_consistent(&path, None).unwrap_err();
+        assert!(error.to_string().contains("cert-3"), "{error}");
+    }
+
+    #[test]
+    fn fail_if_a_certified_open_message_has_no_single_signature() {
+        let path = create_database(
+            "fail_if_a_certified_open_message_has_no_single_signature",
+            "insert into open_message values ('om-1', true);",
+        );
+
+        let error = assert_aggregator_database_is_consistent(&path, None).unwrap_err();
+        assert!(error.to_string().contains("om-1"), "{error}");
+    }
+
+    #[test]
+    fn fail_if_certificates_were_removed_since_previous_state() {
+        let path = create_database(
+            "fail_if_certificates_were_removed_since_previous_state",
+            "insert into certificate values ('genesis', null, 1);",
+        );
+        let previous_state = AggregatorDatabaseState {
+            certificates: 2,
+            ..AggregatorDatabaseState::default()
+        };
+
+        assert_aggregator_database_is_consistent(&path, Some(previous_state)).unwrap_err();
+    }
+}
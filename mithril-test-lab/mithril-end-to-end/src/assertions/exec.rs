@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::{Aggregator, Devnet};
+use crate::{Aggregator, Devnet, ScenarioEraTransition};
 use mithril_common::entities::ProtocolParameters;
 use mithril_common::StdResult;
 use slog_scope::info;
@@ -21,16 +21,16 @@ pub async fn bootstrap_genesis_certificate(aggregator: &mut Aggregator) -> StdRe
 pub async fn register_era_marker(
     aggregator: &mut Aggregator,
     devnet: &Devnet,
-    mithril_era: &str,
+    mithril_era_schedule: &[ScenarioEraTransition],
 ) -> StdResult<()> {
-    info!("Register era marker");
+    info!("Register era marker"; "mithril_era_schedule" => ?mithril_era_schedule);
 
     info!("> generating era marker tx datum...");
     let tx_datum_file_path = devnet
         .artifacts_dir()
         .join(PathBuf::from("era-tx-datum.txt".to_string()));
     aggregator
-        .era_generate_tx_datum(&tx_datum_file_path, mithril_era)
+        .era_generate_tx_datum(&tx_datum_file_path, mithril_era_schedule)
         .await?;
 
     info!("> writing era marker on the Cardano chain...");
@@ -55,21 +55,19 @@ pub async fn transfer_funds(devnet: &Devnet) -> StdResult<()> {
     Ok(())
 }
 
-pub async fn update_protocol_parameters(aggregator: &mut Aggregator) -> StdResult<()> {
+pub async fn update_protocol_parameters(
+    aggregator: &mut Aggregator,
+    protocol_parameters_new: &ProtocolParameters,
+) -> StdResult<()> {
     info!("Update protocol parameters");
 
     info!("> stopping aggregator");
     aggregator.stop().await?;
-    let protocol_parameters_new = ProtocolParameters {
-        k: 150,
-        m: 210,
-        phi_f: 0.80,
-    };
     info!(
         "> updating protocol parameters to {:?}...",
         protocol_parameters_new
     );
-    aggregator.set_protocol_parameters(&protocol_parameters_new);
+    aggregator.set_protocol_parameters(protocol_parameters_new);
     info!("> done, restarting aggregator");
     aggregator.serve()?;
 
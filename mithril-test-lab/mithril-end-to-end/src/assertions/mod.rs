@@ -1,7 +1,9 @@
 mod check;
+mod database;
 mod exec;
 mod wait;
 
 pub use check::*;
+pub use database::*;
 pub use exec::*;
 pub use wait::*;
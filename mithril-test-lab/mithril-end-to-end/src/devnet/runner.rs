@@ -12,6 +12,7 @@ use tokio::process::Command;
 pub struct Devnet {
     artifacts_dir: PathBuf,
     number_of_pool_nodes: u8,
+    pool_stake_weights: Vec<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,6 +51,8 @@ pub struct DevnetBootstrapArgs {
     pub devnet_scripts_dir: PathBuf,
     pub artifacts_target_dir: PathBuf,
     pub number_of_pool_nodes: u8,
+    /// Weight of the stake delegated to each pool (in ADA), in the pools order
+    pub pool_stake_weights: Vec<u64>,
     pub cardano_slot_length: f64,
     pub cardano_epoch_length: f64,
     pub cardano_node_version: String,
@@ -124,6 +127,7 @@ impl Devnet {
         Ok(Devnet {
             artifacts_dir: bootstrap_args.artifacts_target_dir.to_owned(),
             number_of_pool_nodes: bootstrap_args.number_of_pool_nodes,
+            pool_stake_weights: bootstrap_args.pool_stake_weights.to_owned(),
         })
     }
 
@@ -133,6 +137,7 @@ impl Devnet {
         Self {
             artifacts_dir,
             number_of_pool_nodes,
+            pool_stake_weights: (1..=number_of_pool_nodes as u64).collect(),
         }
     }
 
@@ -247,6 +252,12 @@ impl Devnet {
             .current_dir(&self.artifacts_dir)
             .kill_on_drop(true);
         run_command.env("DELEGATION_ROUND", delegation_round.to_string());
+        for (index, stake_weight) in self.pool_stake_weights.iter().enumerate() {
+            run_command.env(
+                format!("POOL_STAKE_WEIGHT_{}", index + 1),
+                stake_weight.to_string(),
+            );
+        }
 
         info!("Delegating stakes to the pools"; "script" => &run_script_path.display());
 
@@ -1,14 +1,18 @@
 use crate::assertions;
-use crate::MithrilInfrastructure;
+use crate::{MithrilInfrastructure, Scenario, ScenarioAssertion};
 use mithril_common::StdResult;
 
 pub struct Spec<'a> {
     pub infrastructure: &'a mut MithrilInfrastructure,
+    pub scenario: &'a Scenario,
 }
 
 impl<'a> Spec<'a> {
-    pub fn new(infrastructure: &'a mut MithrilInfrastructure) -> Self {
-        Self { infrastructure }
+    pub fn new(infrastructure: &'a mut MithrilInfrastructure, scenario: &'a Scenario) -> Self {
+        Self {
+            infrastructure,
+            scenario,
+        }
     }
 
     pub async fn run(&mut self) -> StdResult<()> {
@@ -39,6 +43,10 @@ impl<'a> Spec<'a> {
         .await?;
         assertions::bootstrap_genesis_certificate(self.infrastructure.aggregator_mut()).await?;
         assertions::wait_for_epoch_settings(&aggregator_endpoint).await?;
+        let database_state = assertions::assert_aggregator_database_is_consistent(
+            &self.infrastructure.aggregator().database_path(),
+            None,
+        )?;
 
         // Wait 2 epochs before changing stake distribution, so that we use at least one original stake distribution
         target_epoch += 2;
@@ -60,7 +68,15 @@ impl<'a> Spec<'a> {
             "epoch after which the protocol parameters will change".to_string(),
         )
         .await?;
-        assertions::update_protocol_parameters(self.infrastructure.aggregator_mut()).await?;
+        assertions::update_protocol_parameters(
+            self.infrastructure.aggregator_mut(),
+            &self.scenario.updated_protocol_parameters,
+        )
+        .await?;
+        let database_state = assertions::assert_aggregator_database_is_consistent(
+            &self.infrastructure.aggregator().database_path(),
+            Some(database_state),
+        )?;
 
         // Wait 6 epochs after protocol parameters update, so that we make sure that we use new protocol parameters as well as new stake distribution a few times
         target_epoch += 6;
@@ -72,6 +88,9 @@ impl<'a> Spec<'a> {
         .await?;
 
         // Verify that mithril stake distribution artifacts are produced and signed correctly
+        if self
+            .scenario
+            .has_assertion(ScenarioAssertion::MithrilStakeDistribution)
         {
             let hash =
                 assertions::assert_node_producing_mithril_stake_distribution(&aggregator_endpoint)
@@ -94,6 +113,9 @@ impl<'a> Spec<'a> {
         }
 
         // Verify that snapshot artifacts are produced and signed correctly
+        if self
+            .scenario
+            .has_assertion(ScenarioAssertion::CardanoImmutableFilesFull)
         {
             let digest = assertions::assert_node_producing_snapshot(&aggregator_endpoint).await?;
             let certificate_hash = assertions::assert_signer_is_signing_snapshot(
@@ -115,7 +137,11 @@ impl<'a> Spec<'a> {
         }
 
         // Verify that Cardano transactions artifacts are produced and signed correctly
-        if self.infrastructure.is_signing_cardano_transactions() {
+        if self.infrastructure.is_signing_cardano_transactions()
+            && self
+                .scenario
+                .has_assertion(ScenarioAssertion::CardanoTransactions)
+        {
             let hash = assertions::assert_node_producing_cardano_transactions(&aggregator_endpoint)
                 .await?;
             let certificate_hash = assertions::assert_signer_is_signing_cardano_transactions(
@@ -141,6 +167,11 @@ impl<'a> Spec<'a> {
                 .await?;
         }
 
+        assertions::assert_aggregator_database_is_consistent(
+            &self.infrastructure.aggregator().database_path(),
+            Some(database_state),
+        )?;
+
         Ok(())
     }
 }
@@ -2,11 +2,22 @@ pub mod assertions;
 mod devnet;
 mod end_to_end_spec;
 mod mithril;
+mod remote_spec;
 mod run_only;
+mod run_result;
+mod scenario;
 pub mod stress_test;
 mod utils;
 
 pub use devnet::*;
 pub use end_to_end_spec::Spec;
 pub use mithril::*;
+pub use remote_spec::{
+    ensure_conformant, fetch_genesis_verification_key, ConformanceCheck, ConformanceCheckStatus,
+    ConformanceReport, RemoteNetwork, RemoteSpec, RemoteSpecConfig,
+};
 pub use run_only::RunOnly;
+pub use run_result::{RunParameters, RunResult, RunStatus};
+pub use scenario::{
+    Scenario, ScenarioAssertion, ScenarioEraTransition, ScenarioEras, ScenarioPool,
+};
@@ -3,7 +3,10 @@ use clap::{CommandFactory, Parser, Subcommand};
 use mithril_common::StdResult;
 use mithril_doc::GenerateDocCommands;
 use mithril_end_to_end::{
-    Devnet, DevnetBootstrapArgs, MithrilInfrastructure, MithrilInfrastructureConfig, RunOnly, Spec,
+    ensure_conformant, fetch_genesis_verification_key, Devnet, DevnetBootstrapArgs,
+    MithrilInfrastructure, MithrilInfrastructureConfig, RemoteNetwork, RemoteSpec,
+    RemoteSpecConfig, RunOnly, RunParameters, RunResult, Scenario, ScenarioAssertion,
+    ScenarioEraTransition, ScenarioEras, Spec,
 };
 use slog::{Drain, Level, Logger};
 use slog_scope::{error, info};
@@ -12,7 +15,7 @@ use std::{
     path::{Path, PathBuf},
     sync::Arc,
     thread::sleep,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio_util::sync::CancellationToken;
 
@@ -44,6 +47,22 @@ pub struct Args {
     #[clap(long, default_value = ".")]
     bin_directory: PathBuf,
 
+    /// Scenario file (TOML or YAML) describing the devnet topology and the assertions to run
+    ///
+    /// When set, the pools, protocol parameters, eras and signed entity types are read from this
+    /// file instead of the command line arguments.
+    #[clap(long, conflicts_with_all = [
+        "number_of_pool_nodes",
+        "cardano_hard_fork_latest_era_at_epoch",
+        "mithril_era",
+        "mithril_era_transitions",
+        "signed_entity_types",
+        "protocol_parameters_k",
+        "protocol_parameters_m",
+        "protocol_parameters_phi_f",
+    ])]
+    scenario_file: Option<PathBuf>,
+
     /// Number of Pool nodes in the devnet
     #[clap(long, default_value_t = 3, value_parser = has_at_least_two_pool_nodes)]
     number_of_pool_nodes: u8,
@@ -72,6 +91,15 @@ pub struct Args {
     #[clap(long, default_value = "thales")]
     mithril_era: String,
 
+    /// Activation of a Mithril era at an epoch, written as `<era>:<epoch>` (ie: `thales:0`)
+    ///
+    /// Repeat the option to schedule several eras, in their activation order, the last one being
+    /// the Mithril era to run.
+    ///
+    /// Optional: if not set the eras up to the Mithril era are activated at epochs 0, 1, ...
+    #[clap(long = "mithril-era-transition", value_name = "ERA:EPOCH")]
+    mithril_era_transitions: Vec<ScenarioEraTransition>,
+
     /// Mithril era reader adapter
     #[clap(long, default_value = "cardano-chain")]
     mithril_era_reader_adapter: String,
@@ -80,6 +108,31 @@ pub struct Args {
     #[clap(long, value_delimiter = ',', default_value = "CardanoTransactions")]
     signed_entity_types: Vec<String>,
 
+    /// Protocol parameter `k` (quorum) used when the aggregator is started
+    ///
+    /// Optional: if not set the default protocol parameters are used
+    #[clap(long)]
+    protocol_parameters_k: Option<u64>,
+
+    /// Protocol parameter `m` (number of lotteries) used when the aggregator is started
+    ///
+    /// Optional: if not set the default protocol parameters are used
+    #[clap(long)]
+    protocol_parameters_m: Option<u64>,
+
+    /// Protocol parameter `phi_f` (chance of a signer to win a lottery) used when the aggregator
+    /// is started
+    ///
+    /// Optional: if not set the default protocol parameters are used
+    #[clap(long)]
+    protocol_parameters_phi_f: Option<f64>,
+
+    /// Path of the json file where the parameters and the result of the run are written
+    ///
+    /// Optional: if not set it will default to `{work_directory}/run-result.json`
+    #[clap(long)]
+    result_file: Option<PathBuf>,
+
     /// Enable run only mode
     #[clap(long)]
     run_only: bool,
@@ -92,6 +145,12 @@ pub struct Args {
     #[clap(long, default_value = "true")]
     use_p2p_passive_relays: bool,
 
+    /// Stream the logs of the Mithril nodes to the terminal while the test is running
+    ///
+    /// Each line is prefixed with the name of the node and colored according to its level.
+    #[clap(long)]
+    live_logs: bool,
+
     /// Skip cardano binaries download
     #[clap(long)]
     skip_cardano_bin_download: bool,
@@ -107,6 +166,36 @@ pub struct Args {
 }
 
 impl Args {
+    fn scenario(&self) -> StdResult<Scenario> {
+        match &self.scenario_file {
+            Some(path) => Scenario::from_file(path),
+            None => {
+                let mut scenario = Scenario {
+                    eras: ScenarioEras {
+                        cardano_hard_fork_latest_era_at_epoch: self
+                            .cardano_hard_fork_latest_era_at_epoch,
+                        mithril_era: self.mithril_era.clone(),
+                        mithril_era_transitions: self.mithril_era_transitions.clone(),
+                    },
+                    signed_entity_types: self.signed_entity_types.clone(),
+                    ..Scenario::with_pools(self.number_of_pool_nodes)
+                };
+                if let Some(k) = self.protocol_parameters_k {
+                    scenario.protocol_parameters.k = k;
+                }
+                if let Some(m) = self.protocol_parameters_m {
+                    scenario.protocol_parameters.m = m;
+                }
+                if let Some(phi_f) = self.protocol_parameters_phi_f {
+                    scenario.protocol_parameters.phi_f = phi_f;
+                }
+                scenario.validate()?;
+
+                Ok(scenario)
+            }
+        }
+    }
+
     fn log_level(&self) -> Level {
         match self.verbose {
             0 => Level::Error,
@@ -134,6 +223,102 @@ fn has_at_least_two_pool_nodes(s: &str) -> Result<u8, String> {
 enum EndToEndCommands {
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
+
+    /// Run the client side assertions against a live aggregator instead of a local devnet
+    Remote(RemoteCommand),
+}
+
+/// Client side assertions against a live aggregator
+#[derive(Parser, Debug, Clone)]
+pub struct RemoteCommand {
+    /// Cardano network of the live aggregator
+    #[clap(long, value_enum)]
+    network: RemoteNetwork,
+
+    /// Aggregator endpoint
+    ///
+    /// Optional: if not set the aggregator of the network will be used
+    #[clap(long)]
+    aggregator_endpoint: Option<String>,
+
+    /// Genesis verification key
+    ///
+    /// Optional: if not set the genesis verification key will be downloaded from
+    /// `genesis_verification_key_url`
+    #[clap(long)]
+    genesis_verification_key: Option<String>,
+
+    /// Location of the genesis verification key to download
+    ///
+    /// Optional: if not set the genesis verification key of the network published in the Mithril
+    /// repository will be used
+    #[clap(long)]
+    genesis_verification_key_url: Option<String>,
+
+    /// Assertions to run (in a comma separated list)
+    #[clap(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "mithril_stake_distribution,cardano_immutable_files_full,cardano_transactions"
+    )]
+    assertions: Vec<ScenarioAssertion>,
+
+    /// Hashes of the Cardano transactions to certify (in a comma separated list)
+    ///
+    /// The Cardano transactions assertion is skipped if none are given.
+    #[clap(long, value_delimiter = ',')]
+    transaction_hashes: Vec<String>,
+
+    /// Maximum number of certificates to follow when asserting the certificate chain is linked
+    #[clap(long, default_value_t = 100)]
+    certificate_chain_depth: u64,
+
+    /// Path of the json conformance report
+    ///
+    /// Optional: if not set it will default to `{work_directory}/conformance-report.json`
+    #[clap(long)]
+    report_file: Option<PathBuf>,
+}
+
+impl RemoteCommand {
+    async fn execute(&self, work_dir: PathBuf, bin_dir: PathBuf) -> StdResult<()> {
+        let genesis_verification_key = match &self.genesis_verification_key {
+            Some(key) => key.clone(),
+            None => {
+                let url = self
+                    .genesis_verification_key_url
+                    .as_deref()
+                    .unwrap_or_else(|| self.network.genesis_verification_key_url());
+                fetch_genesis_verification_key(url).await?
+            }
+        };
+        let report_file = self
+            .report_file
+            .clone()
+            .unwrap_or_else(|| work_dir.join("conformance-report.json"));
+
+        let report = RemoteSpec::new(RemoteSpecConfig {
+            network: self.network,
+            aggregator_endpoint: self
+                .aggregator_endpoint
+                .clone()
+                .unwrap_or_else(|| self.network.aggregator_endpoint().to_string()),
+            genesis_verification_key,
+            work_dir,
+            bin_dir,
+            assertions: self.assertions.clone(),
+            transaction_hashes: self.transaction_hashes.clone(),
+            certificate_chain_depth: self.certificate_chain_depth,
+        })
+        .run()
+        .await?;
+
+        report.write_to_file(&report_file)?;
+        info!("Conformance report written"; "path" => %report_file.display());
+
+        ensure_conformant(&report)
+    }
 }
 
 #[tokio::main]
@@ -147,52 +332,90 @@ async fn main() -> StdResult<()> {
             .map_err(|message| anyhow!(message));
     }
 
-    let server_port = 8080;
-    let work_dir = match args.work_directory {
-        Some(path) => {
-            create_workdir_if_not_exist_clean_otherwise(&path);
-            path.canonicalize().unwrap()
-        }
-        None => {
-            #[cfg(target_os = "macos")]
-            let work_dir = PathBuf::from("./mithril_end_to_end");
-            #[cfg(not(target_os = "macos"))]
-            let work_dir = std::env::temp_dir().join("mithril_end_to_end");
-            create_workdir_if_not_exist_clean_otherwise(&work_dir);
-            work_dir.canonicalize().unwrap()
-        }
+    let work_dir = prepare_work_dir(args.work_directory.as_deref());
+
+    if let Some(EndToEndCommands::Remote(cmd)) = &args.command {
+        return cmd.execute(work_dir, args.bin_directory.clone()).await;
+    }
+
+    let scenario = args.scenario()?;
+    let result_file = args
+        .result_file
+        .clone()
+        .unwrap_or_else(|| work_dir.join("run-result.json"));
+    let run_parameters = RunParameters {
+        cardano_node_version: args.cardano_node_version.clone(),
+        use_p2p_network: args.use_p2p_network,
+        scenario: scenario.clone(),
     };
+    let started_at = Instant::now();
+
+    let server_port = 8080;
     let run_only_mode = args.run_only;
     let use_p2p_network_mode = args.use_p2p_network;
     let use_p2p_passive_relays = args.use_p2p_passive_relays;
 
-    let devnet = Devnet::bootstrap(&DevnetBootstrapArgs {
+    // The result is written whatever the step that failed, so a failed parameterization of a
+    // matrix is never missing from its results
+    let write_run_result = |runner: &StdResult<()>| -> StdResult<()> {
+        if !run_only_mode {
+            let run_result = RunResult::new(run_parameters.clone(), runner, started_at.elapsed());
+            run_result.write_to_file(&result_file)?;
+            info!("Run result written"; "path" => %result_file.display(), "status" => ?run_result.status);
+        }
+
+        Ok(())
+    };
+
+    let devnet = match Devnet::bootstrap(&DevnetBootstrapArgs {
         devnet_scripts_dir: args.devnet_scripts_directory,
         artifacts_target_dir: work_dir.join("devnet"),
-        number_of_pool_nodes: args.number_of_pool_nodes,
+        number_of_pool_nodes: scenario.number_of_pool_nodes(),
+        pool_stake_weights: scenario.pool_stake_weights(),
         cardano_slot_length: args.cardano_slot_length,
         cardano_epoch_length: args.cardano_epoch_length,
         cardano_node_version: args.cardano_node_version.to_owned(),
-        cardano_hard_fork_latest_era_at_epoch: args.cardano_hard_fork_latest_era_at_epoch,
+        cardano_hard_fork_latest_era_at_epoch: scenario.eras.cardano_hard_fork_latest_era_at_epoch,
         skip_cardano_bin_download: args.skip_cardano_bin_download,
     })
-    .await?;
+    .await
+    {
+        Ok(devnet) => devnet,
+        Err(error) => {
+            let runner = Err(error);
+            write_run_result(&runner)?;
+            return runner;
+        }
+    };
 
-    let mut infrastructure = MithrilInfrastructure::start(&MithrilInfrastructureConfig {
+    let mut infrastructure = match MithrilInfrastructure::start(&MithrilInfrastructureConfig {
         server_port,
         devnet: devnet.clone(),
         work_dir,
         bin_dir: args.bin_directory,
         cardano_node_version: args.cardano_node_version,
         mithril_run_interval: args.mithril_run_interval,
-        mithril_era: args.mithril_era,
+        protocol_parameters: scenario.protocol_parameters.clone(),
+        mithril_era: scenario.eras.mithril_era.clone(),
+        mithril_era_schedule: scenario.eras.mithril_era_schedule(),
         mithril_era_reader_adapter: args.mithril_era_reader_adapter,
-        signed_entity_types: args.signed_entity_types,
+        signed_entity_types: scenario.signed_entity_types.clone(),
         run_only_mode,
         use_p2p_network_mode,
         use_p2p_passive_relays,
+        live_tail: args.live_logs,
     })
-    .await?;
+    .await
+    {
+        Ok(infrastructure) => infrastructure,
+        Err(error) => {
+            error!("Mithril End to End test in failed: {}", error);
+            let runner = Err(error);
+            write_run_result(&runner)?;
+            devnet.stop().await?;
+            return runner;
+        }
+    };
 
     let runner: StdResult<()> = match run_only_mode {
         true => {
@@ -200,11 +423,13 @@ async fn main() -> StdResult<()> {
             run_only.start().await
         }
         false => {
-            let mut spec = Spec::new(&mut infrastructure);
+            let mut spec = Spec::new(&mut infrastructure, &scenario);
             spec.run().await
         }
     };
 
+    write_run_result(&runner)?;
+
     match runner {
         Ok(_) if run_only_mode => run_until_cancelled(devnet).await,
         Ok(_) => {
@@ -250,6 +475,23 @@ fn build_logger(args: &Args) -> Logger {
     Logger::root(Arc::new(drain), slog::o!())
 }
 
+fn prepare_work_dir(work_directory: Option<&Path>) -> PathBuf {
+    match work_directory {
+        Some(path) => {
+            create_workdir_if_not_exist_clean_otherwise(path);
+            path.canonicalize().unwrap()
+        }
+        None => {
+            #[cfg(target_os = "macos")]
+            let work_dir = PathBuf::from("./mithril_end_to_end");
+            #[cfg(not(target_os = "macos"))]
+            let work_dir = std::env::temp_dir().join("mithril_end_to_end");
+            create_workdir_if_not_exist_clean_otherwise(&work_dir);
+            work_dir.canonicalize().unwrap()
+        }
+    }
+}
+
 fn create_workdir_if_not_exist_clean_otherwise(work_dir: &Path) {
     if work_dir.exists() {
         fs::remove_dir_all(work_dir).expect("Previous work dir removal failed");
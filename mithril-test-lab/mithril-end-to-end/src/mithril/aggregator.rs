@@ -1,10 +1,9 @@
 use crate::utils::MithrilCommand;
 use crate::{
-    PoolNode, DEVNET_MAGIC_ID, ERA_MARKERS_SECRET_KEY, ERA_MARKERS_VERIFICATION_KEY,
-    GENESIS_SECRET_KEY, GENESIS_VERIFICATION_KEY,
+    PoolNode, ScenarioEraTransition, DEVNET_MAGIC_ID, ERA_MARKERS_SECRET_KEY,
+    ERA_MARKERS_VERIFICATION_KEY, GENESIS_SECRET_KEY, GENESIS_VERIFICATION_KEY,
 };
 use anyhow::{anyhow, Context};
-use mithril_common::era::SupportedEra;
 use mithril_common::{entities, StdResult};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -31,6 +30,7 @@ pub struct AggregatorConfig<'a> {
 pub struct Aggregator {
     server_port: u64,
     db_directory: PathBuf,
+    store_directory: PathBuf,
     command: MithrilCommand,
     process: Option<Child>,
 }
@@ -110,6 +110,7 @@ impl Aggregator {
         Ok(Self {
             server_port: aggregator_config.server_port,
             db_directory: aggregator_config.pool_node.db_path.clone(),
+            store_directory: aggregator_config.work_dir.join("stores").join("aggregator"),
             command,
             process: None,
         })
@@ -119,6 +120,7 @@ impl Aggregator {
         Self {
             server_port: other.server_port,
             db_directory: other.db_directory.clone(),
+            store_directory: other.store_directory.clone(),
             command: other.command.clone(),
             process: None,
         }
@@ -132,6 +134,11 @@ impl Aggregator {
         &self.db_directory
     }
 
+    /// Path of the aggregator main SQLite database
+    pub fn database_path(&self) -> PathBuf {
+        self.store_directory.join("aggregator.sqlite3")
+    }
+
     pub fn serve(&mut self) -> StdResult<()> {
         self.process = Some(self.command.start(&["serve".to_string()])?);
         Ok(())
@@ -172,16 +179,18 @@ impl Aggregator {
     pub async fn era_generate_tx_datum(
         &mut self,
         target_path: &Path,
-        mithril_era: &str,
+        mithril_era_schedule: &[ScenarioEraTransition],
     ) -> StdResult<()> {
-        let is_not_first_era =
-            SupportedEra::eras().first().map(|e| e.to_string()) != Some(mithril_era.to_string());
+        let current_era_epoch = mithril_era_schedule
+            .first()
+            .map(|transition| transition.epoch)
+            .unwrap_or_default();
 
         let mut args = vec![
             "era".to_string(),
             "generate-tx-datum".to_string(),
             "--current-era-epoch".to_string(),
-            "0".to_string(),
+            current_era_epoch.to_string(),
             "--era-markers-secret-key".to_string(),
             ERA_MARKERS_SECRET_KEY.to_string(),
             "--target-path".to_string(),
@@ -189,9 +198,9 @@ impl Aggregator {
         ];
 
         // If only the first available era is targeted we have no "next-era" to activate
-        if is_not_first_era {
+        if let Some(next_era_transition) = mithril_era_schedule.get(1) {
             args.push("--next-era-epoch".to_string());
-            args.push("1".to_string());
+            args.push(next_era_transition.epoch.to_string());
         }
 
         let exit_status = self
@@ -217,6 +226,10 @@ impl Aggregator {
         }
     }
 
+    pub fn set_live_tail(&mut self, enabled: bool) {
+        self.command.set_live_tail(enabled);
+    }
+
     pub fn set_protocol_parameters(&mut self, protocol_parameters: &entities::ProtocolParameters) {
         self.command.set_env_var(
             "PROTOCOL_PARAMETERS__K",
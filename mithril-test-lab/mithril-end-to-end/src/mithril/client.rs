@@ -157,6 +157,15 @@ impl Client {
         Ok(Self { command })
     }
 
+    pub fn set_genesis_verification_key(&mut self, genesis_verification_key: &str) {
+        self.command
+            .set_env_var("GENESIS_VERIFICATION_KEY", genesis_verification_key);
+    }
+
+    pub fn set_live_tail(&mut self, enabled: bool) {
+        self.command.set_live_tail(enabled);
+    }
+
     pub async fn run(&mut self, command: ClientCommand) -> StdResult<PathBuf> {
         let output_path = self
             .command
@@ -1,6 +1,6 @@
 use crate::{
     assertions, Aggregator, AggregatorConfig, Client, Devnet, PoolNode, RelayAggregator,
-    RelayPassive, RelaySigner, Signer, DEVNET_MAGIC_ID,
+    RelayPassive, RelaySigner, ScenarioEraTransition, Signer, DEVNET_MAGIC_ID,
 };
 use mithril_common::chain_observer::{ChainObserver, PallasChainObserver};
 use mithril_common::entities::{PartyId, ProtocolParameters, SignedEntityTypeDiscriminants};
@@ -21,12 +21,15 @@ pub struct MithrilInfrastructureConfig {
     pub bin_dir: PathBuf,
     pub cardano_node_version: String,
     pub mithril_run_interval: u32,
+    pub protocol_parameters: ProtocolParameters,
     pub mithril_era: String,
+    pub mithril_era_schedule: Vec<ScenarioEraTransition>,
     pub mithril_era_reader_adapter: String,
     pub signed_entity_types: Vec<String>,
     pub run_only_mode: bool,
     pub use_p2p_network_mode: bool,
     pub use_p2p_passive_relays: bool,
+    pub live_tail: bool,
 }
 
 pub struct MithrilInfrastructure {
@@ -41,6 +44,7 @@ pub struct MithrilInfrastructure {
     cardano_chain_observer: Arc<dyn ChainObserver>,
     run_only_mode: bool,
     is_signing_cardano_transactions: bool,
+    live_tail: bool,
 }
 
 impl MithrilInfrastructure {
@@ -90,6 +94,7 @@ impl MithrilInfrastructure {
                     .as_ref()
                     .to_string(),
             ),
+            live_tail: config.live_tail,
         })
     }
 
@@ -113,14 +118,15 @@ impl MithrilInfrastructure {
             chain_observer_type,
         })?;
 
-        aggregator.set_protocol_parameters(&ProtocolParameters {
-            k: 75,
-            m: 105,
-            phi_f: 0.95,
-        });
+        aggregator.set_protocol_parameters(&config.protocol_parameters);
+        aggregator.set_live_tail(config.live_tail);
         if config.mithril_era_reader_adapter == "cardano-chain" {
-            assertions::register_era_marker(&mut aggregator, &config.devnet, &config.mithril_era)
-                .await?;
+            assertions::register_era_marker(
+                &mut aggregator,
+                &config.devnet,
+                &config.mithril_era_schedule,
+            )
+            .await?;
             sleep(Duration::from_secs(5)).await;
         }
         aggregator.serve()?;
@@ -149,6 +155,7 @@ impl MithrilInfrastructure {
             &config.work_dir,
             &config.bin_dir,
         )?;
+        relay_aggregator.set_live_tail(config.live_tail);
         relay_aggregator.start()?;
 
         let mut relay_passive_id = 1;
@@ -160,6 +167,7 @@ impl MithrilInfrastructure {
                 &config.work_dir,
                 &config.bin_dir,
             )?;
+            relay_passive_aggregator.set_live_tail(config.live_tail);
             relay_passive_aggregator.start()?;
             relay_passives.push(relay_passive_aggregator);
         }
@@ -174,6 +182,7 @@ impl MithrilInfrastructure {
                 &config.work_dir,
                 &config.bin_dir,
             )?;
+            relay_signer.set_live_tail(config.live_tail);
             relay_signer.start()?;
 
             if config.use_p2p_passive_relays {
@@ -185,6 +194,7 @@ impl MithrilInfrastructure {
                     &config.work_dir,
                     &config.bin_dir,
                 )?;
+                relay_passive_signer.set_live_tail(config.live_tail);
                 relay_passive_signer.start()?;
                 relay_passives.push(relay_passive_signer);
             }
@@ -229,6 +239,7 @@ impl MithrilInfrastructure {
                 mithril_era_marker_address: &config.devnet.mithril_era_marker_address()?,
                 enable_certification,
             })?;
+            signer.set_live_tail(config.live_tail);
             signer.start()?;
 
             signers.push(signer);
@@ -274,7 +285,10 @@ impl MithrilInfrastructure {
     }
 
     pub fn build_client(&self) -> StdResult<Client> {
-        Client::new(self.aggregator.endpoint(), &self.work_dir, &self.bin_dir)
+        let mut client = Client::new(self.aggregator.endpoint(), &self.work_dir, &self.bin_dir)?;
+        client.set_live_tail(self.live_tail);
+
+        Ok(client)
     }
 
     pub fn run_only_mode(&self) -> bool {
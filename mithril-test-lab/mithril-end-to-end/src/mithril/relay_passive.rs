@@ -42,6 +42,10 @@ impl RelayPassive {
         format!("/ip4/127.0.0.1/tcp/{}", self.listen_port)
     }
 
+    pub fn set_live_tail(&mut self, enabled: bool) {
+        self.command.set_live_tail(enabled);
+    }
+
     pub fn start(&mut self) -> StdResult<()> {
         self.process = Some(self.command.start(&[])?);
         Ok(())
@@ -55,6 +55,10 @@ impl RelaySigner {
         format!("http://localhost:{}", &self.server_port)
     }
 
+    pub fn set_live_tail(&mut self, enabled: bool) {
+        self.command.set_live_tail(enabled);
+    }
+
     pub fn start(&mut self) -> StdResult<()> {
         self.process = Some(self.command.start(&[])?);
         Ok(())
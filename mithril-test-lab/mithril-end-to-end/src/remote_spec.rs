@@ -0,0 +1,342 @@
+use anyhow::{anyhow, Context};
+use mithril_common::entities::TransactionHash;
+use mithril_common::StdResult;
+use serde::{Deserialize, Serialize};
+use slog_scope::{error, info};
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::{assertions, Client, ScenarioAssertion};
+
+/// Live Cardano networks on which the client side assertions can be run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum RemoteNetwork {
+    /// Cardano preprod network (release-preprod Mithril network)
+    Preprod,
+
+    /// Cardano preview network (pre-release-preview Mithril network)
+    Preview,
+}
+
+impl RemoteNetwork {
+    /// Endpoint of the aggregator of the network
+    pub fn aggregator_endpoint(&self) -> &'static str {
+        match self {
+            RemoteNetwork::Preprod => {
+                "https://aggregator.release-preprod.api.mithril.network/aggregator"
+            }
+            RemoteNetwork::Preview => {
+                "https://aggregator.pre-release-preview.api.mithril.network/aggregator"
+            }
+        }
+    }
+
+    /// Default location of the genesis verification key of the network
+    pub fn genesis_verification_key_url(&self) -> &'static str {
+        match self {
+            RemoteNetwork::Preprod => "https://raw.githubusercontent.com/input-output-hk/mithril/main/mithril-infra/configuration/release-preprod/genesis.vkey",
+            RemoteNetwork::Preview => "https://raw.githubusercontent.com/input-output-hk/mithril/main/mithril-infra/configuration/pre-release-preview/genesis.vkey",
+        }
+    }
+}
+
+/// Download the genesis verification key at the given url
+pub async fn fetch_genesis_verification_key(url: &str) -> StdResult<String> {
+    let response = reqwest::get(url)
+        .await
+        .with_context(|| format!("Request to `{url}` failed"))?
+        .error_for_status()
+        .with_context(|| format!("Could not fetch genesis verification key from `{url}`"))?;
+
+    Ok(response.text().await?.trim().to_string())
+}
+
+/// Configuration of a run against a live aggregator
+pub struct RemoteSpecConfig {
+    pub network: RemoteNetwork,
+    pub aggregator_endpoint: String,
+    pub genesis_verification_key: String,
+    pub work_dir: PathBuf,
+    pub bin_dir: PathBuf,
+    pub assertions: Vec<ScenarioAssertion>,
+    pub transaction_hashes: Vec<TransactionHash>,
+    pub certificate_chain_depth: u64,
+}
+
+/// Status of a conformance check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConformanceCheckStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// Result of a conformance check
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConformanceCheck {
+    /// Name of the check
+    pub name: String,
+
+    /// Status of the check
+    pub status: ConformanceCheckStatus,
+
+    /// Duration of the check in milliseconds
+    pub duration_ms: u128,
+
+    /// Details on the failure or the reason of the skip
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+/// Machine readable report of a run against a live aggregator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConformanceReport {
+    /// Network on which the checks were run
+    pub network: RemoteNetwork,
+
+    /// Endpoint of the aggregator on which the checks were run
+    pub aggregator_endpoint: String,
+
+    /// Results of the checks, in execution order
+    pub checks: Vec<ConformanceCheck>,
+}
+
+impl ConformanceReport {
+    /// Check if no check of the report has failed
+    pub fn is_conformant(&self) -> bool {
+        self.checks
+            .iter()
+            .all(|check| check.status != ConformanceCheckStatus::Failed)
+    }
+
+    /// Write the report as json in the given file
+    pub fn write_to_file(&self, path: &Path) -> StdResult<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(path, content)
+            .with_context(|| format!("Could not write conformance report to `{}`", path.display()))
+    }
+}
+
+/// Run client side assertions against a live aggregator, without bootstrapping a devnet
+pub struct RemoteSpec {
+    config: RemoteSpecConfig,
+    checks: Vec<ConformanceCheck>,
+}
+
+impl RemoteSpec {
+    pub fn new(config: RemoteSpecConfig) -> Self {
+        Self {
+            config,
+            checks: vec![],
+        }
+    }
+
+    pub async fn run(mut self) -> StdResult<ConformanceReport> {
+        let aggregator_endpoint = self.config.aggregator_endpoint.clone();
+        let certificate_chain_depth = self.config.certificate_chain_depth;
+        info!("Running conformance checks against a live aggregator"; "network" => ?self.config.network, "aggregator_endpoint" => &aggregator_endpoint);
+
+        self.check("certificate_chain", async {
+            let certificate_hash =
+                assertions::assert_aggregator_has_certificate(&aggregator_endpoint).await?;
+            assertions::assert_certificate_chain_is_linked(
+                &aggregator_endpoint,
+                &certificate_hash,
+                certificate_chain_depth,
+            )
+            .await
+        })
+        .await;
+
+        if self.has_assertion(ScenarioAssertion::MithrilStakeDistribution) {
+            let mut client = self.build_client()?;
+            self.check("mithril_stake_distribution", async {
+                let hash = assertions::assert_node_producing_mithril_stake_distribution(
+                    &aggregator_endpoint,
+                )
+                .await?;
+                assertions::assert_client_can_verify_mithril_stake_distribution(&mut client, &hash)
+                    .await
+            })
+            .await;
+        }
+
+        if self.has_assertion(ScenarioAssertion::CardanoImmutableFilesFull) {
+            let mut client = self.build_client()?;
+            self.check("cardano_immutable_files_full", async {
+                let digest =
+                    assertions::assert_node_producing_snapshot(&aggregator_endpoint).await?;
+                assertions::assert_client_can_verify_snapshot(&mut client, &digest).await
+            })
+            .await;
+        }
+
+        if self.has_assertion(ScenarioAssertion::CardanoTransactions) {
+            if self.config.transaction_hashes.is_empty() {
+                self.skip(
+                    "cardano_transactions",
+                    "no transaction hashes given to certify",
+                );
+            } else {
+                let mut client = self.build_client()?;
+                let transaction_hashes = self.config.transaction_hashes.clone();
+                self.check("cardano_transactions", async {
+                    assertions::assert_client_can_verify_transactions(
+                        &mut client,
+                        transaction_hashes,
+                    )
+                    .await
+                })
+                .await;
+            }
+        }
+
+        Ok(ConformanceReport {
+            network: self.config.network,
+            aggregator_endpoint: self.config.aggregator_endpoint,
+            checks: self.checks,
+        })
+    }
+
+    fn has_assertion(&self, assertion: ScenarioAssertion) -> bool {
+        self.config.assertions.contains(&assertion)
+    }
+
+    fn build_client(&self) -> StdResult<Client> {
+        let mut client = Client::new(
+            self.config.aggregator_endpoint.clone(),
+            &self.config.work_dir,
+            &self.config.bin_dir,
+        )?;
+        client.set_genesis_verification_key(&self.config.genesis_verification_key);
+
+        Ok(client)
+    }
+
+    async fn check<F: Future<Output = StdResult<()>>>(&mut self, name: &str, check: F) {
+        let started_at = Instant::now();
+        let result = check.await;
+        let duration_ms = started_at.elapsed().as_millis();
+
+        let (status, details) = match result {
+            Ok(()) => {
+                info!("Conformance check passed"; "check" => name, "duration_ms" => duration_ms);
+                (ConformanceCheckStatus::Passed, None)
+            }
+            Err(err) => {
+                error!("Conformance check failed"; "check" => name, "error" => ?err);
+                (ConformanceCheckStatus::Failed, Some(format!("{err:?}")))
+            }
+        };
+        self.checks.push(ConformanceCheck {
+            name: name.to_string(),
+            status,
+            duration_ms,
+            details,
+        });
+    }
+
+    fn skip(&mut self, name: &str, reason: &str) {
+        info!("Conformance check skipped"; "check" => name, "reason" => reason);
+        self.checks.push(ConformanceCheck {
+            name: name.to_string(),
+            status: ConformanceCheckStatus::Skipped,
+            duration_ms: 0,
+            details: Some(reason.to_string()),
+        });
+    }
+}
+
+/// Turn a report into an error if it's not conformant
+pub fn ensure_conformant(report: &ConformanceReport) -> StdResult<()> {
+    if report.is_conformant() {
+        Ok(())
+    } else {
+        let failed_checks = report
+            .checks
+            .iter()
+            .filter(|check| check.status == ConformanceCheckStatus::Failed)
+            .map(|check| check.name.as_str())
+            .collect::<Vec<_>>();
+        Err(anyhow!(
+            "Aggregator `{}` is not conformant, failed checks: {}",
+            report.aggregator_endpoint,
+            failed_checks.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(name: &str, status: ConformanceCheckStatus) -> ConformanceCheck {
+        ConformanceCheck {
+            name: name.to_string(),
+            status,
+            duration_ms: 10,
+            details: None,
+        }
+    }
+
+    fn report(checks: Vec<ConformanceCheck>) -> ConformanceReport {
+        ConformanceReport {
+            network: RemoteNetwork::Preprod,
+            aggregator_endpoint: RemoteNetwork::Preprod.aggregator_endpoint().to_string(),
+            checks,
+        }
+    }
+
+    #[test]
+    fn report_with_skipped_checks_is_conformant() {
+        let report = report(vec![
+            check("certificate_chain", ConformanceCheckStatus::Passed),
+            check("cardano_transactions", ConformanceCheckStatus::Skipped),
+        ]);
+
+        assert!(report.is_conformant());
+        ensure_conformant(&report).unwrap();
+    }
+
+    #[test]
+    fn report_with_a_failed_check_is_not_conformant() {
+        let report = report(vec![
+            check("certificate_chain", ConformanceCheckStatus::Passed),
+            check("mithril_stake_distribution", ConformanceCheckStatus::Failed),
+        ]);
+
+        assert!(!report.is_conformant());
+        let error = ensure_conformant(&report).unwrap_err();
+        assert!(error.to_string().contains("mithril_stake_distribution"));
+    }
+
+    #[test]
+    fn report_json_serialization_is_machine_readable() {
+        let report = report(vec![ConformanceCheck {
+            name: "cardano_transactions".to_string(),
+            status: ConformanceCheckStatus::Skipped,
+            duration_ms: 0,
+            details: Some("no transaction hashes given to certify".to_string()),
+        }]);
+
+        let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(
+            serde_json::json!({
+                "network": "preprod",
+                "aggregator_endpoint": "https://aggregator.release-preprod.api.mithril.network/aggregator",
+                "checks": [{
+                    "name": "cardano_transactions",
+                    "status": "skipped",
+                    "duration_ms": 0,
+                    "details": "no transaction hashes given to certify"
+                }]
+            }),
+            json
+        );
+    }
+}
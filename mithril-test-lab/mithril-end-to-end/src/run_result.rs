@@ -0,0 +1,134 @@
+use anyhow::Context;
+use mithril_common::StdResult;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::Scenario;
+
+/// Status of an end to end run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStatus {
+    Succeeded,
+    Failed,
+}
+
+/// Parameters of an end to end run, so the results of the runs of a matrix can be told apart
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunParameters {
+    /// Cardano node version of the devnet
+    pub cardano_node_version: String,
+
+    /// Whether the Mithril nodes communicated through the P2P network
+    pub use_p2p_network: bool,
+
+    /// Topology, protocol parameters, eras and signed entity types of the run
+    pub scenario: Scenario,
+}
+
+/// Machine readable result of an end to end run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunResult {
+    /// Parameters of the run
+    pub parameters: RunParameters,
+
+    /// Status of the run
+    pub status: RunStatus,
+
+    /// Duration of the run in seconds
+    pub duration_seconds: u64,
+
+    /// Error that made the run fail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl RunResult {
+    /// Build the result of a run given its outcome
+    pub fn new(parameters: RunParameters, outcome: &StdResult<()>, duration: Duration) -> Self {
+        let (status, error) = match outcome {
+            Ok(()) => (RunStatus::Succeeded, None),
+            Err(error) => (RunStatus::Failed, Some(format!("{error:?}"))),
+        };
+
+        Self {
+            parameters,
+            status,
+            duration_seconds: duration.as_secs(),
+            error,
+        }
+    }
+
+    /// Write the result as json in the given file
+    pub fn write_to_file(&self, path: &Path) -> StdResult<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(path, content)
+            .with_context(|| format!("Could not write run result to `{}`", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    fn parameters() -> RunParameters {
+        RunParameters {
+            cardano_node_version: "8.9.0".to_string(),
+            use_p2p_network: false,
+            scenario: Scenario::with_pools(3),
+        }
+    }
+
+    #[test]
+    fn successful_run_result_has_no_error() {
+        let result = RunResult::new(parameters(), &Ok(()), Duration::from_secs(125));
+
+        assert_eq!(RunStatus::Succeeded, result.status);
+        assert_eq!(125, result.duration_seconds);
+        assert_eq!(None, result.error);
+    }
+
+    #[test]
+    fn failed_run_result_keeps_the_error() {
+        let result = RunResult::new(
+            parameters(),
+            &Err(anyhow!("certificate chain is not valid")),
+            Duration::from_secs(60),
+        );
+
+        assert_eq!(RunStatus::Failed, result.status);
+        assert!(result
+            .error
+            .unwrap()
+            .contains("certificate chain is not valid"));
+    }
+
+    #[test]
+    fn run_result_json_serialization_is_machine_readable() {
+        let result = RunResult::new(parameters(), &Ok(()), Duration::from_secs(125));
+
+        let json: serde_json::Value = serde_json::to_value(&result).unwrap();
+
+        assert_eq!("succeeded", json["status"]);
+        assert_eq!(
+            3,
+            json["parameters"]["scenario"]["pools"]
+                .as_array()
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            75,
+            json["parameters"]["scenario"]["protocol_parameters"]["k"]
+        );
+        assert_eq!(
+            serde_json::json!(["CardanoTransactions"]),
+            json["parameters"]["scenario"]["signed_entity_types"]
+        );
+        assert!(json.get("error").is_none());
+    }
+}
@@ -0,0 +1,588 @@
+use anyhow::{anyhow, Context};
+use mithril_common::entities::ProtocolParameters;
+use mithril_common::era::SupportedEra;
+use mithril_common::StdResult;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Description of the devnet topology and of the assertions run by the end to end test.
+///
+/// A scenario can be loaded from a TOML or a YAML file, which allows to reproduce exactly
+/// a topology reported by a user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    /// Pools of the devnet, the first one is used by the aggregator, the others by the signers
+    pub pools: Vec<ScenarioPool>,
+
+    /// Protocol parameters used when the aggregator is started
+    #[serde(default = "Scenario::default_protocol_parameters")]
+    pub protocol_parameters: ProtocolParameters,
+
+    /// Protocol parameters set on the aggregator during the run
+    #[serde(default = "Scenario::default_updated_protocol_parameters")]
+    pub updated_protocol_parameters: ProtocolParameters,
+
+    /// Cardano and Mithril eras configuration
+    #[serde(default)]
+    pub eras: ScenarioEras,
+
+    /// Signed entity types parameters (discriminants names)
+    #[serde(default = "Scenario::default_signed_entity_types")]
+    pub signed_entity_types: Vec<String>,
+
+    /// Assertions to run once the certificate chain is long enough
+    #[serde(default = "Scenario::default_assertions")]
+    pub assertions: Vec<ScenarioAssertion>,
+}
+
+/// A pool of the devnet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioPool {
+    /// Weight of the stake delegated to the pool (in ADA)
+    pub stake_weight: u64,
+}
+
+/// Eras configuration of a scenario
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioEras {
+    /// Epoch at which hard fork to the latest Cardano era will be made
+    #[serde(default)]
+    pub cardano_hard_fork_latest_era_at_epoch: u16,
+
+    /// Mithril era to run
+    #[serde(default = "ScenarioEras::default_mithril_era")]
+    pub mithril_era: String,
+
+    /// Epochs at which the Mithril eras are activated by the era markers written on the Cardano
+    /// chain, the eras must be listed in their activation order and end with `mithril_era`.
+    ///
+    /// Optional: if not set, the eras up to `mithril_era` are activated at consecutive epochs
+    /// starting from epoch 0.
+    #[serde(default)]
+    pub mithril_era_transitions: Vec<ScenarioEraTransition>,
+}
+
+/// Activation of a Mithril era at an epoch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioEraTransition {
+    /// Mithril era activated
+    pub era: String,
+
+    /// Epoch at which the era is activated
+    pub epoch: u64,
+}
+
+/// Assertions that can be run by a scenario
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+#[clap(rename_all = "snake_case")]
+pub enum ScenarioAssertion {
+    /// Mithril stake distribution artifacts are produced, signed and verified by the client
+    MithrilStakeDistribution,
+
+    /// Snapshot artifacts are produced, signed and verified by the client
+    CardanoImmutableFilesFull,
+
+    /// Cardano transactions artifacts are produced, signed and verified by the client
+    CardanoTransactions,
+}
+
+impl Scenario {
+    /// Build a scenario with the given number of pools, their stake weight being
+    /// their position in the devnet (this is the topology used when no scenario file is given).
+    pub fn with_pools(number_of_pool_nodes: u8) -> Self {
+        Self {
+            pools: (1..=number_of_pool_nodes as u64)
+                .map(|stake_weight| ScenarioPool { stake_weight })
+                .collect(),
+            protocol_parameters: Self::default_protocol_parameters(),
+            updated_protocol_parameters: Self::default_updated_protocol_parameters(),
+            eras: ScenarioEras::default(),
+            signed_entity_types: Self::default_signed_entity_types(),
+            assertions: Self::default_assertions(),
+        }
+    }
+
+    /// Load a scenario from a file, its format is deduced from the file extension
+    /// (`toml`, `yaml` or `yml`).
+    pub fn from_file(path: &Path) -> StdResult<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Can't read scenario file '{}'", path.display()))?;
+        let scenario: Scenario = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&content)
+                .with_context(|| format!("Invalid TOML scenario file '{}'", path.display()))?,
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&content)
+                .with_context(|| format!("Invalid YAML scenario file '{}'", path.display()))?,
+            _ => {
+                return Err(anyhow!(
+                "Unsupported scenario file extension for '{}', expected 'toml', 'yaml' or 'yml'",
+                path.display()
+            ))
+            }
+        };
+        scenario.validate()?;
+
+        Ok(scenario)
+    }
+
+    /// Check that the scenario can be run on a devnet
+    pub fn validate(&self) -> StdResult<()> {
+        if self.pools.len() < 2 {
+            return Err(anyhow!(
+                "At least two pools are required (one for the aggregator, one for at least one \
+                signer), number given: {}",
+                self.pools.len()
+            ));
+        }
+        if self.pools.len() > u8::MAX as usize {
+            return Err(anyhow!(
+                "At most {} pools are supported, number given: {}",
+                u8::MAX,
+                self.pools.len()
+            ));
+        }
+        if let Some(index) = self.pools.iter().position(|p| p.stake_weight == 0) {
+            return Err(anyhow!(
+                "Pool #{} must have a strictly positive stake weight",
+                index + 1
+            ));
+        }
+        for protocol_parameters in [&self.protocol_parameters, &self.updated_protocol_parameters] {
+            if protocol_parameters.k == 0 || protocol_parameters.k > protocol_parameters.m {
+                return Err(anyhow!(
+                    "Protocol parameter k must be strictly positive and lower than or equal to m, \
+                    given: {protocol_parameters:?}"
+                ));
+            }
+            if !(protocol_parameters.phi_f > 0.0 && protocol_parameters.phi_f <= 1.0) {
+                return Err(anyhow!(
+                    "Protocol parameter phi_f must be in ]0, 1], given: {protocol_parameters:?}"
+                ));
+            }
+        }
+        self.eras.validate()?;
+
+        Ok(())
+    }
+
+    /// Number of pool nodes of the devnet
+    pub fn number_of_pool_nodes(&self) -> u8 {
+        self.pools.len() as u8
+    }
+
+    /// Stake weights of the pools, in the devnet pools order
+    pub fn pool_stake_weights(&self) -> Vec<u64> {
+        self.pools.iter().map(|p| p.stake_weight).collect()
+    }
+
+    /// Check if the given assertion must be run
+    pub fn has_assertion(&self, assertion: ScenarioAssertion) -> bool {
+        self.assertions.contains(&assertion)
+    }
+
+    fn default_protocol_parameters() -> ProtocolParameters {
+        ProtocolParameters {
+            k: 75,
+            m: 105,
+            phi_f: 0.95,
+        }
+    }
+
+    fn default_updated_protocol_parameters() -> ProtocolParameters {
+        ProtocolParameters {
+            k: 150,
+            m: 210,
+            phi_f: 0.80,
+        }
+    }
+
+    fn default_signed_entity_types() -> Vec<String> {
+        vec!["CardanoTransactions".to_string()]
+    }
+
+    fn default_assertions() -> Vec<ScenarioAssertion> {
+        vec![
+            ScenarioAssertion::MithrilStakeDistribution,
+            ScenarioAssertion::CardanoImmutableFilesFull,
+            ScenarioAssertion::CardanoTransactions,
+        ]
+    }
+}
+
+impl ScenarioEras {
+    fn default_mithril_era() -> String {
+        "thales".to_string()
+    }
+
+    /// Epochs at which the Mithril eras are activated, in their activation order
+    pub fn mithril_era_schedule(&self) -> Vec<ScenarioEraTransition> {
+        if !self.mithril_era_transitions.is_empty() {
+            return self.mithril_era_transitions.clone();
+        }
+
+        let eras = SupportedEra::eras();
+        let last_era_index = eras
+            .iter()
+            .position(|era| era.to_string() == self.mithril_era)
+            .unwrap_or_default();
+        eras[..=last_era_index]
+            .iter()
+            .zip(0..)
+            .map(|(era, epoch)| ScenarioEraTransition {
+                era: era.to_string(),
+                epoch,
+            })
+            .collect()
+    }
+
+    fn validate(&self) -> StdResult<()> {
+        SupportedEra::from_str(&self.mithril_era)
+            .with_context(|| format!("Unsupported Mithril era '{}'", self.mithril_era))?;
+        if self.mithril_era_transitions.is_empty() {
+            return Ok(());
+        }
+
+        let supported_eras: Vec<String> = SupportedEra::eras()
+            .iter()
+            .map(|era| era.to_string())
+            .collect();
+        let transitions_eras: Vec<String> = self
+            .mithril_era_transitions
+            .iter()
+            .map(|transition| transition.era.clone())
+            .collect();
+        if !supported_eras.starts_with(&transitions_eras) {
+            return Err(anyhow!(
+                "Mithril era transitions must list the supported eras in their activation order \
+                ({supported_eras:?}), given: {transitions_eras:?}"
+            ));
+        }
+        if transitions_eras.last() != Some(&self.mithril_era) {
+            return Err(anyhow!(
+                "The last Mithril era transition must activate the Mithril era to run '{}', \
+                given: {transitions_eras:?}",
+                self.mithril_era
+            ));
+        }
+        if self
+            .mithril_era_transitions
+            .windows(2)
+            .any(|transitions| transitions[0].epoch >= transitions[1].epoch)
+        {
+            return Err(anyhow!(
+                "Mithril era transitions epochs must be strictly increasing, given: {:?}",
+                self.mithril_era_transitions
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ScenarioEraTransition {
+    type Err = anyhow::Error;
+
+    /// Parse a transition written as `<era>:<epoch>`, ie: `thales:0`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (era, epoch) = s.split_once(':').ok_or_else(|| {
+            anyhow!("Mithril era transition must be written as '<era>:<epoch>', given: '{s}'")
+        })?;
+        let epoch = epoch.trim().parse().with_context(|| {
+            format!("Mithril era transition epoch must be a number, given: '{s}'")
+        })?;
+
+        Ok(Self {
+            era: era.trim().to_string(),
+            epoch,
+        })
+    }
+}
+
+impl Default for ScenarioEras {
+    fn default() -> Self {
+        Self {
+            cardano_hard_fork_latest_era_at_epoch: 0,
+            mithril_era: Self::default_mithril_era(),
+            mithril_era_transitions: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// Write the scenario file in a directory of its own, cleaned before each test run
+    fn write_scenario_file(file_name: &str, content: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join("mithril_end_to_end_test")
+            .join("scenario")
+            .join(Path::new(file_name).file_stem().unwrap());
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(file_name);
+        fs::write(&path, content).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn with_pools_yield_the_default_topology() {
+        let scenario = Scenario::with_pools(3);
+
+        assert_eq!(vec![1, 2, 3], scenario.pool_stake_weights());
+        assert_eq!(
+            ScenarioEras {
+                cardano_hard_fork_latest_era_at_epoch: 0,
+                mithril_era: "thales".to_string(),
+                mithril_era_transitions: vec![],
+            },
+            scenario.eras
+        );
+        assert!(scenario.has_assertion(ScenarioAssertion::CardanoTransactions));
+    }
+
+    #[test]
+    fn load_yaml_scenario_file() {
+        let path = write_scenario_file(
+            "load_yaml_scenario_file.yaml",
+            r#"
+pools:
+  - stake_weight: 10
+  - stake_weight: 1
+  - stake_weight: 1
+protocol_parameters:
+  k: 5
+  m: 100
+  phi_f: 0.65
+eras:
+  cardano_hard_fork_latest_era_at_epoch: 2
+assertions:
+  - mithril_stake_distribution
+"#,
+        );
+
+        let scenario = Scenario::from_file(&path).unwrap();
+
+        assert_eq!(vec![10, 1, 1], scenario.pool_stake_weights());
+        assert_eq!(
+            ProtocolParameters::new(5, 100, 0.65),
+            scenario.protocol_parameters
+        );
+        assert_eq!(
+            Scenario::default_updated_protocol_parameters(),
+            scenario.updated_protocol_parameters
+        );
+        assert_eq!(2, scenario.eras.cardano_hard_fork_latest_era_at_epoch);
+        assert_eq!("thales", scenario.eras.mithril_era);
+        assert_eq!(
+            vec![ScenarioAssertion::MithrilStakeDistribution],
+            scenario.assertions
+        );
+    }
+
+    #[test]
+    fn load_toml_scenario_file() {
+        let path = write_scenario_file(
+            "load_toml_scenario_file.toml",
+            r#"
+signed_entity_types = []
+assertions = ["cardano_immutable_files_full"]
+
+[[pools]]
+stake_weight = 1
+
+[[pools]]
+stake_weight = 7
+
+[eras]
+mithril_era = "pythagoras"
+"#,
+        );
+
+        let scenario = Scenario::from_file(&path).unwrap();
+
+        assert_eq!(vec![1, 7], scenario.pool_stake_weights());
+        assert_eq!("pythagoras", scenario.eras.mithril_era);
+        assert!(scenario.signed_entity_types.is_empty());
+        assert_eq!(
+            vec![ScenarioAssertion::CardanoImmutableFilesFull],
+            scenario.assertions
+        );
+    }
+
+    #[test]
+    fn load_scenario_file_with_mithril_era_transitions() {
+        let path = write_scenario_file(
+            "load_scenario_file_with_mithril_era_transitions.yaml",
+            r#"
+pools:
+  - stake_weight: 1
+  - stake_weight: 1
+eras:
+  mithril_era: pythagoras
+  mithril_era_transitions:
+    - era: thales
+      epoch: 0
+    - era: pythagoras
+      epoch: 4
+"#,
+        );
+
+        let scenario = Scenario::from_file(&path).unwrap();
+
+        assert_eq!(
+            vec![
+                ScenarioEraTransition {
+                    era: "thales".to_string(),
+                    epoch: 0
+                },
+                ScenarioEraTransition {
+                    era: "pythagoras".to_string(),
+                    epoch: 4
+                },
+            ],
+            scenario.eras.mithril_era_schedule()
+        );
+    }
+
+    #[test]
+    fn default_mithril_era_schedule_activates_the_eras_up_to_the_mithril_era() {
+        let eras = |mithril_era: &str| ScenarioEras {
+            mithril_era: mithril_era.to_string(),
+            ..ScenarioEras::default()
+        };
+
+        assert_eq!(
+            vec![ScenarioEraTransition {
+                era: "thales".to_string(),
+                epoch: 0
+            }],
+            eras("thales").mithril_era_schedule()
+        );
+        assert_eq!(
+            vec![
+                ScenarioEraTransition {
+                    era: "thales".to_string(),
+                    epoch: 0
+                },
+                ScenarioEraTransition {
+                    era: "pythagoras".to_string(),
+                    epoch: 1
+                },
+            ],
+            eras("pythagoras").mithril_era_schedule()
+        );
+    }
+
+    #[test]
+    fn validate_fails_with_invalid_mithril_era_transitions() {
+        let transition = |era: &str, epoch: u64| ScenarioEraTransition {
+            era: era.to_string(),
+            epoch,
+        };
+        for (mithril_era, mithril_era_transitions) in [
+            ("unknown", vec![]),
+            ("pythagoras", vec![transition("pythagoras", 2)]),
+            (
+                "thales",
+                vec![transition("thales", 0), transition("pythagoras", 2)],
+            ),
+            (
+                "pythagoras",
+                vec![transition("thales", 3), transition("pythagoras", 3)],
+            ),
+        ] {
+            let scenario = Scenario {
+                eras: ScenarioEras {
+                    mithril_era: mithril_era.to_string(),
+                    mithril_era_transitions: mithril_era_transitions.clone(),
+                    ..ScenarioEras::default()
+                },
+                ..Scenario::with_pools(3)
+            };
+
+            scenario.validate().expect_err(&format!(
+                "Validating a scenario with Mithril era '{mithril_era}' and transitions \
+                {mithril_era_transitions:?} should fail"
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_mithril_era_transition() {
+        assert_eq!(
+            ScenarioEraTransition {
+                era: "pythagoras".to_string(),
+                epoch: 12,
+            },
+            "pythagoras:12".parse::<ScenarioEraTransition>().unwrap()
+        );
+        "pythagoras"
+            .parse::<ScenarioEraTransition>()
+            .expect_err("Parsing a Mithril era transition without an epoch should fail");
+        "pythagoras:twelve"
+            .parse::<ScenarioEraTransition>()
+            .expect_err("Parsing a Mithril era transition with an invalid epoch should fail");
+    }
+
+    #[test]
+    fn load_scenario_file_with_unsupported_extension_fails() {
+        let path = write_scenario_file(
+            "load_scenario_file_with_unsupported_extension_fails.json",
+            "{}",
+        );
+
+        Scenario::from_file(&path).expect_err("Loading a json scenario file should fail");
+    }
+
+    #[test]
+    fn validate_fails_with_less_than_two_pools() {
+        let mut scenario = Scenario::with_pools(2);
+        scenario.validate().unwrap();
+
+        scenario.pools.pop();
+        scenario
+            .validate()
+            .expect_err("Validating a scenario with only one pool should fail");
+    }
+
+    #[test]
+    fn validate_fails_with_a_pool_without_stake() {
+        let mut scenario = Scenario::with_pools(3);
+        scenario.pools[1].stake_weight = 0;
+
+        scenario
+            .validate()
+            .expect_err("Validating a scenario with a pool without stake should fail");
+    }
+
+    #[test]
+    fn validate_fails_with_invalid_protocol_parameters() {
+        for protocol_parameters in [
+            ProtocolParameters::new(0, 100, 0.65),
+            ProtocolParameters::new(101, 100, 0.65),
+            ProtocolParameters::new(5, 100, 0.0),
+            ProtocolParameters::new(5, 100, 1.2),
+        ] {
+            let scenario = Scenario {
+                protocol_parameters: protocol_parameters.clone(),
+                ..Scenario::with_pools(3)
+            };
+
+            scenario.validate().expect_err(&format!(
+                "Validating a scenario with protocol parameters {protocol_parameters:?} should fail"
+            ));
+        }
+    }
+}
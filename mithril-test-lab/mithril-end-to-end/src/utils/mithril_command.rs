@@ -1,9 +1,12 @@
 use crate::utils::file_utils;
 use anyhow::{anyhow, Context};
 use mithril_common::StdResult;
-use slog_scope::info;
+use slog_scope::{info, warn};
 use std::collections::HashMap;
+use std::fs::File;
 use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
 use tokio::process::{Child, Command};
 
 #[derive(Debug, Clone)]
@@ -15,6 +18,7 @@ pub struct MithrilCommand {
     work_dir: PathBuf,
     env_vars: HashMap<String, String>,
     default_args: Vec<String>,
+    live_tail: bool,
 }
 
 impl MithrilCommand {
@@ -63,6 +67,7 @@ impl MithrilCommand {
             work_dir: work_dir.to_path_buf(),
             env_vars,
             default_args,
+            live_tail: false,
         })
     }
 
@@ -90,6 +95,23 @@ impl MithrilCommand {
         self.env_vars.insert(name.to_string(), value.to_string());
     }
 
+    /// Enable or disable the live tail of the logs of the command.
+    ///
+    /// When enabled, the stderr (and stdout if an output_filename is not set) of the command is
+    /// streamed to the terminal while the command is running, each line being prefixed with the
+    /// name of the log file and colored according to its level. The logs are still written to
+    /// the log file.
+    pub fn set_live_tail(&mut self, enabled: bool) {
+        self.live_tail = enabled;
+    }
+
+    fn live_tail_prefix(&self) -> String {
+        self.log_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.name.clone())
+    }
+
     pub fn start(&mut self, args: &[String]) -> StdResult<Child> {
         let args = [&self.default_args, args].concat();
 
@@ -120,20 +142,42 @@ impl MithrilCommand {
                 })?,
         };
 
+        let live_tail_stdout = self.live_tail && self.output_path.is_none();
         let mut command = Command::new(&self.process_path);
         command
             .current_dir(&self.work_dir)
-            .stdout(log_file_stdout)
-            .stderr(log_file_stderr)
             .envs(&self.env_vars)
             .args(&args)
             .kill_on_drop(true);
+        if live_tail_stdout {
+            command.stdout(Stdio::piped());
+        } else {
+            command.stdout(log_file_stdout.try_clone()?);
+        }
+        if self.live_tail {
+            command.stderr(Stdio::piped());
+        } else {
+            command.stderr(log_file_stderr.try_clone()?);
+        }
 
         info!("Starting {}", self.name; "work_dir" => &self.work_dir.display(), "env" => #?&self.env_vars, "args" => #?&args);
 
-        command
+        let mut child = command
             .spawn()
-            .with_context(|| format!("{} failed to start", self.name))
+            .with_context(|| format!("{} failed to start", self.name))?;
+
+        if live_tail_stdout {
+            if let Some(stdout) = child.stdout.take() {
+                tokio::spawn(live_tail(self.live_tail_prefix(), stdout, log_file_stdout));
+            }
+        }
+        if self.live_tail {
+            if let Some(stderr) = child.stderr.take() {
+                tokio::spawn(live_tail(self.live_tail_prefix(), stderr, log_file_stderr));
+            }
+        }
+
+        Ok(child)
     }
 
     /// Tail the command log
@@ -172,3 +216,163 @@ impl MithrilCommand {
         Ok(())
     }
 }
+
+/// Forward each line read from a child output to the terminal, prefixed with `[prefix]` and
+/// colored according to its log level, and to the given log file.
+///
+/// The output is drained until its end so the child never blocks on a full pipe: lines that are
+/// not valid UTF-8 are printed lossily and written unchanged to the log file.
+async fn live_tail<R: AsyncRead + Unpin>(prefix: String, output: R, log_file: File) {
+    let mut log_file = tokio::fs::File::from_std(log_file);
+    let mut reader = BufReader::new(output);
+    let mut line = Vec::new();
+
+    loop {
+        line.clear();
+        match reader.read_until(b'\n', &mut line).await {
+            Ok(0) => break,
+            Ok(_) => {
+                let printable_line = String::from_utf8_lossy(&line);
+                let printable_line = printable_line.trim_end_matches(['\r', '\n']);
+                println!(
+                    "{}",
+                    colorize(
+                        &format!("[{prefix}] {printable_line}"),
+                        LogLevel::parse(printable_line)
+                    )
+                );
+                if !line.ends_with(b"\n") {
+                    line.push(b'\n');
+                }
+                if let Err(error) = log_file.write_all(&line).await {
+                    warn!("Failed to write {prefix} logs to file"; "error" => ?error);
+                }
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(error) => {
+                warn!("Failed to read {prefix} logs"; "error" => ?error);
+                break;
+            }
+        }
+    }
+    let _ = log_file.flush().await;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogLevel {
+    Error,
+    Warning,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    /// Detect the level of a log line, either a bunyan json line or a terminal formatted line.
+    fn parse(line: &str) -> Option<Self> {
+        if let Some(index) = line.find("\"level\":") {
+            let level = line[index + 8..]
+                .trim_start()
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<String>();
+            return match level.parse::<u8>().ok()? {
+                50.. => Some(Self::Error),
+                40..=49 => Some(Self::Warning),
+                30..=39 => Some(Self::Info),
+                _ => Some(Self::Debug),
+            };
+        }
+
+        [
+            (" CRIT ", Self::Error),
+            (" ERRO ", Self::Error),
+            (" WARN ", Self::Warning),
+            (" INFO ", Self::Info),
+            (" DEBG ", Self::Debug),
+            (" TRCE ", Self::Debug),
+        ]
+        .into_iter()
+        .find(|(tag, _)| line.contains(tag))
+        .map(|(_, level)| level)
+    }
+}
+
+fn colorize(line: &str, level: Option<LogLevel>) -> String {
+    let color_code = match level {
+        Some(LogLevel::Error) => "31",
+        Some(LogLevel::Warning) => "33",
+        Some(LogLevel::Debug) => "2",
+        Some(LogLevel::Info) | None => return line.to_string(),
+    };
+
+    format!("\x1b[{color_code}m{line}\x1b[0m")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bunyan_log_level() {
+        assert_eq!(
+            Some(LogLevel::Error),
+            LogLevel::parse(r#"{"msg":"oops","level":50,"time":"2024-01-01"}"#)
+        );
+        assert_eq!(
+            Some(LogLevel::Warning),
+            LogLevel::parse(r#"{"msg":"careful","level": 40}"#)
+        );
+        assert_eq!(
+            Some(LogLevel::Info),
+            LogLevel::parse(r#"{"msg":"hello","level":30}"#)
+        );
+        assert_eq!(
+            Some(LogLevel::Debug),
+            LogLevel::parse(r#"{"msg":"details","level":20}"#)
+        );
+    }
+
+    #[test]
+    fn parse_terminal_log_level() {
+        assert_eq!(
+            Some(LogLevel::Error),
+            LogLevel::parse("Jan 01 00:00:00.000 ERRO something failed")
+        );
+        assert_eq!(
+            Some(LogLevel::Warning),
+            LogLevel::parse("Jan 01 00:00:00.000 WARN something odd")
+        );
+        assert_eq!(
+            Some(LogLevel::Debug),
+            LogLevel::parse("Jan 01 00:00:00.000 TRCE details")
+        );
+        assert_eq!(None, LogLevel::parse("a line without level"));
+    }
+
+    #[tokio::test]
+    async fn live_tail_write_all_lines_to_the_log_file_even_if_not_valid_utf8() {
+        let log_path = std::env::temp_dir()
+            .join("mithril_end_to_end")
+            .join("live_tail_write_all_lines_to_the_log_file_even_if_not_valid_utf8.log");
+        std::fs::create_dir_all(log_path.parent().unwrap()).unwrap();
+        let log_file = File::create(&log_path).unwrap();
+        let output: &[u8] = b"first line\ninvalid \xff line\nlast line without end of line";
+
+        live_tail("test".to_string(), output, log_file).await;
+
+        assert_eq!(
+            b"first line\ninvalid \xff line\nlast line without end of line\n".to_vec(),
+            std::fs::read(&log_path).unwrap()
+        );
+    }
+
+    #[test]
+    fn colorize_only_non_info_lines() {
+        assert_eq!("line", colorize("line", Some(LogLevel::Info)));
+        assert_eq!("line", colorize("line", None));
+        assert_eq!(
+            "\x1b[31mline\x1b[0m",
+            colorize("line", Some(LogLevel::Error))
+        );
+    }
+}